@@ -0,0 +1,56 @@
+#![deny(warnings)]
+#![no_std]
+
+use soroban_sdk::{contractclient, contractspecfn, Address, Bytes, Env};
+pub struct Spec;
+
+/// Interface for contracts receiving SoroswapPair flash swaps
+#[contractspecfn(name = "Spec", export = false)]
+#[contractclient(name = "SoroswapCalleeClient")]
+
+/// Trait that the `to` address of a flash swap must implement.
+pub trait SoroswapCalleeTrait {
+
+    /// Called by a SoroswapPair during `swap` when a non-empty `data` is given, after the requested
+    /// amounts have been transferred to the callee and before the constant product is checked.
+    /// Before returning, the callee must have sent back to the pair enough tokens to pay for the
+    /// amounts out plus the swap fee.
+    ///
+    /// Anybody can call this function, so the callee should only act on flash swaps it started.
+    /// Soroban does not allow contract re-entry, so the callee cannot call the pair from here:
+    /// it must already know the pair tokens and fee.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `sender` - The address of the SoroswapPair executing the swap.
+    /// * `amount_0` - The amount of token 0 transferred to the callee.
+    /// * `amount_1` - The amount of token 1 transferred to the callee.
+    /// * `data` - The payload given to `swap`.
+    fn soroswap_call(e: Env, sender: Address, amount_0: i128, amount_1: i128, data: Bytes);
+}
+
+/// Interface for contracts receiving SoroswapPair flash loans
+#[contractspecfn(name = "Spec", export = false)]
+#[contractclient(name = "SoroswapFlashLoanReceiverClient")]
+
+/// Trait that the `receiver` of a flash loan must implement.
+pub trait SoroswapFlashLoanReceiverTrait {
+
+    /// Called by a SoroswapPair during `flash_loan`, after the requested amounts have been transferred
+    /// to the receiver. Before returning, the receiver must have sent back to the pair the borrowed
+    /// amounts plus `fee_0` and `fee_1`.
+    ///
+    /// As in `soroswap_call`, the receiver should only act on flash loans it started and cannot call the pair from here.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `sender` - The address of the SoroswapPair executing the flash loan.
+    /// * `amount_0` - The amount of token 0 lent to the receiver.
+    /// * `amount_1` - The amount of token 1 lent to the receiver.
+    /// * `fee_0` - The fee to pay in token 0.
+    /// * `fee_1` - The fee to pay in token 1.
+    /// * `data` - The payload given to `flash_loan`.
+    fn soroswap_flash_loan(e: Env, sender: Address, amount_0: i128, amount_1: i128, fee_0: i128, fee_1: i128, data: Bytes);
+}
@@ -0,0 +1,66 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapConcentratedPoolError {
+    /// SoroswapConcentratedPool: already initialized
+    InitializeAlreadyInitialized = 1101,
+    /// SoroswapConcentratedPool: not yet initialized
+    NotInitialized = 1102,
+
+    /// SoroswapConcentratedPool: token_0 must be less than token_1 while initializing
+    InitializeTokenOrderInvalid = 1103,
+    /// SoroswapConcentratedPool: fee must be less than 10000 basis points while initializing
+    InitializeFeeInvalid = 1104,
+    /// SoroswapConcentratedPool: tick spacing must be positive and not above MAX_TICK while initializing
+    InitializeTickSpacingInvalid = 1105,
+
+    /// SoroswapConcentratedPool: the price has already been initialized
+    PriceAlreadyInitialized = 1106,
+    /// SoroswapConcentratedPool: the price has not been initialized yet
+    PriceNotInitialized = 1107,
+    /// SoroswapConcentratedPool: the square root price is out of the range of the ticks
+    PriceInvalid = 1108,
+
+    /// SoroswapConcentratedPool: the ticks of a position must be ordered, within range and multiples of the tick spacing
+    PositionTicksInvalid = 1109,
+    /// SoroswapConcentratedPool: the liquidity of a position can not change by zero
+    PositionLiquidityZero = 1110,
+    /// SoroswapConcentratedPool: the position does not exist
+    PositionDoesNotExist = 1111,
+    /// SoroswapConcentratedPool: the position has less liquidity than the one removed
+    PositionInsufficientLiquidity = 1112,
+    /// SoroswapConcentratedPool: the amount of token 0 required is above the maximum
+    PositionExcessiveAmount0 = 1113,
+    /// SoroswapConcentratedPool: the amount of token 1 required is above the maximum
+    PositionExcessiveAmount1 = 1114,
+    /// SoroswapConcentratedPool: the amount of token 0 returned is below the minimum
+    PositionInsufficientAmount0 = 1115,
+    /// SoroswapConcentratedPool: the amount of token 1 returned is below the minimum
+    PositionInsufficientAmount1 = 1116,
+
+    /// SoroswapConcentratedPool: insufficient output amount while doing swap
+    SwapInsufficientOutputAmount = 1117,
+    /// SoroswapConcentratedPool: negatives amounts out dont supported while doing swap
+    SwapNegativesOutNotSupported = 1118,
+    /// SoroswapConcentratedPool: only one of the tokens can be bought while doing swap
+    SwapBothTokensOut = 1119,
+    /// SoroswapConcentratedPool: insufficient liquidity within the range of the ticks to do the swap
+    SwapInsufficientLiquidity = 1120,
+    /// SoroswapConcentratedPool: invalid to to do the swap
+    SwapInvalidTo = 1121,
+    /// SoroswapConcentratedPool: insufficient input amount while doing swap
+    SwapInsufficientInputAmount = 1122,
+    /// SoroswapConcentratedPool: the input amount does not buy the output amount while doing swap
+    SwapInvariantNotMet = 1123,
+
+    /// SoroswapConcentratedPool: an amount or a price does not fit in 128 bits
+    MathOverflow = 1124,
+
+    /// SoroswapConcentratedPool: locked, nested calls are not allowed
+    Locked = 1125,
+
+    /// SoroswapConcentratedPool: storage version is newer than the contract version while doing migrate
+    MigrateVersionInvalid = 1126,
+}
@@ -0,0 +1,134 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address};
+
+// INITIALIZE PRICE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializePriceEvent {
+    pub sqrt_price: u128,
+    pub tick: i32,
+}
+
+pub(crate) fn initialize_price(e: &Env, sqrt_price: u128, tick: i32) {
+    let event = InitializePriceEvent { sqrt_price, tick };
+    e.events().publish(("SoroswapConcentratedPool", symbol_short!("init")), event);
+}
+
+// MINT EVENT, when liquidity is added to a new or an existing position
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintEvent {
+    pub owner: Address,
+    pub position_id: u32,
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    pub amount_0: i128,
+    pub amount_1: i128,
+}
+
+pub(crate) fn mint(e: &Env, event: MintEvent) {
+    e.events().publish(("SoroswapConcentratedPool", symbol_short!("mint")), event);
+}
+
+// BURN EVENT, when liquidity is removed from a position
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnEvent {
+    pub owner: Address,
+    pub position_id: u32,
+    pub liquidity: u128,
+    pub amount_0: i128,
+    pub amount_1: i128,
+}
+
+pub(crate) fn burn(
+    e: &Env,
+    owner: Address,
+    position_id: u32,
+    liquidity: u128,
+    amount_0: i128,
+    amount_1: i128,
+) {
+    let event = BurnEvent {
+        owner,
+        position_id,
+        liquidity,
+        amount_0,
+        amount_1,
+    };
+    e.events().publish(("SoroswapConcentratedPool", symbol_short!("burn")), event);
+}
+
+// COLLECT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectEvent {
+    pub owner: Address,
+    pub position_id: u32,
+    pub to: Address,
+    pub amount_0: i128,
+    pub amount_1: i128,
+}
+
+pub(crate) fn collect(
+    e: &Env,
+    owner: Address,
+    position_id: u32,
+    to: Address,
+    amount_0: i128,
+    amount_1: i128,
+) {
+    let event = CollectEvent {
+        owner,
+        position_id,
+        to,
+        amount_0,
+        amount_1,
+    };
+    e.events().publish(("SoroswapConcentratedPool", symbol_short!("collect")), event);
+}
+
+// SWAP EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapEvent {
+    pub to: Address,
+    pub amount_0_in: i128,
+    pub amount_1_in: i128,
+    pub amount_0_out: i128,
+    pub amount_1_out: i128,
+    pub sqrt_price: u128,
+    pub tick: i32,
+    pub liquidity: u128,
+}
+
+pub(crate) fn swap(e: &Env, event: SwapEvent) {
+    e.events().publish(("SoroswapConcentratedPool", symbol_short!("swap")), event);
+}
+
+// SKIM EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SkimEvent {
+    pub skimmed_0: i128,
+    pub skimmed_1: i128,
+}
+
+pub(crate) fn skim(e: &Env, skimmed_0: i128, skimmed_1: i128) {
+    let event = SkimEvent { skimmed_0, skimmed_1 };
+    e.events().publish(("SoroswapConcentratedPool", symbol_short!("skim")), event);
+}
+
+// MIGRATE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateEvent {
+    pub old_version: u32,
+    pub new_version: u32,
+}
+
+pub(crate) fn migrate(e: &Env, old_version: u32, new_version: u32) {
+    let event = MigrateEvent { old_version, new_version };
+    e.events().publish(("SoroswapConcentratedPool", symbol_short!("migrate")), event);
+}
@@ -0,0 +1,784 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Bytes, BytesN, Env};
+use soroban_sdk::token::TokenClient;
+use soroswap_callee_interface::SoroswapCalleeClient;
+
+mod storage;
+mod event;
+mod error;
+mod math;
+mod tick;
+mod swap;
+mod migration;
+mod test;
+
+use storage::*;
+use error::SoroswapConcentratedPoolError;
+use event::{MintEvent, SwapEvent};
+use math::{
+    add_delta, get_amount_0_delta, get_amount_1_delta, get_sqrt_price_at_tick, get_tick_at_sqrt_price, mul_div,
+    FEE_DENOMINATOR, MAX_SQRT_PRICE, MAX_TICK, MIN_SQRT_PRICE, MIN_TICK, Q64,
+};
+use tick::{clear_tick, get_fee_growth_inside, update_tick};
+use swap::compute_swap;
+
+pub use storage::{Position, TickInfo};
+
+// Metadata that is added on to the WASM custom section
+contractmeta!(
+    key = "Description",
+    val = "Concentrated liquidity AMM where liquidity providers choose the price range of their positions"
+);
+
+pub trait SoroswapConcentratedPoolTrait {
+    // Sets the token contract addresses, the swap fee (in basis points) and the tick spacing of the positions for this pool
+    fn initialize_pool(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32, tick_spacing: i32) -> Result<(), SoroswapConcentratedPoolError>;
+
+    // Sets the starting price of the pool, before any position is opened. Anyone can call it once.
+    fn initialize_price(e: Env, sqrt_price: u128) -> Result<(), SoroswapConcentratedPoolError>;
+
+    // Opens a new position of `owner`, paid by `owner`
+    fn mint(e: Env, owner: Address, tick_lower: i32, tick_upper: i32, liquidity: u128, amount_0_max: i128, amount_1_max: i128) -> Result<(u32, i128, i128), SoroswapConcentratedPoolError>;
+
+    fn increase_liquidity(e: Env, owner: Address, position_id: u32, liquidity: u128, amount_0_max: i128, amount_1_max: i128) -> Result<(i128, i128), SoroswapConcentratedPoolError>;
+
+    // Removes liquidity from a position. The tokens are owed to the position until they are collected
+    fn decrease_liquidity(e: Env, owner: Address, position_id: u32, liquidity: u128, amount_0_min: i128, amount_1_min: i128) -> Result<(i128, i128), SoroswapConcentratedPoolError>;
+
+    // Sends the fees and the removed liquidity owed to a position to `to`
+    fn collect(e: Env, owner: Address, position_id: u32, to: Address) -> Result<(i128, i128), SoroswapConcentratedPoolError>;
+
+    // Swaps. This function should be called from another contract that has already sent tokens to the pool contract,
+    // unless a non-empty data is given: then `to` is called back to pay for the tokens (flash swap)
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapConcentratedPoolError>;
+
+    // Amount received for an input amount, or required for an output amount, at the current state of the pool
+    fn quote_exact_input(e: Env, zero_for_one: bool, amount_in: i128) -> Result<i128, SoroswapConcentratedPoolError>;
+    fn quote_exact_output(e: Env, zero_for_one: bool, amount_out: i128) -> Result<i128, SoroswapConcentratedPoolError>;
+
+    // Transfers the excess token balances from the pool to the specified to address
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapConcentratedPoolError>;
+
+    fn token_0(e: Env) -> Address;
+    fn token_1(e: Env) -> Address;
+    fn factory(e: Env) -> Address;
+    fn fee(e: Env) -> u32;
+    fn tick_spacing(e: Env) -> i32;
+
+    fn sqrt_price(e: Env) -> Result<u128, SoroswapConcentratedPoolError>;
+    fn current_tick(e: Env) -> Result<i32, SoroswapConcentratedPoolError>;
+    fn liquidity(e: Env) -> u128;
+    fn fee_growth_global(e: Env) -> (u128, u128);
+    fn get_reserves(e: Env) -> (i128, i128);
+
+    fn get_tick(e: Env, tick: i32) -> TickInfo;
+    fn tick_bitmap(e: Env, word_pos: i32) -> u128;
+    fn get_position(e: Env, owner: Address, position_id: u32) -> Result<Position, SoroswapConcentratedPoolError>;
+    fn position_count(e: Env, owner: Address) -> u32;
+
+    // Extends the TTL of the pool instance and code, so the pool does not get archived. Anyone can call it.
+    fn extend_pool_ttl(e: Env) -> Result<(), SoroswapConcentratedPoolError>;
+
+    // Replaces the code of the pool. Only the factory can call it, and it must call `migrate` afterwards.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapConcentratedPoolError>;
+
+    // Runs the pending storage migrations after an upgrade. Only the factory can call it.
+    fn migrate(e: Env) -> Result<u32, SoroswapConcentratedPoolError>;
+
+    // Returns the version of the storage layout of the pool.
+    fn version(e: Env) -> u32;
+}
+
+#[contract]
+struct SoroswapConcentratedPool;
+
+#[contractimpl]
+impl SoroswapConcentratedPoolTrait for SoroswapConcentratedPool {
+
+    /// Initializes a new Soroswap concentrated liquidity pool by setting token addresses, factory, swap fee and tick spacing.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `factory` - The address of the Soroswap factory contract.
+    /// * `token_0` - The address of the first token in the pool.
+    /// * `token_1` - The address of the second token in the pool.
+    /// * `fee` - The swap fee of the pool, in basis points.
+    /// * `tick_spacing` - The ticks of the positions must be multiples of this spacing.
+    fn initialize_pool(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32, tick_spacing: i32) -> Result<(), SoroswapConcentratedPoolError> {
+        if has_token_0(&e) {
+            return Err(SoroswapConcentratedPoolError::InitializeAlreadyInitialized);
+        }
+
+        if token_0 >= token_1 {
+            return Err(SoroswapConcentratedPoolError::InitializeTokenOrderInvalid);
+        }
+
+        if u128::from(fee) >= FEE_DENOMINATOR {
+            return Err(SoroswapConcentratedPoolError::InitializeFeeInvalid);
+        }
+
+        if tick_spacing <= 0 || tick_spacing > MAX_TICK {
+            return Err(SoroswapConcentratedPoolError::InitializeTickSpacingInvalid);
+        }
+
+        put_factory(&e, factory);
+        put_token_0(&e, token_0);
+        put_token_1(&e, token_1);
+        put_fee(&e, fee);
+        put_tick_spacing(&e, tick_spacing);
+        put_reserve_0(&e, 0);
+        put_reserve_1(&e, 0);
+        put_version(&e, migration::CONTRACT_VERSION);
+        extend_instance_ttl(&e);
+
+        Ok(())
+    }
+
+    /// Sets the starting square root price of the pool. Positions can only be opened once the price is set.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `sqrt_price` - The square root of the price of token 0 in token 1, with 64 fractional bits.
+    ///
+    /// # Errors
+    /// - `SoroswapConcentratedPoolError::NotInitialized`
+    /// - `SoroswapConcentratedPoolError::PriceAlreadyInitialized`
+    /// - `SoroswapConcentratedPoolError::PriceInvalid` if the price is not within the range of the ticks.
+    fn initialize_price(e: Env, sqrt_price: u128) -> Result<(), SoroswapConcentratedPoolError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPoolError::NotInitialized);
+        }
+        if has_sqrt_price(&e) {
+            return Err(SoroswapConcentratedPoolError::PriceAlreadyInitialized);
+        }
+        if !(MIN_SQRT_PRICE..MAX_SQRT_PRICE).contains(&sqrt_price) {
+            return Err(SoroswapConcentratedPoolError::PriceInvalid);
+        }
+        extend_instance_ttl(&e);
+
+        let tick = get_tick_at_sqrt_price(sqrt_price)?;
+        put_sqrt_price(&e, sqrt_price);
+        put_current_tick(&e, tick);
+        put_liquidity(&e, 0);
+
+        event::initialize_price(&e, sqrt_price, tick);
+        Ok(())
+    }
+
+    /// Opens a new position of `owner` between `tick_lower` and `tick_upper`, and transfers from `owner` the amounts
+    /// of both tokens that back `liquidity` at the current price. Positions are identified by their owner and
+    /// an id that increases with every position the owner opens.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `owner` - The owner of the new position, who pays for it.
+    /// * `tick_lower` - The lower tick of the range of the position.
+    /// * `tick_upper` - The upper tick of the range of the position.
+    /// * `liquidity` - The liquidity of the position.
+    /// * `amount_0_max` - The maximum amount of token 0 to pay.
+    /// * `amount_1_max` - The maximum amount of token 1 to pay.
+    ///
+    /// # Returns
+    /// The id of the position and the amounts of token 0 and token 1 paid.
+    fn mint(e: Env, owner: Address, tick_lower: i32, tick_upper: i32, liquidity: u128, amount_0_max: i128, amount_1_max: i128) -> Result<(u32, i128, i128), SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        owner.require_auth();
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        check_ticks(&e, tick_lower, tick_upper)?;
+        let position_id = next_position_id(&e, &owner);
+        let mut position = Position {
+            tick_lower,
+            tick_upper,
+            liquidity: 0,
+            fee_growth_inside_0_last: 0,
+            fee_growth_inside_1_last: 0,
+            tokens_owed_0: 0,
+            tokens_owed_1: 0,
+        };
+
+        let (amount_0, amount_1) = add_liquidity(&e, &owner, &mut position, liquidity, amount_0_max, amount_1_max)?;
+        put_position(&e, &owner, position_id, &position);
+
+        event::mint(&e, MintEvent { owner, position_id, tick_lower, tick_upper, liquidity, amount_0, amount_1 });
+
+        unlock(&e);
+        Ok((position_id, amount_0, amount_1))
+    }
+
+    /// Adds `liquidity` to an existing position of `owner`, and transfers from `owner` the amounts of both tokens
+    /// that back it at the current price. The fees earned by the position so far are kept owed to it.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `owner` - The owner of the position, who pays for the liquidity.
+    /// * `position_id` - The id of the position.
+    /// * `liquidity` - The liquidity to add.
+    /// * `amount_0_max` - The maximum amount of token 0 to pay.
+    /// * `amount_1_max` - The maximum amount of token 1 to pay.
+    ///
+    /// # Returns
+    /// The amounts of token 0 and token 1 paid.
+    fn increase_liquidity(e: Env, owner: Address, position_id: u32, liquidity: u128, amount_0_max: i128, amount_1_max: i128) -> Result<(i128, i128), SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        owner.require_auth();
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let mut position = get_position(&e, &owner, position_id).ok_or(SoroswapConcentratedPoolError::PositionDoesNotExist)?;
+        let (amount_0, amount_1) = add_liquidity(&e, &owner, &mut position, liquidity, amount_0_max, amount_1_max)?;
+        put_position(&e, &owner, position_id, &position);
+
+        event::mint(&e, MintEvent {
+            owner,
+            position_id,
+            tick_lower: position.tick_lower,
+            tick_upper: position.tick_upper,
+            liquidity,
+            amount_0,
+            amount_1,
+        });
+
+        unlock(&e);
+        Ok((amount_0, amount_1))
+    }
+
+    /// Removes `liquidity` from a position of `owner`. The amounts of both tokens that backed it at the current price
+    /// are owed to the position, together with its fees, until they are sent with `collect`.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `owner` - The owner of the position.
+    /// * `position_id` - The id of the position.
+    /// * `liquidity` - The liquidity to remove.
+    /// * `amount_0_min` - The minimum amount of token 0 to receive.
+    /// * `amount_1_min` - The minimum amount of token 1 to receive.
+    ///
+    /// # Returns
+    /// The amounts of token 0 and token 1 removed.
+    fn decrease_liquidity(e: Env, owner: Address, position_id: u32, liquidity: u128, amount_0_min: i128, amount_1_min: i128) -> Result<(i128, i128), SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        owner.require_auth();
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let mut position = get_position(&e, &owner, position_id).ok_or(SoroswapConcentratedPoolError::PositionDoesNotExist)?;
+        if liquidity == 0 {
+            return Err(SoroswapConcentratedPoolError::PositionLiquidityZero);
+        }
+        if liquidity > position.liquidity {
+            return Err(SoroswapConcentratedPoolError::PositionInsufficientLiquidity);
+        }
+        let liquidity_delta = i128::try_from(liquidity).map_err(|_| SoroswapConcentratedPoolError::MathOverflow)?;
+
+        let (amount_0, amount_1) = modify_position(&e, &mut position, -liquidity_delta)?;
+        if amount_0 < amount_0_min {
+            return Err(SoroswapConcentratedPoolError::PositionInsufficientAmount0);
+        }
+        if amount_1 < amount_1_min {
+            return Err(SoroswapConcentratedPoolError::PositionInsufficientAmount1);
+        }
+        position.tokens_owed_0 = position.tokens_owed_0.checked_add(amount_0).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+        position.tokens_owed_1 = position.tokens_owed_1.checked_add(amount_1).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+        put_position(&e, &owner, position_id, &position);
+
+        event::burn(&e, owner, position_id, liquidity, amount_0, amount_1);
+
+        unlock(&e);
+        Ok((amount_0, amount_1))
+    }
+
+    /// Sends to `to` the fees earned by a position of `owner` and the liquidity removed from it.
+    /// A position without liquidity is deleted once everything owed to it has been collected.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `owner` - The owner of the position.
+    /// * `position_id` - The id of the position.
+    /// * `to` - The address where the tokens will be sent.
+    ///
+    /// # Returns
+    /// The amounts of token 0 and token 1 sent.
+    fn collect(e: Env, owner: Address, position_id: u32, to: Address) -> Result<(i128, i128), SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        owner.require_auth();
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let mut position = get_position(&e, &owner, position_id).ok_or(SoroswapConcentratedPoolError::PositionDoesNotExist)?;
+        // Accounts the fees earned since the last change of the position
+        if position.liquidity > 0 {
+            modify_position(&e, &mut position, 0)?;
+        }
+
+        let (amount_0, amount_1) = (position.tokens_owed_0, position.tokens_owed_1);
+        if amount_0 > 0 {
+            transfer(&e, get_token_0(&e), &e.current_contract_address(), &to, amount_0);
+            put_reserve_0(&e, get_reserve_0(&e).checked_sub(amount_0).unwrap());
+        }
+        if amount_1 > 0 {
+            transfer(&e, get_token_1(&e), &e.current_contract_address(), &to, amount_1);
+            put_reserve_1(&e, get_reserve_1(&e).checked_sub(amount_1).unwrap());
+        }
+        position.tokens_owed_0 = 0;
+        position.tokens_owed_1 = 0;
+
+        if position.liquidity == 0 {
+            remove_position(&e, &owner, position_id);
+        } else {
+            put_position(&e, &owner, position_id, &position);
+        }
+
+        event::collect(&e, owner, position_id, to, amount_0, amount_1);
+
+        unlock(&e);
+        Ok((amount_0, amount_1))
+    }
+
+    /// Executes a token swap within the pool. The tokens sent to the pool are swapped through the ranges of the
+    /// positions, crossing ticks as needed, and must buy at least the requested output amount. What they buy above
+    /// it is also sent to `to`, so the whole output of the swap leaves the pool.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `amount_0_out` - The desired amount of the first token to receive.
+    /// * `amount_1_out` - The desired amount of the second token to receive.
+    /// * `to` - The address where the swapped tokens will be sent.
+    /// * `data` - If not empty, `soroswap_call` is invoked on `to` with this payload after the tokens have been sent,
+    ///   so `to` can pay for them before the swap is checked (flash swap).
+    ///
+    /// # Errors
+    /// - `SoroswapConcentratedPoolError::SwapInsufficientOutputAmount`
+    /// - `SoroswapConcentratedPoolError::SwapBothTokensOut`
+    /// - `SoroswapConcentratedPoolError::SwapInsufficientLiquidity`
+    /// - `SoroswapConcentratedPoolError::SwapInsufficientInputAmount`
+    /// - `SoroswapConcentratedPoolError::SwapInvariantNotMet`
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        if amount_0_out == 0 && amount_1_out == 0 {
+            return Err(SoroswapConcentratedPoolError::SwapInsufficientOutputAmount);
+        }
+        if amount_0_out < 0 || amount_1_out < 0 {
+            return Err(SoroswapConcentratedPoolError::SwapNegativesOutNotSupported);
+        }
+        if amount_0_out > 0 && amount_1_out > 0 {
+            return Err(SoroswapConcentratedPoolError::SwapBothTokensOut);
+        }
+        let (token_0, token_1) = (get_token_0(&e), get_token_1(&e));
+        if to == token_0 || to == token_1 {
+            return Err(SoroswapConcentratedPoolError::SwapInvalidTo);
+        }
+
+        // Buying token 1 moves the price of token 0 down
+        let zero_for_one = amount_1_out > 0;
+        let (token_in, token_out) = if zero_for_one { (token_0, token_1) } else { (token_1, token_0) };
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (reserve_in, reserve_out, amount_out) = if zero_for_one {
+            (reserve_0, reserve_1, amount_1_out)
+        } else {
+            (reserve_1, reserve_0, amount_0_out)
+        };
+        if amount_out >= reserve_out {
+            return Err(SoroswapConcentratedPoolError::SwapInsufficientLiquidity);
+        }
+
+        transfer(&e, token_out.clone(), &e.current_contract_address(), &to, amount_out);
+        if !data.is_empty() {
+            SoroswapCalleeClient::new(&e, &to).soroswap_call(&e.current_contract_address(), &amount_0_out, &amount_1_out, &data);
+        }
+
+        let amount_in = get_balance(&e, token_in).checked_sub(reserve_in).unwrap();
+        if amount_in <= 0 {
+            return Err(SoroswapConcentratedPoolError::SwapInsufficientInputAmount);
+        }
+
+        let state = compute_swap(&e, zero_for_one, amount_in.unsigned_abs(), true, true)?;
+        let amount_out_swapped = i128::try_from(state.amount_out).map_err(|_| SoroswapConcentratedPoolError::MathOverflow)?;
+        if amount_out_swapped < amount_out {
+            return Err(SoroswapConcentratedPoolError::SwapInvariantNotMet);
+        }
+        // The output bought above the requested amount is not backed by the positions anymore
+        if amount_out_swapped > amount_out {
+            transfer(&e, token_out, &e.current_contract_address(), &to, amount_out_swapped - amount_out);
+        }
+
+        put_sqrt_price(&e, state.sqrt_price);
+        put_current_tick(&e, state.tick);
+        put_liquidity(&e, state.liquidity);
+        let new_reserve_in = reserve_in.checked_add(amount_in).unwrap();
+        let new_reserve_out = reserve_out.checked_sub(amount_out_swapped).unwrap();
+        if zero_for_one {
+            put_fee_growth_global_0(&e, state.fee_growth_global);
+            put_reserve_0(&e, new_reserve_in);
+            put_reserve_1(&e, new_reserve_out);
+            event::swap(&e, SwapEvent {
+                to,
+                amount_0_in: amount_in,
+                amount_1_in: 0,
+                amount_0_out: 0,
+                amount_1_out: amount_out_swapped,
+                sqrt_price: state.sqrt_price,
+                tick: state.tick,
+                liquidity: state.liquidity,
+            });
+        } else {
+            put_fee_growth_global_1(&e, state.fee_growth_global);
+            put_reserve_1(&e, new_reserve_in);
+            put_reserve_0(&e, new_reserve_out);
+            event::swap(&e, SwapEvent {
+                to,
+                amount_0_in: 0,
+                amount_1_in: amount_in,
+                amount_0_out: amount_out_swapped,
+                amount_1_out: 0,
+                sqrt_price: state.sqrt_price,
+                tick: state.tick,
+                liquidity: state.liquidity,
+            });
+        }
+
+        unlock(&e);
+        Ok(())
+    }
+
+    /// Returns the output amount that `swap` gives for `amount_in` at the current state of the pool.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `zero_for_one` - Whether token 0 is sold for token 1, or token 1 for token 0.
+    /// * `amount_in` - The input amount, fee included.
+    fn quote_exact_input(e: Env, zero_for_one: bool, amount_in: i128) -> Result<i128, SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        if amount_in <= 0 {
+            return Err(SoroswapConcentratedPoolError::SwapInsufficientInputAmount);
+        }
+        let state = compute_swap(&e, zero_for_one, amount_in.unsigned_abs(), true, false)?;
+        i128::try_from(state.amount_out).map_err(|_| SoroswapConcentratedPoolError::MathOverflow)
+    }
+
+    /// Returns an input amount, fee included, for which `swap` gives at least `amount_out` at the current state of the pool.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `zero_for_one` - Whether token 0 is sold for token 1, or token 1 for token 0.
+    /// * `amount_out` - The output amount.
+    fn quote_exact_output(e: Env, zero_for_one: bool, amount_out: i128) -> Result<i128, SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        if amount_out <= 0 {
+            return Err(SoroswapConcentratedPoolError::SwapInsufficientOutputAmount);
+        }
+        let amount_out = amount_out.unsigned_abs();
+        let mut amount_in = compute_swap(&e, zero_for_one, amount_out, false, false)?.amount_in;
+
+        // Both directions round against the trader, but they are not exact inverses of each other,
+        // so the input is increased until it gives at least amount_out
+        let mut step = 1;
+        while compute_swap(&e, zero_for_one, amount_in, true, false)?.amount_out < amount_out {
+            amount_in = amount_in.checked_add(step).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+            step = step.checked_mul(2).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+        }
+        i128::try_from(amount_in).map_err(|_| SoroswapConcentratedPoolError::MathOverflow)
+    }
+
+    /// Skims the token balances above the amounts owed to the positions and sends them to the specified address.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the excess tokens will be sent.
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapConcentratedPoolError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPoolError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+        let (token_0, token_1) = (get_token_0(&e), get_token_1(&e));
+        let skimmed_0 = get_balance(&e, token_0.clone()).checked_sub(get_reserve_0(&e)).unwrap();
+        let skimmed_1 = get_balance(&e, token_1.clone()).checked_sub(get_reserve_1(&e)).unwrap();
+        transfer(&e, token_0, &e.current_contract_address(), &to, skimmed_0);
+        transfer(&e, token_1, &e.current_contract_address(), &to, skimmed_1);
+        event::skim(&e, skimmed_0, skimmed_1);
+        unlock(&e);
+        Ok(())
+    }
+
+    /// Returns the address of the first token in the pool.
+    fn token_0(e: Env) -> Address {
+        get_token_0(&e)
+    }
+
+    /// Returns the address of the second token in the pool.
+    fn token_1(e: Env) -> Address {
+        get_token_1(&e)
+    }
+
+    /// Returns the address of the Soroswap factory contract.
+    fn factory(e: Env) -> Address {
+        get_factory(&e)
+    }
+
+    /// Returns the swap fee of the pool, in basis points.
+    fn fee(e: Env) -> u32 {
+        get_fee(&e)
+    }
+
+    /// Returns the spacing of the ticks that positions can use.
+    fn tick_spacing(e: Env) -> i32 {
+        get_tick_spacing(&e)
+    }
+
+    /// Returns the current square root price of token 0 in token 1, with 64 fractional bits.
+    fn sqrt_price(e: Env) -> Result<u128, SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        Ok(get_sqrt_price(&e))
+    }
+
+    /// Returns the greatest tick whose square root price is at most the current one.
+    fn current_tick(e: Env) -> Result<i32, SoroswapConcentratedPoolError> {
+        check_price_initialized(&e)?;
+        Ok(get_current_tick(&e))
+    }
+
+    /// Returns the liquidity of the positions in range of the current price.
+    fn liquidity(e: Env) -> u128 {
+        get_liquidity(&e)
+    }
+
+    /// Returns the fee growth per unit of liquidity of token 0 and token 1 since the pool was created, with 64 fractional bits.
+    fn fee_growth_global(e: Env) -> (u128, u128) {
+        (get_fee_growth_global_0(&e), get_fee_growth_global_1(&e))
+    }
+
+    /// Returns the amounts of token 0 and token 1 owed to the positions.
+    fn get_reserves(e: Env) -> (i128, i128) {
+        (get_reserve_0(&e), get_reserve_1(&e))
+    }
+
+    /// Returns the state of a tick, empty when no position uses it.
+    fn get_tick(e: Env, tick: i32) -> TickInfo {
+        storage::get_tick(&e, tick)
+    }
+
+    /// Returns a word of the bitmap of the ticks used by at least one position. Bit `i` of word `word_pos` is set
+    /// when the tick `(word_pos * 128 + i) * tick_spacing` is initialized.
+    fn tick_bitmap(e: Env, word_pos: i32) -> u128 {
+        get_tick_bitmap(&e, word_pos)
+    }
+
+    /// Returns a position of `owner` by id.
+    ///
+    /// # Errors
+    /// - `SoroswapConcentratedPoolError::PositionDoesNotExist` if it was never opened, or was deleted after being emptied and collected.
+    fn get_position(e: Env, owner: Address, position_id: u32) -> Result<Position, SoroswapConcentratedPoolError> {
+        storage::get_position(&e, &owner, position_id).ok_or(SoroswapConcentratedPoolError::PositionDoesNotExist)
+    }
+
+    /// Returns the number of positions opened by `owner`, which is also the id of its next position.
+    fn position_count(e: Env, owner: Address) -> u32 {
+        get_position_count(&e, &owner)
+    }
+
+    /// Extends the time to live of the pool instance storage and of its code, so the pool is not archived
+    /// while nobody interacts with it. Anyone can call this function.
+    ///
+    /// # Errors
+    /// - `SoroswapConcentratedPoolError::NotInitialized`
+    fn extend_pool_ttl(e: Env) -> Result<(), SoroswapConcentratedPoolError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPoolError::NotInitialized);
+        }
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Replaces the code of the pool with the Wasm identified by `new_wasm_hash`. The price, the positions, the ticks
+    /// and the rest of the storage are kept. The factory must then call `migrate` in order to update the storage layout.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `new_wasm_hash` - The hash of the new Wasm, that must already be uploaded.
+    ///
+    /// # Errors
+    /// - `SoroswapConcentratedPoolError::NotInitialized`
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapConcentratedPoolError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPoolError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+        extend_instance_ttl(&e);
+        e.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Runs the storage migrations between the stored version and the version of the current code.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The new storage version.
+    ///
+    /// # Errors
+    /// - `SoroswapConcentratedPoolError::NotInitialized`
+    /// - `SoroswapConcentratedPoolError::MigrateVersionInvalid`: The storage was written by a newer version of the pool.
+    fn migrate(e: Env) -> Result<u32, SoroswapConcentratedPoolError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapConcentratedPoolError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+        extend_instance_ttl(&e);
+        let (old_version, new_version) = migration::migrate(&e)?;
+        if old_version != new_version {
+            event::migrate(&e, old_version, new_version);
+        }
+        Ok(new_version)
+    }
+
+    /// Returns the version of the storage layout of the pool.
+    fn version(e: Env) -> u32 {
+        get_version(&e)
+    }
+}
+
+fn check_price_initialized(e: &Env) -> Result<(), SoroswapConcentratedPoolError> {
+    if !has_token_0(e) {
+        return Err(SoroswapConcentratedPoolError::NotInitialized);
+    }
+    if !has_sqrt_price(e) {
+        return Err(SoroswapConcentratedPoolError::PriceNotInitialized);
+    }
+    Ok(())
+}
+
+fn check_ticks(e: &Env, tick_lower: i32, tick_upper: i32) -> Result<(), SoroswapConcentratedPoolError> {
+    let tick_spacing = get_tick_spacing(e);
+    if tick_lower >= tick_upper
+        || tick_lower < MIN_TICK
+        || tick_upper > MAX_TICK
+        || tick_lower % tick_spacing != 0
+        || tick_upper % tick_spacing != 0
+    {
+        return Err(SoroswapConcentratedPoolError::PositionTicksInvalid);
+    }
+    Ok(())
+}
+
+// Adds liquidity to a position and transfers its amounts from the owner
+fn add_liquidity(
+    e: &Env,
+    owner: &Address,
+    position: &mut Position,
+    liquidity: u128,
+    amount_0_max: i128,
+    amount_1_max: i128,
+) -> Result<(i128, i128), SoroswapConcentratedPoolError> {
+    if liquidity == 0 {
+        return Err(SoroswapConcentratedPoolError::PositionLiquidityZero);
+    }
+    let liquidity_delta = i128::try_from(liquidity).map_err(|_| SoroswapConcentratedPoolError::MathOverflow)?;
+
+    let (amount_0, amount_1) = modify_position(e, position, liquidity_delta)?;
+    if amount_0 > amount_0_max {
+        return Err(SoroswapConcentratedPoolError::PositionExcessiveAmount0);
+    }
+    if amount_1 > amount_1_max {
+        return Err(SoroswapConcentratedPoolError::PositionExcessiveAmount1);
+    }
+
+    if amount_0 > 0 {
+        transfer(e, get_token_0(e), owner, &e.current_contract_address(), amount_0);
+        put_reserve_0(e, get_reserve_0(e).checked_add(amount_0).unwrap());
+    }
+    if amount_1 > 0 {
+        transfer(e, get_token_1(e), owner, &e.current_contract_address(), amount_1);
+        put_reserve_1(e, get_reserve_1(e).checked_add(amount_1).unwrap());
+    }
+    Ok((amount_0, amount_1))
+}
+
+// Changes the liquidity of a position by `liquidity_delta`, updating its ticks and the liquidity in range, and
+// accounts the fees it earned since its last change. Returns the amounts of token 0 and token 1 that back the
+// liquidity delta at the current price, rounded up when liquidity is added and down when it is removed.
+fn modify_position(e: &Env, position: &mut Position, liquidity_delta: i128) -> Result<(i128, i128), SoroswapConcentratedPoolError> {
+    let (tick_lower, tick_upper) = (position.tick_lower, position.tick_upper);
+    let sqrt_price = get_sqrt_price(e);
+    let current_tick = get_current_tick(e);
+    let (fee_growth_global_0, fee_growth_global_1) = (get_fee_growth_global_0(e), get_fee_growth_global_1(e));
+
+    let (flipped_lower, flipped_upper) = if liquidity_delta != 0 {
+        (
+            update_tick(e, tick_lower, current_tick, liquidity_delta, false, fee_growth_global_0, fee_growth_global_1)?,
+            update_tick(e, tick_upper, current_tick, liquidity_delta, true, fee_growth_global_0, fee_growth_global_1)?,
+        )
+    } else {
+        (false, false)
+    };
+
+    let (fee_growth_inside_0, fee_growth_inside_1) =
+        get_fee_growth_inside(e, tick_lower, tick_upper, current_tick, fee_growth_global_0, fee_growth_global_1);
+    let fees_0 = mul_div(fee_growth_inside_0.wrapping_sub(position.fee_growth_inside_0_last), position.liquidity, Q64, false)?;
+    let fees_1 = mul_div(fee_growth_inside_1.wrapping_sub(position.fee_growth_inside_1_last), position.liquidity, Q64, false)?;
+    position.tokens_owed_0 = position.tokens_owed_0.checked_add(to_amount(fees_0)?).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+    position.tokens_owed_1 = position.tokens_owed_1.checked_add(to_amount(fees_1)?).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+    position.fee_growth_inside_0_last = fee_growth_inside_0;
+    position.fee_growth_inside_1_last = fee_growth_inside_1;
+    position.liquidity = add_delta(position.liquidity, liquidity_delta)?;
+
+    // Ticks that are not the boundary of any position anymore are removed once their fee growth has been read
+    if liquidity_delta < 0 && flipped_lower {
+        clear_tick(e, tick_lower);
+    }
+    if liquidity_delta < 0 && flipped_upper {
+        clear_tick(e, tick_upper);
+    }
+
+    if liquidity_delta == 0 {
+        return Ok((0, 0));
+    }
+    let round_up = liquidity_delta > 0;
+    let liquidity = liquidity_delta.unsigned_abs();
+    let (sqrt_price_lower, sqrt_price_upper) = (get_sqrt_price_at_tick(tick_lower)?, get_sqrt_price_at_tick(tick_upper)?);
+
+    let (amount_0, amount_1) = if current_tick < tick_lower {
+        // Only token 0 below the range, which becomes token 1 as the price goes up
+        (get_amount_0_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?, 0)
+    } else if current_tick < tick_upper {
+        put_liquidity(e, add_delta(get_liquidity(e), liquidity_delta)?);
+        (
+            get_amount_0_delta(sqrt_price, sqrt_price_upper, liquidity, round_up)?,
+            get_amount_1_delta(sqrt_price_lower, sqrt_price, liquidity, round_up)?,
+        )
+    } else {
+        (0, get_amount_1_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?)
+    };
+
+    Ok((to_amount(amount_0)?, to_amount(amount_1)?))
+}
+
+fn to_amount(amount: u128) -> Result<i128, SoroswapConcentratedPoolError> {
+    i128::try_from(amount).map_err(|_| SoroswapConcentratedPoolError::MathOverflow)
+}
+
+fn get_balance(e: &Env, contract_id: Address) -> i128 {
+    TokenClient::new(e, &contract_id).balance(&e.current_contract_address())
+}
+
+fn transfer(e: &Env, contract_id: Address, from: &Address, to: &Address, amount: i128) {
+    TokenClient::new(e, &contract_id).transfer(from, to, &amount);
+}
+
+// Nested calls into the functions that move tokens are rejected while one of them is being executed
+fn lock(e: &Env) -> Result<(), SoroswapConcentratedPoolError> {
+    if get_locked(e) {
+        return Err(SoroswapConcentratedPoolError::Locked);
+    }
+    put_locked(e, true);
+    Ok(())
+}
+
+fn unlock(e: &Env) {
+    put_locked(e, false);
+}
@@ -0,0 +1,259 @@
+use crate::error::SoroswapConcentratedPoolError;
+
+/// Square root prices are fixed point numbers with 64 fractional bits.
+pub const Q64: u128 = 1 << 64;
+/// Range of the ticks, where the price of token 0 in token 1 is `1.0001^tick`.
+pub const MIN_TICK: i32 = -400_000;
+pub const MAX_TICK: i32 = 400_000;
+/// Square root prices of `MIN_TICK` and `MAX_TICK`.
+pub const MIN_SQRT_PRICE: u128 = 38_059_611_424;
+pub const MAX_SQRT_PRICE: u128 = 8_940_773_544_377_188_876_727_933_131;
+/// Swap fees are expressed in basis points.
+pub const FEE_DENOMINATOR: u128 = 10_000;
+
+// 2^128 / sqrt(1.0001)^(2^i), for i from 0 to 18
+const TICK_RATIOS: [u128; 19] = [
+    0xfffcb933bd6fad37aa2d162d1a594001,
+    0xfff97272373d413259a46990580e213a,
+    0xfff2e50f5f656932ef12357cf3c7fdcc,
+    0xffe5caca7e10e4e61c3624eaa0941cd0,
+    0xffcb9843d60f6159c9db58835c926644,
+    0xff973b41fa98c081472e6896dfb254c0,
+    0xff2ea16466c96a3843ec78b326b52861,
+    0xfe5dee046a99a2a811c461f1969c3053,
+    0xfcbe86c7900a88aedcffc83b479aa3a4,
+    0xf987a7253ac413176f2b074cf7815e54,
+    0xf3392b0822b70005940c7a398e4b70f3,
+    0xe7159475a2c29b7443b29c7fa6e889d9,
+    0xd097f3bdfd2022b8845ad8f792aa5825,
+    0xa9f746462d870fdf8a65dc1f90e061e5,
+    0x70d869a156d2a1b890bb3df62baf32f7,
+    0x31be135f97d08fd981231505542fcfa6,
+    0x9aa508b5b7a84e1c677de54f3e99bc9,
+    0x5d6af8dedb81196699c329225ee604,
+    0x2216e584f5fa1ea926041bedfe98,
+];
+
+// 256 bits product of a and b, as (high, low) 128 bits words
+fn full_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_1, a_0) = (a >> 64, a & MASK);
+    let (b_1, b_0) = (b >> 64, b & MASK);
+
+    let p_00 = a_0 * b_0;
+    let p_01 = a_0 * b_1;
+    let p_10 = a_1 * b_0;
+    let p_11 = a_1 * b_1;
+
+    let middle = (p_00 >> 64) + (p_01 & MASK) + (p_10 & MASK);
+    let low = (p_00 & MASK) | (middle << 64);
+    let high = p_11 + (p_01 >> 64) + (p_10 >> 64) + (middle >> 64);
+    (high, low)
+}
+
+/// `a · b / denominator` with a 256 bits intermediate product, rounded down or up.
+/// Fails when the denominator is zero or when the result does not fit in 128 bits.
+pub fn mul_div(a: u128, b: u128, denominator: u128, round_up: bool) -> Result<u128, SoroswapConcentratedPoolError> {
+    if denominator == 0 {
+        return Err(SoroswapConcentratedPoolError::MathOverflow);
+    }
+    let (high, low) = full_mul(a, b);
+    if high >= denominator {
+        return Err(SoroswapConcentratedPoolError::MathOverflow);
+    }
+
+    let (quotient, remainder) = if high == 0 {
+        (low / denominator, low % denominator)
+    } else {
+        // Long division of the 256 bits product, one bit at a time
+        let mut quotient: u128 = 0;
+        let mut remainder = high;
+        for i in (0..128).rev() {
+            let carry = remainder >> 127;
+            remainder = (remainder << 1) | ((low >> i) & 1);
+            quotient <<= 1;
+            if carry == 1 || remainder >= denominator {
+                remainder = remainder.wrapping_sub(denominator);
+                quotient |= 1;
+            }
+        }
+        (quotient, remainder)
+    };
+
+    if round_up && remainder > 0 {
+        quotient.checked_add(1).ok_or(SoroswapConcentratedPoolError::MathOverflow)
+    } else {
+        Ok(quotient)
+    }
+}
+
+/// Adds a signed liquidity delta to a liquidity.
+pub fn add_delta(liquidity: u128, delta: i128) -> Result<u128, SoroswapConcentratedPoolError> {
+    if delta < 0 {
+        liquidity.checked_sub(delta.unsigned_abs()).ok_or(SoroswapConcentratedPoolError::PositionInsufficientLiquidity)
+    } else {
+        liquidity.checked_add(delta.unsigned_abs()).ok_or(SoroswapConcentratedPoolError::MathOverflow)
+    }
+}
+
+/// Square root of `1.0001^tick`, as a fixed point number with 64 fractional bits.
+pub fn get_sqrt_price_at_tick(tick: i32) -> Result<u128, SoroswapConcentratedPoolError> {
+    if !(MIN_TICK..=MAX_TICK).contains(&tick) {
+        return Err(SoroswapConcentratedPoolError::PositionTicksInvalid);
+    }
+    let abs_tick = tick.unsigned_abs();
+
+    // 1 / sqrt(1.0001)^|tick| with 128 fractional bits, where u128::MAX stands for 1
+    let mut ratio = if abs_tick & 1 != 0 { TICK_RATIOS[0] } else { u128::MAX };
+    for (i, tick_ratio) in TICK_RATIOS.iter().enumerate().skip(1) {
+        if abs_tick & (1 << i) != 0 {
+            ratio = full_mul(ratio, *tick_ratio).0;
+        }
+    }
+
+    if tick > 0 {
+        // 2^192 / ratio is the inverse with 64 fractional bits
+        mul_div(1 << 96, 1 << 96, ratio, false)
+    } else {
+        Ok((ratio >> 64) + u128::from(ratio & (Q64 - 1) != 0))
+    }
+}
+
+/// Greatest tick whose square root price is at most `sqrt_price`.
+pub fn get_tick_at_sqrt_price(sqrt_price: u128) -> Result<i32, SoroswapConcentratedPoolError> {
+    if !(MIN_SQRT_PRICE..=MAX_SQRT_PRICE).contains(&sqrt_price) {
+        return Err(SoroswapConcentratedPoolError::PriceInvalid);
+    }
+    let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+    while low < high {
+        let middle = low + (high - low + 1) / 2;
+        if get_sqrt_price_at_tick(middle)? <= sqrt_price {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+    Ok(low)
+}
+
+/// Amount of token 0 between two square root prices for a liquidity: `L / sqrt_price_a - L / sqrt_price_b`.
+pub fn get_amount_0_delta(sqrt_price_a: u128, sqrt_price_b: u128, liquidity: u128, round_up: bool) -> Result<u128, SoroswapConcentratedPoolError> {
+    let (lower, upper) = if sqrt_price_a < sqrt_price_b { (sqrt_price_a, sqrt_price_b) } else { (sqrt_price_b, sqrt_price_a) };
+    if lower == 0 {
+        return Err(SoroswapConcentratedPoolError::PriceInvalid);
+    }
+    let liquidity_over_lower = mul_div(liquidity, Q64, lower, round_up)?;
+    mul_div(liquidity_over_lower, upper - lower, upper, round_up)
+}
+
+/// Amount of token 1 between two square root prices for a liquidity: `L · (sqrt_price_b - sqrt_price_a)`.
+pub fn get_amount_1_delta(sqrt_price_a: u128, sqrt_price_b: u128, liquidity: u128, round_up: bool) -> Result<u128, SoroswapConcentratedPoolError> {
+    let (lower, upper) = if sqrt_price_a < sqrt_price_b { (sqrt_price_a, sqrt_price_b) } else { (sqrt_price_b, sqrt_price_a) };
+    mul_div(liquidity, upper - lower, Q64, round_up)
+}
+
+// Square root price after adding or removing an amount of token 0, rounded up: L · P / (L ± amount · P)
+fn get_next_sqrt_price_from_amount_0(sqrt_price: u128, liquidity: u128, amount: u128, add: bool) -> Result<u128, SoroswapConcentratedPoolError> {
+    if amount == 0 {
+        return Ok(sqrt_price);
+    }
+    let denominator = if add {
+        liquidity.checked_add(mul_div(amount, sqrt_price, Q64, false)?).ok_or(SoroswapConcentratedPoolError::MathOverflow)?
+    } else {
+        let product = mul_div(amount, sqrt_price, Q64, true)?;
+        if product >= liquidity {
+            return Err(SoroswapConcentratedPoolError::SwapInsufficientLiquidity);
+        }
+        liquidity - product
+    };
+    mul_div(liquidity, sqrt_price, denominator, true)
+}
+
+// Square root price after adding or removing an amount of token 1, rounded down: P ± amount / L
+fn get_next_sqrt_price_from_amount_1(sqrt_price: u128, liquidity: u128, amount: u128, add: bool) -> Result<u128, SoroswapConcentratedPoolError> {
+    if add {
+        let quotient = mul_div(amount, Q64, liquidity, false)?;
+        sqrt_price.checked_add(quotient).ok_or(SoroswapConcentratedPoolError::MathOverflow)
+    } else {
+        let quotient = mul_div(amount, Q64, liquidity, true)?;
+        if quotient >= sqrt_price {
+            return Err(SoroswapConcentratedPoolError::SwapInsufficientLiquidity);
+        }
+        Ok(sqrt_price - quotient)
+    }
+}
+
+/// Result of swapping within a single range of ticks.
+pub struct SwapStep {
+    pub sqrt_price_next: u128,
+    pub amount_in: u128,
+    pub amount_out: u128,
+    pub fee_amount: u128,
+}
+
+/// Swaps `amount_remaining` between the current square root price and a target one, with a constant liquidity.
+/// `amount_remaining` is the input amount, fee included, when `exact_in`, and the output amount otherwise.
+/// The step stops at the target price when the amount is enough to reach it.
+pub fn compute_swap_step(
+    sqrt_price: u128,
+    sqrt_price_target: u128,
+    liquidity: u128,
+    amount_remaining: u128,
+    exact_in: bool,
+    fee: u32,
+) -> Result<SwapStep, SoroswapConcentratedPoolError> {
+    let zero_for_one = sqrt_price >= sqrt_price_target;
+    let fee = u128::from(fee);
+
+    let amount_in_to_target = || if zero_for_one {
+        get_amount_0_delta(sqrt_price_target, sqrt_price, liquidity, true)
+    } else {
+        get_amount_1_delta(sqrt_price, sqrt_price_target, liquidity, true)
+    };
+    let amount_out_to_target = || if zero_for_one {
+        get_amount_1_delta(sqrt_price_target, sqrt_price, liquidity, false)
+    } else {
+        get_amount_0_delta(sqrt_price, sqrt_price_target, liquidity, false)
+    };
+
+    let sqrt_price_next = if exact_in {
+        let amount_remaining_less_fee = mul_div(amount_remaining, FEE_DENOMINATOR - fee, FEE_DENOMINATOR, false)?;
+        if amount_remaining_less_fee >= amount_in_to_target()? {
+            sqrt_price_target
+        } else if zero_for_one {
+            get_next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_remaining_less_fee, true)?
+        } else {
+            get_next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_remaining_less_fee, true)?
+        }
+    } else if amount_remaining >= amount_out_to_target()? {
+        sqrt_price_target
+    } else if zero_for_one {
+        get_next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_remaining, false)?
+    } else {
+        get_next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_remaining, false)?
+    };
+
+    let (amount_in, mut amount_out) = if zero_for_one {
+        (
+            get_amount_0_delta(sqrt_price_next, sqrt_price, liquidity, true)?,
+            get_amount_1_delta(sqrt_price_next, sqrt_price, liquidity, false)?,
+        )
+    } else {
+        (
+            get_amount_1_delta(sqrt_price, sqrt_price_next, liquidity, true)?,
+            get_amount_0_delta(sqrt_price, sqrt_price_next, liquidity, false)?,
+        )
+    };
+    if !exact_in && amount_out > amount_remaining {
+        amount_out = amount_remaining;
+    }
+
+    let fee_amount = if exact_in && sqrt_price_next != sqrt_price_target {
+        // The whole remaining amount is taken: what is not swapped is the fee
+        amount_remaining.checked_sub(amount_in).ok_or(SoroswapConcentratedPoolError::MathOverflow)?
+    } else {
+        mul_div(amount_in, fee, FEE_DENOMINATOR - fee, true)?
+    };
+
+    Ok(SwapStep { sqrt_price_next, amount_in, amount_out, fee_amount })
+}
@@ -0,0 +1,22 @@
+//! Versioned storage migrations. After its code is upgraded by the factory, the pool runs every
+//! migration step between the version stored in the instance storage and `CONTRACT_VERSION`.
+//! New storage layouts must bump `CONTRACT_VERSION` and migrate the older versions in `migrate`.
+use soroban_sdk::Env;
+use crate::storage::*;
+use crate::error::SoroswapConcentratedPoolError;
+
+pub(crate) const CONTRACT_VERSION: u32 = 1;
+
+/// Runs the pending migration steps. Pools deployed before the storage was versioned
+/// already have the layout of version 1, so there is nothing to migrate yet.
+///
+/// # Returns
+/// A tuple with the old and the new storage version.
+pub(crate) fn migrate(e: &Env) -> Result<(u32, u32), SoroswapConcentratedPoolError> {
+    let old_version = get_version(e);
+    if old_version > CONTRACT_VERSION {
+        return Err(SoroswapConcentratedPoolError::MigrateVersionInvalid);
+    }
+    put_version(e, CONTRACT_VERSION);
+    Ok((old_version, CONTRACT_VERSION))
+}
@@ -0,0 +1,273 @@
+use soroban_sdk::{contracttype, Env, Address};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// State of an initialized tick, a boundary of at least one position.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TickInfo {
+    /// Total liquidity of the positions that use this tick as a boundary.
+    pub liquidity_gross: u128,
+    /// Liquidity added to the pool when the price crosses this tick upwards, and removed when it crosses it downwards.
+    pub liquidity_net: i128,
+    /// Fee growth per unit of liquidity on the other side of this tick from the current price, with 64 fractional bits.
+    pub fee_growth_outside_0: u128,
+    pub fee_growth_outside_1: u128,
+}
+
+/// Liquidity provided by an owner within a range of ticks.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Position {
+    pub tick_lower: i32,
+    pub tick_upper: i32,
+    pub liquidity: u128,
+    /// Fee growth inside the range of the position when its fees were last accounted, with 64 fractional bits.
+    pub fee_growth_inside_0_last: u128,
+    pub fee_growth_inside_1_last: u128,
+    /// Fees and withdrawn liquidity owed to the owner, until they are collected.
+    pub tokens_owed_0: i128,
+    pub tokens_owed_1: i128,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub(crate) enum DataKey {
+    Token0,
+    Token1,
+    Factory,
+    Fee,                       // swap fee in basis points
+    TickSpacing,               // positions can only use ticks that are multiples of the tick spacing
+    SqrtPrice,                 // current square root price, with 64 fractional bits
+    CurrentTick,               // greatest tick whose square root price is at most the current one
+    Liquidity,                 // liquidity of the positions in range of the current price
+    FeeGrowthGlobal0,          // fee growth per unit of liquidity since the pool was created
+    FeeGrowthGlobal1,
+    Reserve0,                  // tokens owed to the positions, and so not skimmable
+    Reserve1,
+    Locked,                    // reentrancy lock
+    Version,                   // version of the storage layout, see migration.rs
+    TickBitmap(i32),           // bitmap of the ticks of a word that are a boundary of a position. Persistent storage
+    Tick(i32),                 // TickInfo of an initialized tick. Persistent storage
+    PositionCount(Address),    // number of positions opened by an owner, used as the next position id. Persistent storage
+    Position(Address, u32),    // position of an owner by id. Persistent storage
+}
+
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Factory).unwrap()
+}
+
+// Helper function in order to know if the contract has been initialized or not
+pub fn has_token_0(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Token0)
+}
+
+pub fn get_token_0(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token0).unwrap()
+}
+
+pub fn get_token_1(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token1).unwrap()
+}
+
+pub fn get_fee(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Fee).unwrap()
+}
+
+pub fn get_tick_spacing(e: &Env) -> i32 {
+    e.storage().instance().get(&DataKey::TickSpacing).unwrap()
+}
+
+// Helper function in order to know if the price has been initialized or not
+pub fn has_sqrt_price(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::SqrtPrice)
+}
+
+pub fn get_sqrt_price(e: &Env) -> u128 {
+    e.storage().instance().get(&DataKey::SqrtPrice).unwrap()
+}
+
+pub fn get_current_tick(e: &Env) -> i32 {
+    e.storage().instance().get(&DataKey::CurrentTick).unwrap()
+}
+
+pub fn get_liquidity(e: &Env) -> u128 {
+    e.storage().instance().get(&DataKey::Liquidity).unwrap_or(0)
+}
+
+pub fn get_fee_growth_global_0(e: &Env) -> u128 {
+    e.storage().instance().get(&DataKey::FeeGrowthGlobal0).unwrap_or(0)
+}
+
+pub fn get_fee_growth_global_1(e: &Env) -> u128 {
+    e.storage().instance().get(&DataKey::FeeGrowthGlobal1).unwrap_or(0)
+}
+
+pub fn get_reserve_0(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::Reserve0).unwrap()
+}
+
+pub fn get_reserve_1(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::Reserve1).unwrap()
+}
+
+pub fn get_locked(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::Locked).unwrap_or(false)
+}
+
+pub fn put_factory(e: &Env, factory: Address) {
+    e.storage().instance().set(&DataKey::Factory, &factory);
+}
+
+pub fn put_token_0(e: &Env, contract_id: Address) {
+    e.storage().instance().set(&DataKey::Token0, &contract_id);
+}
+
+pub fn put_token_1(e: &Env, contract_id: Address) {
+    e.storage().instance().set(&DataKey::Token1, &contract_id);
+}
+
+pub fn put_fee(e: &Env, fee: u32) {
+    e.storage().instance().set(&DataKey::Fee, &fee);
+}
+
+pub fn put_tick_spacing(e: &Env, tick_spacing: i32) {
+    e.storage().instance().set(&DataKey::TickSpacing, &tick_spacing);
+}
+
+pub fn put_sqrt_price(e: &Env, sqrt_price: u128) {
+    e.storage().instance().set(&DataKey::SqrtPrice, &sqrt_price);
+}
+
+pub fn put_current_tick(e: &Env, tick: i32) {
+    e.storage().instance().set(&DataKey::CurrentTick, &tick);
+}
+
+pub fn put_liquidity(e: &Env, liquidity: u128) {
+    e.storage().instance().set(&DataKey::Liquidity, &liquidity);
+}
+
+pub fn put_fee_growth_global_0(e: &Env, fee_growth: u128) {
+    e.storage().instance().set(&DataKey::FeeGrowthGlobal0, &fee_growth);
+}
+
+pub fn put_fee_growth_global_1(e: &Env, fee_growth: u128) {
+    e.storage().instance().set(&DataKey::FeeGrowthGlobal1, &fee_growth);
+}
+
+pub fn put_reserve_0(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("put_reserve_0: amount cannot be negative")
+    }
+    e.storage().instance().set(&DataKey::Reserve0, &amount)
+}
+
+pub fn put_reserve_1(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("put_reserve_1: amount cannot be negative")
+    }
+    e.storage().instance().set(&DataKey::Reserve1, &amount)
+}
+
+pub fn put_locked(e: &Env, locked: bool) {
+    e.storage().instance().set(&DataKey::Locked, &locked);
+}
+
+// Pools deployed before the storage was versioned do not have a version
+pub fn get_version(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Version).unwrap_or(0)
+}
+
+pub fn put_version(e: &Env, version: u32) {
+    e.storage().instance().set(&DataKey::Version, &version);
+}
+
+/// Returns a word of the tick bitmap, empty when none of its ticks is initialized.
+pub fn get_tick_bitmap(e: &Env, word_pos: i32) -> u128 {
+    let key = DataKey::TickBitmap(word_pos);
+    match e.storage().persistent().get(&key) {
+        Some(word) => {
+            extend_persistent_ttl(e, &key);
+            word
+        }
+        None => 0,
+    }
+}
+
+pub fn put_tick_bitmap(e: &Env, word_pos: i32, word: u128) {
+    let key = DataKey::TickBitmap(word_pos);
+    if word == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &word);
+        extend_persistent_ttl(e, &key);
+    }
+}
+
+/// Returns the state of a tick, empty when it is not initialized.
+pub fn get_tick(e: &Env, tick: i32) -> TickInfo {
+    let key = DataKey::Tick(tick);
+    match e.storage().persistent().get(&key) {
+        Some(tick_info) => {
+            extend_persistent_ttl(e, &key);
+            tick_info
+        }
+        None => TickInfo::default(),
+    }
+}
+
+pub fn put_tick(e: &Env, tick: i32, tick_info: &TickInfo) {
+    let key = DataKey::Tick(tick);
+    e.storage().persistent().set(&key, tick_info);
+    extend_persistent_ttl(e, &key);
+}
+
+pub fn remove_tick(e: &Env, tick: i32) {
+    e.storage().persistent().remove(&DataKey::Tick(tick));
+}
+
+pub fn get_position_count(e: &Env, owner: &Address) -> u32 {
+    e.storage().persistent().get(&DataKey::PositionCount(owner.clone())).unwrap_or(0)
+}
+
+/// Returns the id of the next position of `owner` and increases its position count.
+pub fn next_position_id(e: &Env, owner: &Address) -> u32 {
+    let key = DataKey::PositionCount(owner.clone());
+    let position_id: u32 = e.storage().persistent().get(&key).unwrap_or(0);
+    e.storage().persistent().set(&key, &(position_id + 1));
+    extend_persistent_ttl(e, &key);
+    position_id
+}
+
+pub fn get_position(e: &Env, owner: &Address, position_id: u32) -> Option<Position> {
+    let key = DataKey::Position(owner.clone(), position_id);
+    let position: Option<Position> = e.storage().persistent().get(&key);
+    if position.is_some() {
+        extend_persistent_ttl(e, &key);
+    }
+    position
+}
+
+pub fn put_position(e: &Env, owner: &Address, position_id: u32, position: &Position) {
+    let key = DataKey::Position(owner.clone(), position_id);
+    e.storage().persistent().set(&key, position);
+    extend_persistent_ttl(e, &key);
+}
+
+pub fn remove_position(e: &Env, owner: &Address, position_id: u32) {
+    e.storage().persistent().remove(&DataKey::Position(owner.clone(), position_id));
+}
+
+// Extends the TTL of the instance storage and of the contract code
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
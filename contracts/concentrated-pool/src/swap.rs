@@ -0,0 +1,89 @@
+use soroban_sdk::Env;
+use crate::error::SoroswapConcentratedPoolError;
+use crate::math::{add_delta, compute_swap_step, get_sqrt_price_at_tick, get_tick_at_sqrt_price, mul_div, MAX_TICK, MIN_TICK, Q64};
+use crate::storage::*;
+use crate::tick::{cross_tick, next_initialized_tick_within_one_word};
+
+/// State of the pool after a swap, and the amounts swapped.
+pub struct SwapState {
+    pub amount_in: u128,
+    pub amount_out: u128,
+    pub sqrt_price: u128,
+    pub tick: i32,
+    pub liquidity: u128,
+    /// Fee growth global of the input token.
+    pub fee_growth_global: u128,
+}
+
+/// Swaps `amount` through the ranges of ticks from the current price, crossing the initialized ticks on the way.
+/// `amount` is the input amount, fee included, when `exact_in`, and the output amount otherwise.
+/// The crossed ticks are only updated in storage when `commit` is set; the pool state is returned in any case.
+///
+/// Fails with `SwapInsufficientLiquidity` when the positions can not fill the whole amount.
+pub fn compute_swap(e: &Env, zero_for_one: bool, amount: u128, exact_in: bool, commit: bool) -> Result<SwapState, SoroswapConcentratedPoolError> {
+    let fee = get_fee(e);
+    let tick_spacing = get_tick_spacing(e);
+    let (fee_growth_global_0, fee_growth_global_1) = (get_fee_growth_global_0(e), get_fee_growth_global_1(e));
+
+    let mut state = SwapState {
+        amount_in: 0,
+        amount_out: 0,
+        sqrt_price: get_sqrt_price(e),
+        tick: get_current_tick(e),
+        liquidity: get_liquidity(e),
+        fee_growth_global: if zero_for_one { fee_growth_global_0 } else { fee_growth_global_1 },
+    };
+    let mut amount_remaining = amount;
+
+    while amount_remaining > 0 {
+        // Steps at most up to the end of the word of the bitmap, so every step reads a single word
+        let (tick_next, initialized) = next_initialized_tick_within_one_word(e, state.tick, tick_spacing, zero_for_one);
+        let sqrt_price_target = get_sqrt_price_at_tick(tick_next)?;
+
+        let step = compute_swap_step(state.sqrt_price, sqrt_price_target, state.liquidity, amount_remaining, exact_in, fee)?;
+        state.sqrt_price = step.sqrt_price_next;
+
+        let amount_in = step.amount_in.checked_add(step.fee_amount).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+        state.amount_in = state.amount_in.checked_add(amount_in).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+        state.amount_out = state.amount_out.checked_add(step.amount_out).ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+        amount_remaining -= if exact_in { amount_in } else { step.amount_out };
+
+        // The fee is shared by the liquidity in range
+        if state.liquidity > 0 {
+            let fee_growth = mul_div(step.fee_amount, Q64, state.liquidity, false)?;
+            state.fee_growth_global = state.fee_growth_global.wrapping_add(fee_growth);
+        }
+
+        if state.sqrt_price == sqrt_price_target {
+            // Without initialized ticks left in the direction of the swap, the price can move up to the end of the range
+            if !initialized && (tick_next == MIN_TICK || tick_next == MAX_TICK) {
+                return Err(SoroswapConcentratedPoolError::SwapInsufficientLiquidity);
+            }
+            // The end of a word without initialized ticks does not change the liquidity in range
+            if initialized {
+                let liquidity_net = if commit {
+                    let (global_0, global_1) = if zero_for_one {
+                        (state.fee_growth_global, fee_growth_global_1)
+                    } else {
+                        (fee_growth_global_0, state.fee_growth_global)
+                    };
+                    cross_tick(e, tick_next, global_0, global_1)
+                } else {
+                    get_tick(e, tick_next).liquidity_net
+                };
+                // Crossing a tick downwards is crossing it upwards backwards
+                let liquidity_delta = if zero_for_one {
+                    liquidity_net.checked_neg().ok_or(SoroswapConcentratedPoolError::MathOverflow)?
+                } else {
+                    liquidity_net
+                };
+                state.liquidity = add_delta(state.liquidity, liquidity_delta)?;
+            }
+            state.tick = if zero_for_one { tick_next - 1 } else { tick_next };
+        } else {
+            state.tick = get_tick_at_sqrt_price(state.sqrt_price)?;
+        }
+    }
+
+    Ok(state)
+}
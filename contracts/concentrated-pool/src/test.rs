@@ -0,0 +1,114 @@
+#![cfg(test)]
+extern crate std;
+use soroban_sdk::{
+    testutils::{Address as _},
+    Address,
+    Bytes,
+    BytesN,
+    Env,
+    String,
+};
+use crate::{SoroswapConcentratedPoolClient};
+use crate::math::Q64;
+
+// TOKEN CONTRACT
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+fn create_token_contract<'a>(e: &Env) -> TokenClient<'a> {
+    let token_address = &e.register_contract_wasm(None, token::WASM);
+    let token = TokenClient::new(e, token_address);
+    token
+}
+
+// CONCENTRATED POOL CONTRACT
+fn concentrated_pool_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../concentrated-pool/target/wasm32-unknown-unknown/release/soroswap_concentrated_pool.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+fn create_concentrated_pool_contract<'a>(e: & Env) -> SoroswapConcentratedPoolClient<'a> {
+    SoroswapConcentratedPoolClient::new(e, &e.register_contract(None, crate::SoroswapConcentratedPool {}))
+}
+
+// THE TEST
+pub struct SoroswapConcentratedPoolTest<'a> {
+    env: Env,
+    user: Address,
+    factory: Address,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    contract: SoroswapConcentratedPoolClient<'a>,
+}
+
+impl<'a> SoroswapConcentratedPoolTest<'a> {
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+        let user = Address::generate(&env);
+        let admin = Address::generate(&env);
+        // The pool does not call its factory
+        let factory = Address::generate(&env);
+        let mut token_0 = create_token_contract(&env);
+        let mut token_1 = create_token_contract(&env);
+        if token_1.address < token_0.address {
+            std::mem::swap(&mut token_0, &mut token_1);
+        }
+
+        token_0.initialize(&admin, &7, &String::from_str(&env, "Token 0"), &String::from_str(&env, "TOKEN0"));
+        token_1.initialize(&admin, &7, &String::from_str(&env, "Token 1"), &String::from_str(&env, "TOKEN1"));
+
+        token_0.mint(&user, &123_000_000_000_000_000_000);
+        token_1.mint(&user, &321_000_000_000_000_000_000);
+
+        let contract = create_concentrated_pool_contract(&env);
+
+        SoroswapConcentratedPoolTest {
+            env,
+            user,
+            factory,
+            token_0,
+            token_1,
+            contract,
+        }
+    }
+
+    // Initializes the pool with a 0.3% fee, a tick spacing of 60 and a price of 1
+    fn initialize(&self) {
+        self.contract.initialize_pool(&self.factory, &self.token_0.address, &self.token_1.address, &30, &60);
+        self.contract.initialize_price(&Q64);
+    }
+
+    fn mint(&self, tick_lower: i32, tick_upper: i32, liquidity: u128) -> (u32, i128, i128) {
+        self.contract.mint(&self.user, &tick_lower, &tick_upper, &liquidity, &i128::MAX, &i128::MAX)
+    }
+
+    // Sends amount_in of token 0 and swaps it for token 1 at its quote
+    fn swap_0_for_1(&self, amount_in: i128) -> i128 {
+        let amount_out = self.contract.quote_exact_input(&true, &amount_in);
+        self.token_0.transfer(&self.user, &self.contract.address, &amount_in);
+        self.contract.swap(&0, &amount_out, &self.user, &Bytes::new(&self.env));
+        amount_out
+    }
+
+    // Sends amount_in of token 1 and swaps it for token 0 at its quote
+    fn swap_1_for_0(&self, amount_in: i128) -> i128 {
+        let amount_out = self.contract.quote_exact_input(&false, &amount_in);
+        self.token_1.transfer(&self.user, &self.contract.address, &amount_in);
+        self.contract.swap(&amount_out, &0, &self.user, &Bytes::new(&self.env));
+        amount_out
+    }
+}
+
+mod initialize_pool;
+mod math;
+mod positions;
+mod swap;
+mod fees;
+mod upgrade;
@@ -0,0 +1,86 @@
+use crate::test::SoroswapConcentratedPoolTest;
+use crate::math::Q64;
+
+const LIQUIDITY: u128 = 1_000_000_000_000;
+
+#[test]
+fn fee_growth_global() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, LIQUIDITY);
+
+    // 0.3% of the input, per unit of liquidity
+    test.swap_0_for_1(1_000_000_000);
+    let (fee_growth_global_0, fee_growth_global_1) = test.contract.fee_growth_global();
+    assert_eq!(fee_growth_global_0, 3_000_000 * Q64 / LIQUIDITY);
+    assert_eq!(fee_growth_global_1, 0);
+}
+
+#[test]
+fn fees_collected_by_position() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (position_id, _, _) = test.mint(-600, 600, LIQUIDITY);
+
+    test.swap_0_for_1(1_000_000_000);
+    test.swap_1_for_0(2_000_000_000);
+
+    let balance_0 = test.token_0.balance(&test.user);
+    let balance_1 = test.token_1.balance(&test.user);
+    let (collected_0, collected_1) = test.contract.collect(&test.user, &position_id, &test.user);
+    // The whole fee of both swaps, rounded down
+    assert!(3_000_000 - collected_0 <= 1);
+    assert!(6_000_000 - collected_1 <= 1);
+    assert_eq!(test.token_0.balance(&test.user), balance_0 + collected_0);
+    assert_eq!(test.token_1.balance(&test.user), balance_1 + collected_1);
+
+    // Nothing more until there are new swaps, and the position keeps its liquidity
+    assert_eq!(test.contract.collect(&test.user, &position_id, &test.user), (0, 0));
+    assert_eq!(test.contract.get_position(&test.user, &position_id).liquidity, LIQUIDITY);
+}
+
+#[test]
+fn fees_shared_by_liquidity() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (small, _, _) = test.mint(-600, 600, LIQUIDITY);
+    let (large, _, _) = test.mint(-600, 600, 3 * LIQUIDITY);
+
+    test.swap_0_for_1(4_000_000_000);
+
+    let (small_0, _) = test.contract.collect(&test.user, &small, &test.user);
+    let (large_0, _) = test.contract.collect(&test.user, &large, &test.user);
+    assert!(3_000_000 - small_0 <= 1);
+    assert!(9_000_000 - large_0 <= 1);
+}
+
+#[test]
+fn fees_only_in_range() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (narrow, _, _) = test.mint(-600, 600, LIQUIDITY);
+    let (wide, _, _) = test.mint(-1200, 1200, LIQUIDITY);
+    let (above, _, _) = test.mint(1200, 2400, LIQUIDITY);
+
+    // Down to below the narrow range: the fee is shared while both are in range, and then it all goes to the wide one
+    test.swap_0_for_1(80_000_000_000);
+    assert!(test.contract.current_tick() < -600);
+
+    let (narrow_0, _) = test.contract.collect(&test.user, &narrow, &test.user);
+    let (wide_0, _) = test.contract.collect(&test.user, &wide, &test.user);
+    let (above_0, above_1) = test.contract.collect(&test.user, &above, &test.user);
+    assert!(narrow_0 > 0);
+    assert!(wide_0 > narrow_0);
+    assert!(240_000_000 - (narrow_0 + wide_0) <= 2);
+    assert_eq!((above_0, above_1), (0, 0));
+
+    // Back up: the narrow position earns again once the price is back in its range
+    test.swap_1_for_0(100_000_000_000);
+    assert!(test.contract.current_tick() >= -600);
+    let (_, narrow_1) = test.contract.collect(&test.user, &narrow, &test.user);
+    let (_, wide_1) = test.contract.collect(&test.user, &wide, &test.user);
+    assert!(narrow_1 > 0);
+    assert!(wide_1 > narrow_1);
+    assert!(300_000_000 - (narrow_1 + wide_1) <= 2);
+    assert_eq!(test.contract.collect(&test.user, &above, &test.user), (0, 0));
+}
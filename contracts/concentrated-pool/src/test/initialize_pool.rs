@@ -0,0 +1,75 @@
+use crate::test::SoroswapConcentratedPoolTest;
+use crate::error::SoroswapConcentratedPoolError;
+use crate::math::{MAX_SQRT_PRICE, MIN_SQRT_PRICE, Q64};
+
+#[test]
+fn initialize_pool() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.contract.initialize_pool(&test.factory, &test.token_0.address, &test.token_1.address, &30, &60);
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory);
+    assert_eq!(test.contract.fee(), 30);
+    assert_eq!(test.contract.tick_spacing(), 60);
+    assert_eq!(test.contract.get_reserves(), (0, 0));
+    assert_eq!(test.contract.liquidity(), 0);
+}
+
+#[test]
+fn initialize_pool_twice() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.contract.initialize_pool(&test.factory, &test.token_0.address, &test.token_1.address, &30, &60);
+    let res = test.contract.try_initialize_pool(&test.factory, &test.token_0.address, &test.token_1.address, &30, &60);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_pool_token_order() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    let res = test.contract.try_initialize_pool(&test.factory, &test.token_1.address, &test.token_0.address, &30, &60);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::InitializeTokenOrderInvalid)));
+}
+
+#[test]
+fn initialize_pool_fee() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    let res = test.contract.try_initialize_pool(&test.factory, &test.token_0.address, &test.token_1.address, &10000, &60);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::InitializeFeeInvalid)));
+}
+
+#[test]
+fn initialize_pool_tick_spacing() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    let res = test.contract.try_initialize_pool(&test.factory, &test.token_0.address, &test.token_1.address, &30, &0);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::InitializeTickSpacingInvalid)));
+}
+
+#[test]
+fn initialize_price() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    let res = test.contract.try_initialize_price(&Q64);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::NotInitialized)));
+
+    test.contract.initialize_pool(&test.factory, &test.token_0.address, &test.token_1.address, &30, &60);
+    assert_eq!(test.contract.try_sqrt_price(), Err(Ok(SoroswapConcentratedPoolError::PriceNotInitialized)));
+    let res = test.contract.try_mint(&test.user, &-60, &60, &1000, &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PriceNotInitialized)));
+
+    // A price of 4 is the tick 13863, as 1.0001^13863 <= 4 < 1.0001^13864
+    test.contract.initialize_price(&(2 * Q64));
+    assert_eq!(test.contract.sqrt_price(), 2 * Q64);
+    assert_eq!(test.contract.current_tick(), 13863);
+
+    let res = test.contract.try_initialize_price(&Q64);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PriceAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_price_out_of_range() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.contract.initialize_pool(&test.factory, &test.token_0.address, &test.token_1.address, &30, &60);
+    let res = test.contract.try_initialize_price(&(MIN_SQRT_PRICE - 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PriceInvalid)));
+    let res = test.contract.try_initialize_price(&MAX_SQRT_PRICE);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PriceInvalid)));
+}
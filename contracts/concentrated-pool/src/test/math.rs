@@ -0,0 +1,69 @@
+use crate::error::SoroswapConcentratedPoolError;
+use crate::math::{
+    get_amount_0_delta, get_amount_1_delta, get_sqrt_price_at_tick, get_tick_at_sqrt_price, mul_div,
+    MAX_SQRT_PRICE, MAX_TICK, MIN_SQRT_PRICE, MIN_TICK, Q64,
+};
+
+#[test]
+fn mul_div_full_precision() {
+    // The product does not fit in 128 bits, but the result does
+    assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX, false), Ok(u128::MAX));
+    assert_eq!(mul_div(1 << 100, 1 << 100, 1 << 90, false), Ok(1 << 110));
+    assert_eq!(mul_div(7, 3, 2, false), Ok(10));
+    assert_eq!(mul_div(7, 3, 2, true), Ok(11));
+    assert_eq!(mul_div(1 << 100, 1 << 100, 1 << 60, false), Err(SoroswapConcentratedPoolError::MathOverflow));
+    assert_eq!(mul_div(1, 1, 0, false), Err(SoroswapConcentratedPoolError::MathOverflow));
+}
+
+#[test]
+fn sqrt_price_at_tick() {
+    assert_eq!(get_sqrt_price_at_tick(0), Ok(Q64));
+    assert_eq!(get_sqrt_price_at_tick(MIN_TICK), Ok(MIN_SQRT_PRICE));
+    assert_eq!(get_sqrt_price_at_tick(MAX_TICK), Ok(MAX_SQRT_PRICE));
+    assert_eq!(get_sqrt_price_at_tick(MIN_TICK - 1), Err(SoroswapConcentratedPoolError::PositionTicksInvalid));
+    assert_eq!(get_sqrt_price_at_tick(MAX_TICK + 1), Err(SoroswapConcentratedPoolError::PositionTicksInvalid));
+
+    // sqrt(1.0001) and 1 / sqrt(1.0001)
+    assert_eq!(get_sqrt_price_at_tick(1), Ok(18_447_666_387_855_959_850));
+    assert_eq!(get_sqrt_price_at_tick(-1), Ok(18_445_821_805_675_392_312));
+
+    // Increasing with the tick
+    let mut previous = 0;
+    for tick in [MIN_TICK, -200_000, -13_863, -60, -1, 0, 1, 60, 13_862, 200_000, MAX_TICK] {
+        let sqrt_price = get_sqrt_price_at_tick(tick).unwrap();
+        assert!(sqrt_price > previous);
+        previous = sqrt_price;
+    }
+}
+
+#[test]
+fn tick_at_sqrt_price() {
+    assert_eq!(get_tick_at_sqrt_price(MIN_SQRT_PRICE), Ok(MIN_TICK));
+    assert_eq!(get_tick_at_sqrt_price(MAX_SQRT_PRICE), Ok(MAX_TICK));
+    for tick in [-200_001, -60, -1, 0, 1, 60, 200_001] {
+        let sqrt_price = get_sqrt_price_at_tick(tick).unwrap();
+        assert_eq!(get_tick_at_sqrt_price(sqrt_price), Ok(tick));
+        assert_eq!(get_tick_at_sqrt_price(sqrt_price + 1), Ok(tick));
+        assert_eq!(get_tick_at_sqrt_price(sqrt_price - 1), Ok(tick - 1));
+    }
+    assert_eq!(get_tick_at_sqrt_price(MIN_SQRT_PRICE - 1), Err(SoroswapConcentratedPoolError::PriceInvalid));
+    assert_eq!(get_tick_at_sqrt_price(MAX_SQRT_PRICE + 1), Err(SoroswapConcentratedPoolError::PriceInvalid));
+}
+
+#[test]
+fn amount_deltas() {
+    // Between the prices 1 and 4 a liquidity L is L/2 of token 0 and L of token 1
+    let liquidity = 1_000_000_000;
+    assert_eq!(get_amount_0_delta(Q64, 2 * Q64, liquidity, false), Ok(500_000_000));
+    assert_eq!(get_amount_0_delta(2 * Q64, Q64, liquidity, true), Ok(500_000_000));
+    assert_eq!(get_amount_1_delta(Q64, 2 * Q64, liquidity, false), Ok(1_000_000_000));
+
+    // Rounding up never gives less than rounding down
+    let (sqrt_price_a, sqrt_price_b) = (get_sqrt_price_at_tick(-887).unwrap(), get_sqrt_price_at_tick(1234).unwrap());
+    let amount_0_down = get_amount_0_delta(sqrt_price_a, sqrt_price_b, liquidity, false).unwrap();
+    let amount_0_up = get_amount_0_delta(sqrt_price_a, sqrt_price_b, liquidity, true).unwrap();
+    assert!(amount_0_up == amount_0_down + 1);
+    let amount_1_down = get_amount_1_delta(sqrt_price_a, sqrt_price_b, liquidity, false).unwrap();
+    let amount_1_up = get_amount_1_delta(sqrt_price_a, sqrt_price_b, liquidity, true).unwrap();
+    assert!(amount_1_up == amount_1_down + 1);
+}
@@ -0,0 +1,193 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::test::SoroswapConcentratedPoolTest;
+use crate::error::SoroswapConcentratedPoolError;
+use crate::math::{get_amount_0_delta, get_amount_1_delta, get_sqrt_price_at_tick, Q64};
+use crate::TickInfo;
+
+const LIQUIDITY: u128 = 1_000_000_000_000;
+
+#[test]
+fn mint_in_range() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (balance_0, balance_1) = (test.token_0.balance(&test.user), test.token_1.balance(&test.user));
+
+    let (position_id, amount_0, amount_1) = test.mint(-600, 600, LIQUIDITY);
+    assert_eq!(position_id, 0);
+
+    // Both tokens, rounded up, around a price of 1
+    let sqrt_price_upper = get_sqrt_price_at_tick(600).unwrap();
+    let sqrt_price_lower = get_sqrt_price_at_tick(-600).unwrap();
+    assert_eq!(amount_0 as u128, get_amount_0_delta(Q64, sqrt_price_upper, LIQUIDITY, true).unwrap());
+    assert_eq!(amount_1 as u128, get_amount_1_delta(sqrt_price_lower, Q64, LIQUIDITY, true).unwrap());
+    // L · (1 - 1 / sqrt(1.0001^600))
+    assert_eq!(amount_0, 29_553_010_880);
+
+    assert_eq!(test.token_0.balance(&test.user), balance_0 - amount_0);
+    assert_eq!(test.token_1.balance(&test.user), balance_1 - amount_1);
+    assert_eq!(test.token_0.balance(&test.contract.address), amount_0);
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+    // Ticks -600 and 600 are the compressed ticks -10 and 10, in the words -1 and 0 of the bitmap
+    assert_eq!(test.contract.tick_bitmap(&-1), 1 << 118);
+    assert_eq!(test.contract.tick_bitmap(&0), 1 << 10);
+    assert_eq!(test.contract.get_tick(&-600), TickInfo {
+        liquidity_gross: LIQUIDITY,
+        liquidity_net: LIQUIDITY as i128,
+        fee_growth_outside_0: 0,
+        fee_growth_outside_1: 0,
+    });
+    assert_eq!(test.contract.get_tick(&600).liquidity_net, -(LIQUIDITY as i128));
+
+    let position = test.contract.get_position(&test.user, &0);
+    assert_eq!((position.tick_lower, position.tick_upper, position.liquidity), (-600, 600, LIQUIDITY));
+    assert_eq!((position.tokens_owed_0, position.tokens_owed_1), (0, 0));
+}
+
+#[test]
+fn mint_below_and_above_range() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+
+    // Above the current price the position is only token 0, and below it only token 1
+    let (_, amount_0, amount_1) = test.mint(600, 1200, LIQUIDITY);
+    assert!(amount_0 > 0);
+    assert_eq!(amount_1, 0);
+    let (_, amount_0, amount_1) = test.mint(-1200, -600, LIQUIDITY);
+    assert_eq!(amount_0, 0);
+    assert!(amount_1 > 0);
+
+    // None of them is in range
+    assert_eq!(test.contract.liquidity(), 0);
+    assert_eq!(test.contract.tick_bitmap(&-1), (1 << 108) | (1 << 118));
+    assert_eq!(test.contract.tick_bitmap(&0), (1 << 10) | (1 << 20));
+}
+
+#[test]
+fn mint_ticks_invalid() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    for (tick_lower, tick_upper) in [(60, 60), (120, 60), (-61, 60), (-60, 61), (-400_020, 60), (-60, 400_020)] {
+        let res = test.contract.try_mint(&test.user, &tick_lower, &tick_upper, &LIQUIDITY, &i128::MAX, &i128::MAX);
+        assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionTicksInvalid)));
+    }
+}
+
+#[test]
+fn mint_liquidity_zero() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let res = test.contract.try_mint(&test.user, &-60, &60, &0, &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionLiquidityZero)));
+}
+
+#[test]
+fn mint_excessive_amounts() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let amount_0 = get_amount_0_delta(Q64, get_sqrt_price_at_tick(600).unwrap(), LIQUIDITY, true).unwrap() as i128;
+    let amount_1 = get_amount_1_delta(get_sqrt_price_at_tick(-600).unwrap(), Q64, LIQUIDITY, true).unwrap() as i128;
+
+    let res = test.contract.try_mint(&test.user, &-600, &600, &LIQUIDITY, &(amount_0 - 1), &amount_1);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionExcessiveAmount0)));
+    let res = test.contract.try_mint(&test.user, &-600, &600, &LIQUIDITY, &amount_0, &(amount_1 - 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionExcessiveAmount1)));
+
+    assert_eq!(test.contract.mint(&test.user, &-600, &600, &LIQUIDITY, &amount_0, &amount_1), (0, amount_0, amount_1));
+}
+
+#[test]
+fn position_ids_by_owner() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let other = Address::generate(&test.env);
+    test.token_0.transfer(&test.user, &other, &1_000_000_000_000);
+    test.token_1.transfer(&test.user, &other, &1_000_000_000_000);
+
+    assert_eq!(test.mint(-600, 600, LIQUIDITY).0, 0);
+    assert_eq!(test.mint(-1200, 1200, LIQUIDITY).0, 1);
+    assert_eq!(test.contract.mint(&other, &-600, &600, &LIQUIDITY, &i128::MAX, &i128::MAX).0, 0);
+
+    assert_eq!(test.contract.position_count(&test.user), 2);
+    assert_eq!(test.contract.position_count(&other), 1);
+    assert_eq!(test.contract.get_position(&test.user, &1).tick_lower, -1200);
+    assert_eq!(test.contract.try_get_position(&test.user, &2), Err(Ok(SoroswapConcentratedPoolError::PositionDoesNotExist)));
+
+    // Both positions of the same range share its ticks
+    assert_eq!(test.contract.get_tick(&-600).liquidity_gross, 2 * LIQUIDITY);
+    assert_eq!(test.contract.liquidity(), 3 * LIQUIDITY);
+}
+
+#[test]
+fn increase_liquidity() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (position_id, amount_0, amount_1) = test.mint(-600, 600, LIQUIDITY);
+
+    let res = test.contract.increase_liquidity(&test.user, &position_id, &LIQUIDITY, &i128::MAX, &i128::MAX);
+    assert_eq!(res, (amount_0, amount_1));
+    assert_eq!(test.contract.get_position(&test.user, &position_id).liquidity, 2 * LIQUIDITY);
+    assert_eq!(test.contract.liquidity(), 2 * LIQUIDITY);
+    assert_eq!(test.contract.get_reserves(), (2 * amount_0, 2 * amount_1));
+
+    let res = test.contract.try_increase_liquidity(&test.user, &1, &LIQUIDITY, &i128::MAX, &i128::MAX);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionDoesNotExist)));
+}
+
+#[test]
+fn decrease_liquidity_and_collect() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (position_id, amount_0, amount_1) = test.mint(-600, 600, LIQUIDITY);
+    let (balance_0, balance_1) = (test.token_0.balance(&test.user), test.token_1.balance(&test.user));
+
+    // Rounded down, so at most one unit less than what was paid
+    let (removed_0, removed_1) = test.contract.decrease_liquidity(&test.user, &position_id, &(LIQUIDITY / 2), &0, &0);
+    assert!(amount_0 / 2 - removed_0 <= 1);
+    assert!(amount_1 / 2 - removed_1 <= 1);
+    assert_eq!(test.contract.liquidity(), LIQUIDITY / 2);
+
+    // The tokens are owed until they are collected
+    let position = test.contract.get_position(&test.user, &position_id);
+    assert_eq!((position.tokens_owed_0, position.tokens_owed_1), (removed_0, removed_1));
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+    assert_eq!(test.token_0.balance(&test.user), balance_0);
+
+    let (removed_0_rest, removed_1_rest) = test.contract.decrease_liquidity(&test.user, &position_id, &(LIQUIDITY / 2), &0, &0);
+    assert_eq!(test.contract.liquidity(), 0);
+    // The ticks are not used by any position anymore
+    assert_eq!(test.contract.tick_bitmap(&-1), 0);
+    assert_eq!(test.contract.tick_bitmap(&0), 0);
+    assert_eq!(test.contract.get_tick(&-600), TickInfo::default());
+
+    let (collected_0, collected_1) = test.contract.collect(&test.user, &position_id, &test.user);
+    assert_eq!((collected_0, collected_1), (removed_0 + removed_0_rest, removed_1 + removed_1_rest));
+    assert_eq!(test.token_0.balance(&test.user), balance_0 + collected_0);
+    assert_eq!(test.token_1.balance(&test.user), balance_1 + collected_1);
+    // The rounding is left in the pool
+    assert_eq!(test.contract.get_reserves(), (amount_0 - collected_0, amount_1 - collected_1));
+    assert!(amount_0 - collected_0 <= 2);
+
+    // The empty position is deleted, but its id is not reused
+    assert_eq!(test.contract.try_get_position(&test.user, &position_id), Err(Ok(SoroswapConcentratedPoolError::PositionDoesNotExist)));
+    assert_eq!(test.mint(-600, 600, LIQUIDITY).0, 1);
+}
+
+#[test]
+fn decrease_liquidity_errors() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (position_id, amount_0, amount_1) = test.mint(-600, 600, LIQUIDITY);
+
+    let res = test.contract.try_decrease_liquidity(&test.user, &1, &LIQUIDITY, &0, &0);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionDoesNotExist)));
+    let res = test.contract.try_decrease_liquidity(&test.user, &position_id, &0, &0, &0);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionLiquidityZero)));
+    let res = test.contract.try_decrease_liquidity(&test.user, &position_id, &(LIQUIDITY + 1), &0, &0);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionInsufficientLiquidity)));
+    let res = test.contract.try_decrease_liquidity(&test.user, &position_id, &LIQUIDITY, &amount_0, &0);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionInsufficientAmount0)));
+    let res = test.contract.try_decrease_liquidity(&test.user, &position_id, &LIQUIDITY, &0, &amount_1);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PositionInsufficientAmount1)));
+}
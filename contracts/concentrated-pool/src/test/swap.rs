@@ -0,0 +1,229 @@
+use soroban_sdk::Bytes;
+use crate::test::SoroswapConcentratedPoolTest;
+use crate::error::SoroswapConcentratedPoolError;
+use crate::math::{get_sqrt_price_at_tick, Q64};
+
+const LIQUIDITY: u128 = 1_000_000_000_000;
+
+#[test]
+fn swap_not_yet_initialized() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    let res = test.contract.try_swap(&0, &1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::NotInitialized)));
+
+    test.contract.initialize_pool(&test.factory, &test.token_0.address, &test.token_1.address, &30, &60);
+    let res = test.contract.try_swap(&0, &1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::PriceNotInitialized)));
+}
+
+#[test]
+fn swap_amounts_invalid() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, LIQUIDITY);
+
+    let res = test.contract.try_swap(&0, &0, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapInsufficientOutputAmount)));
+    let res = test.contract.try_swap(&-1, &1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapNegativesOutNotSupported)));
+    let res = test.contract.try_swap(&1, &1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapBothTokensOut)));
+    let res = test.contract.try_swap(&0, &1000, &test.token_0.address, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapInvalidTo)));
+    let (_, reserve_1) = test.contract.get_reserves();
+    let res = test.contract.try_swap(&0, &reserve_1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapInsufficientLiquidity)));
+}
+
+#[test]
+fn swap_nothing_sent() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, LIQUIDITY);
+    let res = test.contract.try_swap(&0, &1000, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapInsufficientInputAmount)));
+}
+
+#[test]
+fn swap_token_0_for_token_1_in_range() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (_, amount_0, amount_1) = test.mint(-600, 600, LIQUIDITY);
+
+    let amount_in = 1_000_000_000;
+    let amount_out = test.contract.quote_exact_input(&true, &amount_in);
+    // Close to 1:1 at a price of 1, minus the 0.3% fee and the price impact on a liquidity of 1000 times the input
+    assert!(amount_out < amount_in * 997 / 1000);
+    assert!(amount_out > amount_in * 995 / 1000);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+    // One more unit than the quote is not bought by the input
+    let res = test.contract.try_swap(&0, &(amount_out + 1), &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapInvariantNotMet)));
+
+    let balance_1 = test.token_1.balance(&test.user);
+    test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.token_1.balance(&test.user), balance_1 + amount_out);
+    assert_eq!(test.contract.get_reserves(), (amount_0 + amount_in, amount_1 - amount_out));
+
+    // The price went down, within the range of the position
+    assert!(test.contract.sqrt_price() < Q64);
+    assert_eq!(test.contract.current_tick(), -20);
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+}
+
+#[test]
+fn swap_token_1_for_token_0_in_range() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, LIQUIDITY);
+
+    let amount_out = test.swap_1_for_0(1_000_000_000);
+    assert!(amount_out < 997_000_000);
+    assert!(amount_out > 995_000_000);
+    assert!(test.contract.sqrt_price() > Q64);
+    assert_eq!(test.contract.current_tick(), 19);
+}
+
+#[test]
+fn swap_less_than_quote_sends_the_whole_output() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (_, amount_0, amount_1) = test.mint(-600, 600, LIQUIDITY);
+
+    let amount_in = 1_000_000_000;
+    let amount_out = test.contract.quote_exact_input(&true, &amount_in);
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+    let balance_1 = test.token_1.balance(&test.user);
+    test.contract.swap(&0, &(amount_out - 1000), &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.token_1.balance(&test.user), balance_1 + amount_out);
+    assert_eq!(test.contract.get_reserves(), (amount_0 + amount_in, amount_1 - amount_out));
+
+    // Nothing is left to skim, and the positions still hold their reserves
+    test.contract.skim(&test.user);
+    assert_eq!(test.token_1.balance(&test.user), balance_1 + amount_out);
+    assert_eq!(test.token_1.balance(&test.contract.address), amount_1 - amount_out);
+}
+
+#[test]
+fn swap_crossing_ticks() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, LIQUIDITY);
+    test.mint(-1200, 1200, LIQUIDITY);
+    assert_eq!(test.contract.liquidity(), 2 * LIQUIDITY);
+
+    // Enough to go below the tick -600, where the narrow position goes out of range
+    let amount_in = 80_000_000_000;
+    test.swap_0_for_1(amount_in);
+    let tick = test.contract.current_tick();
+    assert!((-1200..-600).contains(&tick));
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+
+    // The fee growth outside of the crossed tick was flipped to the side above the price
+    let (fee_growth_global_0, _) = test.contract.fee_growth_global();
+    assert!(test.contract.get_tick(&-600).fee_growth_outside_0 > 0);
+    assert!(test.contract.get_tick(&-600).fee_growth_outside_0 < fee_growth_global_0);
+    assert_eq!(test.contract.get_tick(&-1200).fee_growth_outside_0, 0);
+
+    // And back up into both ranges
+    test.swap_1_for_0(amount_in);
+    assert!(test.contract.current_tick() >= -600);
+    assert_eq!(test.contract.liquidity(), 2 * LIQUIDITY);
+}
+
+#[test]
+fn swap_crossing_empty_range() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    // Two positions below the price, with a gap between them
+    test.mint(-1200, -600, LIQUIDITY);
+    test.mint(-3000, -2400, LIQUIDITY);
+    assert_eq!(test.contract.liquidity(), 0);
+
+    let amount_out = test.swap_0_for_1(40_000_000_000);
+    assert!(amount_out > 0);
+    let tick = test.contract.current_tick();
+    assert!((-3000..-2400).contains(&tick));
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+}
+
+#[test]
+fn swap_crossing_words() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, LIQUIDITY);
+    // A word of the bitmap holds 128 ticks of the spacing of 60, so this position is in the next word
+    test.mint(9600, 10200, LIQUIDITY);
+    assert_eq!(test.contract.tick_bitmap(&1), (1 << 32) | (1 << 42));
+    // And this one two words below
+    test.mint(-10200, -9600, LIQUIDITY);
+    assert_eq!(test.contract.tick_bitmap(&-2), (1 << 86) | (1 << 96));
+
+    let amount_out = test.swap_1_for_0(40_000_000_000);
+    let tick = test.contract.current_tick();
+    assert!((9600..10200).contains(&tick));
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+
+    // And back down into the first position
+    test.swap_0_for_1(amount_out);
+    let tick = test.contract.current_tick();
+    assert!((-600..600).contains(&tick));
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+
+    // And further down, out of the word -1, into the position below
+    test.swap_0_for_1(50_000_000_000);
+    let tick = test.contract.current_tick();
+    assert!((-10200..-9600).contains(&tick));
+    assert_eq!(test.contract.liquidity(), LIQUIDITY);
+}
+
+#[test]
+fn swap_insufficient_liquidity() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (_, _, amount_1) = test.mint(-600, 600, LIQUIDITY);
+
+    // More than the positions hold down to the lowest price
+    let res = test.contract.try_quote_exact_output(&true, &(amount_1 + 1));
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapInsufficientLiquidity)));
+    let res = test.contract.try_quote_exact_input(&true, &1_000_000_000_000_000);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapInsufficientLiquidity)));
+    let res = test.contract.try_quote_exact_input(&true, &0);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::SwapInsufficientInputAmount)));
+}
+
+#[test]
+fn quote_exact_output() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, LIQUIDITY);
+    test.mint(-1200, 1200, LIQUIDITY);
+
+    for (zero_for_one, amount_out) in [(true, 1_000_000), (true, 40_000_000_000), (false, 1_000_000), (false, 40_000_000_000)] {
+        let amount_in = test.contract.quote_exact_output(&zero_for_one, &amount_out);
+        assert!(test.contract.quote_exact_input(&zero_for_one, &amount_in) >= amount_out);
+        assert!(test.contract.quote_exact_input(&zero_for_one, &(amount_in - 2)) < amount_out);
+    }
+
+    // The quote is enough to swap
+    let amount_in = test.contract.quote_exact_output(&true, &40_000_000_000);
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+    test.contract.swap(&0, &40_000_000_000, &test.user, &Bytes::new(&test.env));
+}
+
+#[test]
+fn swap_round_trip_loses_the_fees() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, LIQUIDITY);
+    let sqrt_price_lower = get_sqrt_price_at_tick(-600).unwrap();
+
+    let amount_in = 10_000_000_000;
+    let amount_out = test.swap_0_for_1(amount_in);
+    assert!(test.contract.sqrt_price() > sqrt_price_lower);
+    let amount_back = test.swap_1_for_0(amount_out);
+    // Around 0.6% less after paying the fee twice
+    assert!(amount_back < amount_in * 9941 / 10000);
+    assert!(amount_back > amount_in * 9939 / 10000);
+}
@@ -0,0 +1,115 @@
+use crate::test::{SoroswapConcentratedPoolTest, concentrated_pool_wasm};
+use crate::error::SoroswapConcentratedPoolError;
+use crate::event::MigrateEvent;
+use crate::math::Q64;
+use crate::storage::{DataKey, put_version};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    IntoVal,
+    symbol_short,
+};
+
+#[test]
+fn upgrade_not_yet_initialized() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    let new_wasm_hash = concentrated_pool_wasm(&test.env);
+    let res = test.contract.try_upgrade(&new_wasm_hash);
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::NotInitialized)));
+    let res = test.contract.try_migrate();
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::NotInitialized)));
+}
+
+#[test]
+fn initialize_sets_current_version() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    assert_eq!(test.contract.version(), 1);
+
+    // Nothing to migrate
+    assert_eq!(test.contract.migrate(), 1);
+}
+
+#[test]
+fn migrate_unversioned_pool() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.mint(-600, 600, 1_000_000_000_000);
+
+    // Storage as written by pools deployed before versioning
+    test.env.as_contract(&test.contract.address, || {
+        test.env.storage().instance().remove(&DataKey::Version);
+    });
+    assert_eq!(test.contract.version(), 0);
+
+    assert_eq!(test.contract.migrate(), 1);
+    let migrate_event = test.env.events().all().last().unwrap();
+    let expected_migrate_event: MigrateEvent = MigrateEvent {
+        old_version: 0,
+        new_version: 1,
+    };
+    assert_eq!(
+        vec![&test.env, migrate_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapConcentratedPool", symbol_short!("migrate")).into_val(&test.env),
+                (expected_migrate_event).into_val(&test.env)
+            ),
+        ]
+    );
+    assert_eq!(test.contract.version(), 1);
+}
+
+#[test]
+fn migrate_newer_version() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    test.env.as_contract(&test.contract.address, || put_version(&test.env, 2));
+
+    let res = test.contract.try_migrate();
+    assert_eq!(res, Err(Ok(SoroswapConcentratedPoolError::MigrateVersionInvalid)));
+}
+
+#[test]
+fn upgrade_keeps_price_and_positions() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let (position_id, _, _) = test.mint(-600, 600, 1_000_000_000_000);
+    let position = test.contract.get_position(&test.user, &position_id);
+    let reserves = test.contract.get_reserves();
+
+    let new_wasm_hash = concentrated_pool_wasm(&test.env);
+    test.contract.upgrade(&new_wasm_hash);
+    assert_eq!(test.contract.migrate(), 1);
+
+    assert_eq!(test.contract.sqrt_price(), Q64);
+    assert_eq!(test.contract.liquidity(), 1_000_000_000_000);
+    assert_eq!(test.contract.get_reserves(), reserves);
+    assert_eq!(test.contract.get_position(&test.user, &position_id), position);
+
+    // The upgraded pool keeps working
+    let amount_out = test.swap_0_for_1(1_000_000);
+    assert!(amount_out > 0);
+}
+
+#[test]
+#[should_panic]
+fn upgrade_not_factory() {
+    let test = SoroswapConcentratedPoolTest::setup();
+    test.initialize();
+    let new_wasm_hash = concentrated_pool_wasm(&test.env);
+
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &test.user,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+}
@@ -0,0 +1,144 @@
+use soroban_sdk::Env;
+use crate::error::SoroswapConcentratedPoolError;
+use crate::math::{add_delta, MAX_TICK, MIN_TICK};
+use crate::storage::*;
+
+/// Adds `liquidity_delta` to a boundary tick of a position, and returns whether the tick was initialized or cleared by it.
+/// A cleared tick keeps its state until `clear_tick` is called, so the fee growth inside the position can still be read.
+pub fn update_tick(
+    e: &Env,
+    tick: i32,
+    current_tick: i32,
+    liquidity_delta: i128,
+    upper: bool,
+    fee_growth_global_0: u128,
+    fee_growth_global_1: u128,
+) -> Result<bool, SoroswapConcentratedPoolError> {
+    let mut tick_info = get_tick(e, tick);
+    let liquidity_gross_before = tick_info.liquidity_gross;
+    let liquidity_gross_after = add_delta(liquidity_gross_before, liquidity_delta)?;
+
+    if liquidity_gross_before == 0 {
+        // By convention, all the fee growth before a tick is initialized happened below it
+        if tick <= current_tick {
+            tick_info.fee_growth_outside_0 = fee_growth_global_0;
+            tick_info.fee_growth_outside_1 = fee_growth_global_1;
+        }
+    }
+
+    tick_info.liquidity_gross = liquidity_gross_after;
+    // The liquidity of a position is added when the price crosses its lower tick upwards, and removed at its upper tick
+    tick_info.liquidity_net = if upper {
+        tick_info.liquidity_net.checked_sub(liquidity_delta)
+    } else {
+        tick_info.liquidity_net.checked_add(liquidity_delta)
+    }.ok_or(SoroswapConcentratedPoolError::MathOverflow)?;
+    put_tick(e, tick, &tick_info);
+
+    let flipped = (liquidity_gross_after == 0) != (liquidity_gross_before == 0);
+    if flipped && liquidity_gross_before == 0 {
+        flip_tick(e, tick);
+    }
+    Ok(flipped)
+}
+
+/// Removes a tick that is not the boundary of any position anymore.
+pub fn clear_tick(e: &Env, tick: i32) {
+    remove_tick(e, tick);
+    flip_tick(e, tick);
+}
+
+/*
+    The initialized ticks are kept in a bitmap of the compressed ticks (the ticks divided by the tick spacing),
+    split in words of 128 bits. Compressed tick `c` is the bit `c % 128` of the word `c / 128`, rounding down.
+    The word positions fit in an i16, as in Uniswap V3, but contract values are at least 32 bits wide.
+*/
+const WORD_BITS: i32 = 128;
+
+fn position(compressed: i32) -> (i32, u32) {
+    (compressed.div_euclid(WORD_BITS), compressed.rem_euclid(WORD_BITS) as u32)
+}
+
+// Initializes a tick in the bitmap if it was not, and clears it otherwise
+fn flip_tick(e: &Env, tick: i32) {
+    let (word_pos, bit_pos) = position(tick / get_tick_spacing(e));
+    put_tick_bitmap(e, word_pos, get_tick_bitmap(e, word_pos) ^ (1 << bit_pos));
+}
+
+/// Flips the fee growth outside of a tick when the price crosses it, and returns its net liquidity.
+pub fn cross_tick(e: &Env, tick: i32, fee_growth_global_0: u128, fee_growth_global_1: u128) -> i128 {
+    let mut tick_info = get_tick(e, tick);
+    tick_info.fee_growth_outside_0 = fee_growth_global_0.wrapping_sub(tick_info.fee_growth_outside_0);
+    tick_info.fee_growth_outside_1 = fee_growth_global_1.wrapping_sub(tick_info.fee_growth_outside_1);
+    put_tick(e, tick, &tick_info);
+    tick_info.liquidity_net
+}
+
+/// Fee growth per unit of liquidity between two ticks. The fee growths wrap around,
+/// only their differences are meaningful.
+pub fn get_fee_growth_inside(
+    e: &Env,
+    tick_lower: i32,
+    tick_upper: i32,
+    current_tick: i32,
+    fee_growth_global_0: u128,
+    fee_growth_global_1: u128,
+) -> (u128, u128) {
+    let lower = get_tick(e, tick_lower);
+    let upper = get_tick(e, tick_upper);
+
+    let (below_0, below_1) = if current_tick >= tick_lower {
+        (lower.fee_growth_outside_0, lower.fee_growth_outside_1)
+    } else {
+        (
+            fee_growth_global_0.wrapping_sub(lower.fee_growth_outside_0),
+            fee_growth_global_1.wrapping_sub(lower.fee_growth_outside_1),
+        )
+    };
+    let (above_0, above_1) = if current_tick < tick_upper {
+        (upper.fee_growth_outside_0, upper.fee_growth_outside_1)
+    } else {
+        (
+            fee_growth_global_0.wrapping_sub(upper.fee_growth_outside_0),
+            fee_growth_global_1.wrapping_sub(upper.fee_growth_outside_1),
+        )
+    };
+
+    (
+        fee_growth_global_0.wrapping_sub(below_0).wrapping_sub(above_0),
+        fee_growth_global_1.wrapping_sub(below_1).wrapping_sub(above_1),
+    )
+}
+
+/// Next tick in the direction of a swap, within the word of the bitmap of `tick`: the greatest initialized tick at or
+/// below `tick` when the price goes down, and the lowest one above `tick` when it goes up. When the word has none, the
+/// last tick of the word in that direction is returned instead, as not initialized. Either way, it is clamped to the
+/// range of the ticks.
+pub fn next_initialized_tick_within_one_word(e: &Env, tick: i32, tick_spacing: i32, zero_for_one: bool) -> (i32, bool) {
+    let compressed = tick.div_euclid(tick_spacing);
+    let (tick_next, initialized) = if zero_for_one {
+        let (word_pos, bit_pos) = position(compressed);
+        // The bit of the tick and all the bits below it
+        let mask = u128::MAX >> (WORD_BITS as u32 - 1 - bit_pos);
+        let masked = get_tick_bitmap(e, word_pos) & mask;
+        if masked != 0 {
+            let most_significant_bit = WORD_BITS as u32 - 1 - masked.leading_zeros();
+            ((compressed - (bit_pos - most_significant_bit) as i32) * tick_spacing, true)
+        } else {
+            ((compressed - bit_pos as i32) * tick_spacing, false)
+        }
+    } else {
+        let compressed = compressed + 1;
+        let (word_pos, bit_pos) = position(compressed);
+        // The bit of the next tick and all the bits above it
+        let mask = u128::MAX << bit_pos;
+        let masked = get_tick_bitmap(e, word_pos) & mask;
+        if masked != 0 {
+            let least_significant_bit = masked.trailing_zeros();
+            ((compressed + (least_significant_bit - bit_pos) as i32) * tick_spacing, true)
+        } else {
+            ((compressed + (WORD_BITS as u32 - 1 - bit_pos) as i32) * tick_spacing, false)
+        }
+    };
+    (tick_next.clamp(MIN_TICK, MAX_TICK), initialized)
+}
@@ -20,5 +20,37 @@ pub enum FactoryError {
 
     /// SoroswapFactory: index does not exist
     IndexDoesNotExist = 206,
+
+    /// SoroswapFactory: fee tier is not enabled
+    CreatePairFeeTierNotEnabled = 207,
+    /// SoroswapFactory: fee tier must be less than 10000 basis points
+    FeeTierInvalid = 208,
+
+    /// SoroswapFactory: protocol fee share must be a fraction between 0 and 1/2
+    ProtocolFeeShareInvalid = 209,
+
+    /// SoroswapFactory: the stable pair Wasm hash has not been set
+    StablePairWasmHashNotSet = 210,
+    /// SoroswapFactory: amplification coefficient must be between 1 and 10000
+    CreatePairAmpInvalid = 211,
+
+    /// SoroswapFactory: the weighted pair Wasm hash has not been set
+    WeightedPairWasmHashNotSet = 212,
+    /// SoroswapFactory: weight must be between 100 and 9900 basis points
+    CreatePairWeightInvalid = 213,
+
+    /// SoroswapFactory: the concentrated pool Wasm hash has not been set
+    ConcentratedPoolWasmHashNotSet = 214,
+
+    /// SoroswapFactory: pair creation is permissioned and token_a or token_b is not allowed
+    CreatePairTokenNotAllowed = 215,
+
+    /// SoroswapFactory: pair creation is paused
+    CreatePairPaused = 216,
+
+    /// SoroswapFactory: the ownership has been renounced
+    OwnershipRenounced = 217,
+    /// SoroswapFactory: there is no pending owner
+    NoPendingOwner = 218,
 }
 
@@ -1,12 +1,21 @@
 #![deny(warnings)]
 #![no_std]
 
-use soroban_sdk::{contractclient, contractspecfn, Address, Env, BytesN};
+use soroban_sdk::{contractclient, contractspecfn, Address, Env, BytesN, Vec};
 pub struct Spec;
 
 mod error;
 pub use error::FactoryError;
 
+mod pool_type;
+pub use pool_type::PoolType;
+
+mod pair_info;
+pub use pair_info::{PairInfo, TokenPool};
+
+mod role;
+pub use role::Role;
+
 /// Interface for SoroswapFactory
 #[contractspecfn(name = "Spec", export = false)]
 #[contractclient(name = "SoroswapFactoryClient")]
@@ -19,12 +28,25 @@ pub trait SoroswapFactoryTrait {
     /// Returns the recipient of the fee.
     fn fee_to(e: Env) -> Result<Address, FactoryError>;
 
-    /// Returns the address allowed to change `fee_to`.
+    /// Returns the owner of the factory, that grants the roles and holds the ones that have not been granted.
     fn fee_to_setter(e: Env) -> Result<Address, FactoryError>;
 
+    /// Returns the address proposed as the new owner, if it has not accepted the ownership yet.
+    fn pending_owner(e: Env) -> Result<Option<Address>, FactoryError>;
+
+    /// Returns the address holding `role`, which is the owner if the role has not been granted.
+    fn role_holder(e: Env, role: Role) -> Result<Address, FactoryError>;
+
+    /// Returns a boolean indicating if pair creation is paused.
+    fn paused(e: Env) -> Result<bool, FactoryError>;
+
     /// Checks if fees are enabled.
     fn fees_enabled(e: Env) -> Result<bool, FactoryError>;
 
+    /// Returns the share of the liquidity providers fee growth minted to `fee_to` when fees are enabled,
+    /// as a `(numerator, denominator)` fraction.
+    fn protocol_fee_share(e: Env) -> Result<(u32, u32), FactoryError>;
+
     /// Returns the total number of pairs created through the factory so far.
     fn all_pairs_length(e: Env) -> Result<u32, FactoryError>;
 
@@ -34,9 +56,66 @@ pub trait SoroswapFactoryTrait {
     /// Returns the address of the nth pair (0-indexed) created through the factory.
     fn all_pairs(e: Env, n: u32) -> Result<Address, FactoryError>;
 
+    /// Returns the addresses of the pairs created through the factory from index `start`, up to `limit` pairs.
+    /// The result is shorter than `limit`, or empty, past the last pair.
+    fn all_pairs_range(e: Env, start: u32, limit: u32) -> Result<Vec<Address>, FactoryError>;
+
+    /// Returns the tokens, reserves and total shares of the pairs created through the factory from index `start`,
+    /// up to `limit` pairs. The result is shorter than `limit`, or empty, past the last pair.
+    fn pairs_info(e: Env, start: u32, limit: u32) -> Result<Vec<PairInfo>, FactoryError>;
+
+    /// Returns the number of pools of every type created through the factory that contain `token`.
+    fn pairs_for_token_length(e: Env, token: Address) -> Result<u32, FactoryError>;
+
+    /// Returns the addresses and pool types of the pools that contain `token`, in order of creation, from index `start`
+    /// up to `limit` pools. The result is shorter than `limit`, or empty, past the last pool.
+    fn pairs_for_token(e: Env, token: Address, start: u32, limit: u32) -> Result<Vec<TokenPool>, FactoryError>;
+
     /// Returns a boolean indicating if a pair exists for the given `token_a` and `token_b`.
     fn pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError>;
 
+    /// Returns a boolean indicating if pairs can be created with a swap fee of `fee` basis points.
+    fn fee_tier_enabled(e: Env, fee: u32) -> Result<bool, FactoryError>;
+
+    /// Returns a boolean indicating if pair creation is restricted to allowed tokens. It is open by default.
+    fn permissioned_creation(e: Env) -> Result<bool, FactoryError>;
+
+    /// Returns a boolean indicating if `token` is allowed in new pairs while pair creation is permissioned.
+    fn token_allowed(e: Env, token: Address) -> Result<bool, FactoryError>;
+
+    /// Returns the Wasm hash used to deploy new pairs.
+    fn pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError>;
+
+    /// Returns the Wasm hash used to deploy new stable pairs.
+    fn stable_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError>;
+
+    /// Returns the address of the stable pair for `token_a` and `token_b`, if it has been created.
+    fn get_stable_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Returns a boolean indicating if a stable pair exists for the given `token_a` and `token_b`.
+    fn stable_pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError>;
+
+    /// Returns the Wasm hash used to deploy new weighted pairs.
+    fn weighted_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError>;
+
+    /// Returns the address of the weighted pair for `token_a` and `token_b`, if it has been created.
+    fn get_weighted_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Returns a boolean indicating if a weighted pair exists for the given `token_a` and `token_b`.
+    fn weighted_pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError>;
+
+    /// Returns the Wasm hash used to deploy new concentrated pools.
+    fn concentrated_pool_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError>;
+
+    /// Returns the address of the concentrated pool for `token_a` and `token_b` in the `fee` tier, if it has been created.
+    fn get_concentrated_pool(e: Env, token_a: Address, token_b: Address, fee: u32) -> Result<Address, FactoryError>;
+
+    /// Returns a boolean indicating if a concentrated pool exists for the given `token_a` and `token_b` in the `fee` tier.
+    fn concentrated_pool_exists(e: Env, token_a: Address, token_b: Address, fee: u32) -> Result<bool, FactoryError>;
+
+    /// Returns the curve of the nth pair (0-indexed) created through the factory.
+    fn all_pairs_pool_type(e: Env, n: u32) -> Result<PoolType, FactoryError>;
+
     /*  *** State-Changing Functions: *** */
 
     /// Sets the `fee_to_setter` address and initializes the factory.
@@ -56,14 +135,63 @@ pub trait SoroswapFactoryTrait {
     /// * `to` - The address to set as the `fee_to`.
     fn set_fee_to(e: Env, to: Address)-> Result<(), FactoryError>;
 
-    /// Sets the `fee_to_setter` address.
+    /// Proposes `new_setter` as the new `fee_to_setter`. Same as `propose_owner`: the ownership is only transferred
+    /// once `new_setter` calls `accept_ownership`.
     /// 
     /// # Arguments
     /// 
     /// * `e` - An instance of the `Env` struct.
-    /// * `new_setter` - The address to set as the new `fee_to_setter`.
+    /// * `new_setter` - The address proposed as the new `fee_to_setter`.
     fn set_fee_to_setter(e: Env, new_setter: Address)-> Result<(), FactoryError>;
 
+    /// Proposes `new_owner` as the owner of the factory. The ownership is transferred once `new_owner` calls `accept_ownership`.
+    /// A new proposal replaces the previous one.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `new_owner` - The address proposed as the new owner.
+    fn propose_owner(e: Env, new_owner: Address)-> Result<(), FactoryError>;
+
+    /// Transfers the ownership to the pending owner, that must authorize the call. Emits the `setter` event.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    fn accept_ownership(e: Env)-> Result<(), FactoryError>;
+
+    /// Removes the owner for good. The granted roles are kept, but no role can be granted or revoked anymore.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    fn renounce_ownership(e: Env)-> Result<(), FactoryError>;
+
+    /// Grants `role` to `account`, replacing its previous holder.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `role` - The role to grant.
+    /// * `account` - The address that holds the role.
+    fn grant_role(e: Env, role: Role, account: Address)-> Result<(), FactoryError>;
+
+    /// Revokes `role` from its holder, so it is held by the owner again.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `role` - The role to revoke.
+    fn revoke_role(e: Env, role: Role)-> Result<(), FactoryError>;
+
+    /// Pauses or unpauses the creation of pairs of every type. Existing pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `is_paused` - A boolean indicating whether pair creation is paused.
+    fn set_paused(e: Env, is_paused: bool)-> Result<(), FactoryError>;
+
     /// Sets whether fees are enabled or disabled.
     /// 
     /// # Arguments
@@ -72,6 +200,42 @@ pub trait SoroswapFactoryTrait {
     /// * `is_enabled` - A boolean indicating whether fees are enabled or disabled.
     fn set_fees_enabled(e: Env, is_enabled: bool)-> Result<(), FactoryError>;
 
+    /// Sets the share of the liquidity providers fee growth minted to `fee_to` when fees are enabled.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `numerator` - The numerator of the share.
+    /// * `denominator` - The denominator of the share. The share can be at most 1/2.
+    fn set_protocol_fee_share(e: Env, numerator: u32, denominator: u32)-> Result<(), FactoryError>;
+
+    /// Enables or disables a swap fee tier for new pairs.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `fee` - The swap fee, in basis points.
+    /// * `is_enabled` - A boolean indicating whether the fee tier is enabled or disabled.
+    fn set_fee_tier_enabled(e: Env, fee: u32, is_enabled: bool)-> Result<(), FactoryError>;
+
+    /// Sets whether pair creation is permissioned. While it is, pairs of every type can only be created
+    /// between tokens allowed with `set_token_allowed`. Existing pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `is_enabled` - A boolean indicating whether pair creation is permissioned or open.
+    fn set_permissioned_creation(e: Env, is_enabled: bool)-> Result<(), FactoryError>;
+
+    /// Adds `token` to, or removes it from, the tokens allowed in new pairs while pair creation is permissioned.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token` - The address of the token.
+    /// * `is_allowed` - A boolean indicating whether the token is allowed or not.
+    fn set_token_allowed(e: Env, token: Address, is_allowed: bool)-> Result<(), FactoryError>;
+
     /// Creates a pair for `token_a` and `token_b` if one doesn't exist already.
     /// 
     /// # Arguments
@@ -80,4 +244,139 @@ pub trait SoroswapFactoryTrait {
     /// * `token_a` - The address of the first token in the pair.
     /// * `token_b` - The address of the second token in the pair.
     fn create_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError>;
+
+    /// Sets the Wasm hash used to deploy new pairs. Existing pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `pair_wasm_hash` - The Wasm hash of the SoroswapPair contract.
+    fn set_pair_wasm_hash(e: Env, pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Replaces the code of the factory. Only the owner can call it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `new_wasm_hash` - The hash of the new Wasm, that must already be uploaded.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Replaces the code of the pool of `pool_type` for `token_a` and `token_b` and migrates its storage. Only the owner can call it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `pool_type` - The type of the pool to upgrade.
+    /// * `new_wasm_hash` - The hash of the new Wasm of that pool type, that must already be uploaded.
+    fn upgrade_pair(e: Env, token_a: Address, token_b: Address, pool_type: PoolType, new_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Creates a pair for `token_a` and `token_b` with a swap fee of `fee` basis points if one doesn't exist already.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points. Must be an enabled fee tier.
+    fn create_pair_with_fee(e: Env, token_a: Address, token_b: Address, fee: u32) -> Result<Address, FactoryError>;
+
+    /// Sets the Wasm hash used to deploy new stable pairs. Existing stable pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `stable_pair_wasm_hash` - The Wasm hash of the SoroswapStablePair contract.
+    fn set_stable_pair_wasm_hash(e: Env, stable_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Creates a stable pair for `token_a` and `token_b` with an amplification coefficient of `amp`, if one doesn't exist already.
+    /// The stable pair lives alongside the constant product pair of the same tokens.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `amp` - The amplification coefficient of the StableSwap invariant.
+    fn create_stable_pair(e: Env, token_a: Address, token_b: Address, amp: u32) -> Result<Address, FactoryError>;
+
+    /// Sets the Wasm hash used to deploy new weighted pairs. Existing weighted pairs are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `weighted_pair_wasm_hash` - The Wasm hash of the SoroswapWeightedPair contract.
+    fn set_weighted_pair_wasm_hash(e: Env, weighted_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Creates a weighted pair for `token_a` and `token_b` where `token_a` weighs `weight_a` basis points, if one doesn't exist already.
+    /// The weighted pair lives alongside the constant product pair of the same tokens.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `weight_a` - The weight of `token_a`, in basis points. `token_b` weighs the rest up to 10000.
+    fn create_weighted_pair(e: Env, token_a: Address, token_b: Address, weight_a: u32) -> Result<Address, FactoryError>;
+
+    /// Sets the Wasm hash used to deploy new concentrated pools. Existing concentrated pools are not affected.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `concentrated_pool_wasm_hash` - The Wasm hash of the SoroswapConcentratedPool contract.
+    fn set_concentrated_pool_wasm_hash(e: Env, concentrated_pool_wasm_hash: BytesN<32>) -> Result<(), FactoryError>;
+
+    /// Creates a concentrated liquidity pool for `token_a` and `token_b` in the `fee` tier, if one doesn't exist already.
+    /// There is one concentrated pool per enabled fee tier, alongside the other pairs of the same tokens.
+    /// Its tick spacing is twice the fee, and its price must be initialized before liquidity is added.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pool.
+    /// * `token_b` - The address of the second token in the pool.
+    /// * `fee` - The swap fee of the pool in basis points. It must be an enabled fee tier.
+    fn create_concentrated_pool(e: Env, token_a: Address, token_b: Address, fee: u32) -> Result<Address, FactoryError>;
+
+    /// Extends the time to live of the factory instance, code and pair Wasm hash. Anyone can call it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    fn extend_ttl(e: Env) -> Result<(), FactoryError>;
+
+    /// Extends the time to live of the factory, of the registry entry of the pair for `token_a` and `token_b`,
+    /// and of the pair contract itself with its price observations from index `start`, up to `limit` of them,
+    /// so the pool does not get archived. Anyone can call it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `start` - The index of the first price observation of the pair.
+    /// * `limit` - The maximum number of price observations.
+    fn extend_pair_ttl(e: Env, token_a: Address, token_b: Address, start: u32, limit: u32) -> Result<(), FactoryError>;
+
+    /// Extends the time to live of the `all_pairs` entries from index `start`, up to `limit` entries. Anyone can call it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `start` - The index of the first pair.
+    /// * `limit` - The maximum number of pairs.
+    fn extend_all_pairs_ttl(e: Env, start: u32, limit: u32) -> Result<(), FactoryError>;
+
+    /// Extends the time to live of the `pairs_for_token` entries of `token` from index `start`, up to `limit` entries,
+    /// and of its number of pairs. Anyone can call it.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `e` - An instance of the `Env` struct.
+    /// * `token` - The address of the token.
+    /// * `start` - The index of the first pair of the token.
+    /// * `limit` - The maximum number of pairs.
+    fn extend_token_pairs_ttl(e: Env, token: Address, start: u32, limit: u32) -> Result<(), FactoryError>;
 }
@@ -0,0 +1,24 @@
+use soroban_sdk::{self, contracttype, Address};
+
+use crate::PoolType;
+
+/// State of a pair created by the SoroswapFactory, as returned by `pairs_info`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PairInfo {
+    pub pair: Address,
+    pub token_0: Address,
+    pub token_1: Address,
+    pub reserve_0: i128,
+    pub reserve_1: i128,
+    /// Liquidity provider shares of the pair. Concentrated pools track liquidity per position instead, so it is 0 for them.
+    pub total_shares: i128,
+}
+
+/// A pool containing a token, as returned by `pairs_for_token`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenPool {
+    pub pool: Address,
+    pub pool_type: PoolType,
+}
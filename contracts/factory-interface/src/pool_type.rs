@@ -0,0 +1,15 @@
+use soroban_sdk::{self, contracttype};
+
+/// Curve of a pool created by the SoroswapFactory.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PoolType {
+    /// SoroswapPair, `x·y = k`
+    ConstantProduct,
+    /// SoroswapStablePair, StableSwap invariant with an amplification coefficient
+    Stable,
+    /// SoroswapWeightedPair, `x^w_x · y^w_y = k` with weights other than 50/50
+    Weighted,
+    /// SoroswapConcentratedPool with the given fee tier in basis points, liquidity within ranges of ticks
+    Concentrated(u32),
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::{self, contracttype};
+
+/// Role that can be granted by the owner (`fee_to_setter`) of the SoroswapFactory.
+/// A role that has not been granted is held by the owner.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Sets `fee_to`, whether fees are enabled, the protocol fee share and the fee tiers
+    FeeManager,
+    /// Pauses and unpauses pair creation
+    Pauser,
+    /// Sets whether pair creation is permissioned and the allowed tokens
+    PairCreationManager,
+}
@@ -0,0 +1,20 @@
+#![allow(unused)]
+use soroban_sdk::{Address, BytesN, Env};
+use crate::pair::Pair;
+
+soroban_sdk::contractimport!(
+    file = "../concentrated-pool/target/wasm32-unknown-unknown/release/soroswap_concentrated_pool.wasm"
+);
+
+// Deploys a new concentrated pool with the given concentrated_pool_wasm_hash. Its salt depends on the fee tier,
+// so there can be one pool per fee tier alongside the other pairs of the same tokens
+pub fn create_concentrated_contract(
+    e: &Env,
+    concentrated_pool_wasm_hash: BytesN<32>,
+    token_pair: &Pair,
+    fee: u32,
+) -> Address {
+    e.deployer()
+        .with_current_contract(token_pair.concentrated_salt(&e, fee))
+        .deploy(concentrated_pool_wasm_hash)
+}
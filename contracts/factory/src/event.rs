@@ -1,5 +1,6 @@
 //! Definition of the Events used in the contract
-use soroban_sdk::{contracttype, symbol_short, Env, Address};
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN};
+use soroswap_factory_interface::Role;
 
 // INITIALIZED
 #[contracttype]
@@ -43,6 +44,95 @@ pub(crate) fn new_pair(
 }
 
 
+// NEW STABLE PAIR CREATED EVENT: new_stable_pair
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewStablePairEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pair: Address,
+    pub amp: u32,
+    pub new_pairs_length: u32
+}
+
+pub(crate) fn new_stable_pair(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pair: Address,
+    amp: u32,
+    new_pairs_length: u32) {
+
+    let event: NewStablePairEvent = NewStablePairEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pair: pair,
+        amp: amp,
+        new_pairs_length: new_pairs_length,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("new_stbl")), event);
+}
+
+// NEW WEIGHTED PAIR CREATED EVENT: new_weighted_pair
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewWeightedPairEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pair: Address,
+    pub weight_0: u32,
+    pub new_pairs_length: u32
+}
+
+pub(crate) fn new_weighted_pair(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pair: Address,
+    weight_0: u32,
+    new_pairs_length: u32) {
+
+    let event: NewWeightedPairEvent = NewWeightedPairEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pair: pair,
+        weight_0: weight_0,
+        new_pairs_length: new_pairs_length,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("new_wtd")), event);
+}
+
+// NEW CONCENTRATED POOL CREATED EVENT: new_concentrated_pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewConcentratedPoolEvent {
+    pub token_0: Address,
+    pub token_1: Address,
+    pub pool: Address,
+    pub fee: u32,
+    pub tick_spacing: i32,
+    pub new_pairs_length: u32
+}
+
+pub(crate) fn new_concentrated_pool(
+    e: &Env,
+    token_0: Address,
+    token_1: Address,
+    pool: Address,
+    fee: u32,
+    tick_spacing: i32,
+    new_pairs_length: u32) {
+
+    let event: NewConcentratedPoolEvent = NewConcentratedPoolEvent {
+        token_0: token_0,
+        token_1: token_1,
+        pool: pool,
+        fee: fee,
+        tick_spacing: tick_spacing,
+        new_pairs_length: new_pairs_length,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("new_conc")), event);
+}
 
 // NEW "FEE TO" SETTED: new_fee_to // Event is "fee_to"
 #[contracttype]
@@ -106,3 +196,293 @@ pub(crate) fn new_fees_enabled(
     };
     e.events().publish(("SoroswapFactory", symbol_short!("fees")), event);
 }
+
+
+
+// NEW PROTOCOL FEE SHARE
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewProtocolFeeShareEvent {
+    pub numerator: u32,
+    pub denominator: u32
+}
+
+pub(crate) fn new_protocol_fee_share(
+    e: &Env,
+    numerator: u32,
+    denominator: u32) {
+    
+    let event: NewProtocolFeeShareEvent = NewProtocolFeeShareEvent {
+        numerator: numerator,
+        denominator: denominator,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("prot_fee")), event);
+}
+
+
+// FEE TIER ENABLED OR DISABLED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTierEvent {
+    pub fee: u32,
+    pub enabled: bool
+}
+
+pub(crate) fn fee_tier(
+    e: &Env,
+    fee: u32,
+    enabled: bool) {
+    
+    let event: FeeTierEvent = FeeTierEvent {
+        fee: fee,
+        enabled: enabled,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("fee_tier")), event);
+}
+
+
+// PERMISSIONED PAIR CREATION ENABLED OR DISABLED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermissionedCreationEvent {
+    pub enabled: bool
+}
+
+pub(crate) fn permissioned_creation(
+    e: &Env,
+    enabled: bool) {
+
+    let event: PermissionedCreationEvent = PermissionedCreationEvent {
+        enabled: enabled,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("perm_mode")), event);
+}
+
+
+// TOKEN ALLOWED OR DISALLOWED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenAllowedEvent {
+    pub token: Address,
+    pub allowed: bool
+}
+
+pub(crate) fn token_allowed(
+    e: &Env,
+    token: Address,
+    allowed: bool) {
+
+    let event: TokenAllowedEvent = TokenAllowedEvent {
+        token: token,
+        allowed: allowed,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("tkn_allow")), event);
+}
+
+// PAIR CREATION PAUSED OR UNPAUSED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PausedEvent {
+    pub paused: bool
+}
+
+pub(crate) fn paused(
+    e: &Env,
+    paused: bool) {
+
+    let event: PausedEvent = PausedEvent {
+        paused: paused,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("paused")), event);
+}
+
+
+// OWNERSHIP PROPOSED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipProposedEvent {
+    pub owner: Address,
+    pub pending_owner: Address
+}
+
+pub(crate) fn ownership_proposed(
+    e: &Env,
+    owner: Address,
+    pending_owner: Address) {
+
+    let event: OwnershipProposedEvent = OwnershipProposedEvent {
+        owner: owner,
+        pending_owner: pending_owner,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("own_prop")), event);
+}
+
+
+// OWNERSHIP RENOUNCED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipRenouncedEvent {
+    pub previous_owner: Address
+}
+
+pub(crate) fn ownership_renounced(
+    e: &Env,
+    previous_owner: Address) {
+
+    let event: OwnershipRenouncedEvent = OwnershipRenouncedEvent {
+        previous_owner: previous_owner,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("own_renon")), event);
+}
+
+
+// ROLE GRANTED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGrantedEvent {
+    pub role: Role,
+    pub account: Address
+}
+
+pub(crate) fn role_granted(
+    e: &Env,
+    role: Role,
+    account: Address) {
+
+    let event: RoleGrantedEvent = RoleGrantedEvent {
+        role: role,
+        account: account,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("role_grnt")), event);
+}
+
+
+// ROLE REVOKED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevokedEvent {
+    pub role: Role,
+    pub account: Address
+}
+
+pub(crate) fn role_revoked(
+    e: &Env,
+    role: Role,
+    account: Address) {
+
+    let event: RoleRevokedEvent = RoleRevokedEvent {
+        role: role,
+        account: account,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("role_rvk")), event);
+}
+
+// NEW PAIR WASM HASH
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewPairWasmHashEvent {
+    pub pair_wasm_hash: BytesN<32>
+}
+
+pub(crate) fn new_pair_wasm_hash(
+    e: &Env,
+    pair_wasm_hash: BytesN<32>) {
+    
+    let event: NewPairWasmHashEvent = NewPairWasmHashEvent {
+        pair_wasm_hash: pair_wasm_hash,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("pair_hash")), event);
+}
+
+// NEW STABLE PAIR WASM HASH
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewStablePairWasmHashEvent {
+    pub stable_pair_wasm_hash: BytesN<32>
+}
+
+pub(crate) fn new_stable_pair_wasm_hash(
+    e: &Env,
+    stable_pair_wasm_hash: BytesN<32>) {
+
+    let event: NewStablePairWasmHashEvent = NewStablePairWasmHashEvent {
+        stable_pair_wasm_hash: stable_pair_wasm_hash,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("stbl_hash")), event);
+}
+
+// NEW WEIGHTED PAIR WASM HASH
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewWeightedPairWasmHashEvent {
+    pub weighted_pair_wasm_hash: BytesN<32>
+}
+
+pub(crate) fn new_weighted_pair_wasm_hash(
+    e: &Env,
+    weighted_pair_wasm_hash: BytesN<32>) {
+
+    let event: NewWeightedPairWasmHashEvent = NewWeightedPairWasmHashEvent {
+        weighted_pair_wasm_hash: weighted_pair_wasm_hash,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("wtd_hash")), event);
+}
+
+// NEW CONCENTRATED POOL WASM HASH
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NewConcentratedPoolWasmHashEvent {
+    pub concentrated_pool_wasm_hash: BytesN<32>
+}
+
+pub(crate) fn new_concentrated_pool_wasm_hash(
+    e: &Env,
+    concentrated_pool_wasm_hash: BytesN<32>) {
+
+    let event: NewConcentratedPoolWasmHashEvent = NewConcentratedPoolWasmHashEvent {
+        concentrated_pool_wasm_hash: concentrated_pool_wasm_hash,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("conc_hash")), event);
+}
+
+
+// UPGRADE
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    pub new_wasm_hash: BytesN<32>
+}
+
+pub(crate) fn upgrade(
+    e: &Env,
+    new_wasm_hash: BytesN<32>) {
+    
+    let event: UpgradeEvent = UpgradeEvent {
+        new_wasm_hash: new_wasm_hash,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("upgrade")), event);
+}
+
+
+// UPGRADE PAIR
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradePairEvent {
+    pub pair: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub version: u32
+}
+
+pub(crate) fn upgrade_pair(
+    e: &Env,
+    pair: Address,
+    new_wasm_hash: BytesN<32>,
+    version: u32) {
+    
+    let event: UpgradePairEvent = UpgradePairEvent {
+        pair: pair,
+        new_wasm_hash: new_wasm_hash,
+        version: version,
+    };
+    e.events().publish(("SoroswapFactory", symbol_short!("upg_pair")), event);
+}
@@ -2,15 +2,21 @@
 
 mod event;
 mod pair;
+mod stable_pair;
+mod weighted_pair;
+mod concentrated_pool;
 mod test;
 
 use soroban_sdk::{
     contract,
     contractimpl,
-    contracttype, Address, BytesN, Env,
+    contracttype, Address, BytesN, Env, Vec,
 };
-use soroswap_factory_interface::{SoroswapFactoryTrait, FactoryError};
+use soroswap_factory_interface::{SoroswapFactoryTrait, FactoryError, PoolType, PairInfo, TokenPool, Role};
 use pair::{create_contract, Pair, PairError};
+use stable_pair::create_stable_contract;
+use weighted_pair::create_weighted_contract;
+use concentrated_pool::create_concentrated_contract;
 
 impl From<PairError> for FactoryError {
     fn from(pair_error: PairError) -> Self {
@@ -31,11 +37,60 @@ pub enum DataKey {
     FeesEnabled, // Bool. Instance storage
     TotalPairs, // Total pairs created by the Factory. u32, Instance storage
     PairAddressesNIndexed(u32), // Addresses of pairs created by the Factory. Persistent Storage
-    PairAddressesByTokens(Pair)
+    PairAddressesByTokens(Pair),
+    FeeTierEnabled(u32), // Bool. Whether pairs can be created with this swap fee (in basis points). Instance storage
+    ProtocolFeeShare, // (u32, u32). Share of the LP fee growth minted to FeeTo. Instance storage
+    StablePairWasmHash, // BytesN<32>. Persistent storage
+    StablePairAddressesByTokens(Pair), // Address. Persistent storage
+    PoolTypeNIndexed(u32), // PoolType of the nth pair, only stored when it is not ConstantProduct. Persistent storage
+    WeightedPairWasmHash, // BytesN<32>. Persistent storage
+    WeightedPairAddressesByTokens(Pair), // Address. Persistent storage
+    ConcentratedPoolWasmHash, // BytesN<32>. Persistent storage
+    ConcentratedPoolAddresses(Pair, u32), // Address of the concentrated pool of a fee tier. Persistent storage
+    TokenPairsLength(Address), // u32. Number of pools of every type containing a token. Persistent storage
+    TokenPairsNIndexed(Address, u32), // TokenPool. Address and pool type of the nth pool containing a token. Persistent storage
+    PermissionedCreation, // Bool. Whether pair creation is restricted to allowed tokens. Instance storage
+    AllowedToken(Address), // Bool. Only stored while the token is allowed. Persistent storage
+    PendingOwner, // Address. Proposed owner until it accepts the ownership. Instance storage
+    RoleHolder(Role), // Address. Only stored while the role is granted to an account other than the owner. Instance storage
+    Paused, // Bool. Whether pair creation is paused. Instance storage
 }
 
+// By default the protocol takes 1/6 of the LP fee growth
+const DEFAULT_PROTOCOL_FEE_SHARE: (u32, u32) = (1, 6);
+
+// Swap fee of the pairs created through `create_pair`, in basis points
+const DEFAULT_FEE: u32 = 30;
+// Fee tiers enabled at initialization: 0.05%, 0.3% and 1%
+const INITIAL_FEE_TIERS: [u32; 3] = [5, 30, 100];
+const FEE_DENOMINATOR: u32 = 10000;
+
+// Swap fee of the stable pairs, in basis points
+const STABLE_FEE: u32 = 5;
+// Maximum amplification coefficient of a stable pair
+const MAX_AMP: u32 = 10_000;
+// Minimum weight of a token of a weighted pair, in basis points of FEE_DENOMINATOR
+const MIN_WEIGHT: u32 = 100;
+// Tick spacing of a concentrated pool per basis point of its fee, so pools with higher fees have coarser ranges
+const TICK_SPACING_PER_FEE: i32 = 2;
+
+// Time to live of the factory storage, in ledgers
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
 //// --- Storage helper functions ---
 
+// Instance storage and contract code
+fn extend_instance_ttl(e: &Env) {
+    e.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+fn extend_persistent_ttl(e: &Env, key: &DataKey) {
+    e.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
 // TotalPairs
 fn put_total_pairs(e: &Env, n: u32) {
     e.storage().instance().set(&DataKey::TotalPairs, &n);
@@ -46,16 +101,21 @@ fn get_total_pairs(e: &Env) -> u32 {
 
 // PairAddressesByTokens(Address, Address)
 fn put_pair_address_by_token_pair(e: &Env, token_pair: Pair, pair_address: &Address) {
+    let key = DataKey::PairAddressesByTokens(token_pair);
     e.storage()
         .persistent()
-        .set(&DataKey::PairAddressesByTokens(token_pair), &pair_address)
+        .set(&key, &pair_address);
+    extend_persistent_ttl(e, &key);
 }
 fn get_pair_address_by_token_pair(e: &Env, token_pair: Pair) -> Result<Address, FactoryError> {
+    let key = DataKey::PairAddressesByTokens(token_pair);
     // Note: Using unwrap_or_else() can be more efficient because it only evaluates the closure when it is necessary, whereas unwrap_or() always evaluates the default value expression.
-    e.storage()
+    let pair_address = e.storage()
         .persistent()
-        .get(&DataKey::PairAddressesByTokens(token_pair))
-        .ok_or(FactoryError::PairDoesNotExist)
+        .get(&key)
+        .ok_or(FactoryError::PairDoesNotExist)?;
+    extend_persistent_ttl(e, &key);
+    Ok(pair_address)
 }
 fn get_pair_exists(e: &Env, token_pair: Pair) -> bool {
     e.storage()
@@ -77,15 +137,69 @@ fn get_fees_enabled(e: &Env) -> bool {
     }
 }
 
-fn get_fee_to_setter(e: &Env) -> Address {
-    e.storage().instance().get(&DataKey::FeeToSetter).unwrap()
+fn get_protocol_fee_share(e: &Env) -> (u32, u32) {
+    e.storage().instance().get(&DataKey::ProtocolFeeShare).unwrap_or(DEFAULT_PROTOCOL_FEE_SHARE)
+}
+
+// The fee_to_setter is the owner of the factory. It is removed when the ownership is renounced
+fn get_owner(e: &Env) -> Result<Address, FactoryError> {
+    e.storage().instance().get(&DataKey::FeeToSetter).ok_or(FactoryError::OwnershipRenounced)
+}
+
+fn require_owner(e: &Env) -> Result<Address, FactoryError> {
+    let owner = get_owner(e)?;
+    owner.require_auth();
+    Ok(owner)
+}
+
+// A role that has not been granted is held by the owner
+fn get_role_holder(e: &Env, role: Role) -> Result<Address, FactoryError> {
+    match e.storage().instance().get(&DataKey::RoleHolder(role)) {
+        Some(account) => Ok(account),
+        None => get_owner(e),
+    }
+}
+
+fn require_role(e: &Env, role: Role) -> Result<(), FactoryError> {
+    get_role_holder(e, role)?.require_auth();
+    Ok(())
+}
+
+fn put_role_holder(e: &Env, role: Role, account: &Address) {
+    e.storage().instance().set(&DataKey::RoleHolder(role), account);
+}
+
+fn remove_role_holder(e: &Env, role: Role) {
+    e.storage().instance().remove(&DataKey::RoleHolder(role));
+}
+
+fn get_pending_owner(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&DataKey::PendingOwner)
+}
+
+fn put_pending_owner(e: &Env, pending_owner: &Address) {
+    e.storage().instance().set(&DataKey::PendingOwner, pending_owner);
+}
+
+fn remove_pending_owner(e: &Env) {
+    e.storage().instance().remove(&DataKey::PendingOwner);
+}
+
+fn get_paused(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+fn put_paused(e: &Env, is_paused: &bool) {
+    e.storage().instance().set(&DataKey::Paused, is_paused);
 }
 
 
 
 
 fn get_pair_wasm_hash(e: &Env) -> BytesN<32> {
-    e.storage().persistent().get(&DataKey::PairWasmHash).unwrap()
+    let pair_wasm_hash = e.storage().persistent().get(&DataKey::PairWasmHash).unwrap();
+    extend_persistent_ttl(e, &DataKey::PairWasmHash);
+    pair_wasm_hash
 }
 
 fn put_fee_to(e: &Env, to: Address) {
@@ -100,8 +214,21 @@ fn put_fees_enabled(e: &Env, is_enabled: &bool) {
     e.storage().instance().set(&DataKey::FeesEnabled, is_enabled);
 }
 
+fn get_fee_tier_enabled(e: &Env, fee: u32) -> bool {
+    e.storage().instance().get(&DataKey::FeeTierEnabled(fee)).unwrap_or(false)
+}
+
+fn put_fee_tier_enabled(e: &Env, fee: u32, is_enabled: &bool) {
+    e.storage().instance().set(&DataKey::FeeTierEnabled(fee), is_enabled);
+}
+
+fn put_protocol_fee_share(e: &Env, share: &(u32, u32)) {
+    e.storage().instance().set(&DataKey::ProtocolFeeShare, share);
+}
+
 fn put_pair_wasm_hash(e: &Env, pair_wasm_hash: BytesN<32>) {
-    e.storage().persistent().set(&DataKey::PairWasmHash, &pair_wasm_hash)
+    e.storage().persistent().set(&DataKey::PairWasmHash, &pair_wasm_hash);
+    extend_persistent_ttl(e, &DataKey::PairWasmHash);
 }
 
 
@@ -110,12 +237,217 @@ pub fn has_pair_wasm_hash(e: &Env) -> bool {
     e.storage().persistent().has(&DataKey::PairWasmHash)
 }
 
-fn add_pair_to_all_pairs(e: &Env, pair_address: &Address) {
+// StablePairAddressesByTokens(Pair)
+fn put_stable_pair_address_by_token_pair(e: &Env, token_pair: Pair, pair_address: &Address) {
+    let key = DataKey::StablePairAddressesByTokens(token_pair);
+    e.storage().persistent().set(&key, &pair_address);
+    extend_persistent_ttl(e, &key);
+}
+fn get_stable_pair_address_by_token_pair(e: &Env, token_pair: Pair) -> Result<Address, FactoryError> {
+    let key = DataKey::StablePairAddressesByTokens(token_pair);
+    let pair_address = e.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(FactoryError::PairDoesNotExist)?;
+    extend_persistent_ttl(e, &key);
+    Ok(pair_address)
+}
+fn get_stable_pair_exists(e: &Env, token_pair: Pair) -> bool {
+    e.storage()
+        .persistent().has(&DataKey::StablePairAddressesByTokens(token_pair))
+}
+
+fn has_stable_pair_wasm_hash(e: &Env) -> bool {
+    e.storage().persistent().has(&DataKey::StablePairWasmHash)
+}
+
+fn get_stable_pair_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError> {
+    let stable_pair_wasm_hash = e.storage().persistent().get(&DataKey::StablePairWasmHash).ok_or(FactoryError::StablePairWasmHashNotSet)?;
+    extend_persistent_ttl(e, &DataKey::StablePairWasmHash);
+    Ok(stable_pair_wasm_hash)
+}
+
+fn put_stable_pair_wasm_hash(e: &Env, stable_pair_wasm_hash: BytesN<32>) {
+    e.storage().persistent().set(&DataKey::StablePairWasmHash, &stable_pair_wasm_hash);
+    extend_persistent_ttl(e, &DataKey::StablePairWasmHash);
+}
+
+// WeightedPairAddressesByTokens(Pair)
+fn put_weighted_pair_address_by_token_pair(e: &Env, token_pair: Pair, pair_address: &Address) {
+    let key = DataKey::WeightedPairAddressesByTokens(token_pair);
+    e.storage().persistent().set(&key, &pair_address);
+    extend_persistent_ttl(e, &key);
+}
+fn get_weighted_pair_address_by_token_pair(e: &Env, token_pair: Pair) -> Result<Address, FactoryError> {
+    let key = DataKey::WeightedPairAddressesByTokens(token_pair);
+    let pair_address = e.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(FactoryError::PairDoesNotExist)?;
+    extend_persistent_ttl(e, &key);
+    Ok(pair_address)
+}
+fn get_weighted_pair_exists(e: &Env, token_pair: Pair) -> bool {
+    e.storage()
+        .persistent().has(&DataKey::WeightedPairAddressesByTokens(token_pair))
+}
+
+fn has_weighted_pair_wasm_hash(e: &Env) -> bool {
+    e.storage().persistent().has(&DataKey::WeightedPairWasmHash)
+}
+
+fn get_weighted_pair_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError> {
+    let weighted_pair_wasm_hash = e.storage().persistent().get(&DataKey::WeightedPairWasmHash).ok_or(FactoryError::WeightedPairWasmHashNotSet)?;
+    extend_persistent_ttl(e, &DataKey::WeightedPairWasmHash);
+    Ok(weighted_pair_wasm_hash)
+}
+
+fn put_weighted_pair_wasm_hash(e: &Env, weighted_pair_wasm_hash: BytesN<32>) {
+    e.storage().persistent().set(&DataKey::WeightedPairWasmHash, &weighted_pair_wasm_hash);
+    extend_persistent_ttl(e, &DataKey::WeightedPairWasmHash);
+}
+
+// ConcentratedPoolAddresses(Pair, u32)
+fn put_concentrated_pool_address(e: &Env, token_pair: Pair, fee: u32, pool_address: &Address) {
+    let key = DataKey::ConcentratedPoolAddresses(token_pair, fee);
+    e.storage().persistent().set(&key, &pool_address);
+    extend_persistent_ttl(e, &key);
+}
+fn get_concentrated_pool_address(e: &Env, token_pair: Pair, fee: u32) -> Result<Address, FactoryError> {
+    let key = DataKey::ConcentratedPoolAddresses(token_pair, fee);
+    let pool_address = e.storage()
+        .persistent()
+        .get(&key)
+        .ok_or(FactoryError::PairDoesNotExist)?;
+    extend_persistent_ttl(e, &key);
+    Ok(pool_address)
+}
+fn get_concentrated_pool_exists(e: &Env, token_pair: Pair, fee: u32) -> bool {
+    e.storage()
+        .persistent().has(&DataKey::ConcentratedPoolAddresses(token_pair, fee))
+}
+
+fn has_concentrated_pool_wasm_hash(e: &Env) -> bool {
+    e.storage().persistent().has(&DataKey::ConcentratedPoolWasmHash)
+}
+
+fn get_concentrated_pool_wasm_hash(e: &Env) -> Result<BytesN<32>, FactoryError> {
+    let concentrated_pool_wasm_hash = e.storage().persistent().get(&DataKey::ConcentratedPoolWasmHash).ok_or(FactoryError::ConcentratedPoolWasmHashNotSet)?;
+    extend_persistent_ttl(e, &DataKey::ConcentratedPoolWasmHash);
+    Ok(concentrated_pool_wasm_hash)
+}
+
+fn put_concentrated_pool_wasm_hash(e: &Env, concentrated_pool_wasm_hash: BytesN<32>) {
+    e.storage().persistent().set(&DataKey::ConcentratedPoolWasmHash, &concentrated_pool_wasm_hash);
+    extend_persistent_ttl(e, &DataKey::ConcentratedPoolWasmHash);
+}
+
+// PairAddressesNIndexed(u32)
+fn get_pair_address_n_indexed(e: &Env, n: u32) -> Result<Address, FactoryError> {
+    let key = DataKey::PairAddressesNIndexed(n);
+    let pair_address = e.storage().persistent().get(&key).ok_or(FactoryError::IndexDoesNotExist)?;
+    extend_persistent_ttl(e, &key);
+    Ok(pair_address)
+}
+
+// PoolTypeNIndexed(u32)
+fn get_pool_type_n_indexed(e: &Env, n: u32) -> PoolType {
+    let key = DataKey::PoolTypeNIndexed(n);
+    match e.storage().persistent().get(&key) {
+        Some(pool_type) => {
+            extend_persistent_ttl(e, &key);
+            pool_type
+        }
+        None => PoolType::ConstantProduct,
+    }
+}
+
+// TokenPairsLength(Address) and TokenPairsNIndexed(Address, u32)
+fn get_token_pairs_length(e: &Env, token: &Address) -> u32 {
+    let key = DataKey::TokenPairsLength(token.clone());
+    match e.storage().persistent().get(&key) {
+        Some(length) => {
+            extend_persistent_ttl(e, &key);
+            length
+        }
+        None => 0,
+    }
+}
+
+fn get_token_pair_n_indexed(e: &Env, token: &Address, n: u32) -> Result<TokenPool, FactoryError> {
+    let key = DataKey::TokenPairsNIndexed(token.clone(), n);
+    let token_pool = e.storage().persistent().get(&key).ok_or(FactoryError::IndexDoesNotExist)?;
+    extend_persistent_ttl(e, &key);
+    Ok(token_pool)
+}
+
+fn add_pair_to_token_pairs(e: &Env, token: &Address, pair_address: &Address, pool_type: PoolType) {
+    let length = get_token_pairs_length(e, token);
+
+    let key = DataKey::TokenPairsNIndexed(token.clone(), length);
+    e.storage().persistent().set(&key, &TokenPool { pool: pair_address.clone(), pool_type });
+    extend_persistent_ttl(e, &key);
+
+    let key = DataKey::TokenPairsLength(token.clone());
+    e.storage().persistent().set(&key, &length.checked_add(1).unwrap());
+    extend_persistent_ttl(e, &key);
+}
+
+// PermissionedCreation and AllowedToken(Address)
+fn get_permissioned_creation(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::PermissionedCreation).unwrap_or(false) // Open by default
+}
+
+fn put_permissioned_creation(e: &Env, is_enabled: &bool) {
+    e.storage().instance().set(&DataKey::PermissionedCreation, is_enabled);
+}
+
+fn get_token_allowed(e: &Env, token: &Address) -> bool {
+    let key = DataKey::AllowedToken(token.clone());
+    let is_allowed = e.storage().persistent().has(&key);
+    if is_allowed {
+        extend_persistent_ttl(e, &key);
+    }
+    is_allowed
+}
+
+fn put_token_allowed(e: &Env, token: &Address, is_allowed: bool) {
+    let key = DataKey::AllowedToken(token.clone());
+    if is_allowed {
+        e.storage().persistent().set(&key, &true);
+        extend_persistent_ttl(e, &key);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+// No pair can be created while paused, and while pair creation is permissioned both tokens of a new pair must be allowed
+fn check_pair_creation_allowed(e: &Env, token_pair: &Pair) -> Result<(), FactoryError> {
+    if get_paused(e) {
+        return Err(FactoryError::CreatePairPaused);
+    }
+    if get_permissioned_creation(e)
+        && !(get_token_allowed(e, token_pair.token_0()) && get_token_allowed(e, token_pair.token_1())) {
+        return Err(FactoryError::CreatePairTokenNotAllowed);
+    }
+    Ok(())
+}
+
+fn add_pair_to_all_pairs(e: &Env, pair_address: &Address, pool_type: PoolType) {
     // total_pairs is the total amount of pairs created by the Factory
     let mut total_pairs = get_total_pairs(e);
     // Because PairAddressesNIndexed is 0-indexed, we start with 0, default value of total_pairs
 
-    e.storage().persistent().set(&DataKey::PairAddressesNIndexed(total_pairs), &pair_address);
+    let key = DataKey::PairAddressesNIndexed(total_pairs);
+    e.storage().persistent().set(&key, &pair_address);
+    extend_persistent_ttl(e, &key);
+
+    // Constant product pairs are not tagged, so the pairs created before stable pairs existed keep their type
+    if pool_type != PoolType::ConstantProduct {
+        let key = DataKey::PoolTypeNIndexed(total_pairs);
+        e.storage().persistent().set(&key, &pool_type);
+        extend_persistent_ttl(e, &key);
+    }
 
     total_pairs = total_pairs.checked_add(1).unwrap();
     put_total_pairs(&e, total_pairs);
@@ -145,7 +477,7 @@ fn fee_to(e: Env) -> Result<Address, FactoryError> {
     Ok(get_fee_to(&e))
 }
 
-/// Returns the address allowed to change the fee recipient.
+/// Returns the owner of the factory, that grants the roles and holds the ones that have not been granted.
 /// 
 /// # Arguments
 /// 
@@ -153,12 +485,61 @@ fn fee_to(e: Env) -> Result<Address, FactoryError> {
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is not yet initialized.
+/// Returns an error if the Factory is not yet initialized or if the ownership has been renounced.
 fn fee_to_setter(e: Env) -> Result<Address, FactoryError> {
     if !has_pair_wasm_hash(&e) {
         return Err(FactoryError::NotInitialized);
     }
-    Ok(get_fee_to_setter(&e))
+    get_owner(&e)
+}
+
+/// Returns the address proposed as the new owner, if it has not accepted the ownership yet.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn pending_owner(e: Env) -> Result<Option<Address>, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    Ok(get_pending_owner(&e))
+}
+
+/// Returns the address holding `role`, which is the owner if the role has not been granted.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `role` - The role.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, or if the role has not been granted and the ownership has been renounced.
+fn role_holder(e: Env, role: Role) -> Result<Address, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    get_role_holder(&e, role)
+}
+
+/// Returns a boolean indicating if pair creation is paused.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn paused(e: Env) -> Result<bool, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    Ok(get_paused(&e))
 }
 
 /// Checks if fees are enabled.
@@ -177,6 +558,26 @@ fn fees_enabled(e: Env) -> Result<bool, FactoryError> {
     Ok(get_fees_enabled(&e))
 }
 
+/// Returns the share of the liquidity providers fee growth minted to `fee_to` when fees are enabled.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Returns
+/// 
+/// A `(numerator, denominator)` tuple. Defaults to 1/6.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn protocol_fee_share(e: Env) -> Result<(u32, u32), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    Ok(get_protocol_fee_share(&e))
+}
+
 /// Returns the total number of pairs created through the factory so far.
 /// 
 /// # Arguments
@@ -226,8 +627,71 @@ fn all_pairs(e: Env, n: u32) -> Result<Address, FactoryError> {
     if !has_pair_wasm_hash(&e) {
         return Err(FactoryError::NotInitialized);
     }
-    e.storage().persistent().get(&DataKey::PairAddressesNIndexed(n)).ok_or(FactoryError::IndexDoesNotExist)
+    get_pair_address_n_indexed(&e, n)
+}
+
+/// Returns the addresses of the pairs created through the factory from index `start`, up to `limit` pairs.
+/// The result is shorter than `limit`, or empty, past the last pair.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `start` - The index of the first pair.
+/// * `limit` - The maximum number of pairs.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn all_pairs_range(e: Env, start: u32, limit: u32) -> Result<Vec<Address>, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    let end = start.saturating_add(limit).min(get_total_pairs(&e));
+    let mut pairs = Vec::new(&e);
+    for n in start..end {
+        pairs.push_back(get_pair_address_n_indexed(&e, n)?);
+    }
+    Ok(pairs)
+}
 
+/// Returns the tokens, reserves and total shares of the pairs created through the factory from index `start`,
+/// up to `limit` pairs. The result is shorter than `limit`, or empty, past the last pair.
+/// Concentrated pools have no shares, so their `total_shares` is 0.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `start` - The index of the first pair.
+/// * `limit` - The maximum number of pairs.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn pairs_info(e: Env, start: u32, limit: u32) -> Result<Vec<PairInfo>, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    let end = start.saturating_add(limit).min(get_total_pairs(&e));
+    let mut pairs_info = Vec::new(&e);
+    for n in start..end {
+        let pair_address = get_pair_address_n_indexed(&e, n)?;
+        // Every curve shares these read-only functions with the constant product pairs
+        let pair_client = pair::Client::new(&e, &pair_address);
+        let (reserve_0, reserve_1) = pair_client.get_reserves();
+        let total_shares = match get_pool_type_n_indexed(&e, n) {
+            PoolType::Concentrated(_) => 0,
+            _ => pair_client.total_shares(),
+        };
+        pairs_info.push_back(PairInfo {
+            pair: pair_address.clone(),
+            token_0: pair_client.token_0(),
+            token_1: pair_client.token_1(),
+            reserve_0,
+            reserve_1,
+            total_shares,
+        });
+    }
+    Ok(pairs_info)
 }
 
 /// Checks if a pair exists for the given `token_a` and `token_b`.
@@ -252,102 +716,90 @@ fn pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, Facto
     Ok(get_pair_exists(&e, token_pair))
 }
 
+/// Checks if pairs can be created with a swap fee of `fee` basis points.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `fee` - The swap fee, in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn fee_tier_enabled(e: Env, fee: u32) -> Result<bool, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    Ok(get_fee_tier_enabled(&e, fee))
+}
 
-/* *** State-Changing Functions: *** */
 
-/// Sets the `fee_to_setter` address and initializes the factory.
+/// Returns a boolean indicating if pair creation is restricted to allowed tokens. It is open by default.
 /// 
 /// # Arguments
 /// 
 /// * `e` - An instance of the `Env` struct.
-/// * `setter` - The address to set as the current `fee_to_setter`.
-/// * `pair_wasm_hash` - The Wasm hash of the pair.
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is already initialized.
-fn initialize(e: Env, setter: Address, pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
-    if has_pair_wasm_hash(&e) {
-        return Err(FactoryError::InitializeAlreadyInitialized);
+/// Returns an error if the Factory is not yet initialized.
+fn permissioned_creation(e: Env) -> Result<bool, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
     }
-    put_fee_to_setter(&e, &setter);
-    put_fee_to(&e, setter.clone());
-    put_pair_wasm_hash(&e, pair_wasm_hash);
-    event::initialized(&e, setter);
-    Ok(())
+    Ok(get_permissioned_creation(&e))
 }
 
-/// Sets the `fee_to` address.
+/// Returns a boolean indicating if `token` is allowed in new pairs while pair creation is permissioned.
 /// 
 /// # Arguments
 /// 
 /// * `e` - An instance of the `Env` struct.
-/// * `to` - The address to set as the `fee_to`.
+/// * `token` - The address of the token.
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
-fn set_fee_to(e: Env, to: Address) -> Result<(), FactoryError> {
+/// Returns an error if the Factory is not yet initialized.
+fn token_allowed(e: Env, token: Address) -> Result<bool, FactoryError> {
     if !has_pair_wasm_hash(&e) {
         return Err(FactoryError::NotInitialized);
     }
-    
-    let setter = get_fee_to_setter(&e);
-    setter.require_auth();
-
-    let old = get_fee_to(&e);
-    put_fee_to(&e, to.clone());
-    event::new_fee_to(&e, setter, old, to);
-    Ok(())
+    Ok(get_token_allowed(&e, &token))
 }
 
-/// Sets the `fee_to_setter` address.
+/// Returns the Wasm hash used to deploy new pairs.
 /// 
 /// # Arguments
 /// 
 /// * `e` - An instance of the `Env` struct.
-/// * `new_setter` - The address to set as the new `fee_to_setter`.
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is not yet initialized or if the caller is not the existing `fee_to_setter`.
-fn set_fee_to_setter(e: Env, new_setter: Address) -> Result<(), FactoryError> {
+/// Returns an error if the Factory is not yet initialized.
+fn pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError> {
     if !has_pair_wasm_hash(&e) {
         return Err(FactoryError::NotInitialized);
     }
-
-    let setter = get_fee_to_setter(&e);
-    setter.require_auth();
-
-    put_fee_to_setter(&e, &new_setter);
-    event::new_setter(&e, setter, new_setter);
-    Ok(())
+    Ok(get_pair_wasm_hash(&e))
 }
 
-/// Sets whether fees are enabled or disabled.
+/// Returns the Wasm hash used to deploy new stable pairs.
 /// 
 /// # Arguments
 /// 
 /// * `e` - An instance of the `Env` struct.
-/// * `is_enabled` - A boolean indicating whether fees are enabled or disabled.
 /// 
 /// # Errors
 /// 
-/// Returns an error if the Factory is not yet initialized or if the caller is not the current `fee_to_setter`.
-fn set_fees_enabled(e: Env, is_enabled: bool) -> Result<(), FactoryError> {
+/// Returns an error if the Factory is not yet initialized or if the stable pair Wasm hash has not been set.
+fn stable_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError> {
     if !has_pair_wasm_hash(&e) {
         return Err(FactoryError::NotInitialized);
     }
-
-    let setter = get_fee_to_setter(&e);
-    setter.require_auth();
-
-    put_fees_enabled(&e, &is_enabled);
-    event::new_fees_enabled(&e, is_enabled);
-    Ok(())
+    get_stable_pair_wasm_hash(&e)
 }
 
-/// Creates a pair for `token_a` and `token_b` if one doesn't exist already.
+/// Returns the address of the stable pair for `token_a` and `token_b`, if it has been created.
 /// 
 /// # Arguments
 /// 
@@ -357,34 +809,1056 @@ fn set_fees_enabled(e: Env, is_enabled: bool) -> Result<(), FactoryError> {
 /// 
 /// # Errors
 /// 
-/// Returns an error if the pair is not yet initialized, if `token_a` and `token_b` have identical addresses, or if the pair already exists between `token_a` and `token_b`.
-fn create_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
+/// Returns an error if the Factory is not yet initialized or if the stable pair does not exist
+fn get_stable_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
     if !has_pair_wasm_hash(&e) {
         return Err(FactoryError::NotInitialized);
     }
-
     let token_pair = Pair::new(token_a, token_b)?;
+    get_stable_pair_address_by_token_pair(&e, token_pair)
+}
 
-    if get_pair_exists(&e, token_pair.clone()) {
-        return Err(FactoryError::CreatePairAlreadyExists);
-    }
-
-    let pair_wasm_hash = get_pair_wasm_hash(&e);
-    let pair_address = create_contract(&e, pair_wasm_hash, &token_pair);
+/// Checks if a stable pair exists for the given `token_a` and `token_b`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn stable_pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    Ok(get_stable_pair_exists(&e, token_pair))
+}
+
+/// Returns the Wasm hash used to deploy new weighted pairs.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the weighted pair Wasm hash has not been set.
+fn weighted_pair_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    get_weighted_pair_wasm_hash(&e)
+}
+
+/// Returns the address of the weighted pair for `token_a` and `token_b`, if it has been created.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the weighted pair does not exist
+fn get_weighted_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    get_weighted_pair_address_by_token_pair(&e, token_pair)
+}
+
+/// Checks if a weighted pair exists for the given `token_a` and `token_b`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn weighted_pair_exists(e: Env, token_a: Address, token_b: Address) -> Result<bool, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    Ok(get_weighted_pair_exists(&e, token_pair))
+}
+
+/// Returns the Wasm hash used to deploy new concentrated pools.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the concentrated pool Wasm hash has not been set.
+fn concentrated_pool_wasm_hash(e: Env) -> Result<BytesN<32>, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    get_concentrated_pool_wasm_hash(&e)
+}
+
+/// Returns the address of the concentrated pool for `token_a` and `token_b` in the `fee` tier, if it has been created.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pool.
+/// * `token_b` - The address of the second token in the pool.
+/// * `fee` - The fee tier of the pool, in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the concentrated pool does not exist
+fn get_concentrated_pool(e: Env, token_a: Address, token_b: Address, fee: u32) -> Result<Address, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    get_concentrated_pool_address(&e, token_pair, fee)
+}
+
+/// Checks if a concentrated pool exists for the given `token_a` and `token_b` in the `fee` tier.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pool.
+/// * `token_b` - The address of the second token in the pool.
+/// * `fee` - The fee tier of the pool, in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn concentrated_pool_exists(e: Env, token_a: Address, token_b: Address, fee: u32) -> Result<bool, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    Ok(get_concentrated_pool_exists(&e, token_pair, fee))
+}
+
+/// Returns the number of pools of every type created through the factory that contain `token`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token` - The address of the token.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn pairs_for_token_length(e: Env, token: Address) -> Result<u32, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    Ok(get_token_pairs_length(&e, &token))
+}
+
+/// Returns the addresses and pool types of the pools that contain `token`, in order of creation, from index `start`
+/// up to `limit` pools. The result is shorter than `limit`, or empty, past the last pool.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token` - The address of the token.
+/// * `start` - The index of the first pair of the token.
+/// * `limit` - The maximum number of pairs.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn pairs_for_token(e: Env, token: Address, start: u32, limit: u32) -> Result<Vec<TokenPool>, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    let end = start.saturating_add(limit).min(get_token_pairs_length(&e, &token));
+    let mut pairs = Vec::new(&e);
+    for n in start..end {
+        pairs.push_back(get_token_pair_n_indexed(&e, &token, n)?);
+    }
+    Ok(pairs)
+}
+
+/// Returns the curve of the nth pair (0-indexed) created through the factory.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `n` - The index of the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if index `n` does not exist.
+fn all_pairs_pool_type(e: Env, n: u32) -> Result<PoolType, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    if n >= get_total_pairs(&e) {
+        return Err(FactoryError::IndexDoesNotExist);
+    }
+    Ok(get_pool_type_n_indexed(&e, n))
+}
+
+/* *** State-Changing Functions: *** */
+
+/// Sets the `fee_to_setter` address and initializes the factory.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `setter` - The address to set as the current `fee_to_setter`.
+/// * `pair_wasm_hash` - The Wasm hash of the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is already initialized.
+fn initialize(e: Env, setter: Address, pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if has_pair_wasm_hash(&e) {
+        return Err(FactoryError::InitializeAlreadyInitialized);
+    }
+    put_fee_to_setter(&e, &setter);
+    put_fee_to(&e, setter.clone());
+    put_pair_wasm_hash(&e, pair_wasm_hash);
+    for fee in INITIAL_FEE_TIERS {
+        put_fee_tier_enabled(&e, fee, &true);
+    }
+    extend_instance_ttl(&e);
+    event::initialized(&e, setter);
+    Ok(())
+}
+
+/// Sets the `fee_to` address.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `to` - The address to set as the `fee_to`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the fee manager.
+fn set_fee_to(e: Env, to: Address) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    
+    let setter = get_role_holder(&e, Role::FeeManager)?;
+    setter.require_auth();
+
+    let old = get_fee_to(&e);
+    put_fee_to(&e, to.clone());
+    event::new_fee_to(&e, setter, old, to);
+    Ok(())
+}
+
+/// Proposes `new_setter` as the new `fee_to_setter`. Same as `propose_owner`: the ownership is only transferred
+/// once `new_setter` calls `accept_ownership`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `new_setter` - The address proposed as the new `fee_to_setter`.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn set_fee_to_setter(e: Env, new_setter: Address) -> Result<(), FactoryError> {
+    Self::propose_owner(e, new_setter)
+}
+
+/// Proposes `new_owner` as the owner of the factory. The ownership is transferred once `new_owner` calls `accept_ownership`.
+/// A new proposal replaces the previous one.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `new_owner` - The address proposed as the new owner.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn propose_owner(e: Env, new_owner: Address) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    let owner = require_owner(&e)?;
+
+    put_pending_owner(&e, &new_owner);
+    event::ownership_proposed(&e, owner, new_owner);
+    Ok(())
+}
+
+/// Transfers the ownership to the pending owner, that must authorize the call.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if there is no pending owner.
+fn accept_ownership(e: Env) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    let new_owner = get_pending_owner(&e).ok_or(FactoryError::NoPendingOwner)?;
+    new_owner.require_auth();
+
+    let previous_owner = get_owner(&e)?;
+    put_fee_to_setter(&e, &new_owner);
+    remove_pending_owner(&e);
+    event::new_setter(&e, previous_owner, new_owner);
+    Ok(())
+}
+
+/// Removes the owner for good, together with any pending owner. The granted roles are kept,
+/// but no role can be granted or revoked anymore, and the functions restricted to the owner can't be called.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn renounce_ownership(e: Env) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    let owner = require_owner(&e)?;
+
+    e.storage().instance().remove(&DataKey::FeeToSetter);
+    remove_pending_owner(&e);
+    event::ownership_renounced(&e, owner);
+    Ok(())
+}
+
+/// Grants `role` to `account`, replacing its previous holder.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `role` - The role to grant.
+/// * `account` - The address that holds the role.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn grant_role(e: Env, role: Role, account: Address) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_owner(&e)?;
+
+    put_role_holder(&e, role, &account);
+    event::role_granted(&e, role, account);
+    Ok(())
+}
+
+/// Revokes `role` from its holder, so it is held by the owner again.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `role` - The role to revoke.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn revoke_role(e: Env, role: Role) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_owner(&e)?;
+    let account = get_role_holder(&e, role)?;
+
+    remove_role_holder(&e, role);
+    event::role_revoked(&e, role, account);
+    Ok(())
+}
+
+/// Pauses or unpauses the creation of pairs of every type. Existing pairs are not affected.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `is_paused` - A boolean indicating whether pair creation is paused.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the pauser.
+fn set_paused(e: Env, is_paused: bool) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_role(&e, Role::Pauser)?;
+
+    put_paused(&e, &is_paused);
+    event::paused(&e, is_paused);
+    Ok(())
+}
+
+/// Sets whether fees are enabled or disabled.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `is_enabled` - A boolean indicating whether fees are enabled or disabled.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the fee manager.
+fn set_fees_enabled(e: Env, is_enabled: bool) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_role(&e, Role::FeeManager)?;
+
+    put_fees_enabled(&e, &is_enabled);
+    event::new_fees_enabled(&e, is_enabled);
+    Ok(())
+}
+
+/// Sets the share of the liquidity providers fee growth minted to `fee_to` when fees are enabled.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `numerator` - The numerator of the share.
+/// * `denominator` - The denominator of the share.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the share is not between 0 and 1/2, or if the caller is not the fee manager.
+fn set_protocol_fee_share(e: Env, numerator: u32, denominator: u32) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    if denominator == 0 || u64::from(numerator) * 2 > u64::from(denominator) {
+        return Err(FactoryError::ProtocolFeeShareInvalid);
+    }
+
+    require_role(&e, Role::FeeManager)?;
+
+    put_protocol_fee_share(&e, &(numerator, denominator));
+    event::new_protocol_fee_share(&e, numerator, denominator);
+    Ok(())
+}
+
+/// Enables or disables a swap fee tier for new pairs. Existing pairs keep their fee.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `fee` - The swap fee, in basis points.
+/// * `is_enabled` - A boolean indicating whether the fee tier is enabled or disabled.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if `fee` is not less than 10000 basis points, or if the caller is not the fee manager.
+fn set_fee_tier_enabled(e: Env, fee: u32, is_enabled: bool) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    if fee >= FEE_DENOMINATOR {
+        return Err(FactoryError::FeeTierInvalid);
+    }
+
+    require_role(&e, Role::FeeManager)?;
+
+    put_fee_tier_enabled(&e, fee, &is_enabled);
+    event::fee_tier(&e, fee, is_enabled);
+    Ok(())
+}
+
+/// Sets whether pair creation is permissioned. While it is, pairs of every type can only be created
+/// between tokens allowed with `set_token_allowed`. Existing pairs are not affected.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `is_enabled` - A boolean indicating whether pair creation is permissioned or open.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the pair creation manager.
+fn set_permissioned_creation(e: Env, is_enabled: bool) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_role(&e, Role::PairCreationManager)?;
+
+    put_permissioned_creation(&e, &is_enabled);
+    event::permissioned_creation(&e, is_enabled);
+    Ok(())
+}
+
+/// Adds `token` to, or removes it from, the tokens allowed in new pairs while pair creation is permissioned.
+/// Tokens can be allowed before the mode is enabled.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token` - The address of the token.
+/// * `is_allowed` - A boolean indicating whether the token is allowed or not.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the pair creation manager.
+fn set_token_allowed(e: Env, token: Address, is_allowed: bool) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_role(&e, Role::PairCreationManager)?;
+
+    put_token_allowed(&e, &token, is_allowed);
+    event::token_allowed(&e, token, is_allowed);
+    Ok(())
+}
+
+/// Creates a pair for `token_a` and `token_b` with the default 0.3% swap fee if one doesn't exist already.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the pair is not yet initialized, if `token_a` and `token_b` have identical addresses, if pair creation is paused, if it is permissioned and either token is not allowed, or if the pair already exists between `token_a` and `token_b`.
+fn create_pair(e: Env, token_a: Address, token_b: Address) -> Result<Address, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    deploy_pair(&e, token_a, token_b, DEFAULT_FEE)
+}
+
+/// Sets the Wasm hash used to deploy new pairs. Existing pairs keep their code until they are upgraded with `upgrade_pair`.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `pair_wasm_hash` - The Wasm hash of the SoroswapPair contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn set_pair_wasm_hash(e: Env, pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_owner(&e)?;
+
+    put_pair_wasm_hash(&e, pair_wasm_hash.clone());
+    event::new_pair_wasm_hash(&e, pair_wasm_hash);
+    Ok(())
+}
+
+/// Replaces the code of the factory with the Wasm identified by `new_wasm_hash`. The storage is kept.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `new_wasm_hash` - The hash of the new Wasm, that must already be uploaded.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_owner(&e)?;
+
+    e.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+    event::upgrade(&e, new_wasm_hash);
+    Ok(())
+}
+
+/// Replaces the code of the pool of `pool_type` for `token_a` and `token_b` with the Wasm identified by `new_wasm_hash`,
+/// and then runs the pending storage migrations of the pool. Reserves, LP balances and concentrated positions are kept.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `pool_type` - The type of the pool to upgrade.
+/// * `new_wasm_hash` - The hash of the new Wasm of that pool type, that must already be uploaded.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the caller is not the owner, or if the pool does not exist.
+fn upgrade_pair(e: Env, token_a: Address, token_b: Address, pool_type: PoolType, new_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_owner(&e)?;
+
+    let token_pair = Pair::new(token_a, token_b)?;
+    // The new code is already in place when `migrate` is called, so it runs the migrations of the new version
+    let (pair_address, version) = match pool_type {
+        PoolType::ConstantProduct => {
+            let pair_address = get_pair_address_by_token_pair(&e, token_pair)?;
+            let pair_client = pair::Client::new(&e, &pair_address);
+            pair_client.upgrade(&new_wasm_hash);
+            let version = pair_client.migrate();
+            (pair_address, version)
+        }
+        PoolType::Stable => {
+            let pair_address = get_stable_pair_address_by_token_pair(&e, token_pair)?;
+            let pair_client = stable_pair::Client::new(&e, &pair_address);
+            pair_client.upgrade(&new_wasm_hash);
+            let version = pair_client.migrate();
+            (pair_address, version)
+        }
+        PoolType::Weighted => {
+            let pair_address = get_weighted_pair_address_by_token_pair(&e, token_pair)?;
+            let pair_client = weighted_pair::Client::new(&e, &pair_address);
+            pair_client.upgrade(&new_wasm_hash);
+            let version = pair_client.migrate();
+            (pair_address, version)
+        }
+        PoolType::Concentrated(fee) => {
+            let pool_address = get_concentrated_pool_address(&e, token_pair, fee)?;
+            let pool_client = concentrated_pool::Client::new(&e, &pool_address);
+            pool_client.upgrade(&new_wasm_hash);
+            let version = pool_client.migrate();
+            (pool_address, version)
+        }
+    };
+
+    event::upgrade_pair(&e, pair_address, new_wasm_hash, version);
+    Ok(())
+}
+
+/// Creates a pair for `token_a` and `token_b` with a swap fee of `fee` basis points if one doesn't exist already.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the pair is not yet initialized, if the fee tier is not enabled, if `token_a` and `token_b` have identical addresses, if pair creation is paused, if it is permissioned and either token is not allowed, or if the pair already exists between `token_a` and `token_b`.
+fn create_pair_with_fee(e: Env, token_a: Address, token_b: Address, fee: u32) -> Result<Address, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    if !get_fee_tier_enabled(&e, fee) {
+        return Err(FactoryError::CreatePairFeeTierNotEnabled);
+    }
+    deploy_pair(&e, token_a, token_b, fee)
+}
+
+/// Sets the Wasm hash used to deploy new stable pairs. Existing stable pairs keep their code.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `stable_pair_wasm_hash` - The Wasm hash of the SoroswapStablePair contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn set_stable_pair_wasm_hash(e: Env, stable_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_owner(&e)?;
+
+    put_stable_pair_wasm_hash(&e, stable_pair_wasm_hash.clone());
+    event::new_stable_pair_wasm_hash(&e, stable_pair_wasm_hash);
+    Ok(())
+}
+
+/// Creates a stable pair for `token_a` and `token_b` with an amplification coefficient of `amp` and a 0.05% swap fee,
+/// if one doesn't exist already. It is registered in `all_pairs` with the `Stable` pool type.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `amp` - The amplification coefficient of the StableSwap invariant, between 1 and 10000.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the stable pair Wasm hash has not been set, if `amp` is invalid,
+/// if `token_a` and `token_b` have identical addresses, if pair creation is paused, if it is permissioned and either token is not allowed, or if the stable pair already exists between `token_a` and `token_b`.
+fn create_stable_pair(e: Env, token_a: Address, token_b: Address, amp: u32) -> Result<Address, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    if amp == 0 || amp > MAX_AMP {
+        return Err(FactoryError::CreatePairAmpInvalid);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    check_pair_creation_allowed(&e, &token_pair)?;
+    if get_stable_pair_exists(&e, token_pair.clone()) {
+        return Err(FactoryError::CreatePairAlreadyExists);
+    }
+
+    let stable_pair_wasm_hash = get_stable_pair_wasm_hash(&e)?;
+    let pair_address = create_stable_contract(&e, stable_pair_wasm_hash, &token_pair);
+
+    stable_pair::Client::new(&e, &pair_address).initialize_pair(
+        &e.current_contract_address(),
+        &token_pair.token_0(),
+        &token_pair.token_1(),
+        &STABLE_FEE,
+        &amp
+    );
+
+    put_stable_pair_address_by_token_pair(&e, token_pair.clone(), &pair_address);
+    add_pair_to_all_pairs(&e, &pair_address, PoolType::Stable);
+    add_pair_to_token_pairs(&e, token_pair.token_0(), &pair_address, PoolType::Stable);
+    add_pair_to_token_pairs(&e, token_pair.token_1(), &pair_address, PoolType::Stable);
+
+    event::new_stable_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), amp, get_total_pairs(&e));
+
+    Ok(pair_address)
+}
+
+/// Sets the Wasm hash used to deploy new weighted pairs. Existing weighted pairs keep their code.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `weighted_pair_wasm_hash` - The Wasm hash of the SoroswapWeightedPair contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn set_weighted_pair_wasm_hash(e: Env, weighted_pair_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_owner(&e)?;
+
+    put_weighted_pair_wasm_hash(&e, weighted_pair_wasm_hash.clone());
+    event::new_weighted_pair_wasm_hash(&e, weighted_pair_wasm_hash);
+    Ok(())
+}
+
+/// Creates a weighted pair for `token_a` and `token_b` where `token_a` weighs `weight_a` basis points, with a 0.3% swap fee,
+/// if one doesn't exist already. It is registered in `all_pairs` with the `Weighted` pool type.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `weight_a` - The weight of `token_a` in basis points, between 100 and 9900. `token_b` weighs the rest up to 10000.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the weighted pair Wasm hash has not been set, if `weight_a` is invalid,
+/// if `token_a` and `token_b` have identical addresses, if pair creation is paused, if it is permissioned and either token is not allowed, or if the weighted pair already exists between `token_a` and `token_b`.
+fn create_weighted_pair(e: Env, token_a: Address, token_b: Address, weight_a: u32) -> Result<Address, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    if weight_a < MIN_WEIGHT || weight_a > FEE_DENOMINATOR - MIN_WEIGHT {
+        return Err(FactoryError::CreatePairWeightInvalid);
+    }
+    // The pair stores the weight of token_0
+    let weight_0 = if token_a < token_b { weight_a } else { FEE_DENOMINATOR - weight_a };
+    let token_pair = Pair::new(token_a, token_b)?;
+    check_pair_creation_allowed(&e, &token_pair)?;
+    if get_weighted_pair_exists(&e, token_pair.clone()) {
+        return Err(FactoryError::CreatePairAlreadyExists);
+    }
+
+    let weighted_pair_wasm_hash = get_weighted_pair_wasm_hash(&e)?;
+    let pair_address = create_weighted_contract(&e, weighted_pair_wasm_hash, &token_pair);
+
+    weighted_pair::Client::new(&e, &pair_address).initialize_pair(
+        &e.current_contract_address(),
+        &token_pair.token_0(),
+        &token_pair.token_1(),
+        &DEFAULT_FEE,
+        &weight_0
+    );
+
+    put_weighted_pair_address_by_token_pair(&e, token_pair.clone(), &pair_address);
+    add_pair_to_all_pairs(&e, &pair_address, PoolType::Weighted);
+    add_pair_to_token_pairs(&e, token_pair.token_0(), &pair_address, PoolType::Weighted);
+    add_pair_to_token_pairs(&e, token_pair.token_1(), &pair_address, PoolType::Weighted);
+
+    event::new_weighted_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), weight_0, get_total_pairs(&e));
+
+    Ok(pair_address)
+}
+
+/// Sets the Wasm hash used to deploy new concentrated pools. Existing concentrated pools keep their code.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `concentrated_pool_wasm_hash` - The Wasm hash of the SoroswapConcentratedPool contract.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the caller is not the owner.
+fn set_concentrated_pool_wasm_hash(e: Env, concentrated_pool_wasm_hash: BytesN<32>) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    require_owner(&e)?;
+
+    put_concentrated_pool_wasm_hash(&e, concentrated_pool_wasm_hash.clone());
+    event::new_concentrated_pool_wasm_hash(&e, concentrated_pool_wasm_hash);
+    Ok(())
+}
+
+/// Creates a concentrated liquidity pool for `token_a` and `token_b` in the `fee` tier, if one doesn't exist already.
+/// Its tick spacing is twice the fee, and it is registered in `all_pairs` with the `Concentrated(fee)` pool type.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pool.
+/// * `token_b` - The address of the second token in the pool.
+/// * `fee` - The swap fee of the pool in basis points.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized, if the concentrated pool Wasm hash has not been set, if `fee` is not an enabled fee tier,
+/// if `token_a` and `token_b` have identical addresses, if pair creation is paused, if it is permissioned and either token is not allowed, or if the concentrated pool of the fee tier already exists.
+fn create_concentrated_pool(e: Env, token_a: Address, token_b: Address, fee: u32) -> Result<Address, FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+
+    if !get_fee_tier_enabled(&e, fee) {
+        return Err(FactoryError::CreatePairFeeTierNotEnabled);
+    }
+    let token_pair = Pair::new(token_a, token_b)?;
+    check_pair_creation_allowed(&e, &token_pair)?;
+    if get_concentrated_pool_exists(&e, token_pair.clone(), fee) {
+        return Err(FactoryError::CreatePairAlreadyExists);
+    }
+
+    let concentrated_pool_wasm_hash = get_concentrated_pool_wasm_hash(&e)?;
+    let pool_address = create_concentrated_contract(&e, concentrated_pool_wasm_hash, &token_pair, fee);
+
+    // A fee tier of 0 still needs a spacing of at least one tick
+    let tick_spacing = (fee as i32 * TICK_SPACING_PER_FEE).max(1);
+    concentrated_pool::Client::new(&e, &pool_address).initialize_pool(
+        &e.current_contract_address(),
+        &token_pair.token_0(),
+        &token_pair.token_1(),
+        &fee,
+        &tick_spacing
+    );
+
+    put_concentrated_pool_address(&e, token_pair.clone(), fee, &pool_address);
+    add_pair_to_all_pairs(&e, &pool_address, PoolType::Concentrated(fee));
+    add_pair_to_token_pairs(&e, token_pair.token_0(), &pool_address, PoolType::Concentrated(fee));
+    add_pair_to_token_pairs(&e, token_pair.token_1(), &pool_address, PoolType::Concentrated(fee));
+
+    event::new_concentrated_pool(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pool_address.clone(), fee, tick_spacing, get_total_pairs(&e));
+
+    Ok(pool_address)
+}
+
+/// Extends the time to live of the factory instance, code and pair Wasm hash. Anyone can call this function.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn extend_ttl(e: Env) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    extend_persistent_ttl(&e, &DataKey::PairWasmHash);
+    if has_stable_pair_wasm_hash(&e) {
+        extend_persistent_ttl(&e, &DataKey::StablePairWasmHash);
+    }
+    if has_weighted_pair_wasm_hash(&e) {
+        extend_persistent_ttl(&e, &DataKey::WeightedPairWasmHash);
+    }
+    if has_concentrated_pool_wasm_hash(&e) {
+        extend_persistent_ttl(&e, &DataKey::ConcentratedPoolWasmHash);
+    }
+    Ok(())
+}
+
+/// Extends the time to live of the factory, of the registry entry of the pair for `token_a` and `token_b`,
+/// of the number of pairs of each token, and of the pair contract itself with its price observations from index `start`,
+/// up to `limit` of them. Anyone can call this function in order to keep a pool alive.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token_a` - The address of the first token in the pair.
+/// * `token_b` - The address of the second token in the pair.
+/// * `start` - The index of the first price observation of the pair.
+/// * `limit` - The maximum number of price observations.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized or if the pair does not exist.
+fn extend_pair_ttl(e: Env, token_a: Address, token_b: Address, start: u32, limit: u32) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    extend_persistent_ttl(&e, &DataKey::PairWasmHash);
+    let token_pair = Pair::new(token_a, token_b)?;
+    let pair_address = get_pair_address_by_token_pair(&e, token_pair.clone())?;
+    // Creating another pair with either token reads its number of pairs
+    for token in [token_pair.token_0(), token_pair.token_1()] {
+        let key = DataKey::TokenPairsLength(token.clone());
+        if e.storage().persistent().has(&key) {
+            extend_persistent_ttl(&e, &key);
+        }
+    }
+    pair::Client::new(&e, &pair_address).extend_pair_ttl(&start, &limit);
+    Ok(())
+}
+
+/// Extends the time to live of the `all_pairs` entries from index `start`, up to `limit` entries.
+/// Anyone can call this function.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `start` - The index of the first pair.
+/// * `limit` - The maximum number of pairs.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn extend_all_pairs_ttl(e: Env, start: u32, limit: u32) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    let end = start.saturating_add(limit).min(get_total_pairs(&e));
+    for n in start..end {
+        extend_persistent_ttl(&e, &DataKey::PairAddressesNIndexed(n));
+        if e.storage().persistent().has(&DataKey::PoolTypeNIndexed(n)) {
+            extend_persistent_ttl(&e, &DataKey::PoolTypeNIndexed(n));
+        }
+    }
+    Ok(())
+}
+
+/// Extends the time to live of the `pairs_for_token` entries of `token` from index `start`, up to `limit` entries,
+/// and of its number of pairs. Anyone can call this function.
+/// 
+/// # Arguments
+/// 
+/// * `e` - An instance of the `Env` struct.
+/// * `token` - The address of the token.
+/// * `start` - The index of the first pair of the token.
+/// * `limit` - The maximum number of pairs.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the Factory is not yet initialized.
+fn extend_token_pairs_ttl(e: Env, token: Address, start: u32, limit: u32) -> Result<(), FactoryError> {
+    if !has_pair_wasm_hash(&e) {
+        return Err(FactoryError::NotInitialized);
+    }
+    extend_instance_ttl(&e);
+    let end = start.saturating_add(limit).min(get_token_pairs_length(&e, &token));
+    for n in start..end {
+        extend_persistent_ttl(&e, &DataKey::TokenPairsNIndexed(token.clone(), n));
+    }
+    Ok(())
+}
+
+
+}
+
+fn deploy_pair(e: &Env, token_a: Address, token_b: Address, fee: u32) -> Result<Address, FactoryError> {
+    let token_pair = Pair::new(token_a, token_b)?;
+    check_pair_creation_allowed(&e, &token_pair)?;
+
+    if get_pair_exists(&e, token_pair.clone()) {
+        return Err(FactoryError::CreatePairAlreadyExists);
+    }
+
+    let pair_wasm_hash = get_pair_wasm_hash(&e);
+    let pair_address = create_contract(&e, pair_wasm_hash, &token_pair);
 
     pair::Client::new(&e, &pair_address).initialize_pair(
         &e.current_contract_address(),
         &token_pair.token_0(), 
-        &token_pair.token_1()
+        &token_pair.token_1(),
+        &fee
     );
 
     put_pair_address_by_token_pair(&e, token_pair.clone(), &pair_address);
-    add_pair_to_all_pairs(&e, &pair_address);
+    add_pair_to_all_pairs(&e, &pair_address, PoolType::ConstantProduct);
+    add_pair_to_token_pairs(&e, token_pair.token_0(), &pair_address, PoolType::ConstantProduct);
+    add_pair_to_token_pairs(&e, token_pair.token_1(), &pair_address, PoolType::ConstantProduct);
 
     event::new_pair(&e, token_pair.token_0().clone(), token_pair.token_1().clone(), pair_address.clone(), get_total_pairs(&e));
 
     Ok(pair_address)
 }
-
-
-}
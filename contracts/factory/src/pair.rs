@@ -15,6 +15,12 @@ pub enum PairError {
 }
 
 
+// Appended to the salt of stable and weighted pairs. The SoroswapLibrary uses the same suffixes to compute their addresses
+const STABLE_PAIR_SALT_SUFFIX: &[u8] = b"stable";
+const WEIGHTED_PAIR_SALT_SUFFIX: &[u8] = b"weighted";
+// Followed by the big-endian bytes of the fee tier of the concentrated pool
+const CONCENTRATED_POOL_SALT_SUFFIX: &[u8] = b"concentrated";
+
 #[contracttype]
 #[derive(Clone)]
 pub struct Pair(Address, Address);
@@ -41,6 +47,32 @@ impl Pair {
         e.crypto().sha256(&salt)
     }
 
+    pub fn stable_salt(&self, e: &Env) -> BytesN<32> {
+        self.suffixed_salt(e, STABLE_PAIR_SALT_SUFFIX)
+    }
+
+    pub fn weighted_salt(&self, e: &Env) -> BytesN<32> {
+        self.suffixed_salt(e, WEIGHTED_PAIR_SALT_SUFFIX)
+    }
+
+    pub fn concentrated_salt(&self, e: &Env, fee: u32) -> BytesN<32> {
+        let mut suffix = [0u8; CONCENTRATED_POOL_SALT_SUFFIX.len() + 4];
+        suffix[..CONCENTRATED_POOL_SALT_SUFFIX.len()].copy_from_slice(CONCENTRATED_POOL_SALT_SUFFIX);
+        suffix[CONCENTRATED_POOL_SALT_SUFFIX.len()..].copy_from_slice(&fee.to_be_bytes());
+        self.suffixed_salt(e, &suffix)
+    }
+
+    fn suffixed_salt(&self, e: &Env, suffix: &[u8]) -> BytesN<32> {
+        let mut salt = Bytes::new(e);
+
+        // Same as the salt of the constant product pair, followed by a suffix so the pairs can coexist
+        salt.append(&self.0.clone().to_xdr(e));
+        salt.append(&self.1.clone().to_xdr(e));
+        salt.append(&Bytes::from_slice(e, suffix));
+
+        e.crypto().sha256(&salt)
+    }
+
     pub fn token_0(&self) -> &Address {
         &self.0
     }
@@ -0,0 +1,19 @@
+#![allow(unused)]
+use soroban_sdk::{Address, BytesN, Env};
+use crate::pair::Pair;
+
+soroban_sdk::contractimport!(
+    file = "../stable-pair/target/wasm32-unknown-unknown/release/soroswap_stable_pair.wasm"
+);
+
+// Deploys a new stable pair with the given stable_pair_wasm_hash. Its salt differs from the one
+// of the constant product pair of the same tokens, so both pairs can exist at the same time
+pub fn create_stable_contract(
+    e: &Env,
+    stable_pair_wasm_hash: BytesN<32>,
+    token_pair: &Pair,
+) -> Address {
+    e.deployer()
+        .with_current_contract(token_pair.stable_salt(&e))
+        .deploy(stable_pair_wasm_hash)
+}
@@ -30,6 +30,56 @@ fn pair_token_wasm(e: &Env) -> BytesN<32> {
     e.deployer().upload_contract_wasm(WASM)
 }
 
+//  **** STABLE PAIR WASM ****
+fn stable_pair_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../stable-pair/target/wasm32-unknown-unknown/release/soroswap_stable_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+mod stable_pair {
+    soroban_sdk::contractimport!(file = "../stable-pair/target/wasm32-unknown-unknown/release/soroswap_stable_pair.wasm");
+    pub type SoroswapStablePairClient<'a> = Client<'a>;
+}
+use stable_pair::SoroswapStablePairClient;
+
+//  **** WEIGHTED PAIR WASM ****
+fn weighted_pair_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../weighted-pair/target/wasm32-unknown-unknown/release/soroswap_weighted_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+mod weighted_pair {
+    soroban_sdk::contractimport!(file = "../weighted-pair/target/wasm32-unknown-unknown/release/soroswap_weighted_pair.wasm");
+    pub type SoroswapWeightedPairClient<'a> = Client<'a>;
+}
+use weighted_pair::SoroswapWeightedPairClient;
+
+//  **** CONCENTRATED POOL WASM ****
+fn concentrated_pool_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../concentrated-pool/target/wasm32-unknown-unknown/release/soroswap_concentrated_pool.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+mod concentrated_pool {
+    soroban_sdk::contractimport!(file = "../concentrated-pool/target/wasm32-unknown-unknown/release/soroswap_concentrated_pool.wasm");
+    pub type SoroswapConcentratedPoolClient<'a> = Client<'a>;
+}
+use concentrated_pool::SoroswapConcentratedPoolClient;
+
+//  **** FACTORY WASM ****
+fn factory_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "./target/wasm32-unknown-unknown/release/soroswap_factory.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
 // **** TOKEN CONTRACT ****
 mod pair {
     soroban_sdk::contractimport!(file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm");
@@ -55,6 +105,9 @@ pub struct SoroswapFactoryTest<'a> {
     token_2: TokenClient<'a>,
     token_3: TokenClient<'a>,
     pair_wasm: BytesN<32>,
+    stable_pair_wasm: BytesN<32>,
+    weighted_pair_wasm: BytesN<32>,
+    concentrated_pool_wasm: BytesN<32>,
     contract: SoroswapFactoryClient<'a>,
 }
 
@@ -82,12 +135,15 @@ impl<'a> SoroswapFactoryTest<'a> {
         token_2.initialize(&admin, &7, &String::from_str(&env, "Token 2"), &String::from_str(&env, "TOKEN2"));
         token_3.initialize(&admin, &7, &String::from_str(&env, "Token 3"), &String::from_str(&env, "TOKEN3"));
 
-        
-        let pair_wasm = pair_token_wasm(&env);  
-        let contract = create_factory_contract(&env);
 
         // TODO: Get rid of this hack?
         env.budget().reset_unlimited();
+
+        let pair_wasm = pair_token_wasm(&env);  
+        let stable_pair_wasm = stable_pair_wasm(&env);
+        let weighted_pair_wasm = weighted_pair_wasm(&env);
+        let concentrated_pool_wasm = concentrated_pool_wasm(&env);
+        let contract = create_factory_contract(&env);
     
 
         SoroswapFactoryTest {
@@ -99,6 +155,9 @@ impl<'a> SoroswapFactoryTest<'a> {
             token_2,
             token_3,
             pair_wasm,
+            stable_pair_wasm,
+            weighted_pair_wasm,
+            concentrated_pool_wasm,
             contract,
         }
     }
@@ -108,5 +167,15 @@ mod initialize;
 mod fee_to_setter;
 mod pairs;
 mod events;
+mod fee_tiers;
+mod protocol_fee;
+mod ttl;
+mod upgrade;
+mod stable_pairs;
+mod weighted_pairs;
+mod concentrated_pools;
+mod pairs_info;
+mod permissioned;
+mod roles;
 
 pub mod deterministic;
\ No newline at end of file
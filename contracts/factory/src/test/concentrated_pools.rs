@@ -0,0 +1,169 @@
+use crate::test::{SoroswapFactoryTest, SoroswapConcentratedPoolClient};
+use crate::event::{NewConcentratedPoolEvent, NewConcentratedPoolWasmHashEvent};
+use soroban_sdk::{testutils::{Events}, vec, IntoVal, symbol_short, xdr::{ToXdr}, Bytes};
+use soroswap_factory_interface::{FactoryError, PoolType};
+
+#[test]
+fn create_concentrated_pool_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_concentrated_pool_exists(&test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn create_concentrated_pool_wasm_hash_not_set() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.try_concentrated_pool_wasm_hash(), Err(Ok(FactoryError::ConcentratedPoolWasmHashNotSet)));
+    let res = test.contract.try_create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(res, Err(Ok(FactoryError::ConcentratedPoolWasmHashNotSet)));
+}
+
+#[test]
+fn create_concentrated_pool_fee_tier_not_enabled() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+    let res = test.contract.try_create_concentrated_pool(&test.token_0.address, &test.token_1.address, &25);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairFeeTierNotEnabled)));
+
+    test.contract.set_fee_tier_enabled(&25, &true);
+    test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &25);
+}
+
+#[test]
+fn create_concentrated_pool_identical_tokens() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+    let res = test.contract.try_create_concentrated_pool(&test.token_0.address, &test.token_0.address, &30);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+}
+
+#[test]
+fn create_concentrated_pool() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+    assert_eq!(test.contract.concentrated_pool_wasm_hash(), test.concentrated_pool_wasm);
+    assert_eq!(test.contract.concentrated_pool_exists(&test.token_0.address, &test.token_1.address, &30), false);
+
+    let pool_address = test.contract.create_concentrated_pool(&test.token_1.address, &test.token_0.address, &30);
+
+    // Calculating the concentrated pool address:
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&Bytes::from_slice(&test.env, b"concentrated"));
+    salt.append(&Bytes::from_slice(&test.env, &30u32.to_be_bytes()));
+    let bytes_n_32_salt = test.env.crypto().sha256(&salt);
+    let deterministic_pool_address = test.env.deployer().with_address(test.contract.address.clone(), bytes_n_32_salt).deployed_address();
+    assert_eq!(pool_address, deterministic_pool_address);
+
+    assert_eq!(test.contract.get_concentrated_pool(&test.token_0.address, &test.token_1.address, &30), pool_address);
+    assert_eq!(test.contract.get_concentrated_pool(&test.token_1.address, &test.token_0.address, &30), pool_address);
+    assert_eq!(test.contract.concentrated_pool_exists(&test.token_1.address, &test.token_0.address, &30), true);
+    // Other fee tiers and the constant product pair are independent
+    assert_eq!(test.contract.concentrated_pool_exists(&test.token_0.address, &test.token_1.address, &5), false);
+    assert_eq!(test.contract.try_get_concentrated_pool(&test.token_0.address, &test.token_1.address, &5), Err(Ok(FactoryError::PairDoesNotExist)));
+    assert_eq!(test.contract.pair_exists(&test.token_0.address, &test.token_1.address), false);
+
+    assert_eq!(test.contract.all_pairs_length(), 1);
+    assert_eq!(test.contract.all_pairs(&0), pool_address);
+    assert_eq!(test.contract.all_pairs_pool_type(&0), PoolType::Concentrated(30));
+
+    let pool_client = SoroswapConcentratedPoolClient::new(&test.env, &pool_address);
+    assert_eq!(pool_client.factory(), test.contract.address);
+    assert_eq!(pool_client.token_0(), test.token_0.address);
+    assert_eq!(pool_client.token_1(), test.token_1.address);
+    assert_eq!(pool_client.fee(), 30);
+    assert_eq!(pool_client.tick_spacing(), 60);
+}
+
+#[test]
+fn double_concentrated_pool_creation() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+    test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30);
+    let res = test.contract.try_create_concentrated_pool(&test.token_1.address, &test.token_0.address, &30);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn concentrated_pool_per_fee_tier() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+
+    let pair = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pool_5 = test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &5);
+    let pool_30 = test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30);
+    let pool_100 = test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &100);
+    assert_ne!(pool_5, pair);
+    assert_ne!(pool_5, pool_30);
+    assert_ne!(pool_30, pool_100);
+
+    assert_eq!(test.contract.all_pairs_length(), 4);
+    assert_eq!(test.contract.all_pairs_pool_type(&0), PoolType::ConstantProduct);
+    assert_eq!(test.contract.all_pairs_pool_type(&1), PoolType::Concentrated(5));
+    assert_eq!(test.contract.all_pairs_pool_type(&3), PoolType::Concentrated(100));
+
+    // The tick spacing grows with the fee
+    assert_eq!(SoroswapConcentratedPoolClient::new(&test.env, &pool_5).tick_spacing(), 10);
+    assert_eq!(SoroswapConcentratedPoolClient::new(&test.env, &pool_100).tick_spacing(), 200);
+}
+
+#[test]
+fn concentrated_pool_events() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+
+    let concentrated_pool_wasm_hash_event = test.env.events().all().last().unwrap();
+    let expected_concentrated_pool_wasm_hash_event: NewConcentratedPoolWasmHashEvent = NewConcentratedPoolWasmHashEvent {
+        concentrated_pool_wasm_hash: test.concentrated_pool_wasm.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, concentrated_pool_wasm_hash_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("conc_hash")).into_val(&test.env),
+                (expected_concentrated_pool_wasm_hash_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    let pool = test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &100);
+    let new_concentrated_pool_event = test.env.events().all().last().unwrap();
+    let expected_new_concentrated_pool_event: NewConcentratedPoolEvent = NewConcentratedPoolEvent {
+        token_0: test.token_0.address.clone(),
+        token_1: test.token_1.address.clone(),
+        pool: pool.clone(),
+        fee: 100,
+        tick_spacing: 200,
+        new_pairs_length: 1,
+    };
+    assert_eq!(
+        vec![&test.env, new_concentrated_pool_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("new_conc")).into_val(&test.env),
+                (expected_new_concentrated_pool_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn set_concentrated_pool_wasm_hash_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
@@ -78,6 +78,8 @@ impl<'a> SoroswapFactoryTest<'a> {
         factory.create_pair(&token_0.address, &token_1.address);
         let pair_address = factory.get_pair(&token_0.address, &token_1.address);
         let pair = SoroswapPairClient::new(&env, &pair_address);
+        // The setup spends most of the default budget, so each test starts with a fresh one
+        env.budget().reset_default();
 
         SoroswapFactoryTest {
             env,
@@ -125,6 +127,7 @@ pub fn set_fee_to_setter_bob() {
     let factory_test = SoroswapFactoryTest::new();
     let bob = factory_test.bob;
     factory_test.factory.set_fee_to_setter(&bob);
+    factory_test.factory.accept_ownership();
     let setter = factory_test.factory.fee_to_setter();
     assert_eq!(setter, bob);
 }
@@ -343,6 +346,18 @@ pub fn authorized_invocation() {
         }])
         .set_fee_to_setter(&bob);
 
+    let _r = factory
+        .mock_auths(&[MockAuth {
+            address: &bob,
+            invoke: &MockAuthInvoke {
+                contract: &factory.address,
+                fn_name: "accept_ownership",
+                args: ().into_val(&factory_test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .accept_ownership();
+
     // setter is bob
     assert_eq!(bob, factory.fee_to_setter());
 }
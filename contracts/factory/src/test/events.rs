@@ -269,6 +269,7 @@ fn setter_event() {
     let test = SoroswapFactoryTest::setup();
     test.contract.initialize(&test.admin, &test.pair_wasm);
     test.contract.set_fee_to_setter(&test.user);
+    test.contract.accept_ownership();
 
     let new_setter_event = test.env.events().all().last().unwrap();
 
@@ -0,0 +1,135 @@
+use crate::test::{SoroswapFactoryTest, SoroswapPairClient};
+use crate::event::FeeTierEvent;
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    IntoVal,
+    symbol_short,
+};
+use soroswap_factory_interface::{FactoryError};
+
+#[test]
+fn fee_tier_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_fee_tier_enabled(&30);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_create_pair_with_fee(&test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn initial_fee_tiers() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    assert_eq!(test.contract.fee_tier_enabled(&5), true);
+    assert_eq!(test.contract.fee_tier_enabled(&30), true);
+    assert_eq!(test.contract.fee_tier_enabled(&100), true);
+    assert_eq!(test.contract.fee_tier_enabled(&0), false);
+    assert_eq!(test.contract.fee_tier_enabled(&50), false);
+}
+
+#[test]
+fn create_pair_default_fee() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.fee(), 30);
+}
+
+#[test]
+fn create_pair_with_fee() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let pair_address = test.contract.create_pair_with_fee(&test.token_0.address, &test.token_1.address, &5);
+    assert_eq!(test.contract.get_pair(&test.token_1.address, &test.token_0.address), pair_address);
+    assert_eq!(test.contract.all_pairs_length(), 1);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.fee(), 5);
+
+    let pair_address = test.contract.create_pair_with_fee(&test.token_2.address, &test.token_3.address, &100);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.fee(), 100);
+
+    let res = test.contract.try_create_pair_with_fee(&test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn create_pair_with_fee_tier_not_enabled() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_create_pair_with_fee(&test.token_0.address, &test.token_1.address, &50);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairFeeTierNotEnabled)));
+
+    test.contract.set_fee_tier_enabled(&50, &true);
+    let pair_address = test.contract.create_pair_with_fee(&test.token_0.address, &test.token_1.address, &50);
+    let pair_client = SoroswapPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.fee(), 50);
+
+    test.contract.set_fee_tier_enabled(&5, &false);
+    assert_eq!(test.contract.fee_tier_enabled(&5), false);
+    let res = test.contract.try_create_pair_with_fee(&test.token_2.address, &test.token_3.address, &5);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairFeeTierNotEnabled)));
+}
+
+#[test]
+fn set_fee_tier_invalid() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_set_fee_tier_enabled(&10000, &true);
+    assert_eq!(res, Err(Ok(FactoryError::FeeTierInvalid)));
+}
+
+#[test]
+fn fee_tier_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_fee_tier_enabled(&50, &true);
+
+    let fee_tier_event = test.env.events().all().last().unwrap();
+
+    let expected_fee_tier_event: FeeTierEvent = FeeTierEvent {
+        fee: 50,
+        enabled: true,
+    };
+
+    assert_eq!(
+        vec![&test.env, fee_tier_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("fee_tier")).into_val(&test.env),
+                (expected_fee_tier_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn set_fee_tier_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_fee_tier_enabled",
+                    args: (50_u32, true).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_fee_tier_enabled(&50, &true);
+}
@@ -58,6 +58,22 @@ fn changing_with_mock_auth() {
         )]
    );
 
+    // The new fee_to_setter accepts the ownership
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "accept_ownership",
+                    args: ().into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .accept_ownership();
+
     assert_eq!(test.contract.fee_to_setter(), test.user);
     assert_ne!(test.contract.fee_to_setter(), test.admin);
 
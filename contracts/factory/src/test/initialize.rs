@@ -122,6 +122,9 @@ fn initialize_basic_info() {
          )]
     );
 
+    // The ownership is transferred once the new fee_to_setter accepts it
+    assert_eq!(test.contract.fee_to_setter(), test.admin);
+    test.contract.accept_ownership();
     assert_eq!(test.contract.fee_to_setter(), test.user);
     assert_ne!(test.contract.fee_to_setter(), test.admin);
 
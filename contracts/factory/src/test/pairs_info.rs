@@ -0,0 +1,166 @@
+use crate::test::{SoroswapFactoryTest, SoroswapPairClient, SoroswapConcentratedPoolClient};
+use soroban_sdk::{testutils::Address as _, vec, Vec, Address};
+use soroswap_factory_interface::{FactoryError, PairInfo, PoolType, TokenPool};
+
+#[test]
+fn pairs_range_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    assert_eq!(test.contract.try_all_pairs_range(&0, &10), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_pairs_info(&0, &10), Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn all_pairs_range() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.all_pairs_range(&0, &10), Vec::<Address>::new(&test.env));
+
+    let pair_0 = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair_1 = test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+    let pair_2 = test.contract.create_pair(&test.token_0.address, &test.token_2.address);
+
+    assert_eq!(test.contract.all_pairs_range(&0, &10), vec![&test.env, pair_0.clone(), pair_1.clone(), pair_2.clone()]);
+    assert_eq!(test.contract.all_pairs_range(&0, &2), vec![&test.env, pair_0, pair_1.clone()]);
+    assert_eq!(test.contract.all_pairs_range(&1, &1), vec![&test.env, pair_1]);
+    assert_eq!(test.contract.all_pairs_range(&2, &10), vec![&test.env, pair_2]);
+
+    // Past the last pair
+    assert_eq!(test.contract.all_pairs_range(&1, &0), Vec::<Address>::new(&test.env));
+    assert_eq!(test.contract.all_pairs_range(&3, &10), Vec::<Address>::new(&test.env));
+    assert_eq!(test.contract.all_pairs_range(&u32::MAX, &u32::MAX), Vec::<Address>::new(&test.env));
+}
+
+#[test]
+fn pairs_info() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.pairs_info(&0, &10), Vec::<PairInfo>::new(&test.env));
+
+    let pair_0 = SoroswapPairClient::new(&test.env, &test.contract.create_pair(&test.token_1.address, &test.token_0.address));
+    let pair_1 = test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+
+    test.token_0.mint(&test.user, &1_000_000);
+    test.token_1.mint(&test.user, &4_000_000);
+    test.token_0.transfer(&test.user, &pair_0.address, &1_000_000);
+    test.token_1.transfer(&test.user, &pair_0.address, &4_000_000);
+    pair_0.deposit(&test.user);
+
+    assert_eq!(test.contract.pairs_info(&0, &10), vec![
+        &test.env,
+        PairInfo {
+            pair: pair_0.address.clone(),
+            token_0: test.token_0.address.clone(),
+            token_1: test.token_1.address.clone(),
+            reserve_0: 1_000_000,
+            reserve_1: 4_000_000,
+            total_shares: 2_000_000,
+        },
+        PairInfo {
+            pair: pair_1.clone(),
+            token_0: test.token_2.address.clone(),
+            token_1: test.token_3.address.clone(),
+            reserve_0: 0,
+            reserve_1: 0,
+            total_shares: 0,
+        },
+    ]);
+    assert_eq!(test.contract.pairs_info(&1, &1).get(0).unwrap().pair, pair_1);
+    assert_eq!(test.contract.pairs_info(&2, &10), Vec::<PairInfo>::new(&test.env));
+}
+
+#[test]
+fn pairs_info_of_every_curve() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+
+    let stable_pair = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    let pool = SoroswapConcentratedPoolClient::new(&test.env, &test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30));
+    pool.initialize_price(&(1 << 64));
+    test.token_0.mint(&test.user, &1_000_000_000);
+    test.token_1.mint(&test.user, &1_000_000_000);
+    let (_, amount_0, amount_1) = pool.mint(&test.user, &-600, &600, &1_000_000_000, &i128::MAX, &i128::MAX);
+
+    let pairs_info = test.contract.pairs_info(&0, &10);
+    assert_eq!(pairs_info.len(), 2);
+    assert_eq!(pairs_info.get(0).unwrap().pair, stable_pair);
+    // The concentrated pool reports its reserves, but it has no shares
+    assert_eq!(pairs_info.get(1).unwrap(), PairInfo {
+        pair: pool.address.clone(),
+        token_0: test.token_0.address.clone(),
+        token_1: test.token_1.address.clone(),
+        reserve_0: amount_0,
+        reserve_1: amount_1,
+        total_shares: 0,
+    });
+}
+
+#[test]
+fn pairs_for_token_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    assert_eq!(test.contract.try_pairs_for_token_length(&test.token_0.address), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_pairs_for_token(&test.token_0.address, &0, &10), Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn pairs_for_token() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let pool = |pool: &Address| TokenPool { pool: pool.clone(), pool_type: PoolType::ConstantProduct };
+    assert_eq!(test.contract.pairs_for_token_length(&test.token_0.address), 0);
+    assert_eq!(test.contract.pairs_for_token(&test.token_0.address, &0, &10), Vec::<TokenPool>::new(&test.env));
+
+    let pair_0_1 = test.contract.create_pair(&test.token_1.address, &test.token_0.address);
+    let pair_2_3 = test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+    let pair_0_2 = test.contract.create_pair_with_fee(&test.token_0.address, &test.token_2.address, &100);
+
+    assert_eq!(test.contract.pairs_for_token_length(&test.token_0.address), 2);
+    assert_eq!(test.contract.pairs_for_token(&test.token_0.address, &0, &10), vec![&test.env, pool(&pair_0_1), pool(&pair_0_2)]);
+    assert_eq!(test.contract.pairs_for_token(&test.token_1.address, &0, &10), vec![&test.env, pool(&pair_0_1)]);
+    assert_eq!(test.contract.pairs_for_token(&test.token_2.address, &0, &10), vec![&test.env, pool(&pair_2_3), pool(&pair_0_2)]);
+    assert_eq!(test.contract.pairs_for_token(&test.token_3.address, &0, &10), vec![&test.env, pool(&pair_2_3)]);
+
+    // Pages of the pairs of a token
+    assert_eq!(test.contract.pairs_for_token(&test.token_2.address, &1, &1), vec![&test.env, pool(&pair_0_2)]);
+    assert_eq!(test.contract.pairs_for_token(&test.token_2.address, &2, &10), Vec::<TokenPool>::new(&test.env));
+    assert_eq!(test.contract.pairs_for_token(&test.token_2.address, &u32::MAX, &u32::MAX), Vec::<TokenPool>::new(&test.env));
+
+    // Tokens without pairs
+    let other_token = Address::generate(&test.env);
+    assert_eq!(test.contract.pairs_for_token_length(&other_token), 0);
+    assert_eq!(test.contract.pairs_for_token(&other_token, &0, &10), Vec::<TokenPool>::new(&test.env));
+}
+
+#[test]
+fn pairs_for_token_every_pool_type() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+
+    let stable = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    let weighted = test.contract.create_weighted_pair(&test.token_0.address, &test.token_2.address, &8000);
+    let concentrated = test.contract.create_concentrated_pool(&test.token_1.address, &test.token_0.address, &30);
+    let pair = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    assert_eq!(test.contract.pairs_for_token_length(&test.token_0.address), 4);
+    assert_eq!(test.contract.pairs_for_token(&test.token_0.address, &0, &10), vec![
+        &test.env,
+        TokenPool { pool: stable.clone(), pool_type: PoolType::Stable },
+        TokenPool { pool: weighted.clone(), pool_type: PoolType::Weighted },
+        TokenPool { pool: concentrated.clone(), pool_type: PoolType::Concentrated(30) },
+        TokenPool { pool: pair.clone(), pool_type: PoolType::ConstantProduct },
+    ]);
+    assert_eq!(test.contract.pairs_for_token(&test.token_1.address, &0, &10), vec![
+        &test.env,
+        TokenPool { pool: stable, pool_type: PoolType::Stable },
+        TokenPool { pool: concentrated, pool_type: PoolType::Concentrated(30) },
+        TokenPool { pool: pair, pool_type: PoolType::ConstantProduct },
+    ]);
+    assert_eq!(test.contract.pairs_for_token(&test.token_2.address, &0, &10), vec![
+        &test.env,
+        TokenPool { pool: weighted, pool_type: PoolType::Weighted },
+    ]);
+}
@@ -0,0 +1,194 @@
+use crate::test::SoroswapFactoryTest;
+use crate::event::{PermissionedCreationEvent, TokenAllowedEvent};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    IntoVal,
+    symbol_short,
+};
+use soroswap_factory_interface::{FactoryError};
+
+#[test]
+fn permissioned_creation_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    assert_eq!(test.contract.try_permissioned_creation(), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_token_allowed(&test.token_0.address), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_set_permissioned_creation(&true), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_set_token_allowed(&test.token_0.address, &true), Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn open_creation_by_default() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    assert_eq!(test.contract.permissioned_creation(), false);
+    assert_eq!(test.contract.token_allowed(&test.token_0.address), false);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+}
+
+#[test]
+fn create_pair_token_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_permissioned_creation(&true);
+    assert_eq!(test.contract.permissioned_creation(), true);
+
+    let res = test.contract.try_create_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairTokenNotAllowed)));
+
+    // Both tokens need to be allowed
+    test.contract.set_token_allowed(&test.token_0.address, &true);
+    assert_eq!(test.contract.token_allowed(&test.token_0.address), true);
+    let res = test.contract.try_create_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairTokenNotAllowed)));
+    let res = test.contract.try_create_pair_with_fee(&test.token_1.address, &test.token_0.address, &5);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairTokenNotAllowed)));
+
+    test.contract.set_token_allowed(&test.token_1.address, &true);
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+}
+
+#[test]
+fn create_pair_token_disallowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    // Tokens can be allowed before the mode is enabled
+    test.contract.set_token_allowed(&test.token_0.address, &true);
+    test.contract.set_token_allowed(&test.token_1.address, &true);
+    test.contract.set_token_allowed(&test.token_2.address, &true);
+    test.contract.set_permissioned_creation(&true);
+
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    test.contract.set_token_allowed(&test.token_0.address, &false);
+    assert_eq!(test.contract.token_allowed(&test.token_0.address), false);
+    let res = test.contract.try_create_pair(&test.token_0.address, &test.token_2.address);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairTokenNotAllowed)));
+    // Existing pairs are not affected
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+
+    // Back to open creation
+    test.contract.set_permissioned_creation(&false);
+    test.contract.create_pair(&test.token_0.address, &test.token_2.address);
+}
+
+#[test]
+fn create_other_pool_types_token_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+    test.contract.set_permissioned_creation(&true);
+    test.contract.set_token_allowed(&test.token_0.address, &true);
+
+    let res = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairTokenNotAllowed)));
+    let res = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &8000);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairTokenNotAllowed)));
+    let res = test.contract.try_create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairTokenNotAllowed)));
+
+    test.contract.set_token_allowed(&test.token_1.address, &true);
+    test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    test.contract.create_weighted_pair(&test.token_0.address, &test.token_1.address, &8000);
+    test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(test.contract.all_pairs_length(), 3);
+}
+
+#[test]
+fn permissioned_creation_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_permissioned_creation(&true);
+
+    let permissioned_creation_event = test.env.events().all().last().unwrap();
+
+    let expected_permissioned_creation_event: PermissionedCreationEvent = PermissionedCreationEvent {
+        enabled: true,
+    };
+
+    assert_eq!(
+        vec![&test.env, permissioned_creation_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("perm_mode")).into_val(&test.env),
+                (expected_permissioned_creation_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn token_allowed_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_token_allowed(&test.token_0.address, &true);
+
+    let token_allowed_event = test.env.events().all().last().unwrap();
+
+    let expected_token_allowed_event: TokenAllowedEvent = TokenAllowedEvent {
+        token: test.token_0.address.clone(),
+        allowed: true,
+    };
+
+    assert_eq!(
+        vec![&test.env, token_allowed_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("tkn_allow")).into_val(&test.env),
+                (expected_token_allowed_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn set_permissioned_creation_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_permissioned_creation",
+                    args: (true,).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_permissioned_creation(&true);
+}
+
+#[test]
+#[should_panic]
+fn set_token_allowed_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_token_allowed",
+                    args: (test.token_0.address.clone(), true).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_token_allowed(&test.token_0.address, &true);
+}
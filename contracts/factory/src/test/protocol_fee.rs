@@ -0,0 +1,106 @@
+use crate::test::{SoroswapFactoryTest};
+use crate::event::NewProtocolFeeShareEvent;
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    IntoVal,
+    symbol_short,
+};
+use soroswap_factory_interface::{FactoryError};
+
+#[test]
+fn protocol_fee_share_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_protocol_fee_share();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_set_protocol_fee_share(&1, &2);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn default_protocol_fee_share() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.protocol_fee_share(), (1, 6));
+}
+
+#[test]
+fn set_protocol_fee_share() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract.set_protocol_fee_share(&1, &2);
+    assert_eq!(test.contract.protocol_fee_share(), (1, 2));
+
+    test.contract.set_protocol_fee_share(&0, &1);
+    assert_eq!(test.contract.protocol_fee_share(), (0, 1));
+
+    test.contract.set_protocol_fee_share(&1500, &10000);
+    assert_eq!(test.contract.protocol_fee_share(), (1500, 10000));
+}
+
+#[test]
+fn set_protocol_fee_share_invalid() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    let res = test.contract.try_set_protocol_fee_share(&1, &0);
+    assert_eq!(res, Err(Ok(FactoryError::ProtocolFeeShareInvalid)));
+    let res = test.contract.try_set_protocol_fee_share(&0, &0);
+    assert_eq!(res, Err(Ok(FactoryError::ProtocolFeeShareInvalid)));
+    // More than 1/2
+    let res = test.contract.try_set_protocol_fee_share(&5001, &10000);
+    assert_eq!(res, Err(Ok(FactoryError::ProtocolFeeShareInvalid)));
+    let res = test.contract.try_set_protocol_fee_share(&u32::MAX, &u32::MAX);
+    assert_eq!(res, Err(Ok(FactoryError::ProtocolFeeShareInvalid)));
+
+    assert_eq!(test.contract.protocol_fee_share(), (1, 6));
+}
+
+#[test]
+fn protocol_fee_share_event() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_protocol_fee_share(&1, &4);
+
+    let protocol_fee_share_event = test.env.events().all().last().unwrap();
+
+    let expected_protocol_fee_share_event: NewProtocolFeeShareEvent = NewProtocolFeeShareEvent {
+        numerator: 1,
+        denominator: 4,
+    };
+
+    assert_eq!(
+        vec![&test.env, protocol_fee_share_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("prot_fee")).into_val(&test.env),
+                (expected_protocol_fee_share_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+fn set_protocol_fee_share_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_protocol_fee_share",
+                    args: (1_u32, 2_u32).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_protocol_fee_share(&1, &2);
+}
@@ -0,0 +1,353 @@
+use crate::test::SoroswapFactoryTest;
+use crate::event::{
+    OwnershipProposedEvent,
+    NewSetterEvent,
+    OwnershipRenouncedEvent,
+    RoleGrantedEvent,
+    RoleRevokedEvent,
+    PausedEvent};
+use soroban_sdk::{
+    testutils::{Address as _, Events, MockAuth, MockAuthInvoke},
+    vec,
+    Address,
+    IntoVal,
+    symbol_short,
+};
+use soroswap_factory_interface::{FactoryError, Role};
+
+#[test]
+fn roles_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    assert_eq!(test.contract.try_pending_owner(), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_role_holder(&Role::FeeManager), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_paused(), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_propose_owner(&test.user), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_accept_ownership(), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_renounce_ownership(), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_grant_role(&Role::Pauser, &test.user), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_revoke_role(&Role::Pauser), Err(Ok(FactoryError::NotInitialized)));
+    assert_eq!(test.contract.try_set_paused(&true), Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn roles_held_by_owner_by_default() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    assert_eq!(test.contract.role_holder(&Role::FeeManager), test.admin);
+    assert_eq!(test.contract.role_holder(&Role::Pauser), test.admin);
+    assert_eq!(test.contract.role_holder(&Role::PairCreationManager), test.admin);
+    assert_eq!(test.contract.pending_owner(), None);
+    assert_eq!(test.contract.paused(), false);
+
+    test.contract.set_fee_to(&test.user);
+    assert_eq!(test.env.auths()[0].0, test.admin);
+}
+
+#[test]
+fn grant_and_revoke_role() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let fee_manager = Address::generate(&test.env);
+    let pair_creation_manager = Address::generate(&test.env);
+
+    test.contract.grant_role(&Role::FeeManager, &fee_manager);
+    test.contract.grant_role(&Role::PairCreationManager, &pair_creation_manager);
+    assert_eq!(test.contract.role_holder(&Role::FeeManager), fee_manager);
+    assert_eq!(test.contract.role_holder(&Role::PairCreationManager), pair_creation_manager);
+    // The other roles are still held by the owner
+    assert_eq!(test.contract.role_holder(&Role::Pauser), test.admin);
+
+    // Each function is authorized by the holder of its role
+    test.contract.set_fees_enabled(&true);
+    assert_eq!(test.env.auths()[0].0, fee_manager);
+    test.contract.set_fee_tier_enabled(&50, &true);
+    assert_eq!(test.env.auths()[0].0, fee_manager);
+    test.contract.set_token_allowed(&test.token_0.address, &true);
+    assert_eq!(test.env.auths()[0].0, pair_creation_manager);
+    test.contract.set_pair_wasm_hash(&test.pair_wasm);
+    assert_eq!(test.env.auths()[0].0, test.admin);
+
+    test.contract.revoke_role(&Role::FeeManager);
+    assert_eq!(test.contract.role_holder(&Role::FeeManager), test.admin);
+    test.contract.set_fees_enabled(&false);
+    assert_eq!(test.env.auths()[0].0, test.admin);
+}
+
+#[test]
+#[should_panic]
+fn owner_cannot_act_as_granted_role() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.grant_role(&Role::FeeManager, &test.user);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.admin.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "set_fees_enabled",
+                    args: (true,).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .set_fees_enabled(&true);
+}
+
+#[test]
+#[should_panic]
+fn grant_role_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.user.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "grant_role",
+                    args: (Role::FeeManager, test.user.clone()).into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .grant_role(&Role::FeeManager, &test.user);
+}
+
+#[test]
+fn pause_pair_creation() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    let pauser = Address::generate(&test.env);
+    test.contract.grant_role(&Role::Pauser, &pauser);
+
+    test.contract.set_paused(&true);
+    assert_eq!(test.env.auths()[0].0, pauser);
+    assert_eq!(test.contract.paused(), true);
+
+    let res = test.contract.try_create_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairPaused)));
+    let res = test.contract.try_create_pair_with_fee(&test.token_0.address, &test.token_1.address, &5);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairPaused)));
+    let res = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairPaused)));
+
+    test.contract.set_paused(&false);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(test.contract.all_pairs_length(), 1);
+}
+
+#[test]
+fn two_step_ownership_transfer() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let new_owner = Address::generate(&test.env);
+
+    assert_eq!(test.contract.try_accept_ownership(), Err(Ok(FactoryError::NoPendingOwner)));
+
+    // A new proposal replaces the previous one
+    test.contract.propose_owner(&test.user);
+    test.contract.propose_owner(&new_owner);
+    assert_eq!(test.contract.pending_owner(), Some(new_owner.clone()));
+    // The owner does not change until the proposal is accepted
+    assert_eq!(test.contract.fee_to_setter(), test.admin);
+
+    test.contract.accept_ownership();
+    assert_eq!(test.env.auths()[0].0, new_owner);
+    assert_eq!(test.contract.fee_to_setter(), new_owner);
+    assert_eq!(test.contract.pending_owner(), None);
+    assert_eq!(test.contract.role_holder(&Role::Pauser), new_owner);
+
+    test.contract.set_pair_wasm_hash(&test.pair_wasm);
+    assert_eq!(test.env.auths()[0].0, new_owner);
+}
+
+#[test]
+fn set_fee_to_setter_proposes_owner() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    // The ownership is not transferred in one step anymore
+    test.contract.set_fee_to_setter(&test.user);
+    assert_eq!(test.contract.fee_to_setter(), test.admin);
+    assert_eq!(test.contract.pending_owner(), Some(test.user.clone()));
+
+    test.contract.accept_ownership();
+    assert_eq!(test.env.auths()[0].0, test.user);
+    assert_eq!(test.contract.fee_to_setter(), test.user);
+}
+
+#[test]
+#[should_panic]
+fn accept_ownership_with_mock_auth_not_allowed() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.propose_owner(&test.user);
+
+    // Only the pending owner can accept
+    test.contract
+    .mock_auths(&[
+        MockAuth {
+            address: &test.admin.clone(),
+            invoke: 
+                &MockAuthInvoke {
+                    contract: &test.contract.address.clone(),
+                    fn_name: "accept_ownership",
+                    args: ().into_val(&test.env),
+                    sub_invokes: &[],
+                },
+        }
+    ])
+    .accept_ownership();
+}
+
+#[test]
+fn renounce_ownership() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.grant_role(&Role::FeeManager, &test.user);
+    test.contract.propose_owner(&test.user);
+
+    test.contract.renounce_ownership();
+    assert_eq!(test.contract.try_fee_to_setter(), Err(Ok(FactoryError::OwnershipRenounced)));
+    assert_eq!(test.contract.pending_owner(), None);
+    assert_eq!(test.contract.try_accept_ownership(), Err(Ok(FactoryError::NoPendingOwner)));
+
+    // The granted roles are kept, and the others can't be used anymore
+    test.contract.set_fees_enabled(&true);
+    assert_eq!(test.contract.fees_enabled(), true);
+    assert_eq!(test.contract.try_role_holder(&Role::Pauser), Err(Ok(FactoryError::OwnershipRenounced)));
+    assert_eq!(test.contract.try_set_paused(&true), Err(Ok(FactoryError::OwnershipRenounced)));
+    assert_eq!(test.contract.try_grant_role(&Role::Pauser, &test.user), Err(Ok(FactoryError::OwnershipRenounced)));
+    assert_eq!(test.contract.try_revoke_role(&Role::FeeManager), Err(Ok(FactoryError::OwnershipRenounced)));
+    assert_eq!(test.contract.try_propose_owner(&test.user), Err(Ok(FactoryError::OwnershipRenounced)));
+    assert_eq!(test.contract.try_set_fee_to_setter(&test.user), Err(Ok(FactoryError::OwnershipRenounced)));
+    assert_eq!(test.contract.try_renounce_ownership(), Err(Ok(FactoryError::OwnershipRenounced)));
+
+    // Pairs can still be created
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+}
+
+#[test]
+fn ownership_events() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract.propose_owner(&test.user);
+    let ownership_proposed_event = test.env.events().all().last().unwrap();
+    let expected_ownership_proposed_event: OwnershipProposedEvent = OwnershipProposedEvent {
+        owner: test.admin.clone(),
+        pending_owner: test.user.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, ownership_proposed_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("own_prop")).into_val(&test.env),
+                (expected_ownership_proposed_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.accept_ownership();
+    let new_setter_event = test.env.events().all().last().unwrap();
+    let expected_new_setter_event: NewSetterEvent = NewSetterEvent {
+        old: test.admin.clone(),
+        new: test.user.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, new_setter_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("setter")).into_val(&test.env),
+                (expected_new_setter_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.renounce_ownership();
+    let ownership_renounced_event = test.env.events().all().last().unwrap();
+    let expected_ownership_renounced_event: OwnershipRenouncedEvent = OwnershipRenouncedEvent {
+        previous_owner: test.user.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, ownership_renounced_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("own_renon")).into_val(&test.env),
+                (expected_ownership_renounced_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn role_events() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract.grant_role(&Role::Pauser, &test.user);
+    let role_granted_event = test.env.events().all().last().unwrap();
+    let expected_role_granted_event: RoleGrantedEvent = RoleGrantedEvent {
+        role: Role::Pauser,
+        account: test.user.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, role_granted_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("role_grnt")).into_val(&test.env),
+                (expected_role_granted_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.set_paused(&true);
+    let paused_event = test.env.events().all().last().unwrap();
+    let expected_paused_event: PausedEvent = PausedEvent {
+        paused: true,
+    };
+    assert_eq!(
+        vec![&test.env, paused_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("paused")).into_val(&test.env),
+                (expected_paused_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.revoke_role(&Role::Pauser);
+    let role_revoked_event = test.env.events().all().last().unwrap();
+    let expected_role_revoked_event: RoleRevokedEvent = RoleRevokedEvent {
+        role: Role::Pauser,
+        account: test.user.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, role_revoked_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("role_rvk")).into_val(&test.env),
+                (expected_role_revoked_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
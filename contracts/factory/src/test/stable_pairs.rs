@@ -0,0 +1,164 @@
+use crate::test::{SoroswapFactoryTest, SoroswapStablePairClient};
+use crate::event::{NewStablePairEvent, NewStablePairWasmHashEvent};
+use soroban_sdk::{testutils::{Events}, vec, IntoVal, symbol_short, xdr::{ToXdr}, Bytes};
+use soroswap_factory_interface::{FactoryError, PoolType};
+
+#[test]
+fn create_stable_pair_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn create_stable_pair_wasm_hash_not_set() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.try_stable_pair_wasm_hash(), Err(Ok(FactoryError::StablePairWasmHashNotSet)));
+    let res = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    assert_eq!(res, Err(Ok(FactoryError::StablePairWasmHashNotSet)));
+}
+
+#[test]
+fn create_stable_pair_amp_invalid() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    let res = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &0);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairAmpInvalid)));
+    let res = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_1.address, &10_001);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairAmpInvalid)));
+}
+
+#[test]
+fn create_stable_pair_identical_tokens() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    let res = test.contract.try_create_stable_pair(&test.token_0.address, &test.token_0.address, &100);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+}
+
+#[test]
+fn create_stable_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    assert_eq!(test.contract.stable_pair_wasm_hash(), test.stable_pair_wasm);
+    assert_eq!(test.contract.stable_pair_exists(&test.token_0.address, &test.token_1.address), false);
+
+    let pair_address = test.contract.create_stable_pair(&test.token_1.address, &test.token_0.address, &100);
+
+    // Calculating the stable pair address:
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&Bytes::from_slice(&test.env, b"stable"));
+    let bytes_n_32_salt = test.env.crypto().sha256(&salt);
+    let deterministic_pair_address = test.env.deployer().with_address(test.contract.address.clone(), bytes_n_32_salt).deployed_address();
+    assert_eq!(pair_address, deterministic_pair_address);
+
+    assert_eq!(test.contract.get_stable_pair(&test.token_0.address, &test.token_1.address), pair_address);
+    assert_eq!(test.contract.get_stable_pair(&test.token_1.address, &test.token_0.address), pair_address);
+    assert_eq!(test.contract.stable_pair_exists(&test.token_1.address, &test.token_0.address), true);
+    // The constant product pair is independent
+    assert_eq!(test.contract.pair_exists(&test.token_0.address, &test.token_1.address), false);
+    assert_eq!(test.contract.try_get_pair(&test.token_0.address, &test.token_1.address), Err(Ok(FactoryError::PairDoesNotExist)));
+
+    assert_eq!(test.contract.all_pairs_length(), 1);
+    assert_eq!(test.contract.all_pairs(&0), pair_address);
+    assert_eq!(test.contract.all_pairs_pool_type(&0), PoolType::Stable);
+
+    let pair_client = SoroswapStablePairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.factory(), test.contract.address);
+    assert_eq!(pair_client.token_0(), test.token_0.address);
+    assert_eq!(pair_client.token_1(), test.token_1.address);
+    assert_eq!(pair_client.fee(), 5);
+    assert_eq!(pair_client.amp(), 100);
+}
+
+#[test]
+fn double_stable_pair_creation() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    let res = test.contract.try_create_stable_pair(&test.token_1.address, &test.token_0.address, &200);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn stable_and_constant_product_pairs_coexist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+
+    let pair = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let stable_pair = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    let other_pair = test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+    assert_ne!(pair, stable_pair);
+
+    assert_eq!(test.contract.all_pairs_length(), 3);
+    assert_eq!(test.contract.all_pairs(&0), pair);
+    assert_eq!(test.contract.all_pairs(&1), stable_pair);
+    assert_eq!(test.contract.all_pairs(&2), other_pair);
+    assert_eq!(test.contract.all_pairs_pool_type(&0), PoolType::ConstantProduct);
+    assert_eq!(test.contract.all_pairs_pool_type(&1), PoolType::Stable);
+    assert_eq!(test.contract.all_pairs_pool_type(&2), PoolType::ConstantProduct);
+    assert_eq!(test.contract.try_all_pairs_pool_type(&3), Err(Ok(FactoryError::IndexDoesNotExist)));
+
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair);
+    assert_eq!(test.contract.get_stable_pair(&test.token_0.address, &test.token_1.address), stable_pair);
+}
+
+#[test]
+fn stable_pair_events() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+
+    let stable_pair_wasm_hash_event = test.env.events().all().last().unwrap();
+    let expected_stable_pair_wasm_hash_event: NewStablePairWasmHashEvent = NewStablePairWasmHashEvent {
+        stable_pair_wasm_hash: test.stable_pair_wasm.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, stable_pair_wasm_hash_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("stbl_hash")).into_val(&test.env),
+                (expected_stable_pair_wasm_hash_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &250);
+    let new_stable_pair_event = test.env.events().all().last().unwrap();
+    let expected_new_stable_pair_event: NewStablePairEvent = NewStablePairEvent {
+        token_0: test.token_0.address.clone(),
+        token_1: test.token_1.address.clone(),
+        pair: pair.clone(),
+        amp: 250,
+        new_pairs_length: 2,
+    };
+    assert_eq!(
+        vec![&test.env, new_stable_pair_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("new_stbl")).into_val(&test.env),
+                (expected_new_stable_pair_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn set_stable_pair_wasm_hash_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
@@ -0,0 +1,118 @@
+use crate::test::{SoroswapFactoryTest, SoroswapPairClient};
+use crate::{INSTANCE_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT};
+use soroban_sdk::testutils::Ledger;
+use soroswap_factory_interface::{FactoryError, PoolType, TokenPool};
+
+fn advance_ledgers(test: &SoroswapFactoryTest, ledgers: u32) {
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+    });
+}
+
+#[test]
+fn extend_ttl_not_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_extend_ttl();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_extend_pair_ttl(&test.token_0.address, &test.token_1.address, &0, &10);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_extend_all_pairs_ttl(&0, &10);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_extend_token_pairs_ttl(&test.token_0.address, &0, &10);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn extend_pair_ttl_pair_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let res = test.contract.try_extend_pair_ttl(&test.token_0.address, &test.token_1.address, &0, &10);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+}
+
+#[test]
+#[should_panic]
+fn factory_archived_without_extending_ttl() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    advance_ledgers(&test, PERSISTENT_BUMP_AMOUNT + 1);
+    test.contract.extend_ttl();
+}
+
+#[test]
+#[should_panic]
+fn pair_archived_without_extending_ttl() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    // Only the factory is kept alive
+    for _ in 0..10 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.extend_ttl();
+    }
+    test.contract.extend_pair_ttl(&test.token_0.address, &test.token_1.address, &0, &10);
+}
+
+#[test]
+fn extend_ttl_keeps_factory_and_pairs_alive() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+
+    // Way past the instance and persistent TTLs, as long as someone extends them
+    for _ in 0..10 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.extend_pair_ttl(&test.token_0.address, &test.token_1.address, &0, &10);
+        test.contract.extend_pair_ttl(&test.token_2.address, &test.token_3.address, &0, &10);
+        // Out of range indexes are ignored
+        test.contract.extend_all_pairs_ttl(&0, &10);
+    }
+
+    let pair_address = test.contract.get_pair(&test.token_0.address, &test.token_1.address);
+    assert_eq!(test.contract.all_pairs(&0), pair_address);
+    assert_eq!(SoroswapPairClient::new(&test.env, &pair_address).token_0(), test.token_0.address);
+    // Creating new pairs reads the pair Wasm hash
+    test.contract.create_pair(&test.token_0.address, &test.token_2.address);
+    assert_eq!(test.contract.all_pairs_length(), 3);
+}
+
+#[test]
+fn extend_token_pairs_ttl_keeps_pairs_for_token_alive() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let pair_0_1 = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair_0_2 = test.contract.create_pair(&test.token_0.address, &test.token_2.address);
+
+    for _ in 0..10 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.extend_ttl();
+        // Out of range indexes are ignored
+        test.contract.extend_token_pairs_ttl(&test.token_0.address, &0, &10);
+    }
+
+    assert_eq!(test.contract.pairs_for_token_length(&test.token_0.address), 2);
+    assert_eq!(test.contract.pairs_for_token(&test.token_0.address, &0, &10), soroban_sdk::vec![
+        &test.env,
+        TokenPool { pool: pair_0_1, pool_type: PoolType::ConstantProduct },
+        TokenPool { pool: pair_0_2, pool_type: PoolType::ConstantProduct },
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn pairs_for_token_archived_without_extending_ttl() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    // Only the factory is kept alive
+    for _ in 0..10 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.extend_ttl();
+    }
+    test.contract.pairs_for_token(&test.token_0.address, &0, &10);
+}
@@ -0,0 +1,304 @@
+use crate::test::{SoroswapFactoryTest, SoroswapPairClient, SoroswapStablePairClient, SoroswapWeightedPairClient, SoroswapConcentratedPoolClient, factory_wasm};
+use crate::event::{NewPairWasmHashEvent, UpgradePairEvent};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    IntoVal,
+    symbol_short,
+};
+use soroswap_factory_interface::{FactoryError, PoolType};
+
+fn add_liquidity(test: &SoroswapFactoryTest, pair: &SoroswapPairClient, amount_0: i128, amount_1: i128) -> i128 {
+    test.token_0.mint(&test.user, &amount_0);
+    test.token_1.mint(&test.user, &amount_1);
+    test.token_0.transfer(&test.user, &pair.address, &amount_0);
+    test.token_1.transfer(&test.user, &pair.address, &amount_1);
+    pair.deposit(&test.user)
+}
+
+#[test]
+fn upgrade_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_pair_wasm_hash();
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_set_pair_wasm_hash(&test.pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_upgrade(&test.pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+    let res = test.contract.try_upgrade_pair(&test.token_0.address, &test.token_1.address, &PoolType::ConstantProduct, &test.pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn set_pair_wasm_hash() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.pair_wasm_hash(), test.pair_wasm);
+
+    // Any uploaded Wasm will do for the test
+    let new_pair_wasm = factory_wasm(&test.env);
+    test.contract.set_pair_wasm_hash(&new_pair_wasm);
+    let new_pair_wasm_hash_event = test.env.events().all().last().unwrap();
+    let expected_new_pair_wasm_hash_event: NewPairWasmHashEvent = NewPairWasmHashEvent {
+        pair_wasm_hash: new_pair_wasm.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, new_pair_wasm_hash_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("pair_hash")).into_val(&test.env),
+                (expected_new_pair_wasm_hash_event).into_val(&test.env)
+            ),
+        ]
+    );
+    assert_eq!(test.contract.pair_wasm_hash(), new_pair_wasm);
+}
+
+#[test]
+#[should_panic]
+fn set_pair_wasm_hash_not_setter() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &test.user,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "set_pair_wasm_hash",
+                args: (test.pair_wasm.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_pair_wasm_hash(&test.pair_wasm);
+}
+
+#[test]
+fn upgrade_pair_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let res = test.contract.try_upgrade_pair(&test.token_0.address, &test.token_1.address, &PoolType::ConstantProduct, &test.pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+}
+
+#[test]
+fn upgrade_pair_keeps_reserves_and_balances() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let pair = SoroswapPairClient::new(&test.env, &pair_address);
+    let liquidity = add_liquidity(&test, &pair, 1_000_000, 4_000_000);
+    let total_shares = pair.total_shares();
+
+    test.contract.upgrade_pair(&test.token_1.address, &test.token_0.address, &PoolType::ConstantProduct, &test.pair_wasm);
+    let upgrade_pair_event = test.env.events().all().last().unwrap();
+    let expected_upgrade_pair_event: UpgradePairEvent = UpgradePairEvent {
+        pair: pair_address.clone(),
+        new_wasm_hash: test.pair_wasm.clone(),
+        version: 1,
+    };
+    assert_eq!(
+        vec![&test.env, upgrade_pair_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("upg_pair")).into_val(&test.env),
+                (expected_upgrade_pair_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    assert_eq!(pair.version(), 1);
+    assert_eq!(pair.get_reserves(), (1_000_000, 4_000_000));
+    assert_eq!(pair.balance(&test.user), liquidity);
+    assert_eq!(pair.total_shares(), total_shares);
+
+    // The upgraded pair keeps working
+    add_liquidity(&test, &pair, 1_000_000, 4_000_000);
+    assert_eq!(pair.get_reserves(), (2_000_000, 8_000_000));
+}
+
+#[test]
+fn upgrade_stable_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let stable_pair_address = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    let stable_pair = SoroswapStablePairClient::new(&test.env, &stable_pair_address);
+    test.token_0.mint(&test.user, &1_000_000);
+    test.token_1.mint(&test.user, &1_000_000);
+    test.token_0.transfer(&test.user, &stable_pair_address, &1_000_000);
+    test.token_1.transfer(&test.user, &stable_pair_address, &1_000_000);
+    let liquidity = stable_pair.deposit(&test.user);
+
+    // The pool type picks the stable pair, not the constant product pair of the same tokens
+    test.contract.upgrade_pair(&test.token_0.address, &test.token_1.address, &PoolType::Stable, &test.stable_pair_wasm);
+    let upgrade_pair_event = test.env.events().all().last().unwrap();
+    let expected_upgrade_pair_event: UpgradePairEvent = UpgradePairEvent {
+        pair: stable_pair_address.clone(),
+        new_wasm_hash: test.stable_pair_wasm.clone(),
+        version: 1,
+    };
+    assert_eq!(
+        vec![&test.env, upgrade_pair_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("upg_pair")).into_val(&test.env),
+                (expected_upgrade_pair_event).into_val(&test.env)
+            ),
+        ]
+    );
+    assert_ne!(stable_pair_address, pair_address);
+    assert_eq!(stable_pair.version(), 1);
+    assert_eq!(stable_pair.get_reserves(), (1_000_000, 1_000_000));
+    assert_eq!(stable_pair.my_balance(&test.user), liquidity);
+}
+
+#[test]
+fn upgrade_weighted_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    let weighted_pair_address = test.contract.create_weighted_pair(&test.token_0.address, &test.token_1.address, &8000);
+    let weighted_pair = SoroswapWeightedPairClient::new(&test.env, &weighted_pair_address);
+    test.token_0.mint(&test.user, &4_000_000);
+    test.token_1.mint(&test.user, &1_000_000);
+    test.token_0.transfer(&test.user, &weighted_pair_address, &4_000_000);
+    test.token_1.transfer(&test.user, &weighted_pair_address, &1_000_000);
+    let liquidity = weighted_pair.deposit(&test.user);
+    let reserves = weighted_pair.get_reserves();
+
+    test.contract.upgrade_pair(&test.token_1.address, &test.token_0.address, &PoolType::Weighted, &test.weighted_pair_wasm);
+    let upgrade_pair_event = test.env.events().all().last().unwrap();
+    let expected_upgrade_pair_event: UpgradePairEvent = UpgradePairEvent {
+        pair: weighted_pair_address.clone(),
+        new_wasm_hash: test.weighted_pair_wasm.clone(),
+        version: 1,
+    };
+    assert_eq!(
+        vec![&test.env, upgrade_pair_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("upg_pair")).into_val(&test.env),
+                (expected_upgrade_pair_event).into_val(&test.env)
+            ),
+        ]
+    );
+    assert_eq!(weighted_pair.version(), 1);
+    assert_eq!(weighted_pair.get_reserves(), reserves);
+    assert_eq!(weighted_pair.my_balance(&test.user), liquidity);
+
+    // Only the weighted pair exists for these tokens
+    let res = test.contract.try_upgrade_pair(&test.token_0.address, &test.token_1.address, &PoolType::ConstantProduct, &test.pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+}
+
+#[test]
+fn upgrade_concentrated_pool() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_concentrated_pool_wasm_hash(&test.concentrated_pool_wasm);
+    let pool_address = test.contract.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30);
+    let pool = SoroswapConcentratedPoolClient::new(&test.env, &pool_address);
+    // A price of 1
+    pool.initialize_price(&(1u128 << 64));
+
+    test.contract.upgrade_pair(&test.token_1.address, &test.token_0.address, &PoolType::Concentrated(30), &test.concentrated_pool_wasm);
+    let upgrade_pair_event = test.env.events().all().last().unwrap();
+    let expected_upgrade_pair_event: UpgradePairEvent = UpgradePairEvent {
+        pair: pool_address.clone(),
+        new_wasm_hash: test.concentrated_pool_wasm.clone(),
+        version: 1,
+    };
+    assert_eq!(
+        vec![&test.env, upgrade_pair_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("upg_pair")).into_val(&test.env),
+                (expected_upgrade_pair_event).into_val(&test.env)
+            ),
+        ]
+    );
+    assert_eq!(pool.version(), 1);
+    assert_eq!(pool.sqrt_price(), 1u128 << 64);
+    assert_eq!(pool.fee(), 30);
+
+    // Each fee tier is a different pool
+    let res = test.contract.try_upgrade_pair(&test.token_0.address, &test.token_1.address, &PoolType::Concentrated(5), &test.concentrated_pool_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+}
+
+#[test]
+fn upgrade_stable_pair_does_not_exist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let res = test.contract.try_upgrade_pair(&test.token_0.address, &test.token_1.address, &PoolType::Stable, &test.stable_pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::PairDoesNotExist)));
+}
+
+#[test]
+#[should_panic]
+fn upgrade_pair_not_setter() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &test.user,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "upgrade_pair",
+                args: (test.token_0.address.clone(), test.token_1.address.clone(), PoolType::ConstantProduct, test.pair_wasm.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade_pair(&test.token_0.address, &test.token_1.address, &PoolType::ConstantProduct, &test.pair_wasm);
+}
+
+#[test]
+fn upgrade_factory_keeps_pairs() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let pair_address = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+
+    test.contract.upgrade(&factory_wasm(&test.env));
+
+    // The Wasm factory serves the same storage
+    assert_eq!(test.contract.all_pairs_length(), 1);
+    assert_eq!(test.contract.get_pair(&test.token_0.address, &test.token_1.address), pair_address);
+    assert_eq!(test.contract.fee_to_setter(), test.admin);
+    test.contract.create_pair(&test.token_2.address, &test.token_3.address);
+    assert_eq!(test.contract.all_pairs_length(), 2);
+}
+
+#[test]
+#[should_panic]
+fn upgrade_not_setter() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    let new_wasm_hash = factory_wasm(&test.env);
+
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &test.user,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+}
@@ -0,0 +1,160 @@
+use crate::test::{SoroswapFactoryTest, SoroswapWeightedPairClient};
+use crate::event::{NewWeightedPairEvent, NewWeightedPairWasmHashEvent};
+use soroban_sdk::{testutils::{Events}, vec, IntoVal, symbol_short, xdr::{ToXdr}, Bytes};
+use soroswap_factory_interface::{FactoryError, PoolType};
+
+#[test]
+fn create_weighted_pair_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &8000);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
+
+#[test]
+fn create_weighted_pair_wasm_hash_not_set() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    assert_eq!(test.contract.try_weighted_pair_wasm_hash(), Err(Ok(FactoryError::WeightedPairWasmHashNotSet)));
+    let res = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &8000);
+    assert_eq!(res, Err(Ok(FactoryError::WeightedPairWasmHashNotSet)));
+}
+
+#[test]
+fn create_weighted_pair_weight_invalid() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    let res = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &99);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairWeightInvalid)));
+    let res = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_1.address, &9901);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairWeightInvalid)));
+}
+
+#[test]
+fn create_weighted_pair_identical_tokens() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    let res = test.contract.try_create_weighted_pair(&test.token_0.address, &test.token_0.address, &8000);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairIdenticalTokens)));
+}
+
+#[test]
+fn create_weighted_pair() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    assert_eq!(test.contract.weighted_pair_wasm_hash(), test.weighted_pair_wasm);
+    assert_eq!(test.contract.weighted_pair_exists(&test.token_0.address, &test.token_1.address), false);
+
+    // The weight is given for token_a, here token 1
+    let pair_address = test.contract.create_weighted_pair(&test.token_1.address, &test.token_0.address, &8000);
+
+    // Calculating the weighted pair address:
+    let mut salt = Bytes::new(&test.env);
+    salt.append(&test.token_0.address.clone().to_xdr(&test.env));
+    salt.append(&test.token_1.address.clone().to_xdr(&test.env));
+    salt.append(&Bytes::from_slice(&test.env, b"weighted"));
+    let bytes_n_32_salt = test.env.crypto().sha256(&salt);
+    let deterministic_pair_address = test.env.deployer().with_address(test.contract.address.clone(), bytes_n_32_salt).deployed_address();
+    assert_eq!(pair_address, deterministic_pair_address);
+
+    assert_eq!(test.contract.get_weighted_pair(&test.token_0.address, &test.token_1.address), pair_address);
+    assert_eq!(test.contract.get_weighted_pair(&test.token_1.address, &test.token_0.address), pair_address);
+    assert_eq!(test.contract.weighted_pair_exists(&test.token_1.address, &test.token_0.address), true);
+    // The constant product pair is independent
+    assert_eq!(test.contract.pair_exists(&test.token_0.address, &test.token_1.address), false);
+
+    assert_eq!(test.contract.all_pairs_length(), 1);
+    assert_eq!(test.contract.all_pairs(&0), pair_address);
+    assert_eq!(test.contract.all_pairs_pool_type(&0), PoolType::Weighted);
+
+    let pair_client = SoroswapWeightedPairClient::new(&test.env, &pair_address);
+    assert_eq!(pair_client.factory(), test.contract.address);
+    assert_eq!(pair_client.token_0(), test.token_0.address);
+    assert_eq!(pair_client.token_1(), test.token_1.address);
+    assert_eq!(pair_client.fee(), 30);
+    assert_eq!(pair_client.weights(), (2000, 8000));
+}
+
+#[test]
+fn double_weighted_pair_creation() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    test.contract.create_weighted_pair(&test.token_0.address, &test.token_1.address, &8000);
+    let res = test.contract.try_create_weighted_pair(&test.token_1.address, &test.token_0.address, &5000);
+    assert_eq!(res, Err(Ok(FactoryError::CreatePairAlreadyExists)));
+}
+
+#[test]
+fn weighted_stable_and_constant_product_pairs_coexist() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_stable_pair_wasm_hash(&test.stable_pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+
+    let pair = test.contract.create_pair(&test.token_0.address, &test.token_1.address);
+    let stable_pair = test.contract.create_stable_pair(&test.token_0.address, &test.token_1.address, &100);
+    let weighted_pair = test.contract.create_weighted_pair(&test.token_0.address, &test.token_1.address, &8000);
+    assert_ne!(weighted_pair, pair);
+    assert_ne!(weighted_pair, stable_pair);
+
+    assert_eq!(test.contract.all_pairs_length(), 3);
+    assert_eq!(test.contract.all_pairs(&2), weighted_pair);
+    assert_eq!(test.contract.all_pairs_pool_type(&0), PoolType::ConstantProduct);
+    assert_eq!(test.contract.all_pairs_pool_type(&1), PoolType::Stable);
+    assert_eq!(test.contract.all_pairs_pool_type(&2), PoolType::Weighted);
+    assert_eq!(test.contract.get_weighted_pair(&test.token_0.address, &test.token_1.address), weighted_pair);
+}
+
+#[test]
+fn weighted_pair_events() {
+    let test = SoroswapFactoryTest::setup();
+    test.contract.initialize(&test.admin, &test.pair_wasm);
+    test.contract.set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+
+    let weighted_pair_wasm_hash_event = test.env.events().all().last().unwrap();
+    let expected_weighted_pair_wasm_hash_event: NewWeightedPairWasmHashEvent = NewWeightedPairWasmHashEvent {
+        weighted_pair_wasm_hash: test.weighted_pair_wasm.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, weighted_pair_wasm_hash_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("wtd_hash")).into_val(&test.env),
+                (expected_weighted_pair_wasm_hash_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    let pair = test.contract.create_weighted_pair(&test.token_0.address, &test.token_1.address, &8000);
+    let new_weighted_pair_event = test.env.events().all().last().unwrap();
+    let expected_new_weighted_pair_event: NewWeightedPairEvent = NewWeightedPairEvent {
+        token_0: test.token_0.address.clone(),
+        token_1: test.token_1.address.clone(),
+        pair: pair.clone(),
+        weight_0: 8000,
+        new_pairs_length: 1,
+    };
+    assert_eq!(
+        vec![&test.env, new_weighted_pair_event.clone()],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapFactory", symbol_short!("new_wtd")).into_val(&test.env),
+                (expected_new_weighted_pair_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn set_weighted_pair_wasm_hash_not_yet_initialized() {
+    let test = SoroswapFactoryTest::setup();
+    let res = test.contract.try_set_weighted_pair_wasm_hash(&test.weighted_pair_wasm);
+    assert_eq!(res, Err(Ok(FactoryError::NotInitialized)));
+}
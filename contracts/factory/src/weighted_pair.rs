@@ -0,0 +1,19 @@
+#![allow(unused)]
+use soroban_sdk::{Address, BytesN, Env};
+use crate::pair::Pair;
+
+soroban_sdk::contractimport!(
+    file = "../weighted-pair/target/wasm32-unknown-unknown/release/soroswap_weighted_pair.wasm"
+);
+
+// Deploys a new weighted pair with the given weighted_pair_wasm_hash. Its salt differs from the ones
+// of the constant product and stable pairs of the same tokens, so all of them can exist at the same time
+pub fn create_weighted_contract(
+    e: &Env,
+    weighted_pair_wasm_hash: BytesN<32>,
+    token_pair: &Pair,
+) -> Address {
+    e.deployer()
+        .with_current_contract(token_pair.weighted_salt(&e))
+        .deploy(weighted_pair_wasm_hash)
+}
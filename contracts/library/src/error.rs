@@ -1,6 +1,7 @@
 use soroban_sdk::{self, contracterror};
 
-#[contracterror]
+#[cfg_attr(feature = "contract", contracterror)]
+#[cfg_attr(not(feature = "contract"), contracterror(export = false))]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum SoroswapLibraryError {
@@ -21,4 +22,31 @@ pub enum SoroswapLibraryError {
 
     /// SoroswapLibrary: token_a and token_b have identical addresses
     SortIdenticalTokens = 306,
+
+    /// SoroswapLibrary: fee must be less than 10000 basis points
+    InvalidFee = 307,
+
+    /// SoroswapLibrary: no path with liquidity between the tokens
+    NoPathFound = 308,
+
+    /// SoroswapLibrary: amplification coefficient must be between 1 and MAX_AMP
+    InvalidAmp = 309,
+
+    /// SoroswapLibrary: the stable invariant could not be computed for the reserves
+    StableInvariantFailed = 310,
+
+    /// SoroswapLibrary: weight must be between MIN_WEIGHT and WEIGHT_DENOMINATOR - MIN_WEIGHT
+    InvalidWeight = 311,
+
+    /// SoroswapLibrary: the weighted invariant could not be computed for the reserves
+    WeightedInvariantFailed = 312,
+
+    /// SoroswapLibrary: the price of the path rounds to zero or does not fit at PRICE_SCALE
+    PriceOutOfRange = 313,
+
+    /// SoroswapLibrary: max_hops must be at most MAX_HOPS while searching paths
+    TooManyHops = 314,
+
+    /// SoroswapLibrary: there must be at most MAX_INTERMEDIATES intermediate tokens while searching paths
+    TooManyIntermediates = 315,
 }
\ No newline at end of file
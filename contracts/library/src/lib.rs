@@ -1,4 +1,5 @@
 #![no_std] 
+#[cfg(feature = "contract")]
 use soroban_sdk::{
     contract, contractimpl,
     Address, Env, Vec, 
@@ -8,21 +9,57 @@ mod test;
 mod tokens;
 mod reserves;
 mod quotes;
+mod routes;
+mod swap_quote;
+mod stable;
+mod weighted;
+mod pools;
 mod error;
 
 pub use tokens::{
     sort_tokens,
-    pair_for
+    pair_for,
+    stable_pair_for,
+    weighted_pair_for,
+    concentrated_pool_for
 };
 pub use reserves::{
-    get_reserves
+    get_reserves,
+    get_fee
 };
 pub use quotes::{
     quote, 
     get_amount_out, 
     get_amount_in, 
+    get_amount_out_with_fee, 
+    get_amount_in_with_fee, 
     get_amounts_out, 
-    get_amounts_in
+    get_amounts_in,
+    DEFAULT_FEE
+};
+pub use routes::{best_path_out, MAX_HOPS, MAX_INTERMEDIATES};
+pub use swap_quote::{get_swap_quote, SwapQuote, PRICE_SCALE};
+pub use stable::{
+    get_stable_d,
+    get_stable_y,
+    get_stable_amount_out,
+    get_stable_amount_in,
+    MAX_AMP
+};
+pub use weighted::{
+    get_weighted_y,
+    get_weighted_amount_out,
+    get_weighted_amount_in,
+    get_weighted_invariant,
+    get_weighted_invariant_up,
+    WEIGHT_DENOMINATOR,
+    MIN_WEIGHT
+};
+pub use pools::{
+    pool_for,
+    get_amounts_out_mixed,
+    get_amounts_in_mixed,
+    PoolType
 };
 pub use error::SoroswapLibraryError;
 
@@ -30,6 +67,7 @@ pub use error::SoroswapLibraryError;
 
 
 
+#[cfg(feature = "contract")]
 pub trait SoroswapLibraryTrait {
     
     /// Sorts two token addresses in a consistent order.
@@ -73,6 +111,20 @@ pub trait SoroswapLibraryTrait {
     /// Returns `Result<(i128, i128), SoroswapLibraryError>` where `Ok` contains a tuple of sorted reserves, and `Err` indicates an error such as identical tokens or an issue with sorting.
     fn get_reserves(e: Env,factory: Address, token_a: Address, token_b: Address) -> Result<(i128, i128), SoroswapLibraryError>;
 
+    /// Fetches the swap fee of the pair of tokens, in basis points.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<u32, SoroswapLibraryError>` where `Ok` contains the swap fee of the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+    fn get_fee(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<u32, SoroswapLibraryError>;
+
     /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
     ///
     /// # Arguments
@@ -112,6 +164,34 @@ pub trait SoroswapLibraryTrait {
     /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
     fn get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError>;
 
+    /// Given an input amount of an asset, pair reserves and the pair swap fee, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid fee.
+    fn get_amount_out_with_fee(amount_in: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Given an output amount of an asset, pair reserves and the pair swap fee, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid fee.
+    fn get_amount_in_with_fee(amount_out: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
     /// Performs chained get_amount_out calculations on any number of pairs.
     ///
     /// # Arguments
@@ -139,15 +219,184 @@ pub trait SoroswapLibraryTrait {
     ///
     /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
     fn get_amounts_in(e: Env, factory: Address, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, SoroswapLibraryError>;
+
+    /// Finds the path from `token_in` to `token_out` that gives the maximal output for `amount_in`.
+    /// Candidate paths go through at most `max_hops` pairs, using any of the `intermediates` tokens at most once.
+    /// Pairs that do not exist or do not have enough liquidity are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_in` - The address of the input token.
+    /// * `token_out` - The address of the output token.
+    /// * `amount_in` - The input amount.
+    /// * `intermediates` - The tokens that paths can go through, at most `MAX_INTERMEDIATES`.
+    /// * `max_hops` - The maximum number of pairs of a path, at most `MAX_HOPS`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Address>, SoroswapLibraryError>` where `Ok` contains the best path, and `Err` indicates an error such as no path between the tokens or too many hops or intermediates.
+    fn best_path_out(e: Env, factory: Address, token_in: Address, token_out: Address, amount_in: i128, intermediates: Vec<Address>, max_hops: u32) -> Result<Vec<Address>, SoroswapLibraryError>;
+
+    /// Quotes a swap of an exact input amount along a path: amounts of each hop, mid price and execution price
+    /// (scaled by `PRICE_SCALE`), price impact in basis points and the fees paid to each pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<SwapQuote, SoroswapLibraryError>` where `Ok` contains the quote of the swap, and `Err` indicates an error such as an invalid path.
+    fn get_swap_quote(e: Env, factory: Address, amount_in: i128, path: Vec<Address>) -> Result<SwapQuote, SoroswapLibraryError>;
+
+    /// Calculates the deterministic address for the stable pair of two tokens without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the stable pair, and `Err` indicates an error such as identical tokens.
+    fn stable_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError>;
+
+    /// Given an input amount of an asset and the state of a stable pair, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn get_stable_amount_out(e: Env, amount_in: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Given an output amount of an asset and the state of a stable pair, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
+    fn get_stable_amount_in(e: Env, amount_out: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Calculates the deterministic address for the weighted pair of two tokens without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the weighted pair, and `Err` indicates an error such as identical tokens.
+    fn weighted_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError>;
+
+    /// Given an input amount of an asset and the state of a weighted pair, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `weight_in` - The weight of the input asset, in basis points.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid weight.
+    fn get_weighted_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Given an output amount of an asset and the state of a weighted pair, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `weight_in` - The weight of the input asset, in basis points.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid weight.
+    fn get_weighted_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, fee: u32) -> Result<i128, SoroswapLibraryError>;
+
+    /// Calculates the deterministic address for the concentrated pool of two tokens in a fee tier without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    /// * `fee` - The fee tier of the pool, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the concentrated pool, and `Err` indicates an error such as identical tokens.
+    fn concentrated_pool_for(e: Env, factory: Address, token_a: Address, token_b: Address, fee: u32) -> Result<Address, SoroswapLibraryError>;
+
+    /// Performs chained get_amount_out calculations on a path going through pools of different curves.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `pool_types` - The curve of the pool used by each hop of the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn get_amounts_out_mixed(e: Env, factory: Address, amount_in: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, SoroswapLibraryError>;
+
+    /// Performs chained get_amount_in calculations on a path going through pools of different curves.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `pool_types` - The curve of the pool used by each hop of the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn get_amounts_in_mixed(e: Env, factory: Address, amount_out: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, SoroswapLibraryError>;
     
 
 
    
 }
 
+#[cfg(feature = "contract")]
 #[contract]
 pub struct SoroswapLibrary;
 
+#[cfg(feature = "contract")]
 #[contractimpl]
 impl SoroswapLibraryTrait for SoroswapLibrary {
 
@@ -201,6 +450,22 @@ impl SoroswapLibraryTrait for SoroswapLibrary {
 
     }
 
+    /// Fetches the swap fee of the pair of tokens, in basis points.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<u32, SoroswapLibraryError>` where `Ok` contains the swap fee of the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+    fn get_fee(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<u32, SoroswapLibraryError> {
+        get_fee(e, factory, token_a, token_b)
+    }
+
     /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
     ///
     /// # Arguments
@@ -247,6 +512,38 @@ impl SoroswapLibraryTrait for SoroswapLibrary {
         get_amount_in(amount_out, reserve_in, reserve_out)
     }
 
+    /// Given an input amount of an asset, pair reserves and the pair swap fee, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid fee.
+    fn get_amount_out_with_fee(amount_in: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_amount_out_with_fee(amount_in, reserve_in, reserve_out, fee)
+    }
+
+    /// Given an output amount of an asset, pair reserves and the pair swap fee, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid fee.
+    fn get_amount_in_with_fee(amount_out: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_amount_in_with_fee(amount_out, reserve_in, reserve_out, fee)
+    }
+
     /// Performs chained get_amount_out calculations on any number of pairs.
     ///
     /// # Arguments
@@ -279,6 +576,195 @@ impl SoroswapLibraryTrait for SoroswapLibrary {
         get_amounts_in(e, factory, amount_out, path)
     }
 
+    /// Finds the path from `token_in` to `token_out` that gives the maximal output for `amount_in`.
+    /// Candidate paths go through at most `max_hops` pairs, using any of the `intermediates` tokens at most once.
+    /// Pairs that do not exist or do not have enough liquidity are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_in` - The address of the input token.
+    /// * `token_out` - The address of the output token.
+    /// * `amount_in` - The input amount.
+    /// * `intermediates` - The tokens that paths can go through, at most `MAX_INTERMEDIATES`.
+    /// * `max_hops` - The maximum number of pairs of a path, at most `MAX_HOPS`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Address>, SoroswapLibraryError>` where `Ok` contains the best path, and `Err` indicates an error such as no path between the tokens or too many hops or intermediates.
+    fn best_path_out(e: Env, factory: Address, token_in: Address, token_out: Address, amount_in: i128, intermediates: Vec<Address>, max_hops: u32) -> Result<Vec<Address>, SoroswapLibraryError> {
+        best_path_out(e, factory, token_in, token_out, amount_in, intermediates, max_hops)
+    }
+
+    /// Quotes a swap of an exact input amount along a path: amounts of each hop, mid price and execution price
+    /// (scaled by `PRICE_SCALE`), price impact in basis points and the fees paid to each pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<SwapQuote, SoroswapLibraryError>` where `Ok` contains the quote of the swap, and `Err` indicates an error such as an invalid path.
+    fn get_swap_quote(e: Env, factory: Address, amount_in: i128, path: Vec<Address>) -> Result<SwapQuote, SoroswapLibraryError> {
+        get_swap_quote(e, factory, amount_in, path)
+    }
+
+    /// Calculates the deterministic address for the stable pair of two tokens without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the stable pair, and `Err` indicates an error such as identical tokens.
+    fn stable_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+        stable_pair_for(e, factory, token_a, token_b)
+    }
+
+    /// Given an input amount of an asset and the state of a stable pair, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn get_stable_amount_out(e: Env, amount_in: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_stable_amount_out(&e, amount_in, reserve_in, reserve_out, amp, fee)
+    }
+
+    /// Given an output amount of an asset and the state of a stable pair, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `amp` - The amplification coefficient of the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
+    fn get_stable_amount_in(e: Env, amount_out: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_stable_amount_in(&e, amount_out, reserve_in, reserve_out, amp, fee)
+    }
+
+    /// Calculates the deterministic address for the weighted pair of two tokens without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the weighted pair, and `Err` indicates an error such as identical tokens.
+    fn weighted_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+        weighted_pair_for(e, factory, token_a, token_b)
+    }
+
+    /// Given an input amount of an asset and the state of a weighted pair, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `weight_in` - The weight of the input asset, in basis points.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid weight.
+    fn get_weighted_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_weighted_amount_out(amount_in, reserve_in, reserve_out, weight_in, fee)
+    }
+
+    /// Given an output amount of an asset and the state of a weighted pair, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `weight_in` - The weight of the input asset, in basis points.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid weight.
+    fn get_weighted_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+        get_weighted_amount_in(amount_out, reserve_in, reserve_out, weight_in, fee)
+    }
+
+    /// Calculates the deterministic address for the concentrated pool of two tokens in a fee tier without making any external calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    /// * `fee` - The fee tier of the pool, in basis points.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the concentrated pool, and `Err` indicates an error such as identical tokens.
+    fn concentrated_pool_for(e: Env, factory: Address, token_a: Address, token_b: Address, fee: u32) -> Result<Address, SoroswapLibraryError> {
+        concentrated_pool_for(e, factory, token_a, token_b, fee)
+    }
+
+    /// Performs chained get_amount_out calculations on a path going through pools of different curves.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `pool_types` - The curve of the pool used by each hop of the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn get_amounts_out_mixed(e: Env, factory: Address, amount_in: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, SoroswapLibraryError> {
+        get_amounts_out_mixed(e, factory, amount_in, path, pool_types)
+    }
+
+    /// Performs chained get_amount_in calculations on a path going through pools of different curves.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `factory` - The factory address.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `pool_types` - The curve of the pool used by each hop of the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn get_amounts_in_mixed(e: Env, factory: Address, amount_out: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, SoroswapLibraryError> {
+        get_amounts_in_mixed(e, factory, amount_out, path, pool_types)
+    }
+
 
 
 }
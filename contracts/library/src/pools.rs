@@ -0,0 +1,216 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Vec};
+
+use crate::tokens::{sort_tokens, pair_for, stable_pair_for, weighted_pair_for, concentrated_pool_for};
+use crate::reserves::{get_reserves, get_fee};
+use crate::quotes::{get_amount_out_with_fee, get_amount_in_with_fee};
+use crate::stable::{get_stable_amount_out, get_stable_amount_in};
+use crate::weighted::{get_weighted_amount_out, get_weighted_amount_in, WEIGHT_DENOMINATOR};
+use crate::error::SoroswapLibraryError;
+
+/// Curve of a pool created by the SoroswapFactory. Mirrors the `PoolType` of the factory interface.
+// Only in the spec of the SoroswapLibrary contract, not of the contracts that use its functions
+#[cfg_attr(feature = "contract", contracttype)]
+#[cfg_attr(not(feature = "contract"), contracttype(export = false))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PoolType {
+    /// SoroswapPair, `x·y = k`
+    ConstantProduct,
+    /// SoroswapStablePair, StableSwap invariant with an amplification coefficient
+    Stable,
+    /// SoroswapWeightedPair, `x^w_x · y^w_y = k` with weights other than 50/50
+    Weighted,
+    /// SoroswapConcentratedPool with the given fee tier in basis points, liquidity within ranges of ticks
+    Concentrated(u32),
+}
+
+/// Read-only interface of a SoroswapStablePair used by the library.
+#[contractclient(name = "SoroswapStablePairClient")]
+pub trait SoroswapStablePairInterface {
+    fn get_reserves(e: Env) -> (i128, i128);
+    fn fee(e: Env) -> u32;
+    fn amp(e: Env) -> u32;
+}
+
+/// Read-only interface of a SoroswapWeightedPair used by the library.
+#[contractclient(name = "SoroswapWeightedPairClient")]
+pub trait SoroswapWeightedPairInterface {
+    fn get_reserves(e: Env) -> (i128, i128);
+    fn fee(e: Env) -> u32;
+    fn weights(e: Env) -> (u32, u32);
+}
+
+/// Read-only interface of a SoroswapConcentratedPool used by the library. The pool quotes its swaps itself,
+/// as they depend on the liquidity of the ranges of ticks they cross.
+#[contractclient(name = "SoroswapConcentratedPoolClient")]
+pub trait SoroswapConcentratedPoolInterface {
+    fn quote_exact_input(e: Env, zero_for_one: bool, amount_in: i128) -> i128;
+    fn quote_exact_output(e: Env, zero_for_one: bool, amount_out: i128) -> i128;
+}
+
+/// Calculates the deterministic address of the pool of `pool_type` for a pair of tokens.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+/// * `pool_type` - The curve of the pool.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address of the pool, and `Err` indicates an error such as identical tokens.
+pub fn pool_for(e: Env, factory: Address, token_a: Address, token_b: Address, pool_type: PoolType) -> Result<Address, SoroswapLibraryError> {
+    match pool_type {
+        PoolType::ConstantProduct => pair_for(e, factory, token_a, token_b),
+        PoolType::Stable => stable_pair_for(e, factory, token_a, token_b),
+        PoolType::Weighted => weighted_pair_for(e, factory, token_a, token_b),
+        PoolType::Concentrated(fee) => concentrated_pool_for(e, factory, token_a, token_b, fee),
+    }
+}
+
+/// Fetches the reserves sorted as `token_a`, `token_b`, the swap fee and the amplification coefficient of a stable pair.
+fn get_stable_pair_state(e: &Env, factory: &Address, token_a: Address, token_b: Address) -> Result<(i128, i128, u32, u32), SoroswapLibraryError> {
+    let (token_0, token_1) = sort_tokens(token_a.clone(), token_b)?;
+    let pair_client = SoroswapStablePairClient::new(e, &stable_pair_for(e.clone(), factory.clone(), token_0.clone(), token_1)?);
+    let (reserve_0, reserve_1) = pair_client.get_reserves();
+    let (reserve_a, reserve_b) = if token_a == token_0 { (reserve_0, reserve_1) } else { (reserve_1, reserve_0) };
+    Ok((reserve_a, reserve_b, pair_client.fee(), pair_client.amp()))
+}
+
+/// Fetches the reserves sorted as `token_a`, `token_b`, the swap fee and the weight of `token_a` of a weighted pair.
+fn get_weighted_pair_state(e: &Env, factory: &Address, token_a: Address, token_b: Address) -> Result<(i128, i128, u32, u32), SoroswapLibraryError> {
+    let (token_0, token_1) = sort_tokens(token_a.clone(), token_b)?;
+    let pair_client = SoroswapWeightedPairClient::new(e, &weighted_pair_for(e.clone(), factory.clone(), token_0.clone(), token_1)?);
+    let (reserve_0, reserve_1) = pair_client.get_reserves();
+    let (weight_0, _) = pair_client.weights();
+    if token_a == token_0 {
+        Ok((reserve_0, reserve_1, pair_client.fee(), weight_0))
+    } else {
+        Ok((reserve_1, reserve_0, pair_client.fee(), WEIGHT_DENOMINATOR - weight_0))
+    }
+}
+
+// Concentrated pool of token_in and token_out in the fee tier, and whether token_in is its token_0
+fn get_concentrated_pool_client<'a>(e: &Env, factory: &Address, token_in: Address, token_out: Address, fee: u32) -> Result<(SoroswapConcentratedPoolClient<'a>, bool), SoroswapLibraryError> {
+    let (token_0, _) = sort_tokens(token_in.clone(), token_out.clone())?;
+    let pool_address = concentrated_pool_for(e.clone(), factory.clone(), token_in.clone(), token_out, fee)?;
+    Ok((SoroswapConcentratedPoolClient::new(e, &pool_address), token_in == token_0))
+}
+
+// Output of swapping amount_in of token_in in the pool of pool_type
+fn hop_amount_out(e: &Env, factory: &Address, amount_in: i128, token_in: Address, token_out: Address, pool_type: PoolType) -> Result<i128, SoroswapLibraryError> {
+    match pool_type {
+        PoolType::ConstantProduct => {
+            let (reserve_in, reserve_out) = get_reserves(e.clone(), factory.clone(), token_in.clone(), token_out.clone())?;
+            let fee = get_fee(e.clone(), factory.clone(), token_in, token_out)?;
+            get_amount_out_with_fee(amount_in, reserve_in, reserve_out, fee)
+        }
+        PoolType::Stable => {
+            let (reserve_in, reserve_out, fee, amp) = get_stable_pair_state(e, factory, token_in, token_out)?;
+            get_stable_amount_out(e, amount_in, reserve_in, reserve_out, amp, fee)
+        }
+        PoolType::Weighted => {
+            let (reserve_in, reserve_out, fee, weight_in) = get_weighted_pair_state(e, factory, token_in, token_out)?;
+            get_weighted_amount_out(amount_in, reserve_in, reserve_out, weight_in, fee)
+        }
+        PoolType::Concentrated(fee) => {
+            if amount_in <= 0 {
+                return Err(SoroswapLibraryError::InsufficientInputAmount);
+            }
+            // The pool fails when its ranges can not fill the swap, or when it does not exist
+            let (pool_client, zero_for_one) = get_concentrated_pool_client(e, factory, token_in, token_out, fee)?;
+            match pool_client.try_quote_exact_input(&zero_for_one, &amount_in) {
+                Ok(Ok(amount_out)) => Ok(amount_out),
+                _ => Err(SoroswapLibraryError::InsufficientLiquidity),
+            }
+        }
+    }
+}
+
+// Input of token_in required to receive amount_out from the pool of pool_type
+fn hop_amount_in(e: &Env, factory: &Address, amount_out: i128, token_in: Address, token_out: Address, pool_type: PoolType) -> Result<i128, SoroswapLibraryError> {
+    match pool_type {
+        PoolType::ConstantProduct => {
+            let (reserve_in, reserve_out) = get_reserves(e.clone(), factory.clone(), token_in.clone(), token_out.clone())?;
+            let fee = get_fee(e.clone(), factory.clone(), token_in, token_out)?;
+            get_amount_in_with_fee(amount_out, reserve_in, reserve_out, fee)
+        }
+        PoolType::Stable => {
+            let (reserve_in, reserve_out, fee, amp) = get_stable_pair_state(e, factory, token_in, token_out)?;
+            get_stable_amount_in(e, amount_out, reserve_in, reserve_out, amp, fee)
+        }
+        PoolType::Weighted => {
+            let (reserve_in, reserve_out, fee, weight_in) = get_weighted_pair_state(e, factory, token_in, token_out)?;
+            get_weighted_amount_in(amount_out, reserve_in, reserve_out, weight_in, fee)
+        }
+        PoolType::Concentrated(fee) => {
+            if amount_out <= 0 {
+                return Err(SoroswapLibraryError::InsufficientOutputAmount);
+            }
+            let (pool_client, zero_for_one) = get_concentrated_pool_client(e, factory, token_in, token_out, fee)?;
+            match pool_client.try_quote_exact_output(&zero_for_one, &amount_out) {
+                Ok(Ok(amount_in)) => Ok(amount_in),
+                _ => Err(SoroswapLibraryError::InsufficientLiquidity),
+            }
+        }
+    }
+}
+
+/// Performs chained get_amount_out calculations on a path going through pools of different curves.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `amount_in` - The input amount.
+/// * `path` - Vector of token addresses representing the path.
+/// * `pool_types` - The curve of the pool used by each hop of the path, one less than the tokens of the path.
+///
+/// # Returns
+///
+/// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+pub fn get_amounts_out_mixed(e: Env, factory: Address, amount_in: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, SoroswapLibraryError> {
+    if path.len() < 2 || pool_types.len() != path.len() - 1 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+
+    let mut amounts = Vec::new(&e);
+    amounts.push_back(amount_in);
+
+    for i in 0..path.len() - 1 {
+        let amount_out = hop_amount_out(&e, &factory, amounts.get(i).unwrap(), path.get(i).unwrap(), path.get(i + 1).unwrap(), pool_types.get(i).unwrap())?;
+        amounts.push_back(amount_out);
+    }
+
+    Ok(amounts)
+}
+
+/// Performs chained get_amount_in calculations on a path going through pools of different curves.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `amount_out` - The output amount.
+/// * `path` - Vector of token addresses representing the path.
+/// * `pool_types` - The curve of the pool used by each hop of the path, one less than the tokens of the path.
+///
+/// # Returns
+///
+/// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+pub fn get_amounts_in_mixed(e: Env, factory: Address, amount_out: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, SoroswapLibraryError> {
+    if path.len() < 2 || pool_types.len() != path.len() - 1 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+
+    let mut amounts = Vec::new(&e);
+    amounts.push_front(amount_out);
+
+    for i in (1..path.len()).rev() {
+        let amount_in = hop_amount_in(&e, &factory, amounts.get(0).unwrap(), path.get(i - 1).unwrap(), path.get(i).unwrap(), pool_types.get(i - 1).unwrap())?;
+        amounts.push_front(amount_in);
+    }
+
+    Ok(amounts)
+}
@@ -1,8 +1,12 @@
 use soroban_sdk::{Address, Env, Vec};
 
-use crate::reserves::{get_reserves};
+use crate::reserves::{get_reserves, get_fee};
 use crate::error::SoroswapLibraryError;
 
+/// Swap fee of the pairs created with the default fee tier, in basis points.
+pub const DEFAULT_FEE: u32 = 30;
+pub(crate) const FEE_DENOMINATOR: i128 = 10000;
+
 /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
 ///
 /// # Arguments
@@ -24,7 +28,8 @@ pub fn quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, S
     Ok(amount_a.checked_mul(reserve_b).ok_or(SoroswapLibraryError::InsufficientLiquidity)?.checked_div(reserve_a).ok_or(SoroswapLibraryError::InsufficientLiquidity)?)
 }
 
-/// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset.
+/// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset,
+/// for a pair with the default 0.3% swap fee.
 ///
 /// # Arguments
 ///
@@ -36,22 +41,40 @@ pub fn quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, S
 ///
 /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
 pub fn get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError> {
+    get_amount_out_with_fee(amount_in, reserve_in, reserve_out, DEFAULT_FEE)
+}
+
+/// Given an input amount of an asset, pair reserves and the pair swap fee, returns the maximum output amount of the other asset.
+///
+/// # Arguments
+///
+/// * `amount_in` - The input amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid fee.
+pub fn get_amount_out_with_fee(amount_in: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError> {
     if amount_in <= 0 {
         return Err(SoroswapLibraryError::InsufficientInputAmount);
     }
     if reserve_in <= 0 || reserve_out <= 0 {
         return Err(SoroswapLibraryError::InsufficientLiquidity);
     }
+    let fee_complement = fee_complement(fee)?;
 
-    let amount_in_with_fee = amount_in.checked_mul(997).unwrap();
+    let amount_in_with_fee = amount_in.checked_mul(fee_complement).unwrap();
     let numerator = amount_in_with_fee.checked_mul(reserve_out).unwrap();
 
-    let denominator = reserve_in.checked_mul(1000).unwrap().checked_add(amount_in_with_fee).unwrap();
+    let denominator = reserve_in.checked_mul(FEE_DENOMINATOR).unwrap().checked_add(amount_in_with_fee).unwrap();
 
     Ok(numerator.checked_div(denominator).unwrap())
 }
 
-/// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset.
+/// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset,
+/// for a pair with the default 0.3% swap fee.
 ///
 /// # Arguments
 ///
@@ -63,18 +86,45 @@ pub fn get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> R
 ///
 /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
 pub fn get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, SoroswapLibraryError> {
+    get_amount_in_with_fee(amount_out, reserve_in, reserve_out, DEFAULT_FEE)
+}
+
+/// Given an output amount of an asset, pair reserves and the pair swap fee, returns a required input amount of the other asset.
+///
+/// # Arguments
+///
+/// * `amount_out` - The output amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid fee.
+pub fn get_amount_in_with_fee(amount_out: i128, reserve_in: i128, reserve_out: i128, fee: u32) -> Result<i128, SoroswapLibraryError> {
     if amount_out <= 0 {
         return Err(SoroswapLibraryError::InsufficientOutputAmount);
     }
     if reserve_in <= 0 || reserve_out <= 0 {
         return Err(SoroswapLibraryError::InsufficientLiquidity);
     }
-    let numerator = reserve_in.checked_mul(amount_out).unwrap().checked_mul(1000).unwrap();
-    let denominator = reserve_out.checked_sub(amount_out).unwrap().checked_mul(997).unwrap();
+    let fee_complement = fee_complement(fee)?;
+
+    let numerator = reserve_in.checked_mul(amount_out).unwrap().checked_mul(FEE_DENOMINATOR).unwrap();
+    let denominator = reserve_out.checked_sub(amount_out).unwrap().checked_mul(fee_complement).unwrap();
     Ok(numerator.checked_div(denominator).unwrap().checked_add(1).unwrap())
 }
 
-/// Performs chained getAmountOut calculations on any number of pairs.
+// Returns the share of the input amount that is not taken as fee, over FEE_DENOMINATOR
+pub(crate) fn fee_complement(fee: u32) -> Result<i128, SoroswapLibraryError> {
+    let fee: i128 = fee.into();
+    if fee >= FEE_DENOMINATOR {
+        return Err(SoroswapLibraryError::InvalidFee);
+    }
+    Ok(FEE_DENOMINATOR.checked_sub(fee).unwrap())
+}
+
+/// Performs chained getAmountOut calculations on any number of pairs, using the swap fee of each pair.
 ///
 /// # Arguments
 ///
@@ -96,13 +146,14 @@ pub fn get_amounts_out(e: Env, factory: Address, amount_in: i128, path: Vec<Addr
 
     for i in 0..path.len() - 1 {
         let (reserve_in, reserve_out) = get_reserves(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
-        amounts.push_back(get_amount_out(amounts.get(i).unwrap(), reserve_in, reserve_out)?);
+        let fee = get_fee(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        amounts.push_back(get_amount_out_with_fee(amounts.get(i).unwrap(), reserve_in, reserve_out, fee)?);
     }
 
     Ok(amounts)
 }
 
-/// Performs chained getAmountIn calculations on any number of pairs.
+/// Performs chained getAmountIn calculations on any number of pairs, using the swap fee of each pair.
 ///
 /// # Arguments
 ///
@@ -124,7 +175,8 @@ pub fn get_amounts_in(e: Env, factory: Address, amount_out: i128, path: Vec<Addr
 
     for i in (1..path.len()).rev() {
         let (reserve_in, reserve_out) = get_reserves(e.clone(), factory.clone(), path.get(i-1).unwrap(), path.get(i).unwrap())?;
-        let new_amount = get_amount_in(amounts.get(0).unwrap(), reserve_in, reserve_out)?;
+        let fee = get_fee(e.clone(), factory.clone(), path.get(i-1).unwrap(), path.get(i).unwrap())?;
+        let new_amount = get_amount_in_with_fee(amounts.get(0).unwrap(), reserve_in, reserve_out, fee)?;
         amounts.push_front(new_amount);
     }
 
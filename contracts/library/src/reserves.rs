@@ -1,6 +1,7 @@
 use soroban_sdk::{Address, Env};
 use crate::tokens::{sort_tokens, pair_for};
 use crate::error::SoroswapLibraryError;
+use crate::quotes::DEFAULT_FEE;
 
 mod pair {
     soroban_sdk::contractimport!(
@@ -35,4 +36,43 @@ pub fn get_reserves(e: Env,factory: Address, token_a: Address, token_b: Address)
             (reserve_1, reserve_0) };
 
     Ok((reserve_a, reseve_b))
-}
\ No newline at end of file
+}
+
+/// Same as `get_reserves`, but returns `None` instead of failing if the pair of tokens does not exist.
+pub(crate) fn try_get_reserves(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Option<(i128, i128)>, SoroswapLibraryError> {
+    let (token_0, token_1) = sort_tokens(token_a.clone(), token_b.clone())?;
+    let pair_address = pair_for(e.clone(), factory, token_0.clone(), token_1.clone())?;
+    let pair_client = SoroswapPairClient::new(&e, &pair_address);
+    let (reserve_0, reserve_1) = match pair_client.try_get_reserves() {
+        Ok(Ok(reserves)) => reserves,
+        _ => return Ok(None),
+    };
+
+    if token_a == token_0 {
+        Ok(Some((reserve_0, reserve_1)))
+    } else {
+        Ok(Some((reserve_1, reserve_0)))
+    }
+}
+
+/// Fetches the swap fee of the pair of tokens, in basis points.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<u32, SoroswapLibraryError>` where `Ok` contains the swap fee of the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+/// Pairs deployed before swap fees were configurable have no `fee` function, and charge `DEFAULT_FEE`.
+pub fn get_fee(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<u32, SoroswapLibraryError> {
+    let pair_address = pair_for(e.clone(), factory, token_a, token_b)?;
+    let pair_client = SoroswapPairClient::new(&e, &pair_address);
+    match pair_client.try_fee() {
+        Ok(Ok(fee)) => Ok(fee),
+        _ => Ok(DEFAULT_FEE),
+    }
+}
@@ -0,0 +1,127 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::reserves::{try_get_reserves, get_fee};
+use crate::quotes::get_amount_out_with_fee;
+use crate::error::SoroswapLibraryError;
+
+/// Output of swapping `amount_in` of `token_in` for `token_out` in their pair, or `None` if the pair
+/// does not exist or has not enough liquidity for the swap.
+fn hop_amount_out(e: &Env, factory: &Address, token_in: Address, token_out: Address, amount_in: i128) -> Result<Option<i128>, SoroswapLibraryError> {
+    let (reserve_in, reserve_out) = match try_get_reserves(e.clone(), factory.clone(), token_in.clone(), token_out.clone())? {
+        Some(reserves) => reserves,
+        None => return Ok(None),
+    };
+    let fee = get_fee(e.clone(), factory.clone(), token_in, token_out)?;
+    Ok(get_amount_out_with_fee(amount_in, reserve_in, reserve_out, fee).ok())
+}
+
+/// Maximum number of pairs of the paths searched by `best_path_out`.
+pub const MAX_HOPS: u32 = 4;
+/// Maximum number of intermediate tokens that `best_path_out` can search through.
+pub const MAX_INTERMEDIATES: u32 = 4;
+
+// State of a depth first search of the paths to `token_out`, keeping the best one found so far
+struct PathSearch<'a> {
+    e: &'a Env,
+    factory: &'a Address,
+    token_out: &'a Address,
+    intermediates: &'a Vec<Address>,
+    path: Vec<Address>,
+    best: Option<(Vec<Address>, i128)>,
+}
+
+impl<'a> PathSearch<'a> {
+    /// Searches the paths from the last token of `path`, holding `amount` of it, through at most `hops_left` pairs.
+    fn search(&mut self, hops_left: u32, amount: i128) -> Result<(), SoroswapLibraryError> {
+        let current = self.path.last().unwrap();
+
+        if let Some(amount_out) = hop_amount_out(self.e, self.factory, current.clone(), self.token_out.clone(), amount)? {
+            // On ties the path found first, which has less hops through the same intermediates, is kept
+            let is_better = match &self.best {
+                Some((_, best_amount_out)) => amount_out > *best_amount_out,
+                None => true,
+            };
+            if is_better {
+                let mut candidate = self.path.clone();
+                candidate.push_back(self.token_out.clone());
+                self.best = Some((candidate, amount_out));
+            }
+        }
+
+        if hops_left <= 1 {
+            return Ok(());
+        }
+
+        for intermediate in self.intermediates.iter() {
+            if intermediate == *self.token_out || self.path.contains(&intermediate) {
+                continue;
+            }
+            if let Some(amount_out) = hop_amount_out(self.e, self.factory, current.clone(), intermediate.clone(), amount)? {
+                self.path.push_back(intermediate);
+                self.search(hops_left - 1, amount_out)?;
+                self.path.pop_back();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Finds the path from `token_in` to `token_out` that gives the maximal output for `amount_in`.
+/// Candidate paths go through at most `max_hops` pairs, using any of the `intermediates` tokens at most once.
+/// Pairs that do not exist or do not have enough liquidity are skipped. The number of candidate paths grows
+/// exponentially, so `max_hops` is capped to `MAX_HOPS` and `intermediates` to `MAX_INTERMEDIATES` tokens.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_in` - The address of the input token.
+/// * `token_out` - The address of the output token.
+/// * `amount_in` - The input amount.
+/// * `intermediates` - The tokens that paths can go through, at most `MAX_INTERMEDIATES`.
+/// * `max_hops` - The maximum number of pairs of a path, at most `MAX_HOPS`.
+///
+/// # Returns
+///
+/// Returns `Result<Vec<Address>, SoroswapLibraryError>` where `Ok` contains the best path, and `Err` indicates an error such as no path between the tokens or too many hops or intermediates.
+pub fn best_path_out(
+    e: Env,
+    factory: Address,
+    token_in: Address,
+    token_out: Address,
+    amount_in: i128,
+    intermediates: Vec<Address>,
+    max_hops: u32,
+) -> Result<Vec<Address>, SoroswapLibraryError> {
+    if token_in == token_out {
+        return Err(SoroswapLibraryError::SortIdenticalTokens);
+    }
+    if amount_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if max_hops == 0 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+    if max_hops > MAX_HOPS {
+        return Err(SoroswapLibraryError::TooManyHops);
+    }
+    if intermediates.len() > MAX_INTERMEDIATES {
+        return Err(SoroswapLibraryError::TooManyIntermediates);
+    }
+
+    let mut search = PathSearch {
+        e: &e,
+        factory: &factory,
+        token_out: &token_out,
+        intermediates: &intermediates,
+        path: Vec::from_array(&e, [token_in]),
+        best: None,
+    };
+    search.search(max_hops, amount_in)?;
+
+    match search.best {
+        Some((best_path, _)) => Ok(best_path),
+        None => Err(SoroswapLibraryError::NoPathFound),
+    }
+}
@@ -0,0 +1,208 @@
+use soroban_sdk::{Env, U256};
+
+use crate::error::SoroswapLibraryError;
+use crate::quotes::{fee_complement, FEE_DENOMINATOR};
+
+/// Maximum amplification coefficient of a stable pair.
+pub const MAX_AMP: u32 = 10_000;
+
+const N_COINS: u32 = 2;
+const MAX_ITERATIONS: u32 = 255;
+
+/// Computes the StableSwap invariant `D` of a two tokens pool, that solves
+/// `A·n^n·(x + y) + D = A·D·n^n + D^(n+1) / (n^n·x·y)` with `n = 2`, by Newton's method.
+/// The intermediate products are computed in 256 bits.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `reserve_0` - Reserves of the first token in the pair.
+/// * `reserve_1` - Reserves of the second token in the pair.
+/// * `amp` - The amplification coefficient `A` of the pair.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the invariant, and `Err` indicates an error such as
+/// empty reserves or an invariant that can not be computed within 256 bits.
+pub fn get_stable_d(e: &Env, reserve_0: i128, reserve_1: i128, amp: u32) -> Result<i128, SoroswapLibraryError> {
+    if reserve_0 <= 0 || reserve_1 <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    if amp == 0 || amp > MAX_AMP {
+        return Err(SoroswapLibraryError::InvalidAmp);
+    }
+    let n_coins = U256::from_u32(e, N_COINS);
+    let reserve_0 = U256::from_u128(e, reserve_0 as u128);
+    let reserve_1 = U256::from_u128(e, reserve_1 as u128);
+    let sum = reserve_0.add(&reserve_1);
+    let ann = U256::from_u32(e, amp * N_COINS);
+    let ann_sum = checked_mul(e, &ann, &sum)?;
+
+    let mut d = sum.clone();
+    for _ in 0..MAX_ITERATIONS {
+        // d_p = D^3 / (n^n·x·y)
+        let d_p = checked_mul(e, &d, &d)?.div(&reserve_0.mul(&n_coins));
+        let d_p = checked_mul(e, &d_p, &d)?.div(&reserve_1.mul(&n_coins));
+        let previous_d = d.clone();
+
+        let numerator = checked_add(e, &ann_sum, &checked_mul(e, &d_p, &n_coins)?)?;
+        let denominator = checked_add(
+            e,
+            &checked_mul(e, &ann.sub(&U256::from_u32(e, 1)), &d)?,
+            &checked_mul(e, &d_p, &U256::from_u32(e, N_COINS + 1))?,
+        )?;
+        d = checked_mul(e, &numerator, &d)?.div(&denominator);
+
+        if abs_diff(&d, &previous_d) <= U256::from_u32(e, 1) {
+            return to_i128(&d);
+        }
+    }
+    Err(SoroswapLibraryError::StableInvariantFailed)
+}
+
+/// Given the new reserve of one token and the StableSwap invariant `D`, returns the reserve of the other token
+/// that keeps the invariant, solving `y^2 + (x + D/(A·n) - D)·y = D^(n+1) / (n^n·x·A·n)` by Newton's method.
+/// The intermediate products are computed in 256 bits.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `reserve_x` - The new reserve of the first token.
+/// * `d` - The invariant of the pair.
+/// * `amp` - The amplification coefficient `A` of the pair.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the reserve of the other token, and `Err` indicates an error such as
+/// empty reserves or a reserve that can not be computed within 256 bits.
+pub fn get_stable_y(e: &Env, reserve_x: i128, d: i128, amp: u32) -> Result<i128, SoroswapLibraryError> {
+    if reserve_x <= 0 || d <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    if amp == 0 || amp > MAX_AMP {
+        return Err(SoroswapLibraryError::InvalidAmp);
+    }
+    let n_coins = U256::from_u32(e, N_COINS);
+    let reserve_x = U256::from_u128(e, reserve_x as u128);
+    let d = U256::from_u128(e, d as u128);
+    let ann = U256::from_u32(e, amp * N_COINS);
+
+    let c = checked_mul(e, &d, &d)?.div(&reserve_x.mul(&n_coins));
+    let c = checked_mul(e, &c, &d)?.div(&ann.mul(&n_coins));
+    let b = reserve_x.add(&d.div(&ann));
+
+    let mut y = d.clone();
+    for _ in 0..MAX_ITERATIONS {
+        let previous_y = y.clone();
+        let numerator = checked_add(e, &checked_mul(e, &y, &y)?, &c)?;
+        let denominator = checked_add(e, &checked_mul(e, &y, &n_coins)?, &b)?;
+        if denominator <= d {
+            return Err(SoroswapLibraryError::StableInvariantFailed);
+        }
+        y = numerator.div(&denominator.sub(&d));
+
+        if abs_diff(&y, &previous_y) <= U256::from_u32(e, 1) {
+            return to_i128(&y);
+        }
+    }
+    Err(SoroswapLibraryError::StableInvariantFailed)
+}
+
+/// Given an input amount of an asset, the reserves, the amplification coefficient and the swap fee of a stable pair,
+/// returns the maximum output amount of the other asset.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `amount_in` - The input amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `amp` - The amplification coefficient of the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid fee.
+pub fn get_stable_amount_out(e: &Env, amount_in: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+    if amount_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if reserve_in <= 0 || reserve_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    fee_complement(fee)?;
+
+    // The pair takes the fee rounded down from the input amount
+    let fee_amount = amount_in.checked_mul(fee.into()).ok_or(SoroswapLibraryError::StableInvariantFailed)? / FEE_DENOMINATOR;
+    let new_reserve_in = reserve_in.checked_add(amount_in - fee_amount).ok_or(SoroswapLibraryError::StableInvariantFailed)?;
+
+    let d = get_stable_d(e, reserve_in, reserve_out, amp)?;
+    let new_reserve_out = get_stable_y(e, new_reserve_in, d, amp)?;
+
+    // One unit is kept in the pair, as the new reserve is only accurate to one unit
+    Ok(reserve_out.checked_sub(new_reserve_out).unwrap().checked_sub(1).unwrap().max(0))
+}
+
+/// Given an output amount of an asset, the reserves, the amplification coefficient and the swap fee of a stable pair,
+/// returns a required input amount of the other asset.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `amount_out` - The output amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `amp` - The amplification coefficient of the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid fee.
+pub fn get_stable_amount_in(e: &Env, amount_out: i128, reserve_in: i128, reserve_out: i128, amp: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+    if amount_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientOutputAmount);
+    }
+    if reserve_in <= 0 || reserve_out <= amount_out {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    let fee_complement = fee_complement(fee)?;
+
+    let d = get_stable_d(e, reserve_in, reserve_out, amp)?;
+    let new_reserve_in = get_stable_y(e, reserve_out - amount_out, d, amp)?;
+    let amount_in_without_fee = new_reserve_in.checked_sub(reserve_in).unwrap().checked_add(1).unwrap().max(1);
+
+    // Rounded up, so the input left after the fee is at least amount_in_without_fee
+    let numerator = amount_in_without_fee.checked_mul(FEE_DENOMINATOR).ok_or(SoroswapLibraryError::StableInvariantFailed)?;
+    Ok((numerator + fee_complement - 1) / fee_complement)
+}
+
+// a * b, failing if the product does not fit in 256 bits
+fn checked_mul(e: &Env, a: &U256, b: &U256) -> Result<U256, SoroswapLibraryError> {
+    let zero = U256::from_u32(e, 0);
+    if *a != zero && *b > u256_max(e).div(a) {
+        return Err(SoroswapLibraryError::StableInvariantFailed);
+    }
+    Ok(a.mul(b))
+}
+
+// a + b, failing if the sum does not fit in 256 bits
+fn checked_add(e: &Env, a: &U256, b: &U256) -> Result<U256, SoroswapLibraryError> {
+    if *b > u256_max(e).sub(a) {
+        return Err(SoroswapLibraryError::StableInvariantFailed);
+    }
+    Ok(a.add(b))
+}
+
+fn u256_max(e: &Env) -> U256 {
+    U256::from_parts(e, u64::MAX, u64::MAX, u64::MAX, u64::MAX)
+}
+
+fn abs_diff(a: &U256, b: &U256) -> U256 {
+    if a > b { a.sub(b) } else { b.sub(a) }
+}
+
+fn to_i128(a: &U256) -> Result<i128, SoroswapLibraryError> {
+    a.to_u128()
+        .and_then(|a| i128::try_from(a).ok())
+        .ok_or(SoroswapLibraryError::StableInvariantFailed)
+}
@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, Address, Env, U256, Vec};
+
+use crate::reserves::{get_reserves, get_fee};
+use crate::quotes::get_amount_out_with_fee;
+use crate::error::SoroswapLibraryError;
+
+/// Fixed point scale of the prices returned in a `SwapQuote`: a price of `PRICE_SCALE` means
+/// one unit of the output token for each unit of the input token.
+pub const PRICE_SCALE: i128 = 1_000_000_000_000_000_000;
+const BASIS_POINTS: i128 = 10_000;
+
+/// Full quote of a swap of an exact input amount along a path.
+#[cfg_attr(feature = "contract", contracttype)]
+#[cfg_attr(not(feature = "contract"), contracttype(export = false))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapQuote {
+    /// Amounts of each token of the path, starting with the input amount.
+    pub amounts: Vec<i128>,
+    /// Output per unit of input at the current reserves, before fees, scaled by `PRICE_SCALE`.
+    pub mid_price: i128,
+    /// Output per unit of input actually obtained by the swap, scaled by `PRICE_SCALE`.
+    pub execution_price: i128,
+    /// Difference between the execution price and the mid price, in basis points of the mid price.
+    pub price_impact: i128,
+    /// Fees paid to each pair of the path, in units of the input token of the pair.
+    pub fee_amounts: Vec<i128>,
+}
+
+/// Quotes a swap of an exact input amount along a path, using the current reserves and swap fee of each pair.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `amount_in` - The input amount.
+/// * `path` - Vector of token addresses representing the path.
+///
+/// # Returns
+///
+/// Returns `Result<SwapQuote, SoroswapLibraryError>` where `Ok` contains the amounts, prices, price impact and fees of the swap, and `Err` indicates an error such as an invalid path, insufficient liquidity or a price that rounds to zero or does not fit in an i128 at `PRICE_SCALE`.
+pub fn get_swap_quote(e: Env, factory: Address, amount_in: i128, path: Vec<Address>) -> Result<SwapQuote, SoroswapLibraryError> {
+    if path.len() < 2 {
+        return Err(SoroswapLibraryError::InvalidPath);
+    }
+
+    let mut amounts = Vec::new(&e);
+    let mut fee_amounts = Vec::new(&e);
+    let mut mid_price = PRICE_SCALE;
+    amounts.push_back(amount_in);
+
+    for i in 0..path.len() - 1 {
+        let (reserve_in, reserve_out) = get_reserves(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        let fee = get_fee(e.clone(), factory.clone(), path.get(i).unwrap(), path.get(i+1).unwrap())?;
+        let hop_amount_in = amounts.get(i).unwrap();
+        amounts.push_back(get_amount_out_with_fee(hop_amount_in, reserve_in, reserve_out, fee)?);
+
+        fee_amounts.push_back(hop_amount_in.checked_mul(fee.into()).unwrap().checked_div(BASIS_POINTS).unwrap());
+        // The mid price is scaled by the reserve ratio of each hop, so it stays within an i128 after every hop.
+        // Both factors are below 2^127, so their product fits in a U256
+        mid_price = U256::from_u128(&e, mid_price as u128)
+            .mul(&U256::from_u128(&e, reserve_out as u128))
+            .div(&U256::from_u128(&e, reserve_in as u128))
+            .to_u128()
+            .and_then(|mid_price| i128::try_from(mid_price).ok())
+            .ok_or(SoroswapLibraryError::PriceOutOfRange)?;
+    }
+    if mid_price == 0 {
+        return Err(SoroswapLibraryError::PriceOutOfRange);
+    }
+
+    let amount_out = amounts.last().unwrap();
+    let execution_price = amount_out
+        .checked_mul(PRICE_SCALE).ok_or(SoroswapLibraryError::PriceOutOfRange)?
+        .checked_div(amount_in).unwrap();
+    let price_impact = (mid_price - execution_price)
+        .checked_mul(BASIS_POINTS).ok_or(SoroswapLibraryError::PriceOutOfRange)?
+        .checked_div(mid_price).unwrap();
+
+    Ok(SwapQuote {
+        amounts,
+        mid_price,
+        execution_price,
+        price_impact,
+        fee_amounts,
+    })
+}
@@ -22,6 +22,33 @@ fn pair_contract_wasm(e: &Env) -> BytesN<32> {
     e.deployer().upload_contract_wasm(WASM)
 }
 
+mod stable_pair {
+    soroban_sdk::contractimport!(file = "../stable-pair/target/wasm32-unknown-unknown/release/soroswap_stable_pair.wasm");
+    pub type SoroswapStablePairClient<'a> = Client<'a>;
+}
+
+fn stable_pair_contract_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(stable_pair::WASM)
+}
+
+mod weighted_pair {
+    soroban_sdk::contractimport!(file = "../weighted-pair/target/wasm32-unknown-unknown/release/soroswap_weighted_pair.wasm");
+    pub type SoroswapWeightedPairClient<'a> = Client<'a>;
+}
+
+fn weighted_pair_contract_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(weighted_pair::WASM)
+}
+
+mod concentrated_pool {
+    soroban_sdk::contractimport!(file = "../concentrated-pool/target/wasm32-unknown-unknown/release/soroswap_concentrated_pool.wasm");
+    pub type SoroswapConcentratedPoolClient<'a> = Client<'a>;
+}
+
+fn concentrated_pool_contract_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(concentrated_pool::WASM)
+}
+
 mod factory {
     soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
     pub type SoroswapFactoryClient<'a> = Client<'a>;
@@ -29,6 +56,9 @@ mod factory {
 
 use token::TokenClient;
 use pair::SoroswapPairClient;
+use stable_pair::SoroswapStablePairClient;
+use weighted_pair::SoroswapWeightedPairClient;
+use concentrated_pool::SoroswapConcentratedPoolClient;
 use factory::SoroswapFactoryClient;
 
 // Useful functions to create contracts
@@ -67,6 +97,7 @@ impl<'a> SoroswapLibraryTest<'a> {
 
         let env = Env::default();
         env.mock_all_auths();
+        env.budget().reset_unlimited();
         let contract = create_soroswap_library_contract(&env);
 
         let admin = Address::generate(&env);
@@ -100,7 +131,6 @@ impl<'a> SoroswapLibraryTest<'a> {
         //     )
 
         //pair.deposit(&user, &10000, &0, &10000, &0);
-        env.budget().reset_unlimited();
         
         SoroswapLibraryTest {
             env,
@@ -115,4 +145,9 @@ impl<'a> SoroswapLibraryTest<'a> {
 }
 
 mod quote;
-mod get;
\ No newline at end of file
+mod get;
+mod best_path;
+mod swap_quote;
+mod stable;
+mod weighted;
+mod concentrated;
\ No newline at end of file
@@ -0,0 +1,131 @@
+use soroban_sdk::{testutils::Address as _, Address, vec, Vec};
+use crate::test::{SoroswapLibraryTest, SoroswapPairClient, TokenClient, create_token_contract};
+use crate::error::SoroswapLibraryError;
+
+fn add_liquidity(test: &SoroswapLibraryTest, pair: &SoroswapPairClient, token_a: &TokenClient, token_b: &TokenClient, amount_a: i128, amount_b: i128) {
+    token_a.transfer(&test.user, &pair.address, &amount_a);
+    token_b.transfer(&test.user, &pair.address, &amount_b);
+    pair.deposit(&test.user);
+}
+
+fn create_pair<'a>(test: &SoroswapLibraryTest<'a>, token_a: &TokenClient, token_b: &TokenClient) -> SoroswapPairClient<'a> {
+    SoroswapPairClient::new(&test.env, &test.factory.create_pair(&token_a.address, &token_b.address))
+}
+
+// token_0/token_1 is a shallow pool, while token_0/token_2 and token_2/token_1 are deep pools.
+// token_3 does not have any pair.
+fn setup_pools<'a>(test: &SoroswapLibraryTest<'a>) -> (TokenClient<'a>, TokenClient<'a>) {
+    let token_2 = create_token_contract(&test.env, &test.user);
+    let token_3 = create_token_contract(&test.env, &test.user);
+    for token in [&test.token_0, &test.token_1, &token_2, &token_3] {
+        token.mint(&test.user, &10_000_000);
+    }
+    add_liquidity(test, &test.pair, &test.token_0, &test.token_1, 2_000, 2_000);
+    add_liquidity(test, &create_pair(test, &test.token_0, &token_2), &test.token_0, &token_2, 1_000_000, 1_000_000);
+    add_liquidity(test, &create_pair(test, &token_2, &test.token_1), &token_2, &test.token_1, 1_000_000, 1_000_000);
+    (token_2, token_3)
+}
+
+#[test]
+fn best_path_out_through_deeper_pools() {
+    let test = SoroswapLibraryTest::setup();
+    let (token_2, token_3) = setup_pools(&test);
+    let intermediates = vec![&test.env, token_3.address.clone(), token_2.address.clone()];
+
+    let path = test.contract.best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &intermediates, &3);
+    let expected_path = vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()];
+    assert_eq!(path, expected_path);
+
+    let direct_path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let best_amount_out = test.contract.get_amounts_out(&test.factory.address, &100, &path).last().unwrap();
+    let direct_amount_out = test.contract.get_amounts_out(&test.factory.address, &100, &direct_path).last().unwrap();
+    assert!(best_amount_out > direct_amount_out);
+}
+
+#[test]
+fn best_path_out_max_hops() {
+    let test = SoroswapLibraryTest::setup();
+    let (token_2, _) = setup_pools(&test);
+    let intermediates = vec![&test.env, token_2.address.clone()];
+
+    let path = test.contract.best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &intermediates, &1);
+    assert_eq!(path, vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]);
+}
+
+#[test]
+fn best_path_out_direct_pair_is_best() {
+    let test = SoroswapLibraryTest::setup();
+    let (token_2, _) = setup_pools(&test);
+    // Now the direct pool is the deepest one
+    add_liquidity(&test, &test.pair, &test.token_0, &test.token_1, 5_000_000, 5_000_000);
+    let intermediates = vec![&test.env, token_2.address.clone()];
+
+    let path = test.contract.best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &intermediates, &2);
+    assert_eq!(path, vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]);
+}
+
+#[test]
+fn best_path_out_skips_missing_pairs() {
+    let test = SoroswapLibraryTest::setup();
+    let (token_2, token_3) = setup_pools(&test);
+    let intermediates = vec![&test.env, test.token_1.address.clone(), token_2.address.clone()];
+
+    // token_3 does not have any pair
+    let result = test.contract.try_best_path_out(&test.factory.address, &test.token_0.address, &token_3.address, &100, &intermediates, &3);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::NoPathFound)));
+
+    // token_2 -> token_0 -> token_1 is worse than token_2 -> token_1, and token_3 is skipped
+    let intermediates = vec![&test.env, token_3.address.clone(), test.token_0.address.clone()];
+    let path = test.contract.best_path_out(&test.factory.address, &token_2.address, &test.token_1.address, &100, &intermediates, &3);
+    assert_eq!(path, vec![&test.env, token_2.address.clone(), test.token_1.address.clone()]);
+}
+
+#[test]
+fn best_path_out_skips_pairs_without_liquidity() {
+    let test = SoroswapLibraryTest::setup();
+    let (token_2, _) = setup_pools(&test);
+    // Pair created without liquidity
+    let token_4 = create_token_contract(&test.env, &test.user);
+    create_pair(&test, &test.token_0, &token_4);
+    let intermediates = vec![&test.env, token_4.address.clone(), token_2.address.clone()];
+
+    let path = test.contract.best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &intermediates, &3);
+    assert_eq!(path, vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()]);
+}
+
+#[test]
+fn best_path_out_invalid_arguments() {
+    let test = SoroswapLibraryTest::setup();
+    let intermediates: Vec<Address> = Vec::new(&test.env);
+
+    let result = test.contract.try_best_path_out(&test.factory.address, &test.token_0.address, &test.token_0.address, &100, &intermediates, &3);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::SortIdenticalTokens)));
+    let result = test.contract.try_best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &0, &intermediates, &3);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientInputAmount)));
+    let result = test.contract.try_best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &intermediates, &0);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
+    // The only pair has no liquidity
+    let result = test.contract.try_best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &intermediates, &3);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::NoPathFound)));
+}
+
+#[test]
+fn best_path_out_search_too_large() {
+    let test = SoroswapLibraryTest::setup();
+    let intermediates: Vec<Address> = (0..crate::MAX_INTERMEDIATES).fold(Vec::new(&test.env), |mut intermediates, _| {
+        intermediates.push_back(Address::generate(&test.env));
+        intermediates
+    });
+
+    // Both caps can be reached
+    let result = test.contract.try_best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &intermediates, &crate::MAX_HOPS);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::NoPathFound)));
+
+    let result = test.contract.try_best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &intermediates, &(crate::MAX_HOPS + 1));
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::TooManyHops)));
+    let mut too_many_intermediates = intermediates.clone();
+    too_many_intermediates.push_back(Address::generate(&test.env));
+    let result = test.contract.try_best_path_out(&test.factory.address, &test.token_0.address, &test.token_1.address, &100, &too_many_intermediates, &2);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::TooManyIntermediates)));
+}
+
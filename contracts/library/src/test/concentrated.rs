@@ -0,0 +1,93 @@
+use soroban_sdk::{vec, Vec};
+use crate::test::{SoroswapLibraryTest, SoroswapConcentratedPoolClient, concentrated_pool_contract_wasm};
+use crate::error::SoroswapLibraryError;
+use crate::PoolType;
+
+// Square root of a price of 1, with 64 fractional bits
+const SQRT_PRICE_ONE: u128 = 1 << 64;
+
+// Creates the concentrated pool of token_0 and token_1 in the 0.3% tier at a price of 1, with a position between the ticks -600 and 600
+fn create_concentrated_pool<'a>(test: &SoroswapLibraryTest<'a>, liquidity: u128) -> SoroswapConcentratedPoolClient<'a> {
+    test.factory.set_concentrated_pool_wasm_hash(&concentrated_pool_contract_wasm(&test.env));
+    let pool = SoroswapConcentratedPoolClient::new(&test.env, &test.factory.create_concentrated_pool(&test.token_0.address, &test.token_1.address, &30));
+    pool.initialize_price(&SQRT_PRICE_ONE);
+    test.token_0.mint(&test.user, &(liquidity as i128));
+    test.token_1.mint(&test.user, &(liquidity as i128));
+    pool.mint(&test.user, &-600, &600, &liquidity, &i128::MAX, &i128::MAX);
+    pool
+}
+
+#[test]
+fn concentrated_pool_for() {
+    let test = SoroswapLibraryTest::setup();
+    let pool = create_concentrated_pool(&test, 1_000_000_000_000);
+    assert_eq!(test.contract.concentrated_pool_for(&test.factory.address, &test.token_0.address, &test.token_1.address, &30), pool.address);
+    assert_eq!(test.contract.concentrated_pool_for(&test.factory.address, &test.token_1.address, &test.token_0.address, &30), pool.address);
+    // One pool per fee tier
+    assert_ne!(test.contract.concentrated_pool_for(&test.factory.address, &test.token_0.address, &test.token_1.address, &5), pool.address);
+    assert_ne!(pool.address, test.pair.address);
+    assert_eq!(
+        test.contract.try_concentrated_pool_for(&test.factory.address, &test.token_0.address, &test.token_0.address, &30),
+        Err(Ok(SoroswapLibraryError::SortIdenticalTokens))
+    );
+}
+
+#[test]
+fn get_amounts_out_mixed_concentrated() {
+    let test = SoroswapLibraryTest::setup();
+    let pool = create_concentrated_pool(&test, 1_000_000_000_000);
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Concentrated(30)];
+
+    // The pool quotes the swap, in the direction of the path
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let amount_out = pool.quote_exact_input(&true, &1_000_000_000);
+    assert_eq!(test.contract.get_amounts_out_mixed(&test.factory.address, &1_000_000_000, &path, &pool_types), vec![&test.env, 1_000_000_000, amount_out]);
+
+    let path = vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()];
+    let amount_out = pool.quote_exact_input(&false, &1_000_000_000);
+    assert_eq!(test.contract.get_amounts_out_mixed(&test.factory.address, &1_000_000_000, &path, &pool_types), vec![&test.env, 1_000_000_000, amount_out]);
+}
+
+#[test]
+fn get_amounts_in_mixed_concentrated() {
+    let test = SoroswapLibraryTest::setup();
+    let pool = create_concentrated_pool(&test, 1_000_000_000_000);
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Concentrated(30)];
+
+    let path = vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()];
+    let amount_in = pool.quote_exact_output(&false, &1_000_000_000);
+    assert_eq!(test.contract.get_amounts_in_mixed(&test.factory.address, &1_000_000_000, &path, &pool_types), vec![&test.env, amount_in, 1_000_000_000]);
+}
+
+#[test]
+fn get_amounts_mixed_concentrated_errors() {
+    let test = SoroswapLibraryTest::setup();
+    create_concentrated_pool(&test, 1_000_000_000_000);
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Concentrated(30)];
+    assert_eq!(
+        test.contract.try_get_amounts_out_mixed(&test.factory.address, &0, &path, &pool_types),
+        Err(Ok(SoroswapLibraryError::InsufficientInputAmount))
+    );
+    assert_eq!(
+        test.contract.try_get_amounts_in_mixed(&test.factory.address, &0, &path, &pool_types),
+        Err(Ok(SoroswapLibraryError::InsufficientOutputAmount))
+    );
+    // More than the ranges of the pool can fill
+    assert_eq!(
+        test.contract.try_get_amounts_out_mixed(&test.factory.address, &1_000_000_000_000, &path, &pool_types),
+        Err(Ok(SoroswapLibraryError::InsufficientLiquidity))
+    );
+    assert_eq!(
+        test.contract.try_get_amounts_in_mixed(&test.factory.address, &1_000_000_000_000, &path, &pool_types),
+        Err(Ok(SoroswapLibraryError::InsufficientLiquidity))
+    );
+
+    // There is no pool in the 1% tier
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Concentrated(100)];
+    assert_eq!(
+        test.contract.try_get_amounts_out_mixed(&test.factory.address, &1_000_000, &path, &pool_types),
+        Err(Ok(SoroswapLibraryError::InsufficientLiquidity))
+    );
+}
@@ -1,5 +1,5 @@
-use soroban_sdk::{Address, vec, Vec};
-use crate::test::{SoroswapLibraryTest};
+use soroban_sdk::{contract, contractimpl, Address, Env, vec, Vec};
+use crate::test::{SoroswapLibraryTest, SoroswapPairClient, create_token_contract};
 use crate::error::SoroswapLibraryError;
 
 
@@ -103,4 +103,87 @@ fn get_amounts_in_invalid_path() {
     let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
     let result = test.contract.try_get_amounts_in(&test.factory.address, &1, &path);
     assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
-}
\ No newline at end of file
+}
+#[test]
+fn get_amount_out_with_fee() {
+    let test = SoroswapLibraryTest::setup();
+    // Same as get_amount_out with the default fee tier
+    assert_eq!(test.contract.get_amount_out_with_fee(&1000, &100_000, &100_000, &30), test.contract.get_amount_out(&1000, &100_000, &100_000));
+    assert_eq!(989, test.contract.get_amount_out_with_fee(&1000, &100_000, &100_000, &5));
+    assert_eq!(980, test.contract.get_amount_out_with_fee(&1000, &100_000, &100_000, &100));
+    assert_eq!(990, test.contract.get_amount_out_with_fee(&1000, &100_000, &100_000, &0));
+}
+
+#[test]
+fn get_amount_out_with_fee_invalid_fee() {
+    let test = SoroswapLibraryTest::setup();
+    let result = test.contract.try_get_amount_out_with_fee(&1000, &100_000, &100_000, &10000);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidFee)));
+}
+
+#[test]
+fn get_amount_in_with_fee() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.get_amount_in_with_fee(&1000, &100_000, &100_000, &30), test.contract.get_amount_in(&1000, &100_000, &100_000));
+    assert_eq!(1011, test.contract.get_amount_in_with_fee(&1000, &100_000, &100_000, &5));
+    assert_eq!(1021, test.contract.get_amount_in_with_fee(&1000, &100_000, &100_000, &100));
+    let result = test.contract.try_get_amount_in_with_fee(&1000, &100_000, &100_000, &10000);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidFee)));
+}
+
+#[test]
+fn get_amounts_out_uses_pair_fee() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.get_fee(&test.factory.address, &test.token_1.address, &test.token_0.address), 30);
+
+    // A second pair with the 1% fee tier
+    let token_2 = create_token_contract(&test.env, &test.user);
+    token_2.mint(&test.user, &10000);
+    let pair_address = test.factory.create_pair_with_fee(&test.token_1.address, &token_2.address, &100);
+    assert_eq!(test.contract.get_fee(&test.factory.address, &test.token_1.address, &token_2.address), 100);
+
+    test.token_0.transfer(&test.user, &test.pair.address, &5000);
+    test.token_1.transfer(&test.user, &test.pair.address, &5000);
+    test.pair.deposit(&test.user);
+    test.token_1.transfer(&test.user, &pair_address, &5000);
+    token_2.transfer(&test.user, &pair_address, &5000);
+    SoroswapPairClient::new(&test.env, &pair_address).deposit(&test.user);
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let amount_1 = test.contract.get_amount_out_with_fee(&100, &5000, &5000, &30);
+    let amount_2 = test.contract.get_amount_out_with_fee(&amount_1, &5000, &5000, &100);
+    let expected_amounts_out = vec![&test.env, 100, amount_1, amount_2];
+    assert_eq!(expected_amounts_out, test.contract.get_amounts_out(&test.factory.address, &100, &path));
+
+    let amount_1 = test.contract.get_amount_in_with_fee(&50, &5000, &5000, &100);
+    let amount_0 = test.contract.get_amount_in_with_fee(&amount_1, &5000, &5000, &30);
+    let expected_amounts_in = vec![&test.env, amount_0, amount_1, 50];
+    assert_eq!(expected_amounts_in, test.contract.get_amounts_in(&test.factory.address, &50, &path));
+}
+
+// A pair deployed before swap fees were configurable, without a `fee` function
+#[contract]
+struct LegacyPair;
+
+#[contractimpl]
+impl LegacyPair {
+    pub fn get_reserves(_e: Env) -> (i128, i128) {
+        (5000, 5000)
+    }
+}
+
+#[test]
+fn get_amounts_out_legacy_pair() {
+    let test = SoroswapLibraryTest::setup();
+    let token_2 = create_token_contract(&test.env, &test.user);
+    let pair_address = test.contract.pair_for(&test.factory.address, &test.token_1.address, &token_2.address);
+    test.env.register_contract(&pair_address, LegacyPair);
+
+    assert_eq!(test.contract.get_fee(&test.factory.address, &test.token_1.address, &token_2.address), 30);
+    let path: Vec<Address> = vec![&test.env, test.token_1.address.clone(), token_2.address.clone()];
+    let expected_amounts_out = vec![&test.env, 100, test.contract.get_amount_out(&100, &5000, &5000)];
+    assert_eq!(expected_amounts_out, test.contract.get_amounts_out(&test.factory.address, &100, &path));
+    let expected_amounts_in = vec![&test.env, test.contract.get_amount_in(&50, &5000, &5000), 50];
+    assert_eq!(expected_amounts_in, test.contract.get_amounts_in(&test.factory.address, &50, &path));
+}
+
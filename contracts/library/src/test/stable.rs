@@ -0,0 +1,139 @@
+use soroban_sdk::{Address, Env, vec, Vec};
+use crate::test::{SoroswapLibraryTest, SoroswapStablePairClient, stable_pair_contract_wasm, create_token_contract};
+use crate::error::SoroswapLibraryError;
+use crate::PoolType;
+
+// Creates the stable pair of token_0 and token_1 with the given reserves
+fn create_stable_pair<'a>(test: &SoroswapLibraryTest<'a>, amp: u32, reserve_0: i128, reserve_1: i128) -> SoroswapStablePairClient<'a> {
+    test.factory.set_stable_pair_wasm_hash(&stable_pair_contract_wasm(&test.env));
+    let stable_pair = SoroswapStablePairClient::new(&test.env, &test.factory.create_stable_pair(&test.token_0.address, &test.token_1.address, &amp));
+    test.token_0.mint(&test.user, &reserve_0);
+    test.token_1.mint(&test.user, &reserve_1);
+    test.token_0.transfer(&test.user, &stable_pair.address, &reserve_0);
+    test.token_1.transfer(&test.user, &stable_pair.address, &reserve_1);
+    stable_pair.deposit(&test.user);
+    stable_pair
+}
+
+#[test]
+fn stable_pair_for() {
+    let test = SoroswapLibraryTest::setup();
+    let stable_pair = create_stable_pair(&test, 100, 1_000_000, 1_000_000);
+    assert_eq!(test.contract.stable_pair_for(&test.factory.address, &test.token_0.address, &test.token_1.address), stable_pair.address);
+    assert_eq!(test.contract.stable_pair_for(&test.factory.address, &test.token_1.address, &test.token_0.address), stable_pair.address);
+    assert_ne!(stable_pair.address, test.pair.address);
+}
+
+#[test]
+fn get_stable_d() {
+    let e = Env::default();
+    // A balanced pool holds the sum of its reserves, whatever the amplification coefficient
+    assert_eq!(crate::get_stable_d(&e, 1_000_000, 1_000_000, 1).unwrap(), 2_000_000);
+    assert_eq!(crate::get_stable_d(&e, 1_000_000, 1_000_000, 10_000).unwrap(), 2_000_000);
+
+    // The more amplified, the closer to the sum of the reserves an imbalanced pool is
+    let d_low = crate::get_stable_d(&e, 1_000_000, 3_000_000, 1).unwrap();
+    let d_high = crate::get_stable_d(&e, 1_000_000, 3_000_000, 1_000).unwrap();
+    assert!(d_low < d_high);
+    assert!(d_high < 4_000_000);
+
+    assert_eq!(crate::get_stable_d(&e, 0, 1_000_000, 100), Err(SoroswapLibraryError::InsufficientLiquidity));
+    assert_eq!(crate::get_stable_d(&e, 1_000_000, 1_000_000, 0), Err(SoroswapLibraryError::InvalidAmp));
+    assert_eq!(crate::get_stable_d(&e, 1_000_000, 1_000_000, 10_001), Err(SoroswapLibraryError::InvalidAmp));
+}
+
+#[test]
+fn get_stable_d_large_reserves() {
+    let e = Env::default();
+    // The Newton steps overflow 128 bits long before the invariant itself does
+    assert_eq!(crate::get_stable_d(&e, 1_000_000_000_000_000_000_000_000, 1_000_000_000_000_000_000_000_000, 10_000).unwrap(), 2_000_000_000_000_000_000_000_000);
+    let d = crate::get_stable_d(&e, 1_000_000_000_000_000_000_000_000, 3_000_000_000_000_000_000_000_000, 10_000).unwrap();
+    assert!(d < 4_000_000_000_000_000_000_000_000);
+    assert!(d > 3_990_000_000_000_000_000_000_000);
+
+    // The invariant of the largest reserves does not fit in i128
+    assert_eq!(crate::get_stable_d(&e, i128::MAX, i128::MAX, 10_000), Err(SoroswapLibraryError::StableInvariantFailed));
+}
+
+#[test]
+fn get_stable_amount_out() {
+    let test = SoroswapLibraryTest::setup();
+    let amount_out = test.contract.get_stable_amount_out(&100_000, &10_000_000, &10_000_000, &100, &5);
+    let constant_product_out = test.contract.get_amount_out_with_fee(&100_000, &10_000_000, &10_000_000, &5);
+    assert!(amount_out > constant_product_out);
+    // Less than the input minus the fee
+    assert!(amount_out < 100_000 - 50);
+    assert!(amount_out > 99_900);
+}
+
+#[test]
+fn get_stable_amount_out_errors() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.try_get_stable_amount_out(&0, &1_000, &1_000, &100, &5), Err(Ok(SoroswapLibraryError::InsufficientInputAmount)));
+    assert_eq!(test.contract.try_get_stable_amount_out(&100, &0, &1_000, &100, &5), Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    assert_eq!(test.contract.try_get_stable_amount_out(&100, &1_000, &1_000, &0, &5), Err(Ok(SoroswapLibraryError::InvalidAmp)));
+    assert_eq!(test.contract.try_get_stable_amount_out(&100, &1_000, &1_000, &100, &10_000), Err(Ok(SoroswapLibraryError::InvalidFee)));
+}
+
+#[test]
+fn get_stable_amount_in() {
+    let test = SoroswapLibraryTest::setup();
+    for amount_out in [1, 1_000, 250_000, 5_000_000] {
+        let amount_in = test.contract.get_stable_amount_in(&amount_out, &10_000_000, &8_000_000, &100, &5);
+        assert!(test.contract.get_stable_amount_out(&amount_in, &10_000_000, &8_000_000, &100, &5) >= amount_out);
+    }
+    assert_eq!(test.contract.try_get_stable_amount_in(&0, &1_000, &1_000, &100, &5), Err(Ok(SoroswapLibraryError::InsufficientOutputAmount)));
+    assert_eq!(test.contract.try_get_stable_amount_in(&1_000, &1_000, &1_000, &100, &5), Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
+
+#[test]
+fn get_amounts_out_mixed() {
+    let test = SoroswapLibraryTest::setup();
+    let stable_pair = create_stable_pair(&test, 100, 10_000_000, 10_000_000);
+    let token_2 = create_token_contract(&test.env, &test.user);
+
+    // token_1 / token_2 constant product pair
+    test.factory.create_pair(&test.token_1.address, &token_2.address);
+    let pair_12 = test.factory.get_pair(&test.token_1.address, &token_2.address);
+    test.token_1.mint(&test.user, &1_000_000);
+    token_2.mint(&test.user, &2_000_000);
+    test.token_1.transfer(&test.user, &pair_12, &1_000_000);
+    token_2.transfer(&test.user, &pair_12, &2_000_000);
+    crate::test::SoroswapPairClient::new(&test.env, &pair_12).deposit(&test.user);
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Stable, PoolType::ConstantProduct];
+    let amounts = test.contract.get_amounts_out_mixed(&test.factory.address, &100_000, &path, &pool_types);
+
+    let (reserve_0, reserve_1) = stable_pair.get_reserves();
+    let first_hop = test.contract.get_stable_amount_out(&100_000, &reserve_0, &reserve_1, &100, &5);
+    let second_hop = test.contract.get_amount_out(&first_hop, &1_000_000, &2_000_000);
+    assert_eq!(amounts, vec![&test.env, 100_000, first_hop, second_hop]);
+
+    // Backwards, the required input gives at least the output
+    let amounts_in = test.contract.get_amounts_in_mixed(&test.factory.address, &second_hop, &path, &pool_types);
+    assert_eq!(amounts_in.last().unwrap(), second_hop);
+    let amounts_out = test.contract.get_amounts_out_mixed(&test.factory.address, &amounts_in.first().unwrap(), &path, &pool_types);
+    assert!(amounts_out.last().unwrap() >= second_hop);
+
+    // With only constant product pools it is the same as get_amounts_out
+    let path: Vec<Address> = vec![&test.env, test.token_1.address.clone(), token_2.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::ConstantProduct];
+    assert_eq!(
+        test.contract.get_amounts_out_mixed(&test.factory.address, &1_000, &path, &pool_types),
+        test.contract.get_amounts_out(&test.factory.address, &1_000, &path)
+    );
+}
+
+#[test]
+fn get_amounts_mixed_invalid_path() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Stable, PoolType::Stable];
+    assert_eq!(test.contract.try_get_amounts_out_mixed(&test.factory.address, &1_000, &path, &pool_types), Err(Ok(SoroswapLibraryError::InvalidPath)));
+    assert_eq!(test.contract.try_get_amounts_in_mixed(&test.factory.address, &1_000, &path, &pool_types), Err(Ok(SoroswapLibraryError::InvalidPath)));
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
+    let pool_types: Vec<PoolType> = Vec::new(&test.env);
+    assert_eq!(test.contract.try_get_amounts_out_mixed(&test.factory.address, &1_000, &path, &pool_types), Err(Ok(SoroswapLibraryError::InvalidPath)));
+}
@@ -0,0 +1,168 @@
+use soroban_sdk::{Address, vec, Vec};
+use crate::test::{SoroswapLibraryTest, SoroswapPairClient, create_token_contract};
+use crate::error::SoroswapLibraryError;
+use crate::{SwapQuote, PRICE_SCALE};
+
+#[test]
+fn get_swap_quote() {
+    let test = SoroswapLibraryTest::setup();
+    test.token_0.mint(&test.user, &1_000_000);
+    test.token_1.mint(&test.user, &2_000_000);
+    test.token_0.transfer(&test.user, &test.pair.address, &1_000_000);
+    test.token_1.transfer(&test.user, &test.pair.address, &2_000_000);
+    test.pair.deposit(&test.user);
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let quote = test.contract.get_swap_quote(&test.factory.address, &10_000, &path);
+
+    // 10_000 * 9970 * 2_000_000 / (1_000_000 * 10000 + 10_000 * 9970) = 19743
+    let expected_quote = SwapQuote {
+        amounts: vec![&test.env, 10_000, 19_743],
+        mid_price: 2_000_000_000_000_000_000,
+        execution_price: 1_974_300_000_000_000_000,
+        price_impact: 128,
+        fee_amounts: vec![&test.env, 30],
+    };
+    assert_eq!(quote, expected_quote);
+    assert_eq!(quote.amounts, test.contract.get_amounts_out(&test.factory.address, &10_000, &path));
+}
+
+#[test]
+fn get_swap_quote_multi_hop() {
+    let test = SoroswapLibraryTest::setup();
+    let token_2 = create_token_contract(&test.env, &test.user);
+    test.token_0.mint(&test.user, &1_000_000);
+    test.token_1.mint(&test.user, &4_000_000);
+    token_2.mint(&test.user, &1_000_000);
+
+    test.token_0.transfer(&test.user, &test.pair.address, &1_000_000);
+    test.token_1.transfer(&test.user, &test.pair.address, &2_000_000);
+    test.pair.deposit(&test.user);
+    let pair_1_2 = SoroswapPairClient::new(&test.env, &test.factory.create_pair(&test.token_1.address, &token_2.address));
+    test.token_1.transfer(&test.user, &pair_1_2.address, &2_000_000);
+    token_2.transfer(&test.user, &pair_1_2.address, &1_000_000);
+    pair_1_2.deposit(&test.user);
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let quote = test.contract.get_swap_quote(&test.factory.address, &10_000, &path);
+
+    let amounts = test.contract.get_amounts_out(&test.factory.address, &10_000, &path);
+    assert_eq!(quote.amounts, amounts);
+    // 2 token_1 per token_0, then 0.5 token_2 per token_1
+    assert_eq!(quote.mid_price, PRICE_SCALE);
+    assert_eq!(quote.execution_price, amounts.last().unwrap() * PRICE_SCALE / 10_000);
+    // Fees are paid in the input token of each hop
+    assert_eq!(quote.fee_amounts, vec![&test.env, 30, amounts.get(1).unwrap() * 30 / 10_000]);
+    // The price impact includes the fees of both hops
+    assert!(quote.price_impact > 60);
+    assert_eq!(quote.price_impact, (quote.mid_price - quote.execution_price) * 10_000 / quote.mid_price);
+}
+
+#[test]
+fn get_swap_quote_multi_hop_rounds_each_hop_down() {
+    let test = SoroswapLibraryTest::setup();
+    let token_2 = create_token_contract(&test.env, &test.user);
+    test.token_0.mint(&test.user, &3_000_000);
+    test.token_1.mint(&test.user, &2_000_000);
+    token_2.mint(&test.user, &3_000_000);
+
+    test.token_0.transfer(&test.user, &test.pair.address, &3_000_000);
+    test.token_1.transfer(&test.user, &test.pair.address, &1_000_000);
+    test.pair.deposit(&test.user);
+    let pair_1_2 = SoroswapPairClient::new(&test.env, &test.factory.create_pair(&test.token_1.address, &token_2.address));
+    test.token_1.transfer(&test.user, &pair_1_2.address, &1_000_000);
+    token_2.transfer(&test.user, &pair_1_2.address, &3_000_000);
+    pair_1_2.deposit(&test.user);
+
+    // 1/3 token_1 per token_0, then 3 token_2 per token_1. Each hop rounds down, so 0.333... times 3 is 0.999...
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let quote = test.contract.get_swap_quote(&test.factory.address, &10_000, &path);
+    assert_eq!(quote.mid_price, PRICE_SCALE - 1);
+}
+
+#[test]
+fn get_swap_quote_price_out_of_range() {
+    let test = SoroswapLibraryTest::setup();
+    test.token_0.mint(&test.user, &10_000_000_000_000_000_000);
+    test.token_1.mint(&test.user, &1);
+    test.token_0.transfer(&test.user, &test.pair.address, &10_000_000_000_000_000_000);
+    test.token_1.transfer(&test.user, &test.pair.address, &1);
+    test.pair.deposit(&test.user);
+
+    // Less than 1e-18 token_1 per token_0
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let result = test.contract.try_get_swap_quote(&test.factory.address, &10_000, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::PriceOutOfRange)));
+}
+
+#[test]
+fn get_swap_quote_invalid_path() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
+    let result = test.contract.try_get_swap_quote(&test.factory.address, &10_000, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InvalidPath)));
+}
+
+#[test]
+fn get_swap_quote_insufficient_liquidity() {
+    let test = SoroswapLibraryTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let result = test.contract.try_get_swap_quote(&test.factory.address, &10_000, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+}
+
+#[test]
+fn get_swap_quote_multi_hop_large_reserves() {
+    let test = SoroswapLibraryTest::setup();
+    let token_2 = create_token_contract(&test.env, &test.user);
+    let token_3 = create_token_contract(&test.env, &test.user);
+    let small_reserve: i128 = 1_000_000_000_000;
+    let large_reserve: i128 = 100_000_000_000_000_000_000_000_000;
+    test.token_0.mint(&test.user, &small_reserve);
+    test.token_1.mint(&test.user, &(2 * large_reserve));
+    token_2.mint(&test.user, &(2 * small_reserve));
+    token_3.mint(&test.user, &large_reserve);
+
+    test.token_0.transfer(&test.user, &test.pair.address, &small_reserve);
+    test.token_1.transfer(&test.user, &test.pair.address, &large_reserve);
+    test.pair.deposit(&test.user);
+    let pair_1_2 = SoroswapPairClient::new(&test.env, &test.factory.create_pair(&test.token_1.address, &token_2.address));
+    test.token_1.transfer(&test.user, &pair_1_2.address, &large_reserve);
+    token_2.transfer(&test.user, &pair_1_2.address, &small_reserve);
+    pair_1_2.deposit(&test.user);
+    let pair_2_3 = SoroswapPairClient::new(&test.env, &test.factory.create_pair(&token_2.address, &token_3.address));
+    token_2.transfer(&test.user, &pair_2_3.address, &small_reserve);
+    token_3.transfer(&test.user, &pair_2_3.address, &large_reserve);
+    pair_2_3.deposit(&test.user);
+
+    // 1e14 token_1 per token_0, 1e-14 token_2 per token_1 and 1e14 token_3 per token_2. The product of
+    // PRICE_SCALE and the output reserves of the 3 hops, about 1e82, does not fit in 256 bits
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone(), token_3.address.clone()];
+    let quote = test.contract.get_swap_quote(&test.factory.address, &1_000, &path);
+    assert_eq!(quote.mid_price, 100_000_000_000_000 * PRICE_SCALE);
+    assert_eq!(quote.amounts, test.contract.get_amounts_out(&test.factory.address, &1_000, &path));
+}
+
+#[test]
+fn get_swap_quote_multi_hop_price_out_of_range() {
+    let test = SoroswapLibraryTest::setup();
+    let token_2 = create_token_contract(&test.env, &test.user);
+    let large_reserve: i128 = 100_000_000_000_000_000_000_000_000;
+    test.token_0.mint(&test.user, &1_000);
+    test.token_1.mint(&test.user, &(2 * large_reserve));
+    token_2.mint(&test.user, &large_reserve);
+
+    test.token_0.transfer(&test.user, &test.pair.address, &1_000);
+    test.token_1.transfer(&test.user, &test.pair.address, &large_reserve);
+    test.pair.deposit(&test.user);
+    let pair_1_2 = SoroswapPairClient::new(&test.env, &test.factory.create_pair(&test.token_1.address, &token_2.address));
+    test.token_1.transfer(&test.user, &pair_1_2.address, &1_000);
+    token_2.transfer(&test.user, &pair_1_2.address, &large_reserve);
+    pair_1_2.deposit(&test.user);
+
+    // 1e23 token_1 per token_0 does not fit in an i128 scaled by 1e18
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let result = test.contract.try_get_swap_quote(&test.factory.address, &10, &path);
+    assert_eq!(result, Err(Ok(SoroswapLibraryError::PriceOutOfRange)));
+}
+
@@ -0,0 +1,115 @@
+use soroban_sdk::{vec, Vec};
+use crate::test::{SoroswapLibraryTest, SoroswapWeightedPairClient, weighted_pair_contract_wasm};
+use crate::error::SoroswapLibraryError;
+use crate::PoolType;
+
+// Creates the weighted pair of token_0 and token_1 with the given weight of token_0 and reserves
+fn create_weighted_pair<'a>(test: &SoroswapLibraryTest<'a>, weight_0: u32, reserve_0: i128, reserve_1: i128) -> SoroswapWeightedPairClient<'a> {
+    test.factory.set_weighted_pair_wasm_hash(&weighted_pair_contract_wasm(&test.env));
+    let weighted_pair = SoroswapWeightedPairClient::new(&test.env, &test.factory.create_weighted_pair(&test.token_0.address, &test.token_1.address, &weight_0));
+    test.token_0.mint(&test.user, &reserve_0);
+    test.token_1.mint(&test.user, &reserve_1);
+    test.token_0.transfer(&test.user, &weighted_pair.address, &reserve_0);
+    test.token_1.transfer(&test.user, &weighted_pair.address, &reserve_1);
+    weighted_pair.deposit(&test.user);
+    weighted_pair
+}
+
+#[test]
+fn weighted_pair_for() {
+    let test = SoroswapLibraryTest::setup();
+    let weighted_pair = create_weighted_pair(&test, 8000, 4_000_000, 1_000_000);
+    assert_eq!(test.contract.weighted_pair_for(&test.factory.address, &test.token_0.address, &test.token_1.address), weighted_pair.address);
+    assert_eq!(test.contract.weighted_pair_for(&test.factory.address, &test.token_1.address, &test.token_0.address), weighted_pair.address);
+    assert_ne!(weighted_pair.address, test.pair.address);
+    assert_ne!(weighted_pair.address, test.contract.stable_pair_for(&test.factory.address, &test.token_0.address, &test.token_1.address));
+}
+
+#[test]
+fn get_weighted_invariant() {
+    // A 50/50 pool is the geometric mean of its reserves
+    assert!((crate::get_weighted_invariant(1_000_000, 4_000_000, 5000).unwrap() - 2_000_000).abs() <= 2);
+    // 16^0.8 · 1^0.2 = 2^3.2
+    assert!((crate::get_weighted_invariant(16_000_000_000, 1_000_000_000, 8000).unwrap() - 9_189_586_839).abs() <= 2);
+    // Rounded down
+    assert!(crate::get_weighted_invariant(1_000_000, 1_000_000, 8000).unwrap() <= 1_000_000);
+
+    assert_eq!(crate::get_weighted_invariant(0, 1_000_000, 8000), Err(SoroswapLibraryError::InsufficientLiquidity));
+    assert_eq!(crate::get_weighted_invariant(1_000_000, 1_000_000, 99), Err(SoroswapLibraryError::InvalidWeight));
+    assert_eq!(crate::get_weighted_invariant(1_000_000, 1_000_000, 9901), Err(SoroswapLibraryError::InvalidWeight));
+}
+
+#[test]
+fn get_weighted_invariant_up() {
+    assert!((crate::get_weighted_invariant_up(1_000_000, 4_000_000, 5000).unwrap() - 2_000_000).abs() <= 2);
+    // Rounded up, never below the invariant rounded down
+    assert!(crate::get_weighted_invariant_up(1_000_000, 1_000_000, 8000).unwrap() >= 1_000_000);
+    assert!(crate::get_weighted_invariant_up(16_000_000_000, 1_000_000_000, 8000).unwrap() > crate::get_weighted_invariant(16_000_000_000, 1_000_000_000, 8000).unwrap());
+
+    assert_eq!(crate::get_weighted_invariant_up(1_000_000, 0, 8000), Err(SoroswapLibraryError::InsufficientLiquidity));
+    assert_eq!(crate::get_weighted_invariant_up(1_000_000, 1_000_000, 99), Err(SoroswapLibraryError::InvalidWeight));
+}
+
+#[test]
+fn get_weighted_amount_out() {
+    let test = SoroswapLibraryTest::setup();
+    // A 50/50 weighted pair quotes like a constant product pair, keeping one unit more in the pair at most
+    let amount_out = test.contract.get_weighted_amount_out(&100_000, &10_000_000, &10_000_000, &5000, &30);
+    let constant_product_out = test.contract.get_amount_out_with_fee(&100_000, &10_000_000, &10_000_000, &30);
+    assert!(amount_out <= constant_product_out);
+    assert!(amount_out >= constant_product_out - 2);
+
+    // 1 - (16 / (16 + 0.997))^4 of the reserve out when token in weighs 80%
+    let amount_out = test.contract.get_weighted_amount_out(&1_000_000_000, &16_000_000_000, &1_000_000_000, &8000, &30);
+    assert!((amount_out - 214_780_939).abs() <= 1);
+}
+
+#[test]
+fn get_weighted_amount_out_errors() {
+    let test = SoroswapLibraryTest::setup();
+    assert_eq!(test.contract.try_get_weighted_amount_out(&0, &1_000, &1_000, &8000, &30), Err(Ok(SoroswapLibraryError::InsufficientInputAmount)));
+    assert_eq!(test.contract.try_get_weighted_amount_out(&100, &0, &1_000, &8000, &30), Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    assert_eq!(test.contract.try_get_weighted_amount_out(&100, &1_000, &1_000, &0, &30), Err(Ok(SoroswapLibraryError::InvalidWeight)));
+    assert_eq!(test.contract.try_get_weighted_amount_out(&100, &1_000, &1_000, &8000, &10_000), Err(Ok(SoroswapLibraryError::InvalidFee)));
+}
+
+#[test]
+fn get_weighted_amount_in() {
+    let test = SoroswapLibraryTest::setup();
+    for (amount_out, weight_in) in [(1, 8000), (1_000, 2000), (250_000, 8000), (5_000_000, 2000), (2_000_000, 100)] {
+        let amount_in = test.contract.get_weighted_amount_in(&amount_out, &10_000_000, &8_000_000, &weight_in, &30);
+        assert!(test.contract.get_weighted_amount_out(&amount_in, &10_000_000, &8_000_000, &weight_in, &30) >= amount_out);
+    }
+    // Taking most of the reserve out of the token weighing 99% needs an input that does not fit in 128 bits
+    assert_eq!(test.contract.try_get_weighted_amount_in(&7_000_000, &10_000_000, &8_000_000, &100, &30), Err(Ok(SoroswapLibraryError::WeightedInvariantFailed)));
+    assert_eq!(test.contract.try_get_weighted_amount_in(&0, &1_000, &1_000, &8000, &30), Err(Ok(SoroswapLibraryError::InsufficientOutputAmount)));
+    assert_eq!(test.contract.try_get_weighted_amount_in(&1_000, &1_000, &1_000, &8000, &30), Err(Ok(SoroswapLibraryError::InsufficientLiquidity)));
+    assert_eq!(test.contract.try_get_weighted_amount_in(&100, &1_000, &1_000, &9901, &30), Err(Ok(SoroswapLibraryError::InvalidWeight)));
+}
+
+#[test]
+fn get_amounts_out_mixed_weighted() {
+    let test = SoroswapLibraryTest::setup();
+    create_weighted_pair(&test, 8000, 16_000_000_000, 1_000_000_000);
+
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Weighted];
+    let amount_out = crate::get_weighted_amount_out(1_000_000_000, 16_000_000_000, 1_000_000_000, 8000, 30).unwrap();
+    assert_eq!(test.contract.get_amounts_out_mixed(&test.factory.address, &1_000_000_000, &path, &pool_types), vec![&test.env, 1_000_000_000, amount_out]);
+
+    // The weight of the input token is taken from the side of the pair it is on
+    let path = vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()];
+    let amount_out = crate::get_weighted_amount_out(10_000_000, 1_000_000_000, 16_000_000_000, 2000, 30).unwrap();
+    assert_eq!(test.contract.get_amounts_out_mixed(&test.factory.address, &10_000_000, &path, &pool_types), vec![&test.env, 10_000_000, amount_out]);
+}
+
+#[test]
+fn get_amounts_in_mixed_weighted() {
+    let test = SoroswapLibraryTest::setup();
+    create_weighted_pair(&test, 2000, 1_000_000_000, 16_000_000_000);
+
+    let path = vec![&test.env, test.token_1.address.clone(), test.token_0.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Weighted];
+    let amount_in = crate::get_weighted_amount_in(1_000_000, 16_000_000_000, 1_000_000_000, 8000, 30).unwrap();
+    assert_eq!(test.contract.get_amounts_in_mixed(&test.factory.address, &1_000_000, &path, &pool_types), vec![&test.env, amount_in, 1_000_000]);
+}
@@ -1,6 +1,12 @@
 use soroban_sdk::{Address, Env, xdr::ToXdr, BytesN, Bytes};
 use crate::error::SoroswapLibraryError;
 
+// Appended to the salt of the stable and weighted pairs by the SoroswapFactory
+const STABLE_PAIR_SALT_SUFFIX: &[u8] = b"stable";
+const WEIGHTED_PAIR_SALT_SUFFIX: &[u8] = b"weighted";
+// Followed by the big-endian bytes of the fee tier in the salt of the concentrated pools
+const CONCENTRATED_POOL_SALT_SUFFIX: &[u8] = b"concentrated";
+
 
 /// Generates a unique cryptographic salt value for a pair of token addresses.
 ///
@@ -65,4 +71,76 @@ pub fn pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) ->
     let deployer_with_address = e.deployer().with_address(factory.clone(), salt);
     let deterministic_address = deployer_with_address.deployed_address();
     Ok(deterministic_address)
-}
\ No newline at end of file
+}
+
+/// Generates the salt of a pair of two tokens with another curve, so it does not collide with their constant product pair.
+fn pair_salt_with_suffix(e: &Env, token_a: Address, token_b: Address, suffix: &[u8]) -> BytesN<32> {
+    let mut salt = Bytes::new(e);
+
+    salt.append(&token_a.to_xdr(e));
+    salt.append(&token_b.to_xdr(e));
+    salt.append(&Bytes::from_slice(e, suffix));
+
+    e.crypto().sha256(&salt)
+}
+
+/// Calculates the deterministic address for the stable pair of two tokens without making any external calls.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the stable pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+pub fn stable_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+    let (token_0, token_1) = sort_tokens(token_a, token_b)?;
+    let salt = pair_salt_with_suffix(&e, token_0, token_1, STABLE_PAIR_SALT_SUFFIX);
+    let deployer_with_address = e.deployer().with_address(factory.clone(), salt);
+    Ok(deployer_with_address.deployed_address())
+}
+
+/// Calculates the deterministic address for the weighted pair of two tokens without making any external calls.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the weighted pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+pub fn weighted_pair_for(e: Env, factory: Address, token_a: Address, token_b: Address) -> Result<Address, SoroswapLibraryError> {
+    let (token_0, token_1) = sort_tokens(token_a, token_b)?;
+    let salt = pair_salt_with_suffix(&e, token_0, token_1, WEIGHTED_PAIR_SALT_SUFFIX);
+    let deployer_with_address = e.deployer().with_address(factory.clone(), salt);
+    Ok(deployer_with_address.deployed_address())
+}
+
+/// Calculates the deterministic address for the concentrated pool of two tokens in a fee tier without making any external calls.
+///
+/// # Arguments
+///
+/// * `e` - The environment.
+/// * `factory` - The factory address.
+/// * `token_a` - The address of the first token.
+/// * `token_b` - The address of the second token.
+/// * `fee` - The fee tier of the pool, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the concentrated pool, and `Err` indicates an error such as identical tokens or an issue with sorting.
+pub fn concentrated_pool_for(e: Env, factory: Address, token_a: Address, token_b: Address, fee: u32) -> Result<Address, SoroswapLibraryError> {
+    let (token_0, token_1) = sort_tokens(token_a, token_b)?;
+    let mut suffix = [0u8; CONCENTRATED_POOL_SALT_SUFFIX.len() + 4];
+    suffix[..CONCENTRATED_POOL_SALT_SUFFIX.len()].copy_from_slice(CONCENTRATED_POOL_SALT_SUFFIX);
+    suffix[CONCENTRATED_POOL_SALT_SUFFIX.len()..].copy_from_slice(&fee.to_be_bytes());
+    let salt = pair_salt_with_suffix(&e, token_0, token_1, &suffix);
+    let deployer_with_address = e.deployer().with_address(factory.clone(), salt);
+    Ok(deployer_with_address.deployed_address())
+}
@@ -0,0 +1,227 @@
+use crate::error::SoroswapLibraryError;
+use crate::quotes::{fee_complement, FEE_DENOMINATOR};
+
+/// Weights of a weighted pair are expressed in basis points of this denominator, and add up to it.
+pub const WEIGHT_DENOMINATOR: u32 = 10_000;
+/// Minimum weight of a token in a weighted pair, in basis points.
+pub const MIN_WEIGHT: u32 = 100;
+
+// Fixed point numbers with 18 decimals
+const ONE: i128 = 1_000_000_000_000_000_000;
+// ln(2), with 18 decimals
+const LN_2: i128 = 693_147_180_559_945_309;
+// The powers are rounded up by this fraction, above the error of the fixed point logarithm and exponential
+const POW_PRECISION_MARGIN: i128 = 100_000_000_000_000;
+
+/// Natural logarithm of a positive integer, as a fixed point number with 18 decimals.
+fn ln(n: i128) -> Result<i128, SoroswapLibraryError> {
+    if n <= 0 {
+        return Err(SoroswapLibraryError::WeightedInvariantFailed);
+    }
+    // n = m·2^k with m in [1, 2)
+    let k = 127 - n.leading_zeros() as i128;
+    let m = if k >= 60 { ((n >> (k - 60)) * ONE) >> 60 } else { (n * ONE) >> k };
+
+    // ln(m) = 2·atanh((m - 1) / (m + 1)) = 2·(z + z^3/3 + z^5/5 + ...), with z <= 1/3
+    let z = (m - ONE) * ONE / (m + ONE);
+    let z_squared = z * z / ONE;
+    let mut term = z;
+    let mut sum = 0;
+    let mut n = 1;
+    while term != 0 {
+        sum += term / n;
+        term = term * z_squared / ONE;
+        n += 2;
+    }
+    Ok(k * LN_2 + 2 * sum)
+}
+
+/// e^y of a fixed point number with 18 decimals, as a fixed point number with 18 decimals.
+fn exp(y: i128) -> Result<i128, SoroswapLibraryError> {
+    // Below 1e-18
+    if y < -42 * ONE {
+        return Ok(0);
+    }
+    // e^y = e^r·2^k with r in [0, ln(2))
+    let k = y.div_euclid(LN_2);
+    let r = y.rem_euclid(LN_2);
+
+    let mut term = ONE;
+    let mut sum = ONE;
+    let mut n = 1;
+    while term != 0 {
+        term = term * r / ONE / n;
+        sum += term;
+        n += 1;
+    }
+
+    if k >= 0 {
+        let k = u32::try_from(k).map_err(|_| SoroswapLibraryError::WeightedInvariantFailed)?;
+        sum.checked_mul(1i128.checked_shl(k).filter(|power| *power > 0).ok_or(SoroswapLibraryError::WeightedInvariantFailed)?)
+            .ok_or(SoroswapLibraryError::WeightedInvariantFailed)
+    } else {
+        Ok(sum >> (-k).min(127))
+    }
+}
+
+// a * b / ONE, rounded up, without overflowing when a is large and b is at most around ONE
+fn mul_fixed_up(a: i128, b: i128) -> Result<i128, SoroswapLibraryError> {
+    let high = (a / ONE).checked_mul(b).ok_or(SoroswapLibraryError::WeightedInvariantFailed)?;
+    let low = (a % ONE).checked_mul(b).ok_or(SoroswapLibraryError::WeightedInvariantFailed)?;
+    high.checked_add((low + ONE - 1) / ONE).ok_or(SoroswapLibraryError::WeightedInvariantFailed)
+}
+
+fn check_weight(weight: u32) -> Result<(), SoroswapLibraryError> {
+    if !(MIN_WEIGHT..=WEIGHT_DENOMINATOR - MIN_WEIGHT).contains(&weight) {
+        return Err(SoroswapLibraryError::InvalidWeight);
+    }
+    Ok(())
+}
+
+/// Given the new reserve of one token of a weighted pair, returns the minimum reserve of the other token that keeps
+/// the invariant `x^w_x · y^w_y` of the current reserves: `reserve_y · (reserve_x / new_reserve_x)^(w_x / w_y)`, rounded up.
+///
+/// # Arguments
+///
+/// * `new_reserve_x` - The new reserve of the first token.
+/// * `reserve_x` - The current reserve of the first token.
+/// * `reserve_y` - The current reserve of the other token.
+/// * `weight_x` - The weight of the first token, in basis points. The weight of the other token is the rest up to `WEIGHT_DENOMINATOR`.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the minimum reserve of the other token, and `Err` indicates an error such as
+/// empty reserves, an invalid weight or a reserve that can not be computed within 128 bits.
+pub fn get_weighted_y(new_reserve_x: i128, reserve_x: i128, reserve_y: i128, weight_x: u32) -> Result<i128, SoroswapLibraryError> {
+    if new_reserve_x <= 0 || reserve_x <= 0 || reserve_y <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    check_weight(weight_x)?;
+    let weight_y = WEIGHT_DENOMINATOR - weight_x;
+
+    let exponent = (ln(reserve_x)? - ln(new_reserve_x)?)
+        .checked_mul(weight_x.into())
+        .ok_or(SoroswapLibraryError::WeightedInvariantFailed)?
+        / i128::from(weight_y);
+    let power = exp(exponent)?;
+    let power = power.checked_add(power / POW_PRECISION_MARGIN + 1).ok_or(SoroswapLibraryError::WeightedInvariantFailed)?;
+
+    mul_fixed_up(reserve_y, power)?.checked_add(1).ok_or(SoroswapLibraryError::WeightedInvariantFailed)
+}
+
+/// Given an input amount of an asset, the reserves, the weight of the input asset and the swap fee of a weighted pair,
+/// returns the maximum output amount of the other asset.
+///
+/// # Arguments
+///
+/// * `amount_in` - The input amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `weight_in` - The weight of the input asset, in basis points.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount, liquidity or an invalid weight or fee.
+pub fn get_weighted_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+    if amount_in <= 0 {
+        return Err(SoroswapLibraryError::InsufficientInputAmount);
+    }
+    if reserve_in <= 0 || reserve_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    fee_complement(fee)?;
+
+    // The pair takes the fee rounded down from the input amount
+    let fee_amount = amount_in.checked_mul(fee.into()).ok_or(SoroswapLibraryError::WeightedInvariantFailed)? / FEE_DENOMINATOR;
+    let new_reserve_in = reserve_in.checked_add(amount_in - fee_amount).ok_or(SoroswapLibraryError::WeightedInvariantFailed)?;
+
+    let new_reserve_out = get_weighted_y(new_reserve_in, reserve_in, reserve_out, weight_in)?;
+    Ok(reserve_out.checked_sub(new_reserve_out).unwrap().max(0))
+}
+
+/// Given an output amount of an asset, the reserves, the weight of the input asset and the swap fee of a weighted pair,
+/// returns a required input amount of the other asset.
+///
+/// # Arguments
+///
+/// * `amount_out` - The output amount of the asset.
+/// * `reserve_in` - Reserves of the input asset in the pair.
+/// * `reserve_out` - Reserves of the output asset in the pair.
+/// * `weight_in` - The weight of the input asset, in basis points.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount, liquidity or an invalid weight or fee.
+pub fn get_weighted_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128, weight_in: u32, fee: u32) -> Result<i128, SoroswapLibraryError> {
+    if amount_out <= 0 {
+        return Err(SoroswapLibraryError::InsufficientOutputAmount);
+    }
+    if reserve_in <= 0 || reserve_out <= amount_out {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    check_weight(weight_in)?;
+    let fee_complement = fee_complement(fee)?;
+
+    let new_reserve_in = get_weighted_y(reserve_out - amount_out, reserve_out, reserve_in, WEIGHT_DENOMINATOR - weight_in)?;
+    let amount_in_without_fee = new_reserve_in.checked_sub(reserve_in).unwrap().max(1);
+    let numerator = amount_in_without_fee.checked_mul(FEE_DENOMINATOR).ok_or(SoroswapLibraryError::WeightedInvariantFailed)?;
+    let mut amount_in = (numerator + fee_complement - 1) / fee_complement;
+
+    // Both directions round against the trader, but they are not exact inverses of each other,
+    // so the input is increased until it gives at least amount_out
+    let mut step = 1;
+    while get_weighted_amount_out(amount_in, reserve_in, reserve_out, weight_in, fee)? < amount_out {
+        amount_in = amount_in.checked_add(step).ok_or(SoroswapLibraryError::WeightedInvariantFailed)?;
+        step = step.checked_mul(2).ok_or(SoroswapLibraryError::WeightedInvariantFailed)?;
+    }
+    Ok(amount_in)
+}
+
+// Invariant of the reserves, reserve_0^w_0 · reserve_1^w_1, before rounding
+fn invariant(reserve_0: i128, reserve_1: i128, weight_0: u32) -> Result<i128, SoroswapLibraryError> {
+    if reserve_0 <= 0 || reserve_1 <= 0 {
+        return Err(SoroswapLibraryError::InsufficientLiquidity);
+    }
+    check_weight(weight_0)?;
+    let weight_1 = WEIGHT_DENOMINATOR - weight_0;
+
+    let ln_invariant = (ln(reserve_0)? * i128::from(weight_0) + ln(reserve_1)? * i128::from(weight_1)) / i128::from(WEIGHT_DENOMINATOR);
+    // e^(ln(V) - ln(10^18)), with 18 decimals, is V
+    exp(ln_invariant - ln(ONE)?)
+}
+
+/// Invariant of a weighted pair, the weighted geometric mean of its reserves `reserve_0^w_0 · reserve_1^w_1`, rounded down.
+///
+/// # Arguments
+///
+/// * `reserve_0` - Reserves of the first token in the pair.
+/// * `reserve_1` - Reserves of the second token in the pair.
+/// * `weight_0` - The weight of the first token, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the invariant, and `Err` indicates an error such as empty reserves or an invalid weight.
+pub fn get_weighted_invariant(reserve_0: i128, reserve_1: i128, weight_0: u32) -> Result<i128, SoroswapLibraryError> {
+    let invariant = invariant(reserve_0, reserve_1, weight_0)?;
+    // Rounded down, so that the truncation errors never favour the liquidity providers
+    Ok((invariant - invariant / POW_PRECISION_MARGIN - 1).max(0))
+}
+
+/// Invariant of a weighted pair, like `get_weighted_invariant` but rounded up, so that it can be used as the divisor
+/// of the growth of the invariant.
+///
+/// # Arguments
+///
+/// * `reserve_0` - Reserves of the first token in the pair.
+/// * `reserve_1` - Reserves of the second token in the pair.
+/// * `weight_0` - The weight of the first token, in basis points.
+///
+/// # Returns
+///
+/// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the invariant, and `Err` indicates an error such as empty reserves or an invalid weight.
+pub fn get_weighted_invariant_up(reserve_0: i128, reserve_1: i128, weight_0: u32) -> Result<i128, SoroswapLibraryError> {
+    let invariant = invariant(reserve_0, reserve_1, weight_0)?;
+    invariant.checked_add(invariant / POW_PRECISION_MARGIN + 1).ok_or(SoroswapLibraryError::WeightedInvariantFailed)
+}
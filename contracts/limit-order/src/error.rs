@@ -0,0 +1,33 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum LimitOrderError {
+    /// SoroswapLimitOrder: not yet initialized
+    NotInitialized = 601,
+
+    /// SoroswapLimitOrder: already initialized
+    InitializeAlreadyInitialized = 602,
+
+    /// SoroswapLimitOrder: amounts must be positive, and the tip can not be negative
+    InvalidAmount = 603,
+
+    /// SoroswapLimitOrder: the path must have at least two tokens, and different input and output tokens
+    InvalidPath = 604,
+
+    /// SoroswapLimitOrder: the expiration must be in the future
+    InvalidExpiration = 605,
+
+    /// SoroswapLimitOrder: order not found
+    OrderNotFound = 606,
+
+    /// SoroswapLimitOrder: order expired
+    OrderExpired = 607,
+
+    /// SoroswapLimitOrder: the pool price does not reach the order target
+    PriceNotReached = 608,
+
+    /// SoroswapLimitOrder: fill amount is greater than the remaining amount of the order
+    ExcessiveFillAmount = 609,
+}
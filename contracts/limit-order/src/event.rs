@@ -0,0 +1,120 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address, Vec};
+use crate::storage::Order;
+
+// INITIALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub router: Address
+}
+
+pub(crate) fn initialized(e: &Env, router: Address) {
+    let event = InitializedEvent { router };
+    e.events().publish(("SoroswapLimitOrder", symbol_short!("init")), event);
+}
+
+// ORDER CREATED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderCreatedEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub path: Vec<Address>,
+    pub amount_in: i128,
+    pub amount_out_min: i128,
+    pub tip: i128,
+    pub expiration: u64,
+}
+
+/// Publishes an `OrderCreatedEvent` to the event stream.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `order_id` - The id of the new order.
+/// * `order` - The new order.
+pub(crate) fn order_created(e: &Env, order_id: u64, order: Order) {
+    let event = OrderCreatedEvent {
+        order_id,
+        owner: order.owner,
+        path: order.path,
+        amount_in: order.amount_in,
+        amount_out_min: order.amount_out_min,
+        tip: order.tip,
+        expiration: order.expiration,
+    };
+    e.events().publish(("SoroswapLimitOrder", symbol_short!("create")), event);
+}
+
+// ORDER EXECUTED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderExecutedEvent {
+    pub order_id: u64,
+    pub keeper: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub tip: i128,
+    pub amount_in_left: i128,
+}
+
+/// Publishes an `OrderExecutedEvent` to the event stream.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `order_id` - The id of the executed order.
+/// * `keeper` - The address that received the tip.
+/// * `amount_in` - The amount of the input token sold.
+/// * `amount_out` - The amount of the output token sent to the owner.
+/// * `tip` - The amount of the input token paid to the keeper.
+/// * `amount_in_left` - The amount of the input token left to sell, zero when the order is filled.
+pub(crate) fn order_executed(
+    e: &Env,
+    order_id: u64,
+    keeper: Address,
+    amount_in: i128,
+    amount_out: i128,
+    tip: i128,
+    amount_in_left: i128,
+) {
+    let event = OrderExecutedEvent {
+        order_id,
+        keeper,
+        amount_in,
+        amount_out,
+        tip,
+        amount_in_left,
+    };
+    e.events().publish(("SoroswapLimitOrder", symbol_short!("execute")), event);
+}
+
+// ORDER CANCELLED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderCancelledEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub amount_in: i128,
+    pub tip: i128,
+}
+
+/// Publishes an `OrderCancelledEvent` to the event stream.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `order_id` - The id of the cancelled order.
+/// * `owner` - The address the escrowed tokens were returned to.
+/// * `amount_in` - The amount of the input token returned.
+/// * `tip` - The unused tip returned.
+pub(crate) fn order_cancelled(e: &Env, order_id: u64, owner: Address, amount_in: i128, tip: i128) {
+    let event = OrderCancelledEvent {
+        order_id,
+        owner,
+        amount_in,
+        tip,
+    };
+    e.events().publish(("SoroswapLimitOrder", symbol_short!("cancel")), event);
+}
@@ -0,0 +1,281 @@
+#![no_std]
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{
+    contract, contractimpl, vec, Address, Env, IntoVal, Symbol, Vec,
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+};
+
+// The generated client of the router has as many arguments as its functions
+#[allow(clippy::too_many_arguments)]
+mod router;
+mod event;
+mod storage;
+mod error;
+mod test;
+
+use router::SoroswapRouterClient;
+use storage::{
+    put_router, has_router, get_router, next_order_id, get_order, put_order, remove_order, extend_instance_ttl,
+};
+pub use storage::Order;
+pub use error::LimitOrderError;
+
+fn check_initialized(e: &Env) -> Result<(), LimitOrderError> {
+    if has_router(e) {
+        Ok(())
+    } else {
+        Err(LimitOrderError::NotInitialized)
+    }
+}
+
+fn read_order(e: &Env, order_id: u64) -> Result<Order, LimitOrderError> {
+    get_order(e, order_id).ok_or(LimitOrderError::OrderNotFound)
+}
+
+/// Swaps `amount_in` of the first token of `path` held by this contract through the router,
+/// sending the output back to this contract.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `path` - The swap path.
+/// * `amount_in` - The amount of the input token to swap.
+/// * `amount_out_min` - The minimum required amount of the output token.
+///
+/// # Returns
+/// The amount of the output token received.
+fn swap_through_router(e: &Env, path: &Vec<Address>, amount_in: i128, amount_out_min: i128) -> i128 {
+    let router = SoroswapRouterClient::new(e, &get_router(e));
+    let token_in = path.get(0).unwrap();
+    let pair = router.router_pair_for(&token_in, &path.get(1).unwrap());
+
+    // The router takes the input tokens from this contract, which is not its direct invoker
+    e.authorize_as_current_contract(vec![
+        e,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token_in,
+                fn_name: Symbol::new(e, "transfer"),
+                args: (e.current_contract_address(), pair, amount_in).into_val(e),
+            },
+            sub_invocations: vec![e],
+        }),
+    ]);
+
+    let amounts = router.swap_exact_tokens_for_tokens(
+        &amount_in,
+        &amount_out_min,
+        path,
+        &e.current_contract_address(),
+        &e.ledger().timestamp().checked_add(1).unwrap(),
+    );
+    amounts.last().unwrap()
+}
+
+pub trait SoroswapLimitOrderTrait {
+
+    /// Initializes the contract with the address of the Soroswap router used to execute the orders.
+    fn initialize(e: Env, router: Address) -> Result<(), LimitOrderError>;
+
+    /// Creates a limit order, escrowing `amount_in` plus `tip` of the first token of `path` from `owner`.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The address that sells the input token and receives the output token.
+    /// * `path` - The swap path, from the token sold to the token bought.
+    /// * `amount_in` - The amount of the input token to sell.
+    /// * `amount_out_min` - The minimum amount of the output token to receive for `amount_in`, which sets the target price.
+    /// * `tip` - The amount of the input token paid to the keepers executing the order, in proportion to each fill.
+    /// * `expiration` - The ledger timestamp from which the order can not be executed.
+    ///
+    /// # Returns
+    /// The id of the new order.
+    fn create_order(
+        e: Env,
+        owner: Address,
+        path: Vec<Address>,
+        amount_in: i128,
+        amount_out_min: i128,
+        tip: i128,
+        expiration: u64,
+    ) -> Result<u64, LimitOrderError>;
+
+    /// Executes all the amount left of an order, if the router quotes at least its target price.
+    /// Anyone can execute an order. The output goes to the order owner and the tip to `keeper`.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `keeper` - The address receiving the tip.
+    /// * `order_id` - The id of the order.
+    ///
+    /// # Returns
+    /// The amount of the output token sent to the order owner.
+    fn execute(e: Env, keeper: Address, order_id: u64) -> Result<i128, LimitOrderError>;
+
+    /// Same as `execute`, but only for `amount_in` of the amount left of the order.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `keeper` - The address receiving the tip.
+    /// * `order_id` - The id of the order.
+    /// * `amount_in` - The amount of the input token to sell.
+    ///
+    /// # Returns
+    /// The amount of the output token sent to the order owner.
+    fn execute_partial(e: Env, keeper: Address, order_id: u64, amount_in: i128) -> Result<i128, LimitOrderError>;
+
+    /// Cancels an order, returning the amount left and the unused tip to its owner. Expired orders
+    /// can only be cancelled.
+    ///
+    /// # Returns
+    /// A tuple containing the amount of the input token and the tip returned.
+    fn cancel(e: Env, order_id: u64) -> Result<(i128, i128), LimitOrderError>;
+
+    /*  *** Read only functions: *** */
+
+    /// Returns an open order.
+    fn get_order(e: Env, order_id: u64) -> Result<Order, LimitOrderError>;
+
+    /// Returns the address of the Soroswap router.
+    fn get_router(e: Env) -> Result<Address, LimitOrderError>;
+}
+
+#[contract]
+struct SoroswapLimitOrder;
+
+#[contractimpl]
+impl SoroswapLimitOrderTrait for SoroswapLimitOrder {
+
+    fn initialize(e: Env, router: Address) -> Result<(), LimitOrderError> {
+        if has_router(&e) {
+            return Err(LimitOrderError::InitializeAlreadyInitialized);
+        }
+        put_router(&e, &router);
+        extend_instance_ttl(&e);
+        event::initialized(&e, router);
+        Ok(())
+    }
+
+    fn create_order(
+        e: Env,
+        owner: Address,
+        path: Vec<Address>,
+        amount_in: i128,
+        amount_out_min: i128,
+        tip: i128,
+        expiration: u64,
+    ) -> Result<u64, LimitOrderError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        if amount_in <= 0 || amount_out_min <= 0 || tip < 0 {
+            return Err(LimitOrderError::InvalidAmount);
+        }
+        if path.len() < 2 || path.first() == path.last() {
+            return Err(LimitOrderError::InvalidPath);
+        }
+        if expiration <= e.ledger().timestamp() {
+            return Err(LimitOrderError::InvalidExpiration);
+        }
+        owner.require_auth();
+
+        TokenClient::new(&e, &path.get(0).unwrap()).transfer(
+            &owner,
+            &e.current_contract_address(),
+            &amount_in.checked_add(tip).unwrap(),
+        );
+
+        let order_id = next_order_id(&e);
+        let order = Order {
+            owner,
+            path,
+            amount_in,
+            amount_out_min,
+            tip,
+            expiration,
+        };
+        put_order(&e, order_id, &order);
+
+        event::order_created(&e, order_id, order);
+        Ok(order_id)
+    }
+
+    fn execute(e: Env, keeper: Address, order_id: u64) -> Result<i128, LimitOrderError> {
+        check_initialized(&e)?;
+        let order = read_order(&e, order_id)?;
+        Self::execute_partial(e, keeper, order_id, order.amount_in)
+    }
+
+    fn execute_partial(e: Env, keeper: Address, order_id: u64, amount_in: i128) -> Result<i128, LimitOrderError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let mut order = read_order(&e, order_id)?;
+        if e.ledger().timestamp() >= order.expiration {
+            return Err(LimitOrderError::OrderExpired);
+        }
+        if amount_in <= 0 {
+            return Err(LimitOrderError::InvalidAmount);
+        }
+        if amount_in > order.amount_in {
+            return Err(LimitOrderError::ExcessiveFillAmount);
+        }
+
+        // Rounded up, so partial fills are never below the target price
+        let amount_out_min = amount_in
+            .checked_mul(order.amount_out_min).unwrap()
+            .checked_add(order.amount_in - 1).unwrap()
+            .checked_div(order.amount_in).unwrap();
+        let tip = order.tip.checked_mul(amount_in).unwrap().checked_div(order.amount_in).unwrap();
+
+        let router = SoroswapRouterClient::new(&e, &get_router(&e));
+        let quote = router.router_get_amounts_out(&amount_in, &order.path).last().unwrap();
+        if quote < amount_out_min {
+            return Err(LimitOrderError::PriceNotReached);
+        }
+
+        let amount_out = swap_through_router(&e, &order.path, amount_in, amount_out_min);
+        TokenClient::new(&e, &order.path.last().unwrap()).transfer(&e.current_contract_address(), &order.owner, &amount_out);
+        if tip > 0 {
+            TokenClient::new(&e, &order.path.get(0).unwrap()).transfer(&e.current_contract_address(), &keeper, &tip);
+        }
+
+        order.amount_in -= amount_in;
+        order.amount_out_min -= amount_out_min;
+        order.tip -= tip;
+        if order.amount_in == 0 {
+            remove_order(&e, order_id);
+        } else {
+            put_order(&e, order_id, &order);
+        }
+
+        event::order_executed(&e, order_id, keeper, amount_in, amount_out, tip, order.amount_in);
+        Ok(amount_out)
+    }
+
+    fn cancel(e: Env, order_id: u64) -> Result<(i128, i128), LimitOrderError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let order = read_order(&e, order_id)?;
+        order.owner.require_auth();
+
+        remove_order(&e, order_id);
+        TokenClient::new(&e, &order.path.get(0).unwrap()).transfer(
+            &e.current_contract_address(),
+            &order.owner,
+            &order.amount_in.checked_add(order.tip).unwrap(),
+        );
+
+        event::order_cancelled(&e, order_id, order.owner, order.amount_in, order.tip);
+        Ok((order.amount_in, order.tip))
+    }
+
+    /*  *** Read only functions: *** */
+
+    fn get_order(e: Env, order_id: u64) -> Result<Order, LimitOrderError> {
+        check_initialized(&e)?;
+        read_order(&e, order_id)
+    }
+
+    fn get_router(e: Env) -> Result<Address, LimitOrderError> {
+        check_initialized(&e)?;
+        Ok(get_router(&e))
+    }
+}
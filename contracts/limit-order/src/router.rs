@@ -0,0 +1,4 @@
+soroban_sdk::contractimport!(
+    file = "../router/target/wasm32-unknown-unknown/release/soroswap_router.optimized.wasm"
+);
+pub type SoroswapRouterClient<'a> = Client<'a>;
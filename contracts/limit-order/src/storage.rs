@@ -0,0 +1,76 @@
+use soroban_sdk::{contracttype, Env, Address, Vec};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// An open limit order. The amounts are the ones left to fill, so partial fills keep
+/// the target price of `amount_out_min / amount_in`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Order {
+    pub owner: Address,
+    /// Swap path, from the token sold to the token bought.
+    pub path: Vec<Address>,
+    /// Amount of the input token left to sell.
+    pub amount_in: i128,
+    /// Minimum amount of the output token to receive for `amount_in`.
+    pub amount_out_min: i128,
+    /// Amount of the input token left to pay to keepers, paid in proportion to each fill.
+    pub tip: i128,
+    /// Ledger timestamp from which the order can not be executed anymore.
+    pub expiration: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Router,     // Address of the Soroswap Router Contract
+    OrderCount, // Number of orders created, used as the next order id
+    Order(u64), // Open order by id
+}
+
+pub fn put_router(e: &Env, router: &Address) {
+    e.storage().instance().set(&DataKey::Router, router);
+}
+
+pub fn has_router(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Router)
+}
+
+pub fn get_router(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Router).unwrap()
+}
+
+/// Returns the id of the next order and increases the order count.
+pub fn next_order_id(e: &Env) -> u64 {
+    let order_id: u64 = e.storage().instance().get(&DataKey::OrderCount).unwrap_or(0);
+    e.storage().instance().set(&DataKey::OrderCount, &(order_id + 1));
+    order_id
+}
+
+pub fn get_order(e: &Env, order_id: u64) -> Option<Order> {
+    let key = DataKey::Order(order_id);
+    let order: Option<Order> = e.storage().persistent().get(&key);
+    if order.is_some() {
+        e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    order
+}
+
+pub fn put_order(e: &Env, order_id: u64, order: &Order) {
+    let key = DataKey::Order(order_id);
+    e.storage().persistent().set(&key, order);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn remove_order(e: &Env, order_id: u64) {
+    e.storage().persistent().remove(&DataKey::Order(order_id));
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+extern crate std;
+use crate::{SoroswapLimitOrder, SoroswapLimitOrderClient};
+use crate::router::{self, SoroswapRouterClient};
+use soroban_sdk::{
+    vec,
+    Env,
+    BytesN,
+    Address,
+    Vec,
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+fn pair_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// SoroswapFactory Contract
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryClient<'a> {
+    let pair_hash = pair_contract_wasm(e);
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address);
+    factory.initialize(setter, &pair_hash);
+    factory
+}
+
+fn create_soroswap_router<'a>(e: &Env, factory: &Address, native: &Address) -> SoroswapRouterClient<'a> {
+    let router = SoroswapRouterClient::new(e, &e.register_contract_wasm(None, router::WASM));
+    router.initialize(factory, native);
+    router
+}
+
+fn create_soroswap_limit_order<'a>(e: &Env) -> SoroswapLimitOrderClient<'a> {
+    SoroswapLimitOrderClient::new(e, &e.register_contract(None, SoroswapLimitOrder {}))
+}
+
+// SoroswapLimitOrder TEST
+
+pub struct SoroswapLimitOrderTest<'a> {
+    env: Env,
+    contract: SoroswapLimitOrderClient<'a>,
+    router: SoroswapRouterClient<'a>,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    owner: Address,
+    trader: Address,
+    keeper: Address,
+}
+
+impl<'a> SoroswapLimitOrderTest<'a> {
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+        let contract = create_soroswap_limit_order(&env);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let trader = Address::generate(&env);
+        let keeper = Address::generate(&env);
+
+        let mut token_0 = create_token_contract(&env, &admin);
+        let mut token_1 = create_token_contract(&env, &admin);
+        if token_1.address < token_0.address {
+            std::mem::swap(&mut token_0, &mut token_1);
+        }
+        token_0.mint(&owner, &1_000_000_000);
+        token_0.mint(&trader, &10_000_000_000);
+        token_1.mint(&trader, &10_000_000_000);
+
+        let native = create_token_contract(&env, &admin).address;
+        let factory = create_soroswap_factory(&env, &admin);
+        let router = create_soroswap_router(&env, &factory.address, &native);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1000;
+        });
+        router.add_liquidity(
+            &token_0.address,
+            &token_1.address,
+            &1_000_000_000,
+            &1_000_000_000,
+            &0,
+            &0,
+            &trader,
+            &2000,
+        );
+
+        SoroswapLimitOrderTest {
+            env,
+            contract,
+            router,
+            token_0,
+            token_1,
+            owner,
+            trader,
+            keeper,
+        }
+    }
+
+    fn path(&self) -> Vec<Address> {
+        vec![&self.env, self.token_0.address.clone(), self.token_1.address.clone()]
+    }
+
+    // Order selling 10_000_000 of token_0 for at least 11_000_000 of token_1, with a tip of 100_000 token_0
+    fn create_order(&self) -> u64 {
+        self.contract.create_order(&self.owner, &self.path(), &10_000_000, &11_000_000, &100_000, &2000)
+    }
+
+    // Buys token_0 with `amount` of token_1, raising the price of token_0
+    fn move_price(&self, amount: i128) {
+        let path = vec![&self.env, self.token_1.address.clone(), self.token_0.address.clone()];
+        let deadline = self.env.ledger().timestamp() + 1000;
+        self.router.swap_exact_tokens_for_tokens(&amount, &0, &path, &self.trader, &deadline);
+    }
+}
+
+mod initialize;
+mod create_order;
+mod execute;
+mod cancel;
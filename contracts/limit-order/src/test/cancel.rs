@@ -0,0 +1,81 @@
+use soroban_sdk::{vec, IntoVal, symbol_short, testutils::{Events, Ledger}};
+
+use crate::test::SoroswapLimitOrderTest;
+use crate::error::LimitOrderError;
+use crate::event::OrderCancelledEvent;
+
+#[test]
+fn cancel() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+
+    assert_eq!(test.contract.cancel(&order_id), (10_000_000, 100_000));
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(LimitOrderError::OrderNotFound)));
+
+    let cancelled_event = test.env.events().all().last().unwrap();
+    let expected_event = OrderCancelledEvent {
+        order_id,
+        owner: test.owner.clone(),
+        amount_in: 10_000_000,
+        tip: 100_000,
+    };
+    assert_eq!(
+        vec![&test.env, cancelled_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapLimitOrder", symbol_short!("cancel")).into_val(&test.env),
+                (expected_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn cancel_after_partial_fill() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+    test.move_price(200_000_000);
+    test.contract.execute_partial(&test.keeper, &order_id, &4_000_000);
+
+    assert_eq!(test.contract.cancel(&order_id), (6_000_000, 60_000));
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000 - 4_000_000 - 40_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn cancel_expired() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 3000;
+    });
+    assert_eq!(test.contract.cancel(&order_id), (10_000_000, 100_000));
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000);
+}
+
+#[test]
+fn cancel_order_not_found() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let result = test.contract.try_cancel(&0);
+    assert_eq!(result, Err(Ok(LimitOrderError::OrderNotFound)));
+}
+
+#[test]
+#[should_panic]
+fn cancel_requires_owner_auth() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+
+    test.env.set_auths(&[]);
+    test.contract.cancel(&order_id);
+}
@@ -0,0 +1,87 @@
+use soroban_sdk::{Address, IntoVal, Vec, vec, symbol_short, testutils::Events};
+
+use crate::test::SoroswapLimitOrderTest;
+use crate::error::LimitOrderError;
+use crate::event::OrderCreatedEvent;
+use crate::Order;
+
+#[test]
+fn create_order() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+
+    assert_eq!(test.create_order(), 0);
+    assert_eq!(test.create_order(), 1);
+
+    // The amount to sell and the tip are escrowed
+    assert_eq!(test.token_0.balance(&test.owner), 1_000_000_000 - 2 * 10_100_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 2 * 10_100_000);
+    let expected_order = Order {
+        owner: test.owner.clone(),
+        path: test.path(),
+        amount_in: 10_000_000,
+        amount_out_min: 11_000_000,
+        tip: 100_000,
+        expiration: 2000,
+    };
+    assert_eq!(test.contract.get_order(&1), expected_order);
+
+    let created_event = test.env.events().all().last().unwrap();
+    let expected_event = OrderCreatedEvent {
+        order_id: 1,
+        owner: test.owner.clone(),
+        path: test.path(),
+        amount_in: 10_000_000,
+        amount_out_min: 11_000_000,
+        tip: 100_000,
+        expiration: 2000,
+    };
+    assert_eq!(
+        vec![&test.env, created_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapLimitOrder", symbol_short!("create")).into_val(&test.env),
+                (expected_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn create_order_invalid_amount() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+
+    let result = test.contract.try_create_order(&test.owner, &test.path(), &0, &11_000_000, &0, &2000);
+    assert_eq!(result, Err(Ok(LimitOrderError::InvalidAmount)));
+    let result = test.contract.try_create_order(&test.owner, &test.path(), &10_000_000, &0, &0, &2000);
+    assert_eq!(result, Err(Ok(LimitOrderError::InvalidAmount)));
+    let result = test.contract.try_create_order(&test.owner, &test.path(), &10_000_000, &11_000_000, &-1, &2000);
+    assert_eq!(result, Err(Ok(LimitOrderError::InvalidAmount)));
+}
+
+#[test]
+fn create_order_invalid_path() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
+    let result = test.contract.try_create_order(&test.owner, &path, &10_000_000, &11_000_000, &0, &2000);
+    assert_eq!(result, Err(Ok(LimitOrderError::InvalidPath)));
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+    let result = test.contract.try_create_order(&test.owner, &path, &10_000_000, &11_000_000, &0, &2000);
+    assert_eq!(result, Err(Ok(LimitOrderError::InvalidPath)));
+}
+
+#[test]
+fn create_order_invalid_expiration() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+
+    // The ledger timestamp is 1000
+    let result = test.contract.try_create_order(&test.owner, &test.path(), &10_000_000, &11_000_000, &0, &1000);
+    assert_eq!(result, Err(Ok(LimitOrderError::InvalidExpiration)));
+}
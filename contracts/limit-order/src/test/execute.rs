@@ -0,0 +1,137 @@
+use soroban_sdk::{vec, IntoVal, symbol_short, testutils::{Events, Ledger}};
+
+use crate::test::SoroswapLimitOrderTest;
+use crate::error::LimitOrderError;
+use crate::event::OrderExecutedEvent;
+
+#[test]
+fn execute_price_not_reached() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+
+    // The pool gives less than 1 token_1 per token_0
+    let result = test.contract.try_execute(&test.keeper, &order_id);
+    assert_eq!(result, Err(Ok(LimitOrderError::PriceNotReached)));
+
+    // Still below the target of 1.1 token_1 per token_0
+    test.move_price(20_000_000);
+    let result = test.contract.try_execute(&test.keeper, &order_id);
+    assert_eq!(result, Err(Ok(LimitOrderError::PriceNotReached)));
+    assert_eq!(test.contract.get_order(&order_id).amount_in, 10_000_000);
+}
+
+#[test]
+fn execute() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+
+    test.move_price(200_000_000);
+    let expected_amount_out = test.router.router_get_amounts_out(&10_000_000, &test.path()).last().unwrap();
+    let amount_out = test.contract.execute(&test.keeper, &order_id);
+
+    assert_eq!(amount_out, expected_amount_out);
+    assert!(amount_out >= 11_000_000);
+    assert_eq!(test.token_1.balance(&test.owner), amount_out);
+    assert_eq!(test.token_0.balance(&test.keeper), 100_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+    // Filled orders are removed
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(LimitOrderError::OrderNotFound)));
+    assert_eq!(test.contract.try_execute(&test.keeper, &order_id), Err(Ok(LimitOrderError::OrderNotFound)));
+
+    let executed_event = test.env.events().all().last().unwrap();
+    let expected_event = OrderExecutedEvent {
+        order_id,
+        keeper: test.keeper.clone(),
+        amount_in: 10_000_000,
+        amount_out,
+        tip: 100_000,
+        amount_in_left: 0,
+    };
+    assert_eq!(
+        vec![&test.env, executed_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapLimitOrder", symbol_short!("execute")).into_val(&test.env),
+                (expected_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn execute_partial() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+    test.move_price(200_000_000);
+
+    let first_amount_out = test.contract.execute_partial(&test.keeper, &order_id, &4_000_000);
+    assert!(first_amount_out >= 4_400_000);
+    assert_eq!(test.token_1.balance(&test.owner), first_amount_out);
+    assert_eq!(test.token_0.balance(&test.keeper), 40_000);
+
+    // The order keeps its target price for the amount left
+    let order = test.contract.get_order(&order_id);
+    assert_eq!(order.amount_in, 6_000_000);
+    assert_eq!(order.amount_out_min, 6_600_000);
+    assert_eq!(order.tip, 60_000);
+
+    let second_amount_out = test.contract.execute(&test.keeper, &order_id);
+    assert_eq!(test.token_1.balance(&test.owner), first_amount_out + second_amount_out);
+    assert_eq!(test.token_0.balance(&test.keeper), 100_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(LimitOrderError::OrderNotFound)));
+}
+
+#[test]
+fn execute_partial_invalid_amount() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+    test.move_price(200_000_000);
+
+    let result = test.contract.try_execute_partial(&test.keeper, &order_id, &0);
+    assert_eq!(result, Err(Ok(LimitOrderError::InvalidAmount)));
+    let result = test.contract.try_execute_partial(&test.keeper, &order_id, &10_000_001);
+    assert_eq!(result, Err(Ok(LimitOrderError::ExcessiveFillAmount)));
+}
+
+#[test]
+fn execute_expired() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+    test.move_price(200_000_000);
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 2000;
+    });
+    let result = test.contract.try_execute(&test.keeper, &order_id);
+    assert_eq!(result, Err(Ok(LimitOrderError::OrderExpired)));
+}
+
+#[test]
+fn execute_order_not_found() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let result = test.contract.try_execute(&test.keeper, &0);
+    assert_eq!(result, Err(Ok(LimitOrderError::OrderNotFound)));
+}
+
+#[test]
+fn execute_without_keeper_auth() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let order_id = test.create_order();
+    test.move_price(200_000_000);
+
+    // Only the limit order contract authorizes the transfer of the escrowed tokens
+    test.env.set_auths(&[]);
+    let amount_out = test.contract.execute(&test.keeper, &order_id);
+    assert_eq!(test.token_1.balance(&test.owner), amount_out);
+}
@@ -0,0 +1,45 @@
+use soroban_sdk::{vec, IntoVal, symbol_short, testutils::Events};
+
+use crate::test::SoroswapLimitOrderTest;
+use crate::error::LimitOrderError;
+use crate::event::InitializedEvent;
+
+#[test]
+fn not_yet_initialized() {
+    let test = SoroswapLimitOrderTest::setup();
+    assert_eq!(test.contract.try_get_router(), Err(Ok(LimitOrderError::NotInitialized)));
+    assert_eq!(test.contract.try_get_order(&0), Err(Ok(LimitOrderError::NotInitialized)));
+    let result = test.contract.try_create_order(&test.owner, &test.path(), &1, &1, &0, &2000);
+    assert_eq!(result, Err(Ok(LimitOrderError::NotInitialized)));
+    assert_eq!(test.contract.try_execute(&test.keeper, &0), Err(Ok(LimitOrderError::NotInitialized)));
+    assert_eq!(test.contract.try_cancel(&0), Err(Ok(LimitOrderError::NotInitialized)));
+}
+
+#[test]
+fn initialize() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    assert_eq!(test.contract.get_router(), test.router.address);
+
+    let initialized_event = test.env.events().all().last().unwrap();
+    let expected_event = InitializedEvent { router: test.router.address.clone() };
+    assert_eq!(
+        vec![&test.env, initialized_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapLimitOrder", symbol_short!("init")).into_val(&test.env),
+                (expected_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapLimitOrderTest::setup();
+    test.contract.initialize(&test.router.address);
+    let result = test.contract.try_initialize(&test.router.address);
+    assert_eq!(result, Err(Ok(LimitOrderError::InitializeAlreadyInitialized)));
+}
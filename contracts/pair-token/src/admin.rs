@@ -1,6 +1,6 @@
 use soroban_sdk::{Address, Env};
 
-use crate::soroswap_pair_token::storage_types::DataKey;
+use crate::storage_types::DataKey;
 
 pub fn has_administrator(e: &Env) -> bool {
     let key = DataKey::Admin;
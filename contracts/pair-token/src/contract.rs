@@ -1,14 +1,15 @@
 //! This contract demonstrates a sample implementation of the Soroban token
 //! interface.
-use crate::soroswap_pair_token::admin::{has_administrator, read_administrator, write_administrator};
-use crate::soroswap_pair_token::allowance::{read_allowance, spend_allowance, write_allowance};
-use crate::soroswap_pair_token::balance::{read_balance, receive_balance, spend_balance};
-use crate::soroswap_pair_token::metadata::{read_decimal, read_name, read_symbol, write_metadata};
+use crate::admin::{has_administrator, read_administrator, write_administrator};
+use crate::allowance::{read_allowance, spend_allowance, write_allowance};
+use crate::balance::{read_balance, receive_balance, spend_balance};
+use crate::metadata::{read_decimal, read_name, read_symbol, write_metadata};
+use crate::nonce::{read_nonce, spend_nonce};
 //#[cfg(test)]
-use crate::soroswap_pair_token::storage_types::{AllowanceDataKey, AllowanceValue, DataKey};
-use crate::soroswap_pair_token::storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
+use crate::storage_types::{AllowanceDataKey, AllowanceValue, DataKey};
+use crate::storage_types::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
 use soroban_sdk::token::{self, Interface as _};
-use soroban_sdk::{contract, contractimpl, Address, Env, String};
+use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal, String};
 use soroban_token_sdk::metadata::TokenMetadata;
 use soroban_token_sdk::TokenUtils;
 
@@ -67,11 +68,44 @@ impl SoroswapPairToken {
         TokenUtils::new(&e).events().set_admin(admin, new_admin);
     }
 
+    /// Approves `spender` to spend `amount` of `owner` tokens until `expiration_ledger`, authorized by
+    /// `owner` for exactly these arguments. The authorization is checked by the `owner` account itself
+    /// (its signers and thresholds, or its `__check_auth` for contract accounts), so it can be signed
+    /// offline and submitted by anyone (for example a relayer) or required by a contract on its behalf.
+    /// `nonce` must be the current `nonce` of `owner`, and it is incremented so the permit can't be replayed.
+    pub fn permit(
+        e: Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        nonce: u64,
+    ) {
+        owner.require_auth_for_args((spender.clone(), amount, expiration_ledger, nonce).into_val(&e));
+
+        check_nonnegative_amount(amount);
+
+        e.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+
+        spend_nonce(&e, owner.clone(), nonce);
+
+        write_allowance(&e, owner.clone(), spender.clone(), amount, expiration_ledger);
+        TokenUtils::new(&e)
+            .events()
+            .approve(owner, spender, amount, expiration_ledger);
+    }
+
+    /// Returns the nonce that the next `permit` of `owner` must use.
+    pub fn nonce(e: Env, owner: Address) -> u64 {
+        read_nonce(&e, owner)
+    }
+
 //    #[cfg(test)]
     pub fn get_allowance(e: Env, from: Address, spender: Address) -> Option<AllowanceValue> {
         let key = DataKey::Allowance(AllowanceDataKey { from, spender });
-        let allowance = e.storage().temporary().get::<_, AllowanceValue>(&key);
-        allowance
+        e.storage().temporary().get::<_, AllowanceValue>(&key)
     }
 }
 
@@ -1,12 +1,14 @@
-//#![no_std]
+#![no_std]
 
 mod admin;
 mod allowance;
 mod balance;
 mod contract;
 mod metadata;
+mod nonce;
 mod storage_types;
 mod internal_fn;
+mod test;
 
 pub use contract::SoroswapPairTokenClient; 
 pub use contract::SoroswapPairToken;
@@ -0,0 +1,25 @@
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Env};
+
+pub fn read_nonce(e: &Env, owner: Address) -> u64 {
+    let key = DataKey::Nonce(owner);
+    if let Some(nonce) = e.storage().persistent().get::<DataKey, u64>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        nonce
+    } else {
+        0
+    }
+}
+
+pub fn spend_nonce(e: &Env, owner: Address, nonce: u64) {
+    if read_nonce(e, owner.clone()) != nonce {
+        panic!("invalid nonce");
+    }
+    let key = DataKey::Nonce(owner);
+    e.storage().persistent().set(&key, &(nonce + 1));
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
@@ -1,10 +1,10 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::soroswap_pair_token::{SoroswapPairToken, SoroswapPairTokenClient};
+use crate::{SoroswapPairToken, SoroswapPairTokenClient};
 use soroban_sdk::{
     symbol_short,
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthInvoke},
     Address, Env, IntoVal, Symbol,
 };
 
@@ -254,3 +254,120 @@ fn decimal_is_over_max() {
         &"symbol".into_val(&e),
     );
 }
+
+// Mocks the authorization of `owner` for a permit of `token` with the given arguments only
+fn mock_permit_auth(
+    e: &Env,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+) {
+    e.mock_auths(&[MockAuth {
+        address: owner,
+        invoke: &MockAuthInvoke {
+            contract: token,
+            fn_name: "permit",
+            args: (spender, amount, expiration_ledger, nonce).into_val(e),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn permit() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let receiver = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&owner, &1000);
+    assert_eq!(token.nonce(&owner), 0);
+
+    token.permit(&owner, &spender, &500, &200, &0);
+    // The owner authorizes the arguments of the allowance, but not itself
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            owner.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "permit"),
+                    (&spender, 500_i128, 200_u32, 0_u64).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.allowance(&owner, &spender), 500);
+    assert_eq!(token.nonce(&owner), 1);
+
+    token.transfer_from(&spender, &owner, &receiver, &400);
+    assert_eq!(token.balance(&owner), 600);
+    assert_eq!(token.balance(&receiver), 400);
+    assert_eq!(token.allowance(&owner, &spender), 100);
+
+    // The next permit uses the next nonce
+    token.permit(&owner, &spender, &0, &200, &1);
+    assert_eq!(token.allowance(&owner, &spender), 0);
+    assert_eq!(token.nonce(&owner), 2);
+}
+
+#[test]
+#[should_panic(expected = "invalid nonce")]
+fn permit_replayed() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.permit(&owner, &spender, &500, &200, &0);
+    token.permit(&owner, &spender, &500, &200, &0);
+}
+
+#[test]
+#[should_panic]
+fn permit_not_authorized_by_owner() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.permit(&owner, &spender, &500, &200, &0);
+}
+
+#[test]
+#[should_panic]
+fn permit_amount_not_authorized() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    mock_permit_auth(&e, &token.address, &owner, &spender, 500, 200, 0);
+    token.permit(&owner, &spender, &501, &200, &0);
+}
+
+#[test]
+#[should_panic]
+fn permit_authorized_for_other_token() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let other_token = create_token(&e, &admin);
+
+    mock_permit_auth(&e, &other_token.address, &owner, &spender, 500, 200, 0);
+    token.permit(&owner, &spender, &500, &200, &0);
+}
@@ -43,8 +43,36 @@ pub enum SoroswapPairError {
     /// SoroswapPair: insufficient liquidity burned while doing withdraw
     WithdrawInsufficientLiquidityBurned = 117,
 
-    /// SoroswapPair: OVERFLOW while updating
+    /// SoroswapPair: OVERFLOW while updating. No longer returned since reserves are not capped to u64 anymore,
+    /// kept so that its code is never reused
     UpdateOverflow = 118,
+
+    /// SoroswapPair: target is older than the oldest observation while doing observe
+    ObserveOldObservation = 119,
+    /// SoroswapPair: observation cardinality is too high
+    ObservationCardinalityTooHigh = 120,
+    /// SoroswapPair: window must be greater than zero while doing consult
+    ConsultInvalidWindow = 121,
+
+    /// SoroswapPair: fee must be less than 10000 basis points while initializing
+    InitializeFeeInvalid = 122,
+
+    /// SoroswapPair: locked, nested calls are not allowed
+    Locked = 123,
+
+    /// SoroswapPair: insufficient amount while doing flash loan
+    FlashLoanInsufficientAmount = 124,
+    /// SoroswapPair: negatives amounts dont supported while doing flash loan
+    FlashLoanNegativesNotSupported = 125,
+    /// SoroswapPair: insufficient liquidity while doing flash loan
+    FlashLoanInsufficientLiquidity = 126,
+    /// SoroswapPair: token 0 was not repaid with the fee while doing flash loan
+    FlashLoanInsufficientRepaymentToken0 = 127,
+    /// SoroswapPair: token 1 was not repaid with the fee while doing flash loan
+    FlashLoanInsufficientRepaymentToken1 = 128,
+
+    /// SoroswapPair: storage version is newer than the contract version while doing migrate
+    MigrateVersionInvalid = 129,
 }
 
 
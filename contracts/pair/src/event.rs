@@ -64,6 +64,35 @@ pub(crate) fn swap(
     e.events().publish(("SoroswapPair", symbol_short!("swap")), event);
 }
 
+// FLASH LOAN EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlashLoanEvent {
+    pub receiver: Address,
+    pub amount_0: i128,
+    pub amount_1: i128,
+    pub paid_0: i128,
+    pub paid_1: i128,
+}
+
+pub(crate) fn flash_loan(
+    e: &Env,
+    receiver: Address,
+    amount_0: i128,
+    amount_1: i128,
+    paid_0: i128,
+    paid_1: i128,
+) {
+    let event: FlashLoanEvent = FlashLoanEvent {
+        receiver: receiver,
+        amount_0: amount_0,
+        amount_1: amount_1,
+        paid_0: paid_0,
+        paid_1: paid_1,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("flash")), event);
+}
+
 // WITHDRAW EVENT
 
 
@@ -131,4 +160,40 @@ pub(crate) fn skim(e: &Env, skimmed_0: i128, skimmed_1: i128) {
         skimmed_1: skimmed_1,
     };
     e.events().publish(("SoroswapPair", symbol_short!("skim")), event);
-}
\ No newline at end of file
+}
+
+
+// OBSERVATION CARDINALITY NEXT EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ObservationCardinalityEvent {
+    pub old_cardinality_next: u32,
+    pub new_cardinality_next: u32,
+}
+
+pub(crate) fn observation_cardinality(e: &Env, old_cardinality_next: u32, new_cardinality_next: u32) {
+    let event: ObservationCardinalityEvent = ObservationCardinalityEvent {
+        old_cardinality_next: old_cardinality_next,
+        new_cardinality_next: new_cardinality_next,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("obs_card")), event);
+}
+
+
+// MIGRATE EVENT
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateEvent {
+    pub old_version: u32,
+    pub new_version: u32,
+}
+
+pub(crate) fn migrate(e: &Env, old_version: u32, new_version: u32) {
+    let event: MigrateEvent = MigrateEvent {
+        old_version: old_version,
+        new_version: new_version,
+    };
+    e.events().publish(("SoroswapPair", symbol_short!("migrate")), event);
+}
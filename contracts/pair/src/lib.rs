@@ -1,14 +1,17 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contractmeta, Address, Env, IntoVal}; 
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Bytes, BytesN, Env, IntoVal, Vec}; 
 use soroban_sdk::token::Interface;
 use num_integer::Roots; 
 use soroswap_factory_interface::SoroswapFactoryClient;
+use soroswap_callee_interface::{SoroswapCalleeClient, SoroswapFlashLoanReceiverClient};
 
-pub mod soroswap_pair_token;
 mod storage;
 mod balances;
 mod event;
 mod error; 
+mod uq128x128;
+mod oracle;
+mod migration;
 mod test;
 
 // ANY TOKEN CONTRACT
@@ -23,45 +26,85 @@ use storage::*;
 use balances::*;
 use soroswap_pair_token::{SoroswapPairToken, internal_mint, internal_burn};
 use error::SoroswapPairError;
+use oracle::Observation;
+use uq128x128::UQ128x128;
 
 
 static MINIMUM_LIQUIDITY: i128 = 1000;
+// Swap fees are expressed in basis points
+static FEE_DENOMINATOR: i128 = 10000;
+// Flash loans pay a fixed 0.09% fee, in basis points
+static FLASH_LOAN_FEE: i128 = 9;
 
 // Metadata that is added on to the WASM custom section
 contractmeta!(
     key = "Description",
-    val = "Constant product AMM with a .3% swap fee"
+    val = "Constant product AMM with a configurable swap fee"
 );
 
 pub trait SoroswapPairTrait{
-    // Sets the token contract addresses for this pool
-    fn initialize_pair(e: Env, factory: Address, token_0: Address, token_1: Address)-> Result<(), SoroswapPairError>;
+    // Sets the token contract addresses and the swap fee (in basis points) for this pool
+    fn initialize_pair(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32)-> Result<(), SoroswapPairError>;
 
     fn deposit(e:Env, to: Address)  -> Result<i128, SoroswapPairError>;
 
-    // Swaps. This function should be called from another contract that has already sent tokens to the pair contract
-    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapPairError>;
+    // Swaps. This function should be called from another contract that has already sent tokens to the pair contract,
+    // unless a non-empty data is given: then `to` is called back to pay for the tokens (flash swap)
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapPairError>;
 
     fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapPairError>;
 
+    // Lends the reserves to `receiver`, that must pay them back with a fixed fee during the `soroswap_flash_loan` callback
+    fn flash_loan(e: Env, receiver: Address, amount_0: i128, amount_1: i128, data: Bytes) -> Result<(), SoroswapPairError>;
+
     // transfers the excess token balances from the pair to the specified to address, 
     // ensuring that the balances match the reserves by subtracting the reserve amounts 
     // from the current balances.
-    fn skim(e: Env, to: Address);
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapPairError>;
 
     // updates the reserves of the pair to match the current token balances.
     // It retrieves the balances and reserves from the environment, then calls the update
     // function to synchronize the reserves with the balances.
-    fn sync(e: Env);
+    fn sync(e: Env) -> Result<(), SoroswapPairError>;
 
     fn token_0(e: Env) -> Address;
     fn token_1(e: Env) -> Address;
     fn factory(e: Env) -> Address;
+    fn fee(e: Env) -> u32;
 
     fn k_last(e: Env) -> i128;
 
     fn get_reserves(e: Env) -> (i128, i128);
 
+    fn price_0_cumulative_last(e: Env) -> UQ128x128;
+    fn price_1_cumulative_last(e: Env) -> UQ128x128;
+    fn block_timestamp_last(e: Env) -> u64;
+
+    // Prepares the observations ring buffer to store up to `observation_cardinality_next` observations.
+    fn increase_observation_cardinality(e: Env, observation_cardinality_next: u32) -> Result<(), SoroswapPairError>;
+
+    // Returns the current and the next cardinality of the observations ring buffer.
+    fn observation_cardinality(e: Env) -> (u32, u32);
+
+    // Returns the cumulative prices and liquidity as of each `seconds_agos` from the current ledger timestamp.
+    fn observe(e: Env, seconds_agos: Vec<u64>) -> Result<Vec<Observation>, SoroswapPairError>;
+
+    // Returns the time-weighted average prices of token 0 and token 1 over the last `window` seconds, encoded as UQ128x128.
+    fn consult(e: Env, window: u64) -> Result<(UQ128x128, UQ128x128), SoroswapPairError>;
+
+    // Extends the TTL of the pair instance, code and of the observations from index `start`, up to `limit` of them,
+    // so the pool does not get archived. Anyone can call it.
+    fn extend_pair_ttl(e: Env, start: u32, limit: u32) -> Result<(), SoroswapPairError>;
+
+    // Replaces the code of the pair. Only the factory can call it, and it must call `migrate` afterwards.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapPairError>;
+
+    // Runs the pending storage migrations after an upgrade. Only the factory can call it.
+    fn migrate(e: Env) -> Result<u32, SoroswapPairError>;
+
+    // Returns the version of the storage layout of the pair.
+    fn version(e: Env) -> u32;
+
     // TODO: Just use the token "balance" function
     fn my_balance(e: Env, id: Address) -> i128;
     // TODO: Analize using "total_supply"
@@ -81,7 +124,8 @@ impl SoroswapPairTrait for SoroswapPair {
     /// * `factory` - The address of the Soroswap factory contract.
     /// * `token_0` - The address of the first token in the pair.
     /// * `token_1` - The address of the second token in the pair.
-    fn initialize_pair(e: Env, factory: Address, token_0: Address, token_1: Address) -> Result<(), SoroswapPairError> {
+    /// * `fee` - The swap fee of the pair, in basis points.
+    fn initialize_pair(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32) -> Result<(), SoroswapPairError> {
         if has_token_0(&e) {
             return Err(SoroswapPairError::InitializeAlreadyInitialized);
         }
@@ -90,6 +134,10 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::InitializeTokenOrderInvalid);
         }
 
+        if i128::from(fee) >= FEE_DENOMINATOR {
+            return Err(SoroswapPairError::InitializeFeeInvalid);
+        }
+
         put_factory(&e, factory);
 
         SoroswapPairToken::initialize(
@@ -102,9 +150,14 @@ impl SoroswapPairTrait for SoroswapPair {
 
         put_token_0(&e, token_0);
         put_token_1(&e, token_1);
+        put_fee(&e, fee);
         put_total_shares(&e, 0);
         put_reserve_0(&e, 0);
         put_reserve_1(&e, 0);
+        put_block_timestamp_last(&e, e.ledger().timestamp());
+        oracle::initialize(&e, e.ledger().timestamp());
+        put_version(&e, migration::CONTRACT_VERSION);
+        extend_instance_ttl(&e);
 
         Ok(())
     }
@@ -124,6 +177,11 @@ impl SoroswapPairTrait for SoroswapPair {
         get_factory(&e)
     }
 
+    /// Returns the swap fee of the Soroswap pair, in basis points.
+    fn fee(e: Env) -> u32 {
+        get_fee(&e)
+    }
+
     /// Deposits tokens into the Soroswap pair and mints LP tokens in return.
     ///
     /// # Arguments
@@ -138,11 +196,12 @@ impl SoroswapPairTrait for SoroswapPair {
     /// - `SoroswapPairError::DepositInsufficientAmountToken1`: Insufficient amount of token 1 sent.
     /// - `SoroswapPairError::DepositInsufficientFirstLiquidity`: Insufficient first liquidity minted.
     /// - `SoroswapPairError::DepositInsufficientLiquidityMinted`: Insufficient liquidity minted.
-    /// - `SoroswapPairError::UpdateOverflow`: Overflow occurred during update.
     fn deposit(e: Env, to: Address) -> Result<i128, SoroswapPairError> {
         if !has_token_0(&e){
             return Err(SoroswapPairError::NotInitialized)
         }
+        lock(&e)?;
+        extend_instance_ttl(&e);
 
         let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
@@ -179,7 +238,7 @@ impl SoroswapPairTrait for SoroswapPair {
         }
 
         mint_shares(&e, &to, liquidity.clone());
-        let _ = update(&e, balance_0, balance_1);
+        update(&e, balance_0, balance_1);
 
         (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         if fee_on {
@@ -188,6 +247,7 @@ impl SoroswapPairTrait for SoroswapPair {
 
         event::deposit(&e, to, amount_0, amount_1, liquidity, reserve_0, reserve_1);
 
+        unlock(&e);
         Ok(liquidity)
     }
 
@@ -198,6 +258,8 @@ impl SoroswapPairTrait for SoroswapPair {
     /// * `amount_0_out` - The desired amount of the first token to receive.
     /// * `amount_1_out` - The desired amount of the second token to receive.
     /// * `to` - The address where the swapped tokens will be sent.
+    /// * `data` - If not empty, `soroswap_call` is invoked on `to` with this payload after the tokens have been sent,
+    ///   so `to` can pay for them before the constant product is checked (flash swap).
     ////// # Errors
     /// Returns an error if the swap cannot be executed. Possible errors include:
     /// - `SoroswapPairError::NotInitialized`
@@ -208,10 +270,12 @@ impl SoroswapPairTrait for SoroswapPair {
     /// - `SoroswapPairError::SwapInsufficientInputAmount`
     /// - `SoroswapPairError::SwapNegativesInNotSupported`
     /// - `SoroswapPairError::SwapKConstantNotMet`: If the K constant condition is not met after the swap.
-    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address) -> Result<(), SoroswapPairError> {
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapPairError> {
         if !has_token_0(&e) {
             return Err(SoroswapPairError::NotInitialized);
         }
+        lock(&e)?;
+        extend_instance_ttl(&e);
     
         let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
     
@@ -234,6 +298,9 @@ impl SoroswapPairTrait for SoroswapPair {
         if amount_1_out > 0 {
             transfer_token_1_from_pair(&e, &to, amount_1_out);
         }
+        if !data.is_empty() {
+            SoroswapCalleeClient::new(&e, &to).soroswap_call(&e.current_contract_address(), &amount_0_out, &amount_1_out, &data);
+        }
 
         let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
 
@@ -255,8 +322,9 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::SwapNegativesInNotSupported);
         }
 
-        let fee_0 = (amount_0_in.checked_mul(3).unwrap()).checked_div(1000).unwrap();
-        let fee_1 = (amount_1_in.checked_mul(3).unwrap()).checked_div(1000).unwrap();
+        let fee: i128 = get_fee(&e).into();
+        let fee_0 = (amount_0_in.checked_mul(fee).unwrap()).checked_div(FEE_DENOMINATOR).unwrap();
+        let fee_1 = (amount_1_in.checked_mul(fee).unwrap()).checked_div(FEE_DENOMINATOR).unwrap();
 
         let balance_0_minus_fee = balance_0.checked_sub(fee_0).unwrap();
         let balance_1_minus_fee = balance_1.checked_sub(fee_1).unwrap();
@@ -266,10 +334,11 @@ impl SoroswapPairTrait for SoroswapPair {
             return Err(SoroswapPairError::SwapKConstantNotMet);
         }
 
-        let _ = update(&e, balance_0, balance_1);
+        update(&e, balance_0, balance_1);
         
         event::swap(&e, to, amount_0_in, amount_1_in, amount_0_out, amount_1_out);
 
+        unlock(&e);
         Ok(())
     }
 
@@ -286,6 +355,8 @@ impl SoroswapPairTrait for SoroswapPair {
         if !has_token_0(&e) {
             return Err(SoroswapPairError::NotInitialized);
         }
+        lock(&e)?;
+        extend_instance_ttl(&e);
     
         let balance_shares = get_balance_shares(&e);
         if balance_shares == 0 {
@@ -318,7 +389,7 @@ impl SoroswapPairTrait for SoroswapPair {
 
         (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
 
-        let _ = update(&e, balance_0, balance_1);
+        update(&e, balance_0, balance_1);
 
         (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         if fee_on {
@@ -326,15 +397,86 @@ impl SoroswapPairTrait for SoroswapPair {
         }
 
         event::withdraw(&e, to, user_sent_shares, amount_0, amount_1, reserve_0, reserve_1);
+        unlock(&e);
         Ok((amount_0, amount_1))
     }
 
+    /// Lends `amount_0` of token 0 and `amount_1` of token 1 to `receiver`, and calls its `soroswap_flash_loan`
+    /// entrypoint. The receiver must pay back the borrowed amounts plus a fixed 0.09% fee before returning.
+    /// The fee stays in the reserves, so it accrues to the liquidity providers (and to the protocol through
+    /// `mint_fee` when fees are enabled).
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `receiver` - The address receiving the tokens and the callback.
+    /// * `amount_0` - The amount of token 0 to lend.
+    /// * `amount_1` - The amount of token 1 to lend.
+    /// * `data` - Payload forwarded to the receiver.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::FlashLoanInsufficientAmount`
+    /// - `SoroswapPairError::FlashLoanNegativesNotSupported`
+    /// - `SoroswapPairError::FlashLoanInsufficientLiquidity`
+    /// - `SoroswapPairError::FlashLoanInsufficientRepaymentToken0`
+    /// - `SoroswapPairError::FlashLoanInsufficientRepaymentToken1`
+    fn flash_loan(e: Env, receiver: Address, amount_0: i128, amount_1: i128, data: Bytes) -> Result<(), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        if amount_0 == 0 && amount_1 == 0 {
+            return Err(SoroswapPairError::FlashLoanInsufficientAmount);
+        }
+        if amount_0 < 0 || amount_1 < 0 {
+            return Err(SoroswapPairError::FlashLoanNegativesNotSupported);
+        }
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        if amount_0 >= reserve_0 || amount_1 >= reserve_1 {
+            return Err(SoroswapPairError::FlashLoanInsufficientLiquidity);
+        }
+
+        let fee_0 = flash_loan_fee(amount_0);
+        let fee_1 = flash_loan_fee(amount_1);
+        let (balance_0_before, balance_1_before) = (get_balance_0(&e), get_balance_1(&e));
+
+        if amount_0 > 0 {
+            transfer_token_0_from_pair(&e, &receiver, amount_0);
+        }
+        if amount_1 > 0 {
+            transfer_token_1_from_pair(&e, &receiver, amount_1);
+        }
+        SoroswapFlashLoanReceiverClient::new(&e, &receiver).soroswap_flash_loan(
+            &e.current_contract_address(), &amount_0, &amount_1, &fee_0, &fee_1, &data);
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        if balance_0 < balance_0_before.checked_add(fee_0).unwrap() {
+            return Err(SoroswapPairError::FlashLoanInsufficientRepaymentToken0);
+        }
+        if balance_1 < balance_1_before.checked_add(fee_1).unwrap() {
+            return Err(SoroswapPairError::FlashLoanInsufficientRepaymentToken1);
+        }
+
+        update(&e, balance_0, balance_1);
+
+        let paid_0 = balance_0.checked_sub(balance_0_before).unwrap();
+        let paid_1 = balance_1.checked_sub(balance_1_before).unwrap();
+        event::flash_loan(&e, receiver, amount_0, amount_1, paid_0, paid_1);
+
+        unlock(&e);
+        Ok(())
+    }
+
     /// Skims excess tokens from reserves and sends them to the specified address.
     ///
     /// # Arguments
     /// * `e` - The runtime environment.
     /// * `to` - The address where the excess tokens will be sent.
-    fn skim(e: Env, to: Address) {
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapPairError> {
+        lock(&e)?;
+        extend_instance_ttl(&e);
         let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
         let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
         let skimmed_0 = balance_0.checked_sub(reserve_0).unwrap();
@@ -342,15 +484,21 @@ impl SoroswapPairTrait for SoroswapPair {
         transfer_token_0_from_pair(&e, &to, skimmed_0);
         transfer_token_1_from_pair(&e, &to, skimmed_1);
         event::skim(&e, skimmed_0, skimmed_1);
+        unlock(&e);
+        Ok(())
     }
 
     /// Forces reserves to match current balances.
     ///
     /// # Arguments
     /// * `e` - The runtime environment.
-    fn sync(e: Env) {
+    fn sync(e: Env) -> Result<(), SoroswapPairError> {
+        lock(&e)?;
+        extend_instance_ttl(&e);
         let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
         update(&e, balance_0, balance_1);
+        unlock(&e);
+        Ok(())
     }
 
     /// Returns the current reserves and the last block timestamp.
@@ -364,6 +512,198 @@ impl SoroswapPairTrait for SoroswapPair {
         (get_reserve_0(&e), get_reserve_1(&e))
     }
 
+    /// Returns the accumulated price of token 0 (denominated in token 1), encoded as a UQ128x128 and
+    /// multiplied by the seconds elapsed since each reserves update. It is meant to wrap on overflow.
+    /// It is a `UQ128x128 { hi, lo }` struct with the integer part in `hi` and the fraction in `lo`, instead of
+    /// a single UQ64x64 `u128`, so that any i128 reserves can be accumulated.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The cumulative price of token 0.
+    fn price_0_cumulative_last(e: Env) -> UQ128x128 {
+        get_price_0_cumulative_last(&e)
+    }
+
+    /// Returns the accumulated price of token 1 (denominated in token 0), encoded as a UQ128x128 and
+    /// multiplied by the seconds elapsed since each reserves update. It is meant to wrap on overflow.
+    /// It is a `UQ128x128 { hi, lo }` struct with the integer part in `hi` and the fraction in `lo`, instead of
+    /// a single UQ64x64 `u128`, so that any i128 reserves can be accumulated.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The cumulative price of token 1.
+    fn price_1_cumulative_last(e: Env) -> UQ128x128 {
+        get_price_1_cumulative_last(&e)
+    }
+
+    /// Returns the ledger timestamp of the last reserves update.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The timestamp of the last reserves update.
+    fn block_timestamp_last(e: Env) -> u64 {
+        get_block_timestamp_last(&e)
+    }
+
+    /// Prepares the observations ring buffer to store up to `observation_cardinality_next` observations.
+    /// New slots are populated by the following updates, once the last populated slot has been written.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `observation_cardinality_next` - The desired number of observations to store.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::ObservationCardinalityTooHigh`
+    fn increase_observation_cardinality(e: Env, observation_cardinality_next: u32) -> Result<(), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        extend_instance_ttl(&e);
+        let (old, new) = oracle::grow(&e, observation_cardinality_next)?;
+        if old != new {
+            event::observation_cardinality(&e, old, new);
+        }
+        Ok(())
+    }
+
+    /// Extends the time to live of the pair instance storage, of its code and of the populated observations
+    /// from index `start`, up to `limit` observations, so the pool is not archived while nobody interacts with it.
+    /// Anyone can call this function.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `start` - The index of the first observation to extend.
+    /// * `limit` - The maximum number of observations to extend.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    fn extend_pair_ttl(e: Env, start: u32, limit: u32) -> Result<(), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        extend_instance_ttl(&e);
+        let end = start.saturating_add(limit).min(get_observation_cardinality(&e));
+        for index in start..end {
+            extend_observation_ttl(&e, index);
+        }
+        Ok(())
+    }
+
+    /// Replaces the code of the pair with the Wasm identified by `new_wasm_hash`. Reserves, LP balances and the
+    /// rest of the storage are kept. The new code takes effect once this call returns, and the factory must then
+    /// call `migrate` in order to update the storage layout.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `new_wasm_hash` - The hash of the new Wasm, that must already be uploaded.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+        extend_instance_ttl(&e);
+        e.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Runs the storage migrations between the stored version and the version of the current code.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The new storage version.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::MigrateVersionInvalid`: The storage was written by a newer version of the pair.
+    fn migrate(e: Env) -> Result<u32, SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+        extend_instance_ttl(&e);
+        let (old_version, new_version) = migration::migrate(&e)?;
+        if old_version != new_version {
+            event::migrate(&e, old_version, new_version);
+        }
+        Ok(new_version)
+    }
+
+    /// Returns the version of the storage layout of the pair.
+    fn version(e: Env) -> u32 {
+        get_version(&e)
+    }
+
+    /// Returns the current and the next cardinality of the observations ring buffer.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// A tuple containing the number of populated observations and the number of observations to be populated.
+    fn observation_cardinality(e: Env) -> (u32, u32) {
+        (get_observation_cardinality(&e), get_observation_cardinality_next(&e))
+    }
+
+    /// Returns the cumulative prices and liquidity as of each `seconds_agos` from the current ledger timestamp.
+    /// Values between two observations are interpolated, and values newer than the last observation are
+    /// extrapolated with the current reserves. The TTL of the observations it reads is extended.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `seconds_agos` - How many seconds ago each observation should be returned.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::ObserveOldObservation`: If a target is older than the oldest observation.
+    fn observe(e: Env, seconds_agos: Vec<u64>) -> Result<Vec<Observation>, SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        extend_instance_ttl(&e);
+        oracle::observe(&e, seconds_agos)
+    }
+
+    /// Returns the time-weighted average prices over the last `window` seconds.
+    /// The TTL of the observations it reads is extended.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `window` - The amount of seconds to average over.
+    ///
+    /// # Returns
+    /// A tuple containing the average price of token 0 (in token 1) and of token 1 (in token 0), encoded as UQ128x128.
+    ///
+    /// # Errors
+    /// - `SoroswapPairError::NotInitialized`
+    /// - `SoroswapPairError::ConsultInvalidWindow`: If `window` is zero.
+    /// - `SoroswapPairError::ObserveOldObservation`: If `window` is older than the oldest observation.
+    fn consult(e: Env, window: u64) -> Result<(UQ128x128, UQ128x128), SoroswapPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapPairError::NotInitialized);
+        }
+        if window == 0 {
+            return Err(SoroswapPairError::ConsultInvalidWindow);
+        }
+        extend_instance_ttl(&e);
+        let start = oracle::observe_single(&e, window)?;
+        let end = oracle::observe_single(&e, 0)?;
+        let price_0_average = end.price_0_cumulative.wrapping_sub(start.price_0_cumulative).div(window);
+        let price_1_average = end.price_1_cumulative.wrapping_sub(start.price_1_cumulative).div(window);
+        Ok((price_0_average, price_1_average))
+    }
+
     /// Returns the total number of LP shares in circulation.
     ///
     /// # Arguments
@@ -431,6 +771,30 @@ fn transfer_token_1_from_pair(e: &Env, to: &Address, amount: i128) {
     transfer(e, get_token_1(e), &to, amount);
 }
 
+// Fee of a flash loan of `amount`, rounded up so that small loans still pay it
+fn flash_loan_fee(amount: i128) -> i128 {
+    let numerator = amount.checked_mul(FLASH_LOAN_FEE).unwrap();
+    let fee = numerator.checked_div(FEE_DENOMINATOR).unwrap();
+    if numerator % FEE_DENOMINATOR == 0 { fee } else { fee.checked_add(1).unwrap() }
+}
+
+/*
+    Equivalent to the UniswapV2 `lock` modifier: deposit, swap, flash_loan, withdraw, skim and sync call into
+    the token contracts, so nested calls into any of them are rejected while one is being executed.
+    If the outer call fails, the lock is rolled back together with the rest of its changes.
+*/
+fn lock(e: &Env) -> Result<(), SoroswapPairError> {
+    if get_locked(e) {
+        return Err(SoroswapPairError::Locked);
+    }
+    put_locked(e, true);
+    Ok(())
+}
+
+fn unlock(e: &Env) {
+    put_locked(e, false);
+}
+
 fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> bool{
 
     /*
@@ -446,14 +810,20 @@ fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> bool{
      
     if fee_on{
         let fee_to: Address = factory_client.fee_to();
+        // The protocol takes share_numerator/share_denominator of the growth in sqrt(k)
+        let (share_numerator, share_denominator) = factory_client.protocol_fee_share();
+        let share_numerator: i128 = share_numerator.into();
+        let share_denominator: i128 = share_denominator.into();
 
-        if klast != 0 {
+        if klast != 0 && share_numerator > 0 {
             let root_k = (reserve_0.checked_mul(reserve_1).unwrap()).sqrt();
             let root_klast = (klast).sqrt();
             if root_k > root_klast{
                 let total_shares = get_total_shares(&e);
-                let numerator = total_shares.checked_mul(root_k.checked_sub(root_klast).unwrap()).unwrap();
-                let denominator = root_k.checked_mul(5_i128).unwrap().checked_add(root_klast).unwrap();
+                let numerator = total_shares.checked_mul(root_k.checked_sub(root_klast).unwrap()).unwrap()
+                    .checked_mul(share_numerator).unwrap();
+                let denominator = root_k.checked_mul(share_denominator.checked_sub(share_numerator).unwrap()).unwrap()
+                    .checked_add(root_klast.checked_mul(share_numerator).unwrap()).unwrap();
                 let liquidity_pool_shares_fees = numerator.checked_div(denominator).unwrap();
 
                 if liquidity_pool_shares_fees > 0 {
@@ -469,7 +839,25 @@ fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> bool{
 }
 
 fn update(e: &Env, balance_0: i128, balance_1: i128) {
+    /*
+            Prices are encoded as UQ128x128, so any i128 reserves fit in them.
+            On the first call of each ledger timestamp, the prices of the previous reserves
+            are accumulated, weighted by the seconds elapsed since the last update, and a new
+            observation is written. Overflows of the cumulative prices and of the elapsed time
+            are desired, as in UniswapV2.
+    */
+    let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+    let block_timestamp = e.ledger().timestamp();
+    let time_elapsed = block_timestamp.wrapping_sub(get_block_timestamp_last(&e));
+
+    if time_elapsed > 0 {
+        let observation = oracle::write(&e, block_timestamp, reserve_0, reserve_1);
+        put_price_0_cumulative_last(&e, observation.price_0_cumulative);
+        put_price_1_cumulative_last(&e, observation.price_1_cumulative);
+    }
+
     put_reserve_0(&e, balance_0);
     put_reserve_1(&e, balance_1);
+    put_block_timestamp_last(&e, block_timestamp);
     event::sync(&e, balance_0, balance_1);
 }
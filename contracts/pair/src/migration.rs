@@ -0,0 +1,41 @@
+//! Versioned storage migrations. After its code is upgraded by the factory, the pair runs every
+//! migration step between the version stored in the instance storage and `CONTRACT_VERSION`.
+//! New storage layouts must bump `CONTRACT_VERSION` and add a step to `migrate_step`.
+use soroban_sdk::Env;
+use crate::storage::*;
+use crate::error::SoroswapPairError;
+use crate::oracle;
+
+pub(crate) const CONTRACT_VERSION: u32 = 1;
+
+// Swap fee of the pairs deployed before fee tiers were introduced, in basis points
+const LEGACY_FEE: u32 = 30;
+
+// Migrates the storage from `version` to `version + 1`
+fn migrate_step(e: &Env, version: u32) {
+    // Unversioned pairs were deployed with a fixed fee and without the observations buffer
+    if version == 0 {
+        if !has_fee(e) {
+            put_fee(e, LEGACY_FEE);
+        }
+        if get_observation_cardinality(e) == 0 {
+            oracle::initialize(e, e.ledger().timestamp());
+        }
+    }
+}
+
+/// Runs the pending migration steps.
+///
+/// # Returns
+/// A tuple with the old and the new storage version.
+pub(crate) fn migrate(e: &Env) -> Result<(u32, u32), SoroswapPairError> {
+    let old_version = get_version(e);
+    if old_version > CONTRACT_VERSION {
+        return Err(SoroswapPairError::MigrateVersionInvalid);
+    }
+    for version in old_version..CONTRACT_VERSION {
+        migrate_step(e, version);
+    }
+    put_version(e, CONTRACT_VERSION);
+    Ok((old_version, CONTRACT_VERSION))
+}
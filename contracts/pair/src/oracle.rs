@@ -0,0 +1,231 @@
+//! Ring buffer of price and liquidity observations, inspired by the UniswapV3 oracle.
+//! Observations are written from `update` at most once per ledger timestamp, and can be
+//! queried by other contracts in order to compute time-weighted averages on-chain.
+use soroban_sdk::{contracttype, Env, Vec};
+use num_integer::Roots;
+use crate::storage::*;
+use crate::uq128x128::{fraction, UQ128x128};
+use crate::error::SoroswapPairError;
+
+// Each slot is a persistent entry whose rent is paid by the updates that populate it and kept alive by the reads
+// of `observe` and `consult` and by `extend_pair_ttl`, so the buffer is kept small.
+pub(crate) const MAX_OBSERVATION_CARDINALITY: u32 = 1024;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Observation {
+    pub timestamp: u64,
+    pub price_0_cumulative: UQ128x128,
+    pub price_1_cumulative: UQ128x128,
+    pub liquidity_cumulative: u128,
+}
+
+const MASK_64: u128 = u64::MAX as u128;
+
+// Full 256 bits product of two u128, as its high and low halves
+fn mul_wide(x: u128, y: u128) -> (u128, u128) {
+    let (x_hi, x_lo) = (x >> 64, x & MASK_64);
+    let (y_hi, y_lo) = (y >> 64, y & MASK_64);
+    let lo_lo = x_lo * y_lo;
+    let hi_lo = x_hi * y_lo;
+    let lo_hi = x_lo * y_hi;
+    let middle = (lo_lo >> 64) + (hi_lo & MASK_64) + (lo_hi & MASK_64);
+    let hi = x_hi * y_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64);
+    (hi, (middle << 64) | (lo_lo & MASK_64))
+}
+
+// Returns the square root of `x * y`, rounded down, computed on the full 256 bits product.
+// Both factors are reserves, so they are below 2^127 and so is the result.
+pub(crate) fn geometric_mean(x: u128, y: u128) -> u128 {
+    let product = mul_wide(x, y);
+    // sqrt(x)·sqrt(y) <= sqrt(x·y) < (sqrt(x) + 1)·(sqrt(y) + 1)
+    let mut low = x.sqrt() * y.sqrt();
+    let mut high = (x.sqrt() + 1) * (y.sqrt() + 1);
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if mul_wide(middle, middle) <= product {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    low
+}
+
+// Returns the observation that `last` would become at `block_timestamp`, given the reserves held since `last` was written.
+// As cumulative prices, the cumulative liquidity is meant to wrap on overflow.
+fn transform(last: &Observation, block_timestamp: u64, reserve_0: i128, reserve_1: i128) -> Observation {
+    let time_elapsed = block_timestamp.wrapping_sub(last.timestamp);
+
+    if time_elapsed == 0 || reserve_0 == 0 || reserve_1 == 0 {
+        return Observation {
+            timestamp: block_timestamp,
+            ..last.clone()
+        };
+    }
+
+    // reserves are never negative
+    let reserve_0 = reserve_0 as u128;
+    let reserve_1 = reserve_1 as u128;
+    let liquidity = geometric_mean(reserve_0, reserve_1);
+
+    Observation {
+        timestamp: block_timestamp,
+        price_0_cumulative: last.price_0_cumulative
+            .wrapping_add(fraction(reserve_1, reserve_0).wrapping_mul(time_elapsed)),
+        price_1_cumulative: last.price_1_cumulative
+            .wrapping_add(fraction(reserve_0, reserve_1).wrapping_mul(time_elapsed)),
+        liquidity_cumulative: last.liquidity_cumulative
+            .wrapping_add(liquidity.wrapping_mul(time_elapsed.into())),
+    }
+}
+
+// Interpolates the observation at `target`, given the observations right before and right after it.
+fn interpolate(before: &Observation, after: &Observation, target: u64) -> Observation {
+    let observation_time_delta = after.timestamp.checked_sub(before.timestamp).unwrap();
+    let target_delta = target.checked_sub(before.timestamp).unwrap();
+
+    let interpolate_price = |before_value: UQ128x128, after_value: UQ128x128| -> UQ128x128 {
+        before_value.wrapping_add(
+            after_value.wrapping_sub(before_value).div(observation_time_delta).wrapping_mul(target_delta)
+        )
+    };
+    let liquidity_delta = after.liquidity_cumulative.wrapping_sub(before.liquidity_cumulative);
+
+    Observation {
+        timestamp: target,
+        price_0_cumulative: interpolate_price(before.price_0_cumulative, after.price_0_cumulative),
+        price_1_cumulative: interpolate_price(before.price_1_cumulative, after.price_1_cumulative),
+        liquidity_cumulative: before.liquidity_cumulative.wrapping_add(
+            (liquidity_delta / u128::from(observation_time_delta)).wrapping_mul(target_delta.into())
+        ),
+    }
+}
+
+/// Writes the first observation of the buffer, with a cardinality of 1.
+pub(crate) fn initialize(e: &Env, block_timestamp: u64) {
+    put_observation(e, 0, &Observation {
+        timestamp: block_timestamp,
+        price_0_cumulative: UQ128x128::default(),
+        price_1_cumulative: UQ128x128::default(),
+        liquidity_cumulative: 0,
+    });
+    put_observation_index(e, 0);
+    put_observation_cardinality(e, 1);
+    put_observation_cardinality_next(e, 1);
+}
+
+/// Writes a new observation at `block_timestamp`, accumulating the reserves held since the last observation.
+/// The buffer grows to `observation_cardinality_next` only once the last populated slot has been written.
+///
+/// # Returns
+/// The written observation.
+pub(crate) fn write(e: &Env, block_timestamp: u64, reserve_0: i128, reserve_1: i128) -> Observation {
+    let index = get_observation_index(e);
+    let cardinality = get_observation_cardinality(e);
+    let cardinality_next = get_observation_cardinality_next(e);
+    let last = get_observation(e, index).unwrap();
+
+    let cardinality_updated = if cardinality_next > cardinality && index == cardinality - 1 {
+        cardinality_next
+    } else {
+        cardinality
+    };
+
+    let index_updated = (index + 1) % cardinality_updated;
+    let observation = transform(&last, block_timestamp, reserve_0, reserve_1);
+
+    put_observation(e, index_updated, &observation);
+    put_observation_index(e, index_updated);
+    put_observation_cardinality(e, cardinality_updated);
+    // The oldest observation is the next one to be overwritten, so it must not get archived before
+    extend_observation_ttl(e, (index_updated + 1) % cardinality_updated);
+    observation
+}
+
+/// Prepares the buffer to store up to `next` observations.
+///
+/// # Returns
+/// A tuple with the old and the new `observation_cardinality_next`.
+pub(crate) fn grow(e: &Env, next: u32) -> Result<(u32, u32), SoroswapPairError> {
+    if next > MAX_OBSERVATION_CARDINALITY {
+        return Err(SoroswapPairError::ObservationCardinalityTooHigh);
+    }
+    let current = get_observation_cardinality_next(e);
+    if next <= current {
+        return Ok((current, current));
+    }
+    put_observation_cardinality_next(e, next);
+    Ok((current, next))
+}
+
+// Returns the observations right before or at, and right at or after `target`.
+// `target` must be older than the newest observation.
+fn binary_search(e: &Env, target: u64, index: u32, cardinality: u32) -> (Observation, Observation) {
+    let mut l = (index + 1) % cardinality; // oldest observation
+    let mut r = l + cardinality - 1; // newest observation
+
+    loop {
+        let i = (l + r) / 2;
+
+        let before_or_at = match get_observation(e, i % cardinality) {
+            Some(observation) => observation,
+            // we've landed on an uninitialized slot, keep searching higher (more recently)
+            None => {
+                l = i + 1;
+                continue;
+            }
+        };
+        let at_or_after = get_observation(e, (i + 1) % cardinality).unwrap();
+
+        let target_at_or_after = before_or_at.timestamp <= target;
+        if target_at_or_after && target <= at_or_after.timestamp {
+            return (before_or_at, at_or_after);
+        }
+
+        if !target_at_or_after {
+            r = i - 1;
+        } else {
+            l = i + 1;
+        }
+    }
+}
+
+/// Returns the cumulative values as of `seconds_ago` from the current ledger timestamp.
+/// If `seconds_ago` falls between two observations, the values are interpolated.
+/// If it is newer than the last observation, the values are extrapolated with the current reserves.
+pub(crate) fn observe_single(e: &Env, seconds_ago: u64) -> Result<Observation, SoroswapPairError> {
+    let block_timestamp = e.ledger().timestamp();
+    let target = block_timestamp.checked_sub(seconds_ago).ok_or(SoroswapPairError::ObserveOldObservation)?;
+
+    let index = get_observation_index(e);
+    let cardinality = get_observation_cardinality(e);
+
+    let newest = get_observation(e, index).unwrap();
+    if newest.timestamp <= target {
+        return Ok(transform(&newest, target, get_reserve_0(e), get_reserve_1(e)));
+    }
+
+    let oldest = get_observation(e, (index + 1) % cardinality).unwrap_or_else(|| get_observation(e, 0).unwrap());
+    if target < oldest.timestamp {
+        return Err(SoroswapPairError::ObserveOldObservation);
+    }
+
+    let (before_or_at, at_or_after) = binary_search(e, target, index, cardinality);
+    if target == before_or_at.timestamp {
+        Ok(before_or_at)
+    } else if target == at_or_after.timestamp {
+        Ok(at_or_after)
+    } else {
+        Ok(interpolate(&before_or_at, &at_or_after, target))
+    }
+}
+
+/// Returns the cumulative values as of each `seconds_agos` from the current ledger timestamp.
+pub(crate) fn observe(e: &Env, seconds_agos: Vec<u64>) -> Result<Vec<Observation>, SoroswapPairError> {
+    let mut observations = Vec::new(e);
+    for seconds_ago in seconds_agos.iter() {
+        observations.push_back(observe_single(e, seconds_ago)?);
+    }
+    Ok(observations)
+}
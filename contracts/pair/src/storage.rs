@@ -1,5 +1,14 @@
 use soroban_sdk::{ Env, Address, ConversionError,
-    TryFromVal, Val}; 
+    TryFromVal, Val, contracttype}; 
+use crate::oracle::Observation;
+use crate::uq128x128::UQ128x128;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 #[derive(Clone, Copy)] 
 #[repr(u32)]
@@ -14,10 +23,22 @@ pub enum DataKey {
     BlockTimestampLast = 6, // accessible via getReserves,
     Price0CumulativeLast = 7, // uint public price0CumulativeLast;
     Price1CumulativeLast = 8, // uint public price1CumulativeLast;
-    KLast = 9
+    KLast = 9,
+    ObservationIndex = 10, // index of the last written observation
+    ObservationCardinality = 11, // number of populated observation slots
+    ObservationCardinalityNext = 12, // number of observation slots to be populated
+    Fee = 13, // swap fee in basis points
+    Locked = 14, // reentrancy lock
+    Version = 15, // version of the storage layout, see migration.rs
 
 }
 
+#[derive(Clone)]
+#[contracttype]
+pub enum ObservationDataKey {
+    Observation(u32), // Observation. Persistent storage
+}
+
 
 impl TryFromVal<Env, DataKey> for Val {
     type Error = ConversionError;
@@ -65,6 +86,38 @@ get(&DataKey::Reserve1).unwrap()
 
 
 
+pub fn get_block_timestamp_last(e: &Env) -> u64 {
+    if let Some(block_timestamp_last) = e.storage().instance().
+get(&DataKey::BlockTimestampLast) {
+        block_timestamp_last
+    } else {
+        0
+    }
+}
+
+pub fn get_price_0_cumulative_last(e: &Env) -> UQ128x128 {
+    if let Some(price) = e.storage().instance().
+get(&DataKey::Price0CumulativeLast) {
+        price
+    } else {
+        UQ128x128::default()
+    }
+}
+
+pub fn get_price_1_cumulative_last(e: &Env) -> UQ128x128 {
+    if let Some(price) = e.storage().instance().
+get(&DataKey::Price1CumulativeLast) {
+        price
+    } else {
+        UQ128x128::default()
+    }
+}
+
+pub fn get_fee(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::Fee).unwrap()
+}
+
 pub fn get_klast(e: &Env) -> i128 {
     if let Some(klast) = e.storage().instance().
 get(&DataKey::KLast) {
@@ -111,7 +164,114 @@ set(&DataKey::Reserve1, &amount)
 }
 
 
+pub fn get_locked(e: &Env) -> bool {
+    e.storage().instance().
+get(&DataKey::Locked).unwrap_or(false)
+}
+
+pub fn put_locked(e: &Env, locked: bool) {
+    e.storage().instance().
+set(&DataKey::Locked, &locked);
+}
+
+pub fn has_fee(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Fee)
+}
+
+// Pairs deployed before the storage was versioned do not have a version
+pub fn get_version(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::Version).unwrap_or(0)
+}
+
+pub fn put_version(e: &Env, version: u32) {
+    e.storage().instance().
+set(&DataKey::Version, &version);
+}
+
+pub fn put_fee(e: &Env, fee: u32) {
+    e.storage().instance().
+set(&DataKey::Fee, &fee);
+}
+
 pub fn put_klast(e: &Env, klast: i128) {
     e.storage().instance().
 set(&DataKey::KLast, &klast);
-}
\ No newline at end of file
+}
+
+pub fn put_block_timestamp_last(e: &Env, block_timestamp_last: u64) {
+    e.storage().instance().
+set(&DataKey::BlockTimestampLast, &block_timestamp_last);
+}
+
+pub fn put_price_0_cumulative_last(e: &Env, price_0_cumulative_last: UQ128x128) {
+    e.storage().instance().
+set(&DataKey::Price0CumulativeLast, &price_0_cumulative_last);
+}
+
+pub fn put_price_1_cumulative_last(e: &Env, price_1_cumulative_last: UQ128x128) {
+    e.storage().instance().
+set(&DataKey::Price1CumulativeLast, &price_1_cumulative_last);
+}
+
+pub fn get_observation_index(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::ObservationIndex).unwrap_or(0)
+}
+
+pub fn get_observation_cardinality(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::ObservationCardinality).unwrap_or(0)
+}
+
+pub fn get_observation_cardinality_next(e: &Env) -> u32 {
+    e.storage().instance().
+get(&DataKey::ObservationCardinalityNext).unwrap_or(0)
+}
+
+// Observations are kept alive by the reads of `observe` and `consult`, besides the writes and `extend_pair_ttl`
+pub fn get_observation(e: &Env, index: u32) -> Option<Observation> {
+    let key = ObservationDataKey::Observation(index);
+    let observation = e.storage().persistent().get(&key);
+    if observation.is_some() {
+        e.storage().persistent().
+extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    observation
+}
+
+pub fn put_observation_index(e: &Env, index: u32) {
+    e.storage().instance().
+set(&DataKey::ObservationIndex, &index);
+}
+
+pub fn put_observation_cardinality(e: &Env, cardinality: u32) {
+    e.storage().instance().
+set(&DataKey::ObservationCardinality, &cardinality);
+}
+
+pub fn put_observation_cardinality_next(e: &Env, cardinality_next: u32) {
+    e.storage().instance().
+set(&DataKey::ObservationCardinalityNext, &cardinality_next);
+}
+
+pub fn put_observation(e: &Env, index: u32, observation: &Observation) {
+    e.storage().persistent().
+set(&ObservationDataKey::Observation(index), observation);
+    extend_observation_ttl(e, index);
+}
+
+// Extends the TTL of the instance storage and of the contract code
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage().instance().
+extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+// Slots of a grown buffer are not populated until they are written, so they are skipped
+pub fn extend_observation_ttl(e: &Env, index: u32) {
+    let key = ObservationDataKey::Observation(index);
+    if e.storage().persistent().has(&key) {
+        e.storage().persistent().
+extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+}
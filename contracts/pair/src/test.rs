@@ -73,6 +73,8 @@ impl<'a> SoroswapPairTest<'a> {
 
         let env = Env::default();
         env.mock_all_auths();
+        // TODO: Get rid of this hack?
+        env.budget().reset_unlimited();
         let user = Address::generate(&env);
         let admin = Address::generate(&env);
         let mut token_0 = create_token_contract(&env);
@@ -100,9 +102,6 @@ impl<'a> SoroswapPairTest<'a> {
             &env,
         );
 
-        // TODO: Get rid of this hack?
-        env.budget().reset_unlimited();
-    
 
         SoroswapPairTest {
             env,
@@ -126,10 +125,16 @@ mod fee;
 mod skim;
 mod sync;
 mod events;
+mod twap;
+mod oracle;
+mod reentrancy;
+mod flash_swap;
+mod flash_loan;
+mod ttl;
+mod upgrade;
 // mod decode; // wont be used for now
 
 // Test forked by stellar/soroban-examples
-mod soroswap_pair_token;
 
 // Uncompleted tests written by labormedia
 // now in unused_files folder
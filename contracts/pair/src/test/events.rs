@@ -1,9 +1,9 @@
 extern crate std;
 use crate::test::{SoroswapPairTest};
 use crate::event::{DepositEvent, SwapEvent, WithdrawEvent, SyncEvent, SkimEvent};
-use crate::soroswap_pair_token::{SoroswapPairTokenClient};
+use soroswap_pair_token::SoroswapPairTokenClient;
 use crate::test::deposit::add_liquidity;
-use soroban_sdk::{testutils::{Ledger, Events}, vec, IntoVal, symbol_short};
+use soroban_sdk::{testutils::{Ledger, Events}, vec, Bytes, IntoVal, symbol_short};
 
 #[test]
 fn deposit_event() {
@@ -12,7 +12,7 @@ fn deposit_event() {
     let amount_0: i128 = 1_001; //
     let amount_1: i128 = 1_001; //
     let expected_liquidity: i128 = 1;
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     test.token_0.transfer(&test.user, &test.contract.address, &amount_0);
     test.token_1.transfer(&test.user, &test.contract.address, &amount_1);
     let executed_liquidity = test.contract.deposit(&test.user);
@@ -73,7 +73,7 @@ fn swap_event() {
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     add_liquidity(&test, &amount_0, &amount_1);
 
     let init_time = 12345;
@@ -86,7 +86,7 @@ fn swap_event() {
 
     // The user sends the token first:
     test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
-    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user, &Bytes::new(&test.env));
     
     let swap_event = test.env.events().all().last().unwrap();
 
@@ -136,7 +136,7 @@ fn swap_event() {
 fn withdraw_event() {
     let test = SoroswapPairTest::setup();    
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 3_000_000;
     let amount_1: i128 = 3_000_000;
     let expected_liquidity: i128 =  3_000_000;
@@ -205,7 +205,7 @@ fn withdraw_event() {
 fn sync_event() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let original_0: i128 = test.token_0.balance(&test.user);
     let original_1: i128 = test.token_1.balance(&test.user);
@@ -276,7 +276,7 @@ fn skim_event() {
     // zero tokens are being sent
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let original_0: i128 = test.token_0.balance(&test.user);
     let original_1: i128 = test.token_1.balance(&test.user);
@@ -1,7 +1,8 @@
 use crate::test::{SoroswapPairTest};
 use crate::test::deposit::add_liquidity;
-use crate::soroswap_pair_token::{SoroswapPairTokenClient};
+use soroswap_pair_token::SoroswapPairTokenClient;
 use num_integer::Roots; 
+use soroban_sdk::Bytes;
 
 
 
@@ -9,7 +10,7 @@ use num_integer::Roots;
 fn fee_off() {
     let test = SoroswapPairTest::setup();    
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     let expected_liquidity: i128 =  70_710_678;
@@ -24,7 +25,7 @@ fn fee_off() {
     let expected_output_amount_1 = 16624979;
 
     test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
-    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user, &Bytes::new(&test.env));
     assert_eq!(test.contract.get_reserves(), (amount_0+swap_amount_0,amount_1-expected_output_amount_1,));
     assert_eq!(test.contract.k_last(), 0);
 
@@ -55,7 +56,7 @@ fn fee_on_add_swap_remove() {
     test.factory.set_fees_enabled(&true);
     assert_eq!(test.factory.fees_enabled(), true);
     assert_eq!(test.factory.fee_to(), test.admin);
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
@@ -76,7 +77,7 @@ fn fee_on_add_swap_remove() {
     let expected_output_amount_1 = 16624979;
 
     test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
-    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user, &Bytes::new(&test.env));
     //klast does not gets updated in swaps
     assert_eq!(test.contract.k_last(), amount_0.checked_mul(amount_1).unwrap());
     let new_expected_reserve_0= amount_0+swap_amount_0; // 60000000
@@ -183,7 +184,7 @@ fn fee_on_add_swap_add() {
     test.factory.set_fees_enabled(&true);
     assert_eq!(test.factory.fees_enabled(), true);
     assert_eq!(test.factory.fee_to(), test.admin);
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
@@ -210,7 +211,7 @@ fn fee_on_add_swap_add() {
     let expected_output_amount_1 = 16624979;
 
     test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
-    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user, &Bytes::new(&test.env));
     //klast does not gets updated in swaps
     assert_eq!(test.contract.k_last(), amount_0.checked_mul(amount_1).unwrap());
     
@@ -290,3 +291,63 @@ fn fee_on_add_swap_add() {
 
 
 }
+
+
+// Deposits, swaps and deposits again with fees on, returning the shares minted to the admin
+fn fee_on_minted_shares(test: &SoroswapPairTest) -> i128 {
+    test.env.budget().reset_unlimited();
+    test.factory.set_fees_enabled(&true);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    add_liquidity(&test, &50_000_000, &100_000_000);
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    test.contract.swap(&0, &16624979, &test.user, &Bytes::new(&test.env));
+    add_liquidity(&test, &1_000_000, &1389583);
+
+    test.contract.my_balance(&test.admin)
+}
+
+#[test]
+fn fee_on_default_protocol_fee_share() {
+    let test = SoroswapPairTest::setup();
+    assert_eq!(test.factory.protocol_fee_share(), (1, 6));
+    // Same as fee_on_add_swap_add: expected_liquidity*(k2_root-k1_root)/(5k2_root + k1_root)
+    assert_eq!(fee_on_minted_shares(&test), 2946);
+}
+
+#[test]
+fn fee_on_protocol_fee_share_half() {
+    let test = SoroswapPairTest::setup();
+    test.factory.set_protocol_fee_share(&1, &2);
+
+    let expected_liquidity: i128 = 70_710_678;
+    let k2_root: i128 = 70728362;
+    let k1_root: i128 = 70_710_678;
+    // n = expected_liquidity*(k2_root-k1_root)*1/((2-1)*k2_root + 1*k1_root)
+    // = 8840,99... --> 8840
+    let n = 8840;
+    assert_eq!(n, expected_liquidity*(k2_root-k1_root)/(k2_root + k1_root));
+    assert_eq!(fee_on_minted_shares(&test), n);
+
+    // The admin owns half of the growth in sqrt(k): n/(expected_liquidity+n) of k2_root
+    let admin_root_k = (k2_root*n)/(expected_liquidity+n);
+    assert_eq!(admin_root_k, 8841);
+    assert_eq!((k2_root-k1_root)/2, 8842);
+}
+
+#[test]
+fn fee_on_protocol_fee_share_tenth() {
+    let test = SoroswapPairTest::setup();
+    test.factory.set_protocol_fee_share(&1, &10);
+    // n = 70710678*(70728362-70710678)/(9*70728362 + 70710678) = 1768
+    assert_eq!(fee_on_minted_shares(&test), 1768);
+}
+
+#[test]
+fn fee_on_protocol_fee_share_zero() {
+    let test = SoroswapPairTest::setup();
+    test.factory.set_protocol_fee_share(&0, &1);
+    assert_eq!(fee_on_minted_shares(&test), 0);
+    // klast is still tracked while fees are enabled
+    assert_eq!(test.contract.k_last(), (60_000_000+1_000_000)*(83375021+1389583));
+}
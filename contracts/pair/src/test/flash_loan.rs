@@ -0,0 +1,171 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use crate::test::token::TokenClient;
+use crate::error::SoroswapPairError;
+use crate::event::FlashLoanEvent;
+use soroban_sdk::{contract, contractimpl, contracttype, testutils::{Events}, vec, Address, Bytes, Env, IntoVal, symbol_short};
+use soroswap_callee_interface::SoroswapFlashLoanReceiverTrait;
+
+// What the receiver does with the borrowed tokens, given as the first byte of `data`
+const REPAY_WITH_FEE: u8 = 1;
+const REPAY_WITHOUT_FEE: u8 = 2;
+const REPAY_ONLY_TOKEN_0: u8 = 3;
+
+#[contracttype]
+#[derive(Clone)]
+enum FlashLoanReceiverDataKey {
+    Tokens,
+    Fees,
+}
+
+// Sample flash loan receiver: pays back the borrowed tokens plus the fees
+#[contract]
+pub struct FlashLoanReceiver;
+
+#[contractimpl]
+impl SoroswapFlashLoanReceiverTrait for FlashLoanReceiver {
+    fn soroswap_flash_loan(e: Env, sender: Address, amount_0: i128, amount_1: i128, fee_0: i128, fee_1: i128, data: Bytes) {
+        e.storage().instance().set(&FlashLoanReceiverDataKey::Fees, &(fee_0, fee_1));
+        let (token_0, token_1): (Address, Address) = e.storage().instance().get(&FlashLoanReceiverDataKey::Tokens).unwrap();
+
+        let mode = data.get(0).unwrap();
+        let (back_0, back_1) = match mode {
+            REPAY_WITH_FEE => (amount_0 + fee_0, amount_1 + fee_1),
+            REPAY_WITHOUT_FEE => (amount_0, amount_1),
+            _ => (amount_0 + fee_0, 0),
+        };
+        if back_0 > 0 {
+            TokenClient::new(&e, &token_0).transfer(&e.current_contract_address(), &sender, &back_0);
+        }
+        if back_1 > 0 {
+            TokenClient::new(&e, &token_1).transfer(&e.current_contract_address(), &sender, &back_1);
+        }
+    }
+}
+
+#[contractimpl]
+impl FlashLoanReceiver {
+    pub fn initialize(e: Env, token_0: Address, token_1: Address) {
+        e.storage().instance().set(&FlashLoanReceiverDataKey::Tokens, &(token_0, token_1));
+    }
+
+    pub fn last_fees(e: Env) -> Option<(i128, i128)> {
+        e.storage().instance().get(&FlashLoanReceiverDataKey::Fees)
+    }
+}
+
+fn setup_flash_loan<'a>(test: &SoroswapPairTest<'a>) -> FlashLoanReceiverClient<'a> {
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+
+    let receiver = FlashLoanReceiverClient::new(&test.env, &test.env.register_contract(None, FlashLoanReceiver {}));
+    receiver.initialize(&test.token_0.address, &test.token_1.address);
+    // The receiver only needs to hold enough tokens to pay for the fees
+    test.token_0.transfer(&test.user, &receiver.address, &10_000);
+    test.token_1.transfer(&test.user, &receiver.address, &10_000);
+    receiver
+}
+
+#[test]
+fn flash_loan_not_initialized() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    let result = test.contract.try_flash_loan(&test.user, &1, &1, &Bytes::new(&test.env));
+    assert_eq!(result, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn flash_loan_invalid_amounts() {
+    let test = SoroswapPairTest::setup();
+    let receiver = setup_flash_loan(&test);
+    let data = Bytes::from_array(&test.env, &[REPAY_WITH_FEE]);
+
+    let result = test.contract.try_flash_loan(&receiver.address, &0, &0, &data);
+    assert_eq!(result, Err(Ok(SoroswapPairError::FlashLoanInsufficientAmount)));
+    let result = test.contract.try_flash_loan(&receiver.address, &-1, &1, &data);
+    assert_eq!(result, Err(Ok(SoroswapPairError::FlashLoanNegativesNotSupported)));
+    let result = test.contract.try_flash_loan(&receiver.address, &50_000_000, &0, &data);
+    assert_eq!(result, Err(Ok(SoroswapPairError::FlashLoanInsufficientLiquidity)));
+    let result = test.contract.try_flash_loan(&receiver.address, &0, &100_000_000, &data);
+    assert_eq!(result, Err(Ok(SoroswapPairError::FlashLoanInsufficientLiquidity)));
+}
+
+#[test]
+fn flash_loan_repay_with_fee() {
+    let test = SoroswapPairTest::setup();
+    let receiver = setup_flash_loan(&test);
+
+    let data = Bytes::from_array(&test.env, &[REPAY_WITH_FEE]);
+    test.contract.flash_loan(&receiver.address, &1_000_000, &2_000_001, &data);
+
+    // 1000000*9/10000 = 900 and 2000001*9/10000 = 1800,0009, rounded up to 1801
+    assert_eq!(receiver.last_fees(), Some((900, 1801)));
+
+    let flash_loan_event = test.env.events().all().last().unwrap();
+    let expected_flash_loan_event: FlashLoanEvent = FlashLoanEvent {
+        receiver: receiver.address.clone(),
+        amount_0: 1_000_000,
+        amount_1: 2_000_001,
+        paid_0: 900,
+        paid_1: 1801,
+    };
+    assert_eq!(
+        vec![&test.env, flash_loan_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPair", symbol_short!("flash")).into_val(&test.env),
+                (expected_flash_loan_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The fees stay in the reserves
+    assert_eq!(test.contract.get_reserves(), (50_000_900, 100_001_801));
+    assert_eq!(test.token_0.balance(&receiver.address), 10_000 - 900);
+    assert_eq!(test.token_1.balance(&receiver.address), 10_000 - 1801);
+}
+
+#[test]
+fn flash_loan_repay_without_fee() {
+    let test = SoroswapPairTest::setup();
+    let receiver = setup_flash_loan(&test);
+
+    let data = Bytes::from_array(&test.env, &[REPAY_WITHOUT_FEE]);
+    let result = test.contract.try_flash_loan(&receiver.address, &1_000_000, &0, &data);
+    assert_eq!(result, Err(Ok(SoroswapPairError::FlashLoanInsufficientRepaymentToken0)));
+    assert_eq!(test.contract.get_reserves(), (50_000_000, 100_000_000));
+}
+
+#[test]
+fn flash_loan_repay_only_token_0() {
+    let test = SoroswapPairTest::setup();
+    let receiver = setup_flash_loan(&test);
+
+    let data = Bytes::from_array(&test.env, &[REPAY_ONLY_TOKEN_0]);
+    let result = test.contract.try_flash_loan(&receiver.address, &1_000_000, &1_000_000, &data);
+    assert_eq!(result, Err(Ok(SoroswapPairError::FlashLoanInsufficientRepaymentToken1)));
+    assert_eq!(test.token_1.balance(&receiver.address), 10_000);
+
+    // Borrowing only token 0 is fine
+    test.contract.flash_loan(&receiver.address, &1_000_000, &0, &data);
+    assert_eq!(test.contract.get_reserves(), (50_000_900, 100_000_000));
+}
+
+#[test]
+fn flash_loan_fee_accrues_to_protocol() {
+    let test = SoroswapPairTest::setup();
+    test.factory.set_fees_enabled(&true);
+    let receiver = setup_flash_loan(&test);
+    assert_eq!(test.contract.my_balance(&test.admin), 0);
+
+    let data = Bytes::from_array(&test.env, &[REPAY_WITH_FEE]);
+    test.contract.flash_loan(&receiver.address, &10_000_000, &0, &data);
+    assert_eq!(test.contract.get_reserves(), (50_009_000, 100_000_000));
+
+    // The growth in sqrt(k) is shared with the protocol on the next deposit
+    add_liquidity(&test, &1_000_000, &2_000_000);
+    assert!(test.contract.my_balance(&test.admin) > 0);
+}
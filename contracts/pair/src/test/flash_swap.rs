@@ -0,0 +1,156 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use crate::test::token::TokenClient;
+use crate::error::SoroswapPairError;
+use crate::SoroswapPairClient;
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Bytes, Env};
+use soroswap_callee_interface::SoroswapCalleeTrait;
+
+// What the flash borrower does with the borrowed tokens, given as the first byte of `data`
+const REPAY_WITH_FEE: u8 = 1;
+const REPAY_WITHOUT_FEE: u8 = 2;
+const DO_NOT_REPAY: u8 = 3;
+
+#[contracttype]
+#[derive(Clone)]
+enum FlashBorrowerDataKey {
+    Tokens,
+    Fee,
+    LastCall,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlashBorrowerCall {
+    pub sender: Address,
+    pub amount_0: i128,
+    pub amount_1: i128,
+}
+
+// Sample flash borrower: pays back the borrowed tokens plus the pair fee
+#[contract]
+pub struct FlashBorrower;
+
+#[contractimpl]
+impl SoroswapCalleeTrait for FlashBorrower {
+    fn soroswap_call(e: Env, sender: Address, amount_0: i128, amount_1: i128, data: Bytes) {
+        e.storage().instance().set(&FlashBorrowerDataKey::LastCall, &FlashBorrowerCall {
+            sender: sender.clone(),
+            amount_0,
+            amount_1,
+        });
+
+        let mode = data.get(0).unwrap();
+        if mode == DO_NOT_REPAY {
+            return;
+        }
+
+        // The pair cannot be called from here, so its tokens and fee are stored beforehand
+        let (token_0, token_1): (Address, Address) = e.storage().instance().get(&FlashBorrowerDataKey::Tokens).unwrap();
+        let fee: i128 = e.storage().instance().get::<_, u32>(&FlashBorrowerDataKey::Fee).unwrap().into();
+        let repay = |token: Address, amount: i128| {
+            if amount == 0 {
+                return;
+            }
+            let amount_back = if mode == REPAY_WITH_FEE {
+                amount.checked_mul(10000).unwrap().checked_div(10000 - fee).unwrap().checked_add(1).unwrap()
+            } else {
+                amount
+            };
+            TokenClient::new(&e, &token).transfer(&e.current_contract_address(), &sender, &amount_back);
+        };
+        repay(token_0, amount_0);
+        repay(token_1, amount_1);
+    }
+}
+
+#[contractimpl]
+impl FlashBorrower {
+    pub fn initialize(e: Env, pair: Address) {
+        let pair = SoroswapPairClient::new(&e, &pair);
+        e.storage().instance().set(&FlashBorrowerDataKey::Tokens, &(pair.token_0(), pair.token_1()));
+        e.storage().instance().set(&FlashBorrowerDataKey::Fee, &pair.fee());
+    }
+
+    pub fn last_call(e: Env) -> Option<FlashBorrowerCall> {
+        e.storage().instance().get(&FlashBorrowerDataKey::LastCall)
+    }
+}
+
+fn setup_flash_swap<'a>(test: &SoroswapPairTest<'a>) -> FlashBorrowerClient<'a> {
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    add_liquidity(&test, &50_000_000, &100_000_000);
+
+    let borrower = FlashBorrowerClient::new(&test.env, &test.env.register_contract(None, FlashBorrower {}));
+    borrower.initialize(&test.contract.address);
+    // The borrower only needs to hold enough tokens to pay for the fees
+    test.token_0.transfer(&test.user, &borrower.address, &10_000);
+    test.token_1.transfer(&test.user, &borrower.address, &10_000);
+    borrower
+}
+
+#[test]
+fn flash_swap_repay_with_fee() {
+    let test = SoroswapPairTest::setup();
+    let borrower = setup_flash_swap(&test);
+
+    let data = Bytes::from_array(&test.env, &[REPAY_WITH_FEE]);
+    test.contract.swap(&0, &1_000_000, &borrower.address, &data);
+
+    assert_eq!(borrower.last_call(), Some(FlashBorrowerCall {
+        sender: test.contract.address.clone(),
+        amount_0: 0,
+        amount_1: 1_000_000,
+    }));
+    // 1000000*10000/9970 + 1 = 1003010
+    let repaid = 1003010;
+    assert_eq!(test.token_1.balance(&borrower.address), 10_000 + 1_000_000 - repaid);
+    assert_eq!(test.contract.get_reserves(), (50_000_000, 100_000_000 - 1_000_000 + repaid));
+}
+
+#[test]
+fn flash_swap_both_tokens_repay_with_fee() {
+    let test = SoroswapPairTest::setup();
+    let borrower = setup_flash_swap(&test);
+
+    let data = Bytes::from_array(&test.env, &[REPAY_WITH_FEE]);
+    test.contract.swap(&500_000, &1_000_000, &borrower.address, &data);
+
+    // 500000*10000/9970 + 1 = 501505
+    assert_eq!(test.contract.get_reserves(), (50_000_000 - 500_000 + 501505, 100_000_000 - 1_000_000 + 1003010));
+}
+
+#[test]
+fn flash_swap_repay_without_fee() {
+    let test = SoroswapPairTest::setup();
+    let borrower = setup_flash_swap(&test);
+
+    let data = Bytes::from_array(&test.env, &[REPAY_WITHOUT_FEE]);
+    let result = test.contract.try_swap(&0, &1_000_000, &borrower.address, &data);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
+    assert_eq!(test.contract.get_reserves(), (50_000_000, 100_000_000));
+}
+
+#[test]
+fn flash_swap_do_not_repay() {
+    let test = SoroswapPairTest::setup();
+    let borrower = setup_flash_swap(&test);
+
+    let data = Bytes::from_array(&test.env, &[DO_NOT_REPAY]);
+    let result = test.contract.try_swap(&0, &1_000_000, &borrower.address, &data);
+    assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientInputAmount)));
+    assert_eq!(test.token_1.balance(&borrower.address), 10_000);
+}
+
+#[test]
+fn swap_empty_data_does_not_call_back() {
+    let test = SoroswapPairTest::setup();
+    let borrower = setup_flash_swap(&test);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &10_000_000);
+    test.contract.swap(&0, &16624979, &borrower.address, &Bytes::new(&test.env));
+
+    assert_eq!(borrower.last_call(), None);
+    assert_eq!(test.token_1.balance(&borrower.address), 10_000 + 16624979);
+}
@@ -1,5 +1,5 @@
 use crate::test::{SoroswapPairTest}; 
-use crate::soroswap_pair_token::{SoroswapPairTokenClient};
+use soroswap_pair_token::SoroswapPairTokenClient;
 use soroban_sdk::{String};
 use crate::error::SoroswapPairError;
 
@@ -7,7 +7,7 @@ use crate::error::SoroswapPairError;
 // #[should_panic(expected = "SoroswapPair: token_0 must be less than token_1")]
 fn initialize_pair_token_1_less_than_token_0() {
     let test = SoroswapPairTest::setup();
-    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_1.address, &test.token_0.address);    
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_1.address, &test.token_0.address, &30);    
     assert_eq!(res, Err(Ok(SoroswapPairError::InitializeTokenOrderInvalid))); 
 
 }
@@ -17,12 +17,19 @@ fn initialize_pair_token_1_less_than_token_0() {
 // #[should_panic(expected = "SoroswapPair: already initialized")]
 fn double_initialize_pair() {
     let test = SoroswapPairTest::setup();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
-    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     assert_eq!(res, Err(Ok(SoroswapPairError::InitializeAlreadyInitialized))); 
 
 }
 
+#[test]
+fn initialize_pair_fee_invalid() {
+    let test = SoroswapPairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &10000);
+    assert_eq!(res, Err(Ok(SoroswapPairError::InitializeFeeInvalid)));
+}
+
 
 #[test]
 fn initialize_pair_initial_values() {
@@ -37,10 +44,11 @@ fn initialize_pair_initial_values() {
     assert_eq!(test.token_1.name(), String::from_str(&test.env, "Token 1"));
 
     // Test liqpool initial values:
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     assert_eq!(test.contract.token_0(), test.token_0.address);
     assert_eq!(test.contract.token_1(), test.token_1.address);
     assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.fee(), 30);
     assert_eq!(test.contract.get_reserves(), (0,0));
     assert_eq!(test.contract.k_last(), 0);
     assert_eq!(test.contract.total_shares(), 0);
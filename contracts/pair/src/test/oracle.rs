@@ -0,0 +1,229 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use crate::uq128x128::{fraction, UQ128x128};
+use crate::error::SoroswapPairError;
+use crate::event::ObservationCardinalityEvent;
+use num_integer::Roots;
+use soroban_sdk::{testutils::{Ledger, Events}, vec, IntoVal, symbol_short};
+
+fn set_timestamp(test: &SoroswapPairTest, timestamp: u64) {
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = timestamp;
+    });
+}
+
+#[test]
+fn oracle_initial_observation() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    set_timestamp(&test, 1000);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    assert_eq!(test.contract.observation_cardinality(), (1, 1));
+    assert_eq!(test.contract.block_timestamp_last(), 1000);
+
+    set_timestamp(&test, 1500);
+    let observations = test.contract.observe(&vec![&test.env, 0, 500]);
+    assert_eq!(observations.len(), 2);
+    // Without reserves, nothing gets accumulated
+    assert_eq!(observations.get(0).unwrap().timestamp, 1500);
+    assert_eq!(observations.get(0).unwrap().price_0_cumulative, UQ128x128::default());
+    assert_eq!(observations.get(1).unwrap().timestamp, 1000);
+    assert_eq!(observations.get(1).unwrap().liquidity_cumulative, 0);
+}
+
+#[test]
+fn oracle_observe_not_initialized() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    let res = test.contract.try_observe(&vec![&test.env, 0]);
+    assert_eq!(res, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn oracle_observe_extrapolates_current_reserves() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    set_timestamp(&test, 1000);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    set_timestamp(&test, 1100);
+    let observation = test.contract.observe(&vec![&test.env, 0]).get(0).unwrap();
+    assert_eq!(observation.timestamp, 1100);
+    assert_eq!(observation.price_0_cumulative, fraction(4_000_000, 1_000_000).wrapping_mul(100));
+    assert_eq!(observation.price_1_cumulative, fraction(1_000_000, 4_000_000).wrapping_mul(100));
+    assert_eq!(observation.liquidity_cumulative, 2_000_000 * 100);
+
+    // Observing does not write anything
+    assert_eq!(test.contract.price_0_cumulative_last(), UQ128x128::default());
+    assert_eq!(test.contract.observation_cardinality(), (1, 1));
+}
+
+#[test]
+fn oracle_observe_too_old_with_cardinality_1() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    set_timestamp(&test, 1000);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    set_timestamp(&test, 1100);
+    test.contract.sync();
+
+    // The only stored observation is the one from the last update
+    set_timestamp(&test, 1200);
+    let res = test.contract.try_observe(&vec![&test.env, 150]);
+    assert_eq!(res, Err(Ok(SoroswapPairError::ObserveOldObservation)));
+    let res = test.contract.try_observe(&vec![&test.env, 1201]);
+    assert_eq!(res, Err(Ok(SoroswapPairError::ObserveOldObservation)));
+    test.contract.observe(&vec![&test.env, 100]);
+}
+
+#[test]
+fn oracle_increase_cardinality() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    test.contract.increase_observation_cardinality(&3);
+    let cardinality_event = test.env.events().all().last().unwrap();
+    let expected_cardinality_event: ObservationCardinalityEvent = ObservationCardinalityEvent {
+        old_cardinality_next: 1,
+        new_cardinality_next: 3,
+    };
+    assert_eq!(
+        vec![&test.env, cardinality_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPair", symbol_short!("obs_card")).into_val(&test.env),
+                (expected_cardinality_event).into_val(&test.env)
+            ),
+        ]
+    );
+    // The cardinality only grows once the last populated slot has been written
+    assert_eq!(test.contract.observation_cardinality(), (1, 3));
+
+    // Decreasing is a no-op
+    test.contract.increase_observation_cardinality(&2);
+    assert_eq!(test.contract.observation_cardinality(), (1, 3));
+
+    add_liquidity(&test, &1_000_000, &4_000_000);
+    set_timestamp(&test, 10);
+    test.contract.sync();
+    assert_eq!(test.contract.observation_cardinality(), (3, 3));
+
+    let res = test.contract.try_increase_observation_cardinality(&1025);
+    assert_eq!(res, Err(Ok(SoroswapPairError::ObservationCardinalityTooHigh)));
+}
+
+#[test]
+fn oracle_observe_interpolates_between_observations() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    set_timestamp(&test, 1000);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test.contract.increase_observation_cardinality(&10);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    // Price of token 0 is 4 from 1000 to 1100
+    set_timestamp(&test, 1100);
+    add_liquidity(&test, &1_000_000, &1_000_000);
+    // Reserves are now (2_000_000, 5_000_000): price of token 0 is 2.5 from 1100 to 1300
+    set_timestamp(&test, 1300);
+    test.contract.sync();
+
+    set_timestamp(&test, 1400);
+    let observations = test.contract.observe(&vec![&test.env, 350, 300, 200, 0]);
+
+    // 1050, in the middle of the first period
+    let observation = observations.get(0).unwrap();
+    assert_eq!(observation.timestamp, 1050);
+    assert_eq!(observation.price_0_cumulative, fraction(4_000_000, 1_000_000).wrapping_mul(50));
+    assert_eq!(observation.liquidity_cumulative, 2_000_000 * 50);
+
+    // 1100, exactly at the second observation
+    let at_1100 = fraction(4_000_000, 1_000_000).wrapping_mul(100);
+    assert_eq!(observations.get(1).unwrap().price_0_cumulative, at_1100);
+
+    // 1200, in the middle of the second period
+    let at_1200 = at_1100.wrapping_add(fraction(5_000_000, 2_000_000).wrapping_mul(100));
+    assert_eq!(observations.get(2).unwrap().price_0_cumulative, at_1200);
+    let liquidity = (2_000_000_u128 * 5_000_000_u128).sqrt();
+    assert_eq!(observations.get(2).unwrap().liquidity_cumulative, 2_000_000 * 100 + liquidity * 100);
+
+    // 1400, extrapolated from the last observation
+    let at_1400 = at_1200.wrapping_add(fraction(5_000_000, 2_000_000).wrapping_mul(200));
+    assert_eq!(observations.get(3).unwrap().price_0_cumulative, at_1400);
+    assert_eq!(observations.get(3).unwrap().timestamp, 1400);
+}
+
+#[test]
+fn oracle_ring_buffer_overwrites_oldest() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    set_timestamp(&test, 1000);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test.contract.increase_observation_cardinality(&3);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    // Observations at 1000, 1100, 1200, 1300, 1400. Only the last 3 remain.
+    for timestamp in [1100, 1200, 1300, 1400] {
+        set_timestamp(&test, timestamp);
+        test.contract.sync();
+    }
+    assert_eq!(test.contract.observation_cardinality(), (3, 3));
+
+    let res = test.contract.try_observe(&vec![&test.env, 201]);
+    assert_eq!(res, Err(Ok(SoroswapPairError::ObserveOldObservation)));
+
+    let observation = test.contract.observe(&vec![&test.env, 200]).get(0).unwrap();
+    assert_eq!(observation.timestamp, 1200);
+    assert_eq!(observation.price_0_cumulative, fraction(4_000_000, 1_000_000).wrapping_mul(200));
+}
+
+#[test]
+fn oracle_consult() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    set_timestamp(&test, 1000);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test.contract.increase_observation_cardinality(&10);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    set_timestamp(&test, 1100);
+    let (price_0, price_1) = test.contract.consult(&100);
+    assert_eq!(price_0, fraction(4_000_000, 1_000_000));
+    assert_eq!(price_1, fraction(1_000_000, 4_000_000));
+
+    // Price of token 0 is 4 from 1000 to 1100 and 2.5 from 1100 to 1300
+    add_liquidity(&test, &1_000_000, &1_000_000);
+    set_timestamp(&test, 1300);
+    test.contract.sync();
+
+    let (price_0, _price_1) = test.contract.consult(&300);
+    let expected_price_0 = fraction(4_000_000, 1_000_000).wrapping_mul(100).wrapping_add(fraction(5_000_000, 2_000_000).wrapping_mul(200)).div(300);
+    assert_eq!(price_0, expected_price_0);
+    // 3 in UQ128x128
+    assert_eq!(price_0.hi, 3);
+
+    let res = test.contract.try_consult(&0);
+    assert_eq!(res, Err(Ok(SoroswapPairError::ConsultInvalidWindow)));
+    let res = test.contract.try_consult(&301);
+    assert_eq!(res, Err(Ok(SoroswapPairError::ObserveOldObservation)));
+}
+
+#[test]
+fn oracle_geometric_mean_full_width() {
+    let max_reserve = i128::MAX as u128;
+    assert_eq!(crate::oracle::geometric_mean(1, max_reserve), max_reserve.sqrt());
+    // The product of the reserves does not fit in a u128, yet no low bit of the small reserve is dropped
+    assert_eq!(crate::oracle::geometric_mean(3, max_reserve), 22_592_555_198_148_962_256);
+    assert_eq!(crate::oracle::geometric_mean(12345, max_reserve - 7), 1_449_273_235_045_584_033_960);
+    assert_eq!(crate::oracle::geometric_mean(max_reserve, max_reserve), max_reserve);
+    assert_eq!(crate::oracle::geometric_mean(max_reserve - 1, max_reserve), max_reserve - 1);
+    assert_eq!(crate::oracle::geometric_mean(1 << 100, 1 << 80), 1 << 90);
+    assert_eq!(crate::oracle::geometric_mean(0, max_reserve), 0);
+}
@@ -0,0 +1,190 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use crate::error::SoroswapPairError;
+use crate::storage::put_locked;
+use crate::SoroswapPairClient;
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Bytes, Env, Symbol};
+
+// Token that tries to re-enter the pair whenever the pair transfers it out.
+// Soroban also rejects contract re-entry at the host level, so the lock itself is
+// tested in `locked_pair_rejects_calls`.
+#[contracttype]
+#[derive(Clone)]
+enum MaliciousTokenDataKey {
+    Balance(Address),
+    Pair,
+    Attack,
+    Attempts,
+    Reentered,
+}
+
+#[contract]
+pub struct MaliciousToken;
+
+#[contractimpl]
+impl MaliciousToken {
+    pub fn mint(e: Env, to: Address, amount: i128) {
+        let balance = Self::balance(e.clone(), to.clone());
+        e.storage().instance().set(&MaliciousTokenDataKey::Balance(to), &(balance + amount));
+    }
+
+    pub fn set_attack(e: Env, pair: Address, attack: Symbol) {
+        e.storage().instance().set(&MaliciousTokenDataKey::Pair, &pair);
+        e.storage().instance().set(&MaliciousTokenDataKey::Attack, &attack);
+    }
+
+    pub fn balance(e: Env, id: Address) -> i128 {
+        e.storage().instance().get(&MaliciousTokenDataKey::Balance(id)).unwrap_or(0)
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        let from_balance = Self::balance(e.clone(), from.clone());
+        let to_balance = Self::balance(e.clone(), to.clone());
+        e.storage().instance().set(&MaliciousTokenDataKey::Balance(from.clone()), &(from_balance - amount));
+        e.storage().instance().set(&MaliciousTokenDataKey::Balance(to.clone()), &(to_balance + amount));
+
+        let pair: Option<Address> = e.storage().instance().get(&MaliciousTokenDataKey::Pair);
+        if let Some(pair) = pair {
+            if from != pair {
+                return;
+            }
+            let attack: Symbol = e.storage().instance().get(&MaliciousTokenDataKey::Attack).unwrap();
+            let pair_client = SoroswapPairClient::new(&e, &pair);
+            let reentered = if attack == symbol_short!("deposit") {
+                pair_client.try_deposit(&to).is_ok()
+            } else if attack == symbol_short!("swap") {
+                pair_client.try_swap(&1, &0, &to, &Bytes::new(&e)).is_ok()
+            } else if attack == symbol_short!("withdraw") {
+                pair_client.try_withdraw(&to).is_ok()
+            } else if attack == symbol_short!("skim") {
+                pair_client.try_skim(&to).is_ok()
+            } else {
+                pair_client.try_sync().is_ok()
+            };
+            let attempts: u32 = e.storage().instance().get(&MaliciousTokenDataKey::Attempts).unwrap_or(0);
+            e.storage().instance().set(&MaliciousTokenDataKey::Attempts, &(attempts + 1));
+            if reentered {
+                e.storage().instance().set(&MaliciousTokenDataKey::Reentered, &true);
+            }
+        }
+    }
+
+    pub fn attempts(e: Env) -> u32 {
+        e.storage().instance().get(&MaliciousTokenDataKey::Attempts).unwrap_or(0)
+    }
+
+    pub fn reentered(e: Env) -> bool {
+        e.storage().instance().get(&MaliciousTokenDataKey::Reentered).unwrap_or(false)
+    }
+}
+
+// Pair between test.token_0 and a malicious token, with liquidity
+fn setup_malicious_pair<'a>(test: &SoroswapPairTest<'a>) -> (SoroswapPairClient<'a>, MaliciousTokenClient<'a>, bool) {
+    let malicious_token = MaliciousTokenClient::new(&test.env, &test.env.register_contract(None, MaliciousToken {}));
+    malicious_token.mint(&test.user, &1_000_000_000);
+    let pair = SoroswapPairClient::new(&test.env, &test.env.register_contract(None, crate::SoroswapPair {}));
+
+    let malicious_is_token_0 = malicious_token.address < test.token_0.address;
+    if malicious_is_token_0 {
+        pair.initialize_pair(&test.factory.address, &malicious_token.address, &test.token_0.address, &30);
+    } else {
+        pair.initialize_pair(&test.factory.address, &test.token_0.address, &malicious_token.address, &30);
+    }
+    malicious_token.transfer(&test.user, &pair.address, &100_000_000);
+    test.token_0.transfer(&test.user, &pair.address, &100_000_000);
+    pair.deposit(&test.user);
+    (pair, malicious_token, malicious_is_token_0)
+}
+
+// Entrypoints the malicious token tries to re-enter
+const ATTACKS: [Symbol; 5] = [
+    symbol_short!("deposit"),
+    symbol_short!("swap"),
+    symbol_short!("withdraw"),
+    symbol_short!("skim"),
+    symbol_short!("sync"),
+];
+
+#[test]
+fn reentrancy_during_swap() {
+    for attack in ATTACKS {
+        let test = SoroswapPairTest::setup();
+        test.env.budget().reset_unlimited();
+        let (pair, malicious_token, malicious_is_token_0) = setup_malicious_pair(&test);
+        malicious_token.set_attack(&pair.address, &attack);
+
+        // Swap token_0 for the malicious token, which is transferred out of the pair
+        test.token_0.transfer(&test.user, &pair.address, &10_000_000);
+        if malicious_is_token_0 {
+            pair.swap(&9_000_000, &0, &test.user, &Bytes::new(&test.env));
+        } else {
+            pair.swap(&0, &9_000_000, &test.user, &Bytes::new(&test.env));
+        }
+
+        assert_eq!(malicious_token.attempts(), 1);
+        assert_eq!(malicious_token.reentered(), false);
+        let (reserve_0, reserve_1) = pair.get_reserves();
+        let (malicious_reserve, reserve) = if malicious_is_token_0 { (reserve_0, reserve_1) } else { (reserve_1, reserve_0) };
+        assert_eq!(malicious_reserve, 91_000_000);
+        assert_eq!(reserve, 110_000_000);
+        assert_eq!(malicious_token.balance(&pair.address), malicious_reserve);
+    }
+}
+
+#[test]
+fn reentrancy_during_withdraw() {
+    for attack in ATTACKS {
+        let test = SoroswapPairTest::setup();
+        test.env.budget().reset_unlimited();
+        let (pair, malicious_token, _) = setup_malicious_pair(&test);
+        malicious_token.set_attack(&pair.address, &attack);
+
+        let pair_token_client = soroswap_pair_token::SoroswapPairTokenClient::new(&test.env, &test.env.register_contract(&pair.address, crate::SoroswapPairToken {}));
+        pair_token_client.transfer(&test.user, &pair.address, &50_000_000);
+        test.env.register_contract(&pair.address, crate::SoroswapPair {});
+        pair.withdraw(&test.user);
+
+        assert_eq!(malicious_token.attempts(), 1);
+        assert_eq!(malicious_token.reentered(), false);
+        assert_eq!(pair.get_reserves(), (50_000_000, 50_000_000));
+    }
+}
+
+#[test]
+fn reentrancy_during_skim() {
+    for attack in ATTACKS {
+        let test = SoroswapPairTest::setup();
+        test.env.budget().reset_unlimited();
+        let (pair, malicious_token, _) = setup_malicious_pair(&test);
+        malicious_token.set_attack(&pair.address, &attack);
+
+        malicious_token.transfer(&test.user, &pair.address, &1_000);
+        pair.skim(&test.admin);
+
+        assert_eq!(malicious_token.attempts(), 1);
+        assert_eq!(malicious_token.reentered(), false);
+        assert_eq!(malicious_token.balance(&test.admin), 1_000);
+        assert_eq!(pair.get_reserves(), (100_000_000, 100_000_000));
+    }
+}
+
+#[test]
+fn locked_pair_rejects_calls() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    // Simulate a call being executed
+    test.env.as_contract(&test.contract.address, || put_locked(&test.env, true));
+
+    assert_eq!(test.contract.try_deposit(&test.user), Err(Ok(SoroswapPairError::Locked)));
+    assert_eq!(test.contract.try_swap(&0, &1, &test.user, &Bytes::new(&test.env)), Err(Ok(SoroswapPairError::Locked)));
+    assert_eq!(test.contract.try_withdraw(&test.user), Err(Ok(SoroswapPairError::Locked)));
+    assert_eq!(test.contract.try_skim(&test.user), Err(Ok(SoroswapPairError::Locked)));
+    assert_eq!(test.contract.try_sync(), Err(Ok(SoroswapPairError::Locked)));
+
+    test.env.as_contract(&test.contract.address, || put_locked(&test.env, false));
+    test.contract.sync();
+    add_liquidity(&test, &1_000_000, &4_000_000);
+}
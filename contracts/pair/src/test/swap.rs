@@ -1,6 +1,6 @@
 use crate::test::deposit::add_liquidity;
 use crate::test::{SoroswapPairTest};
-use soroban_sdk::{testutils::{Ledger}};
+use soroban_sdk::{testutils::{Ledger}, Bytes};
 use crate::error::SoroswapPairError;
 
     
@@ -9,7 +9,7 @@ use crate::error::SoroswapPairError;
 fn try_swap_not_yet_initialized() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    let result = test.contract.try_swap(&0, &0, &test.user);
+    let result = test.contract.try_swap(&0, &0, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::NotInitialized)));
 }
 
@@ -18,8 +18,8 @@ fn try_swap_not_yet_initialized() {
 fn try_swap_amounts_zero() {
     let test = SoroswapPairTest::setup();    
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
-    let result = test.contract.try_swap(&0, &0, &test.user);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let result = test.contract.try_swap(&0, &0, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientOutputAmount)));
 }
 
@@ -28,8 +28,8 @@ fn try_swap_amounts_zero() {
 fn try_swap_amount_0_negative() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
-    let result = test.contract.try_swap(&-1, &1, &test.user);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let result = test.contract.try_swap(&-1, &1, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapNegativesOutNotSupported)));
 }
 
@@ -38,8 +38,8 @@ fn try_swap_amount_0_negative() {
 fn try_swap_amount_1_negative() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
-    let result = test.contract.try_swap(&1, &-1, &test.user);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let result = test.contract.try_swap(&1, &-1, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapNegativesOutNotSupported)));
 }
 
@@ -48,8 +48,8 @@ fn try_swap_amount_1_negative() {
 fn try_swap_no_liquidity() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
-    let result = test.contract.try_swap(&1, &1, &test.user);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let result = test.contract.try_swap(&1, &1, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientLiquidity)));
 }
 
@@ -58,11 +58,11 @@ fn try_swap_no_liquidity() {
 fn try_swap_to_token_0() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
-    let result = test.contract.try_swap(&1000, &0, &test.token_0.address);
+    let result = test.contract.try_swap(&1000, &0, &test.token_0.address, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapInvalidTo)));
 }
 
@@ -71,11 +71,11 @@ fn try_swap_to_token_0() {
 fn try_swap_to_token_1() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
-    let result = test.contract.try_swap(&1000, &0, &test.token_1.address);
+    let result = test.contract.try_swap(&1000, &0, &test.token_1.address, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapInvalidTo)));
 }
 
@@ -85,11 +85,11 @@ fn try_swap_to_token_1() {
 fn try_swap_token_0_insufficient_input() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
-    let result = test.contract.try_swap(&1000, &0, &test.user);
+    let result = test.contract.try_swap(&1000, &0, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientInputAmount)));
 }
 
@@ -98,11 +98,11 @@ fn try_swap_token_0_insufficient_input() {
 fn try_swap_token_1_insufficient_input() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
-    let result = test.contract.try_swap(&0, &1000, &test.user);
+    let result = test.contract.try_swap(&0, &1000, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapInsufficientInputAmount)));
 }
 
@@ -111,12 +111,12 @@ fn try_swap_token_1_insufficient_input() {
 fn try_swap_token_0_low_sent() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
     test.token_0.transfer(&test.user, &test.contract.address, &1);
-    let result = test.contract.try_swap(&0, &1000, &test.user);
+    let result = test.contract.try_swap(&0, &1000, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
 }
 
@@ -125,12 +125,12 @@ fn try_swap_token_0_low_sent() {
 fn try_swap_token_1_low_sent() {
     let test = SoroswapPairTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
     add_liquidity(&test, &amount_0, &amount_1);
     test.token_1.transfer(&test.user, &test.contract.address, &1);
-    let result = test.contract.try_swap(&1000, &0, &test.user);
+    let result = test.contract.try_swap(&1000, &0, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
 }
 
@@ -147,7 +147,7 @@ fn swap_token_0() {
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     add_liquidity(&test, &amount_0, &amount_1);
 
     let init_time = 12345;
@@ -161,7 +161,7 @@ fn swap_token_0() {
     // The user sends the token first:
     test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
 
-    test.contract.swap(&0, &expected_output_amount_1, &test.user);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user, &Bytes::new(&test.env));
     
     assert_eq!(test.contract.get_reserves(),
         (amount_0.checked_add(swap_amount_0).unwrap(),
@@ -185,7 +185,7 @@ fn swap_token_1() {
 
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     add_liquidity(&test, &amount_0, &amount_1);
     let init_time = 12345;
     test.env.ledger().with_mut(|li| {
@@ -198,7 +198,7 @@ fn swap_token_1() {
     // The user sends the token first:
     test.token_1.transfer(&test.user, &test.contract.address, &swap_amount_1);
 
-    test.contract.swap(&expected_output_amount_0, &0, &test.user);
+    test.contract.swap(&expected_output_amount_0, &0, &test.user, &Bytes::new(&test.env));
     
     assert_eq!(test.contract.get_reserves(),
         (amount_0.checked_sub(expected_output_amount_0).unwrap(),
@@ -219,7 +219,7 @@ fn try_swap_token_1_optimal_plus_1() {
     
     let amount_0: i128 = 50_000_000;
     let amount_1: i128 = 100_000_000;
-    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address);
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
     add_liquidity(&test, &amount_0, &amount_1);
     let init_time = 12345;
     test.env.ledger().with_mut(|li| {
@@ -232,6 +232,32 @@ fn try_swap_token_1_optimal_plus_1() {
     // The user sends the token first:
     test.token_1.transfer(&test.user, &test.contract.address, &swap_amount_1);
 
-    let result = test.contract.try_swap(&expected_output_amount_0, &0, &test.user);
+    let result = test.contract.try_swap(&expected_output_amount_0, &0, &test.user, &Bytes::new(&test.env));
     assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
 }
+
+#[test]
+fn swap_token_0_fee_tiers() {
+    // (fee in basis points, optimal output amount)
+    for (fee, expected_output_amount_1) in [(5_u32, 16659721_i128), (100_u32, 16527545_i128)] {
+        let test = SoroswapPairTest::setup();
+        test.env.budget().reset_unlimited();
+
+        let amount_0: i128 = 50_000_000;
+        let amount_1: i128 = 100_000_000;
+        test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &fee);
+        assert_eq!(test.contract.fee(), fee);
+        add_liquidity(&test, &amount_0, &amount_1);
+
+        let swap_amount_0: i128 = 10_000_000;
+        test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+
+        let result = test.contract.try_swap(&0, &(expected_output_amount_1 + 1), &test.user, &Bytes::new(&test.env));
+        assert_eq!(result, Err(Ok(SoroswapPairError::SwapKConstantNotMet)));
+
+        test.contract.swap(&0, &expected_output_amount_1, &test.user, &Bytes::new(&test.env));
+        assert_eq!(test.contract.get_reserves(),
+            (amount_0.checked_add(swap_amount_0).unwrap(),
+            amount_1.checked_sub(expected_output_amount_1).unwrap()));
+    }
+}
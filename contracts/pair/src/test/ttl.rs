@@ -0,0 +1,144 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use crate::error::SoroswapPairError;
+use crate::storage::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT};
+use soroban_sdk::{testutils::{Ledger}, vec};
+
+fn advance_ledgers(test: &SoroswapPairTest, ledgers: u32) {
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+        li.timestamp += 5 * u64::from(ledgers);
+    });
+}
+
+#[test]
+fn extend_pair_ttl_not_initialized() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    let res = test.contract.try_extend_pair_ttl(&0, &10);
+    assert_eq!(res, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+#[should_panic]
+fn pair_archived_without_extending_ttl() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    // Nobody touches the pair after its instance TTL
+    advance_ledgers(&test, INSTANCE_BUMP_AMOUNT + 1);
+    test.contract.extend_pair_ttl(&0, &10);
+}
+
+#[test]
+fn extend_pair_ttl_keeps_pair_alive() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test.contract.increase_observation_cardinality(&3);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+    advance_ledgers(&test, 1);
+    test.contract.sync();
+    // The third slot of the buffer is not populated yet
+    assert_eq!(test.contract.observation_cardinality(), (3, 3));
+
+    // Way past the instance and observations TTL, as long as someone extends it
+    for _ in 0..10 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.extend_pair_ttl(&0, &10);
+    }
+
+    assert_eq!(test.contract.get_reserves(), (1_000_000, 4_000_000));
+    assert_eq!(test.contract.observe(&vec![&test.env, 0]).len(), 1);
+}
+
+#[test]
+fn extend_pair_ttl_in_pages() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test.contract.increase_observation_cardinality(&3);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+    for _ in 0..3 {
+        advance_ledgers(&test, 1);
+        test.contract.sync();
+    }
+
+    // Each observation is extended by one of the pages
+    for _ in 0..10 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.extend_pair_ttl(&0, &2);
+        test.contract.extend_pair_ttl(&2, &2);
+    }
+
+    assert_eq!(test.contract.observe(&vec![&test.env, 10]).len(), 1);
+}
+
+#[test]
+fn update_keeps_next_observation_alive() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test.contract.increase_observation_cardinality(&3);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    // Each slot is overwritten more than PERSISTENT_BUMP_AMOUNT ledgers after it was written, and only the
+    // pair instance is extended, yet updates keep working as each one extends the slot it will overwrite next
+    let ledgers_between_updates = PERSISTENT_BUMP_AMOUNT / 2;
+    for _ in 0..8 {
+        for _ in 0..ledgers_between_updates / INSTANCE_LIFETIME_THRESHOLD {
+            advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+            test.contract.extend_pair_ttl(&0, &0);
+            // The tokens are kept alive too, as `sync` reads the pair balances
+            test.token_0.balance(&test.contract.address);
+            test.token_1.balance(&test.contract.address);
+        }
+        test.contract.sync();
+    }
+
+    assert_eq!(test.contract.observation_cardinality(), (3, 3));
+    assert_eq!(test.contract.get_reserves(), (1_000_000, 4_000_000));
+}
+
+#[test]
+fn state_changing_calls_extend_pair_ttl() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    for _ in 0..5 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.sync();
+    }
+
+    test.contract.extend_pair_ttl(&0, &10);
+    assert_eq!(test.contract.get_reserves(), (1_000_000, 4_000_000));
+}
+
+#[test]
+fn consult_keeps_read_observations_alive() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test.contract.increase_observation_cardinality(&3);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+    for _ in 0..3 {
+        advance_ledgers(&test, 1);
+        test.contract.sync();
+    }
+
+    // The oldest of the 3 observations was written by the first of the last 3 syncs
+    let oldest_timestamp = test.env.ledger().timestamp() - 10;
+
+    // Way past the observations TTL with no update nor `extend_pair_ttl`, the slots read by `consult` are extended.
+    // Over the whole buffer, with 3 slots, it reads all of them
+    for _ in 0..10 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.consult(&(test.env.ledger().timestamp() - oldest_timestamp));
+    }
+    // Extending an archived slot would fail
+    test.contract.extend_pair_ttl(&0, &3);
+}
+
@@ -0,0 +1,165 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest};
+use crate::uq128x128::{fraction, UQ128x128};
+use soroban_sdk::{testutils::{Ledger}, Bytes};
+
+#[test]
+fn twap_initial_values() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    assert_eq!(test.contract.price_0_cumulative_last(), UQ128x128::default());
+    assert_eq!(test.contract.price_1_cumulative_last(), UQ128x128::default());
+    assert_eq!(test.contract.block_timestamp_last(), 0);
+}
+
+#[test]
+fn twap_first_deposit_does_not_accumulate() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let init_time = 12345;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time;
+    });
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    // There were no reserves before the first deposit, so no price has been accumulated yet
+    assert_eq!(test.contract.price_0_cumulative_last(), UQ128x128::default());
+    assert_eq!(test.contract.price_1_cumulative_last(), UQ128x128::default());
+    assert_eq!(test.contract.block_timestamp_last(), init_time);
+}
+
+#[test]
+fn twap_accumulates_on_sync() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let init_time = 12345;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time;
+    });
+    let amount_0: i128 = 1_000_000;
+    let amount_1: i128 = 4_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let passed_time = 100;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time + passed_time;
+    });
+    test.contract.sync();
+
+    let expected_price_0 = fraction(4_000_000, 1_000_000).wrapping_mul(100);
+    let expected_price_1 = fraction(1_000_000, 4_000_000).wrapping_mul(100);
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1);
+    assert_eq!(test.contract.block_timestamp_last(), init_time + passed_time);
+
+    // The price of token 0 is 4 token 1 during 100 seconds
+    assert_eq!(expected_price_0.hi, 400);
+    // The price of token 1 is 0.25 token 0 during 100 seconds
+    assert_eq!(expected_price_1.hi, 25);
+}
+
+#[test]
+fn twap_does_not_accumulate_in_same_timestamp() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let init_time = 12345;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time;
+    });
+    add_liquidity(&test, &1_000_000, &4_000_000);
+    test.contract.sync();
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    assert_eq!(test.contract.price_0_cumulative_last(), UQ128x128::default());
+    assert_eq!(test.contract.price_1_cumulative_last(), UQ128x128::default());
+    assert_eq!(test.contract.block_timestamp_last(), init_time);
+}
+
+#[test]
+fn twap_accumulates_previous_reserves_on_swap() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    let init_time = 12345;
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time;
+    });
+    let amount_0: i128 = 50_000_000;
+    let amount_1: i128 = 100_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    // First swap after 1000 seconds: accumulates the price given by the deposited reserves
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time + 1000;
+    });
+    let swap_amount_0 = 10_000_000;
+    let expected_output_amount_1 = 16624979;
+    test.token_0.transfer(&test.user, &test.contract.address, &swap_amount_0);
+    test.contract.swap(&0, &expected_output_amount_1, &test.user, &Bytes::new(&test.env));
+
+    let mut expected_price_0 = fraction(100_000_000, 50_000_000).wrapping_mul(1000);
+    let mut expected_price_1 = fraction(50_000_000, 100_000_000).wrapping_mul(1000);
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1);
+
+    // Second update after 500 more seconds: accumulates the price given by the post-swap reserves
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = init_time + 1500;
+    });
+    test.contract.sync();
+
+    let (reserve_0, reserve_1) = (60_000_000, 100_000_000 - 16624979);
+    assert_eq!(test.contract.get_reserves(), (reserve_0 as i128, reserve_1 as i128));
+    expected_price_0 = expected_price_0.wrapping_add(fraction(reserve_1, reserve_0).wrapping_mul(500));
+    expected_price_1 = expected_price_1.wrapping_add(fraction(reserve_0, reserve_1).wrapping_mul(500));
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1);
+    assert_eq!(test.contract.block_timestamp_last(), init_time + 1500);
+}
+
+#[test]
+fn twap_accumulates_reserves_above_u64() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+
+    // Prices are encoded as UQ128x128, so reserves are not limited to u64::MAX
+    let amount_0: i128 = 100_000_000_000_000_000_000;
+    let amount_1: i128 = 1_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+    assert_eq!(test.contract.get_reserves(), (amount_0, amount_1));
+
+    test.env.ledger().with_mut(|li| {
+        li.timestamp += 100;
+    });
+    test.contract.sync();
+
+    let expected_price_0 = fraction(1_000_000, 100_000_000_000_000_000_000).wrapping_mul(100);
+    let expected_price_1 = fraction(100_000_000_000_000_000_000, 1_000_000).wrapping_mul(100);
+    assert_eq!(test.contract.price_0_cumulative_last(), expected_price_0);
+    assert_eq!(test.contract.price_1_cumulative_last(), expected_price_1);
+    assert_eq!(expected_price_1.hi, 10_000_000_000_000_000);
+}
+
+#[test]
+fn twap_cumulative_price_wraps() {
+    let max = UQ128x128 { hi: u128::MAX, lo: u128::MAX };
+    let one_bit = UQ128x128 { hi: 0, lo: 1 };
+    assert_eq!(max.wrapping_add(one_bit), UQ128x128::default());
+    assert_eq!(UQ128x128::default().wrapping_sub(one_bit), max);
+
+    // The difference of two accumulators is right across a wrap
+    let price = fraction(3, 7);
+    let before = max.wrapping_sub(price.wrapping_mul(10));
+    let after = before.wrapping_add(price.wrapping_mul(1000));
+    assert_eq!(after.wrapping_sub(before).div(1000), price);
+}
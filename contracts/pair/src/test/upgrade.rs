@@ -0,0 +1,124 @@
+use crate::test::deposit::add_liquidity;
+use crate::test::{SoroswapPairTest, pair_token_wasm};
+use crate::error::SoroswapPairError;
+use crate::event::MigrateEvent;
+use crate::storage::{DataKey, put_version};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    IntoVal,
+    symbol_short,
+};
+
+#[test]
+fn upgrade_not_yet_initialized() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    let new_wasm_hash = pair_token_wasm(&test.env);
+    let res = test.contract.try_upgrade(&new_wasm_hash);
+    assert_eq!(res, Err(Ok(SoroswapPairError::NotInitialized)));
+    let res = test.contract.try_migrate();
+    assert_eq!(res, Err(Ok(SoroswapPairError::NotInitialized)));
+}
+
+#[test]
+fn initialize_sets_current_version() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    assert_eq!(test.contract.version(), 1);
+
+    // Nothing to migrate
+    assert_eq!(test.contract.migrate(), 1);
+}
+
+#[test]
+fn migrate_unversioned_pair() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    add_liquidity(&test, &1_000_000, &4_000_000);
+
+    // Storage as written by pairs deployed before versioning and fee tiers
+    test.env.as_contract(&test.contract.address, || {
+        test.env.storage().instance().remove(&DataKey::Version);
+        test.env.storage().instance().remove(&DataKey::Fee);
+    });
+    assert_eq!(test.contract.version(), 0);
+
+    assert_eq!(test.contract.migrate(), 1);
+    let migrate_event = test.env.events().all().last().unwrap();
+    let expected_migrate_event: MigrateEvent = MigrateEvent {
+        old_version: 0,
+        new_version: 1,
+    };
+    assert_eq!(
+        vec![&test.env, migrate_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapPair", symbol_short!("migrate")).into_val(&test.env),
+                (expected_migrate_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    assert_eq!(test.contract.version(), 1);
+    assert_eq!(test.contract.fee(), 30);
+    assert_eq!(test.contract.get_reserves(), (1_000_000, 4_000_000));
+    assert_eq!(test.contract.observation_cardinality(), (1, 1));
+}
+
+#[test]
+fn migrate_newer_version() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    test.env.as_contract(&test.contract.address, || put_version(&test.env, 2));
+
+    let res = test.contract.try_migrate();
+    assert_eq!(res, Err(Ok(SoroswapPairError::MigrateVersionInvalid)));
+}
+
+#[test]
+fn upgrade_keeps_reserves_and_balances() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let liquidity = add_liquidity(&test, &1_000_000, &4_000_000);
+    let total_shares = test.contract.total_shares();
+
+    let new_wasm_hash = pair_token_wasm(&test.env);
+    test.contract.upgrade(&new_wasm_hash);
+    assert_eq!(test.contract.migrate(), 1);
+
+    assert_eq!(test.contract.get_reserves(), (1_000_000, 4_000_000));
+    assert_eq!(test.contract.my_balance(&test.user), liquidity);
+    assert_eq!(test.contract.total_shares(), total_shares);
+
+    // The upgraded pair keeps working
+    add_liquidity(&test, &1_000_000, &4_000_000);
+    assert_eq!(test.contract.get_reserves(), (2_000_000, 8_000_000));
+}
+
+#[test]
+#[should_panic]
+fn upgrade_not_factory() {
+    let test = SoroswapPairTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30);
+    let new_wasm_hash = pair_token_wasm(&test.env);
+
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &test.user,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+}
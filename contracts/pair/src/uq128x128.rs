@@ -0,0 +1,71 @@
+use soroban_sdk::contracttype;
+
+const MASK_64: u128 = u64::MAX as u128;
+
+/// Unsigned fixed point number with 128 integer bits (`hi`) and 128 fractional bits (`lo`), so its value is
+/// `hi + lo / 2^128`. A former UQ64x64 value `v` is `{ hi: v >> 64, lo: (v & (2^64 - 1)) << 64 }`.
+/// Arithmetic wraps on overflow, as cumulative prices are only meaningful as differences.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct UQ128x128 {
+    pub hi: u128,
+    pub lo: u128,
+}
+
+impl UQ128x128 {
+    pub fn wrapping_add(self, other: UQ128x128) -> UQ128x128 {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        let hi = self.hi.wrapping_add(other.hi).wrapping_add(carry.into());
+        UQ128x128 { hi, lo }
+    }
+
+    pub fn wrapping_sub(self, other: UQ128x128) -> UQ128x128 {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        let hi = self.hi.wrapping_sub(other.hi).wrapping_sub(borrow.into());
+        UQ128x128 { hi, lo }
+    }
+
+    // multiply by a u64, splitting the fractional part in two 64 bits halves so the partial products fit in a u128
+    pub fn wrapping_mul(self, y: u64) -> UQ128x128 {
+        let y: u128 = y.into();
+        let lo_low = (self.lo & MASK_64) * y;
+        let lo_high = (self.lo >> 64) * y;
+        let (lo, carry) = lo_low.overflowing_add(lo_high << 64);
+        let hi = self.hi.wrapping_mul(y).wrapping_add(lo_high >> 64).wrapping_add(carry.into());
+        UQ128x128 { hi, lo }
+    }
+
+    // divide by a u64, one 64 bits limb at a time so each remainder fits in the next dividend
+    pub fn div(self, y: u64) -> UQ128x128 {
+        if y == 0 {
+            panic!("DIV_BY_ZERO")
+        }
+        let y: u128 = y.into();
+        let hi = self.hi / y;
+        let dividend = ((self.hi % y) << 64) | (self.lo >> 64);
+        let lo_high = dividend / y;
+        let dividend = ((dividend % y) << 64) | (self.lo & MASK_64);
+        let lo_low = dividend / y;
+        UQ128x128 { hi, lo: (lo_high << 64) | lo_low }
+    }
+}
+
+// returns a UQ128x128 which represents the ratio of the x to y, both lower than 2^127 as they come from i128 reserves
+pub(crate) fn fraction(x: u128, y: u128) -> UQ128x128 {
+    if y == 0 {
+        panic!("DIV_BY_ZERO")
+    }
+    let hi = x / y;
+    // long division of the remainder, one fractional bit at a time. As remainder < y < 2^127, doubling it fits
+    let mut remainder = x % y;
+    let mut lo: u128 = 0;
+    for _ in 0..128 {
+        remainder <<= 1;
+        lo <<= 1;
+        if remainder >= y {
+            remainder -= y;
+            lo |= 1;
+        }
+    }
+    UQ128x128 { hi, lo }
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env};
+use soroban_sdk::token::{Interface, TokenClient};
+use soroswap_pair_token::SoroswapPairToken;
+use crate::storage::*;
+
+pub fn get_balance(e: &Env, contract_id: Address) -> i128 {
+    TokenClient::new(e, &contract_id).balance(&e.current_contract_address())
+}
+
+pub fn get_balance_0(e: &Env) -> i128 {
+    get_balance(e, get_token_0(e))
+}
+
+pub fn get_balance_1(e: &Env) -> i128 {
+    get_balance(e, get_token_1(e))
+}
+
+// LP shares sent to the pair by the user in order to withdraw
+pub fn get_balance_shares(e: &Env) -> i128 {
+    SoroswapPairToken::balance(e.clone(), e.current_contract_address())
+}
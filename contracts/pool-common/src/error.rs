@@ -0,0 +1,8 @@
+/// Errors of the shared pool helpers. Each pair converts them into its own `contracterror`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PoolError {
+    /// A nested call into deposit, swap, withdraw, skim or sync
+    Locked,
+    /// The storage was written by a newer version of the pair
+    MigrateVersionInvalid,
+}
@@ -0,0 +1,148 @@
+//! Definition of the Events of the stable and weighted pairs, published by `PoolEvents`. Their first topic is
+//! the name of the pair contract.
+use soroban_sdk::{contracttype, symbol_short, Env, Address};
+
+// DEPOSIT EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositEvent {
+    pub to: Address,
+    pub amount_0: i128,
+    pub amount_1: i128,
+    pub liquidity: i128,
+    pub new_reserve_0: i128,
+    pub new_reserve_1: i128,
+}
+
+// SWAP EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapEvent {
+    pub to: Address,
+    pub amount_0_in: i128,
+    pub amount_1_in: i128,
+    pub amount_0_out: i128,
+    pub amount_1_out: i128,
+}
+
+// WITHDRAW EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawEvent {
+    pub to: Address,
+    pub liquidity: i128,
+    pub amount_0: i128,
+    pub amount_1: i128,
+    pub new_reserve_0: i128,
+    pub new_reserve_1: i128,
+}
+
+// SYNC EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SyncEvent {
+    pub new_reserve_0: i128,
+    pub new_reserve_1: i128,
+}
+
+// SKIM EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SkimEvent {
+    pub skimmed_0: i128,
+    pub skimmed_1: i128,
+}
+
+// MIGRATE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrateEvent {
+    pub old_version: u32,
+    pub new_version: u32,
+}
+
+// EVENTS PUBLISHER
+pub struct PoolEvents<'a> {
+    e: &'a Env,
+    pool: &'static str,
+}
+
+impl<'a> PoolEvents<'a> {
+    /// Publishes the events of the pair contract named `pool`.
+    pub fn new(e: &'a Env, pool: &'static str) -> Self {
+        PoolEvents { e, pool }
+    }
+
+    pub fn deposit(
+        &self,
+        to: Address,
+        amount_0: i128,
+        amount_1: i128,
+        liquidity: i128,
+        new_reserve_0: i128,
+        new_reserve_1: i128) {
+
+        let event = DepositEvent {
+            to,
+            amount_0,
+            amount_1,
+            liquidity,
+            new_reserve_0,
+            new_reserve_1,
+        };
+        self.e.events().publish((self.pool, symbol_short!("deposit")), event);
+    }
+
+    pub fn swap(
+        &self,
+        to: Address,
+        amount_0_in: i128,
+        amount_1_in: i128,
+        amount_0_out: i128,
+        amount_1_out: i128,
+    ) {
+        let event = SwapEvent {
+            to,
+            amount_0_in,
+            amount_1_in,
+            amount_0_out,
+            amount_1_out,
+        };
+        self.e.events().publish((self.pool, symbol_short!("swap")), event);
+    }
+
+    pub fn withdraw(
+        &self,
+        to: Address,
+        liquidity: i128,
+        amount_0: i128,
+        amount_1: i128,
+        new_reserve_0: i128,
+        new_reserve_1: i128,
+    ) {
+        let event = WithdrawEvent {
+            to,
+            liquidity,
+            amount_0,
+            amount_1,
+            new_reserve_0,
+            new_reserve_1,
+        };
+        self.e.events().publish((self.pool, symbol_short!("withdraw")), event);
+    }
+
+    pub fn sync(&self, new_reserve_0: i128, new_reserve_1: i128) {
+        let event = SyncEvent { new_reserve_0, new_reserve_1 };
+        self.e.events().publish((self.pool, symbol_short!("sync")), event);
+    }
+
+    pub fn skim(&self, skimmed_0: i128, skimmed_1: i128) {
+        let event = SkimEvent { skimmed_0, skimmed_1 };
+        self.e.events().publish((self.pool, symbol_short!("skim")), event);
+    }
+
+    pub fn migrate(&self, old_version: u32, new_version: u32) {
+        let event = MigrateEvent { old_version, new_version };
+        self.e.events().publish((self.pool, symbol_short!("migrate")), event);
+    }
+}
@@ -0,0 +1,12 @@
+#![no_std]
+
+pub mod balances;
+pub mod error;
+pub mod event;
+pub mod migration;
+pub mod pool;
+pub mod storage;
+#[cfg(feature = "testutils")]
+pub mod testutils;
+
+pub use error::PoolError;
@@ -0,0 +1,24 @@
+//! Versioned storage migrations. After its code is upgraded by the factory, a pair runs every
+//! migration step between the version stored in the instance storage and its `CONTRACT_VERSION`.
+use soroban_sdk::Env;
+use crate::storage::*;
+use crate::error::PoolError;
+
+/// Runs the pending migration steps. Pairs deployed before the storage was versioned already have
+/// the layout of version 1, so there is no step yet: a new storage layout must bump the `CONTRACT_VERSION`
+/// of the pair and migrate the older versions here.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `contract_version` - The storage version of the current code of the pair.
+///
+/// # Returns
+/// A tuple with the old and the new storage version.
+pub fn migrate(e: &Env, contract_version: u32) -> Result<(u32, u32), PoolError> {
+    let old_version = get_version(e);
+    if old_version > contract_version {
+        return Err(PoolError::MigrateVersionInvalid);
+    }
+    put_version(e, contract_version);
+    Ok((old_version, contract_version))
+}
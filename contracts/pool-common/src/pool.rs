@@ -0,0 +1,64 @@
+//! Helpers shared by the functions of the stable and weighted pairs.
+use soroban_sdk::{Address, Env, U256};
+use soroban_sdk::token::TokenClient;
+use soroswap_pair_token::{internal_mint, internal_burn};
+use crate::storage::*;
+use crate::error::PoolError;
+use crate::event::PoolEvents;
+
+pub fn burn_shares(e: &Env, amount: i128) {
+    let total = get_total_shares(e);
+    internal_burn(e.clone(), e.current_contract_address(), amount);
+    put_total_shares(e, total.checked_sub(amount).unwrap());
+}
+
+pub fn mint_shares(e: &Env, to: &Address, amount: i128) {
+    let total = get_total_shares(e);
+    internal_mint(e.clone(), to.clone(), amount);
+    put_total_shares(e, total.checked_add(amount).unwrap());
+}
+
+fn transfer(e: &Env, contract_id: Address, to: &Address, amount: i128) {
+    TokenClient::new(e, &contract_id).transfer(&e.current_contract_address(), to, &amount);
+}
+
+pub fn transfer_token_0_from_pair(e: &Env, to: &Address, amount: i128) {
+    transfer(e, get_token_0(e), to, amount);
+}
+
+pub fn transfer_token_1_from_pair(e: &Env, to: &Address, amount: i128) {
+    transfer(e, get_token_1(e), to, amount);
+}
+
+// Nested calls into deposit, swap, withdraw, skim and sync are rejected while one of them is being executed
+pub fn lock(e: &Env) -> Result<(), PoolError> {
+    if get_locked(e) {
+        return Err(PoolError::Locked);
+    }
+    put_locked(e, true);
+    Ok(())
+}
+
+pub fn unlock(e: &Env) {
+    put_locked(e, false);
+}
+
+// Sets the reserves to the given balances, `pool` is the name of the pair contract in the sync event
+pub fn update(e: &Env, pool: &'static str, balance_0: i128, balance_1: i128) {
+    put_reserve_0(e, balance_0);
+    put_reserve_1(e, balance_1);
+    PoolEvents::new(e, pool).sync(balance_0, balance_1);
+}
+
+// a * b / c, rounded down. The product of two i128 fits in a U256, so only the result has to fit in i128
+pub fn mul_div(e: &Env, a: i128, b: i128, c: i128) -> i128 {
+    let result = U256::from_u128(e, a as u128)
+        .mul(&U256::from_u128(e, b as u128))
+        .div(&U256::from_u128(e, c as u128));
+    i128::try_from(result.to_u128().unwrap()).unwrap()
+}
+
+// a / b, rounded up
+pub fn ceil_div(a: i128, b: i128) -> i128 {
+    a.checked_add(b - 1).unwrap().checked_div(b).unwrap()
+}
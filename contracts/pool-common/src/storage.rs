@@ -0,0 +1,134 @@
+use soroban_sdk::{Env, Address, ConversionError, TryFromVal, Val};
+
+pub const DAY_IN_LEDGERS: u32 = 17280;
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[derive(Clone, Copy)]
+#[repr(u32)]
+pub enum DataKey {
+    Token0 = 0,
+    Token1 = 1,
+    Reserve0 = 2,
+    Reserve1 = 3,
+    Factory = 4,
+    TotalShares = 5,
+    Fee = 6, // swap fee in basis points
+    Parameter = 7, // amplification coefficient of a stable pair, weight of token 0 of a weighted pair
+    InvariantLast = 8, // invariant after the last liquidity event, used for the protocol fee
+    Locked = 9, // reentrancy lock
+    Version = 10, // version of the storage layout, see migration.rs
+}
+
+impl TryFromVal<Env, DataKey> for Val {
+    type Error = ConversionError;
+
+    fn try_from_val(_env: &Env, v: &DataKey) -> Result<Self, Self::Error> {
+        Ok((*v as u32).into())
+    }
+}
+
+pub fn get_factory(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Factory).unwrap()
+}
+
+// Helper function in order to know if the contract has been initialized or not
+pub fn has_token_0(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Token0)
+}
+
+pub fn get_token_0(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token0).unwrap()
+}
+
+pub fn get_token_1(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token1).unwrap()
+}
+
+pub fn get_total_shares(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::TotalShares).unwrap()
+}
+
+pub fn get_reserve_0(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::Reserve0).unwrap()
+}
+
+pub fn get_reserve_1(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::Reserve1).unwrap()
+}
+
+pub fn get_fee(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Fee).unwrap()
+}
+
+pub fn get_parameter(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Parameter).unwrap()
+}
+
+pub fn get_invariant_last(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::InvariantLast).unwrap_or(0)
+}
+
+pub fn get_locked(e: &Env) -> bool {
+    e.storage().instance().get(&DataKey::Locked).unwrap_or(false)
+}
+
+// Pairs deployed before the storage was versioned do not have a version
+pub fn get_version(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Version).unwrap_or(0)
+}
+
+pub fn put_factory(e: &Env, factory: Address) {
+    e.storage().instance().set(&DataKey::Factory, &factory);
+}
+
+pub fn put_token_0(e: &Env, contract_id: Address) {
+    e.storage().instance().set(&DataKey::Token0, &contract_id);
+}
+
+pub fn put_token_1(e: &Env, contract_id: Address) {
+    e.storage().instance().set(&DataKey::Token1, &contract_id);
+}
+
+pub fn put_total_shares(e: &Env, amount: i128) {
+    e.storage().instance().set(&DataKey::TotalShares, &amount)
+}
+
+pub fn put_reserve_0(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("put_reserve_0: amount cannot be negative")
+    }
+    e.storage().instance().set(&DataKey::Reserve0, &amount)
+}
+
+pub fn put_reserve_1(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("put_reserve_1: amount cannot be negative")
+    }
+    e.storage().instance().set(&DataKey::Reserve1, &amount)
+}
+
+pub fn put_fee(e: &Env, fee: u32) {
+    e.storage().instance().set(&DataKey::Fee, &fee);
+}
+
+pub fn put_parameter(e: &Env, parameter: u32) {
+    e.storage().instance().set(&DataKey::Parameter, &parameter);
+}
+
+pub fn put_invariant_last(e: &Env, invariant_last: i128) {
+    e.storage().instance().set(&DataKey::InvariantLast, &invariant_last);
+}
+
+pub fn put_locked(e: &Env, locked: bool) {
+    e.storage().instance().set(&DataKey::Locked, &locked);
+}
+
+pub fn put_version(e: &Env, version: u32) {
+    e.storage().instance().set(&DataKey::Version, &version);
+}
+
+// Extends the TTL of the instance storage and of the contract code
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
@@ -0,0 +1,84 @@
+//! Test fixture of the stable and weighted pairs: two sorted tokens minted to a user, and a factory.
+//! The test of each pair wraps `PoolTest` together with the client of its own contract.
+use soroban_sdk::{
+    testutils::{Address as _},
+    Address,
+    BytesN,
+    Env,
+    String,
+};
+
+// TOKEN CONTRACT
+pub mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+pub fn create_token_contract<'a>(e: &Env) -> TokenClient<'a> {
+    let token_address = &e.register_contract_wasm(None, token::WASM);
+    TokenClient::new(e, token_address)
+}
+
+// FACTORY CONTRACT
+pub mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+pub fn pair_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+pub fn create_factory_contract<'a>(e: &Env, setter: &Address) -> SoroswapFactoryClient<'a> {
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address);
+    factory.initialize(setter, &pair_wasm(e));
+    factory
+}
+
+// THE TEST
+pub struct PoolTest<'a> {
+    pub env: Env,
+    pub admin: Address,
+    pub user: Address,
+    pub token_0: TokenClient<'a>,
+    pub token_1: TokenClient<'a>,
+    pub factory: SoroswapFactoryClient<'a>,
+}
+
+impl<'a> PoolTest<'a> {
+    pub fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+        let user = Address::generate(&env);
+        let admin = Address::generate(&env);
+        let mut token_0 = create_token_contract(&env);
+        let mut token_1 = create_token_contract(&env);
+        if token_1.address < token_0.address {
+            core::mem::swap(&mut token_0, &mut token_1);
+        }
+
+        token_0.initialize(&admin, &7, &String::from_str(&env, "Token 0"), &String::from_str(&env, "TOKEN0"));
+        token_1.initialize(&admin, &7, &String::from_str(&env, "Token 1"), &String::from_str(&env, "TOKEN1"));
+
+        token_0.mint(&user, &123_000_000_000_000_000_000);
+        token_1.mint(&user, &321_000_000_000_000_000_000);
+
+        let factory = create_factory_contract(&env, &admin);
+
+        PoolTest {
+            env,
+            admin,
+            user,
+            token_0,
+            token_1,
+            factory,
+        }
+    }
+}
@@ -33,6 +33,12 @@ pub enum SoroswapRouterError {
     /// SoroswapRouter: pair does not exist
     PairDoesNotExist = 409,
 
+    /// SoroswapRouter: invalid path
+    InvalidPath = 410,
+
+    /// SoroswapRouter: insufficient liquidity minted
+    InsufficientLiquidity = 411,
+
 }
 
 
@@ -57,6 +63,18 @@ pub enum CombinedRouterError {
     LibraryInsufficientOutputAmount = 513,
     LibraryInvalidPath = 514,
     LibrarySortIdenticalTokens = 515,
+    LibraryInvalidFee = 516,
+
+    RouterInvalidPath = 517,
+    LibraryNoPathFound = 518,
+    RouterInsufficientLiquidity = 519,
+    LibraryInvalidAmp = 520,
+    LibraryStableInvariantFailed = 521,
+    LibraryInvalidWeight = 522,
+    LibraryWeightedInvariantFailed = 523,
+    LibraryPriceOutOfRange = 524,
+    LibraryTooManyHops = 525,
+    LibraryTooManyIntermediates = 526,
 }
 
 impl From<SoroswapLibraryError> for CombinedRouterError {
@@ -68,6 +86,15 @@ impl From<SoroswapLibraryError> for CombinedRouterError {
             SoroswapLibraryError::InsufficientOutputAmount => CombinedRouterError::LibraryInsufficientOutputAmount,
             SoroswapLibraryError::InvalidPath => CombinedRouterError::LibraryInvalidPath,
             SoroswapLibraryError::SortIdenticalTokens => CombinedRouterError::LibrarySortIdenticalTokens,
+            SoroswapLibraryError::InvalidFee => CombinedRouterError::LibraryInvalidFee,
+            SoroswapLibraryError::NoPathFound => CombinedRouterError::LibraryNoPathFound,
+            SoroswapLibraryError::InvalidAmp => CombinedRouterError::LibraryInvalidAmp,
+            SoroswapLibraryError::StableInvariantFailed => CombinedRouterError::LibraryStableInvariantFailed,
+            SoroswapLibraryError::InvalidWeight => CombinedRouterError::LibraryInvalidWeight,
+            SoroswapLibraryError::WeightedInvariantFailed => CombinedRouterError::LibraryWeightedInvariantFailed,
+            SoroswapLibraryError::PriceOutOfRange => CombinedRouterError::LibraryPriceOutOfRange,
+            SoroswapLibraryError::TooManyHops => CombinedRouterError::LibraryTooManyHops,
+            SoroswapLibraryError::TooManyIntermediates => CombinedRouterError::LibraryTooManyIntermediates,
         }
     }
 }
@@ -84,6 +111,8 @@ impl From<SoroswapRouterError> for CombinedRouterError {
             SoroswapRouterError::InsufficientOutputAmount => CombinedRouterError::RouterInsufficientOutputAmount,
             SoroswapRouterError::ExcessiveInputAmount => CombinedRouterError::RouterExcessiveInputAmount,
             SoroswapRouterError::PairDoesNotExist => CombinedRouterError::RouterPairDoesNotExist,
+            SoroswapRouterError::InvalidPath => CombinedRouterError::RouterInvalidPath,
+            SoroswapRouterError::InsufficientLiquidity => CombinedRouterError::RouterInsufficientLiquidity,
         }
     }
 }
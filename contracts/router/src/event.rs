@@ -1,17 +1,19 @@
 //! Definition of the Events used in the contract
-use soroban_sdk::{contracttype, symbol_short, Env, Address, Vec};
+use soroban_sdk::{contracttype, symbol_short, Env, Address, BytesN, Vec};
 
 // INITIALIZED
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct InitializedEvent {
-    pub factory: Address
+    pub factory: Address,
+    pub native: Address
 }
 
-pub(crate) fn initialized(e: &Env, factory: Address) {
+pub(crate) fn initialized(e: &Env, factory: Address, native: Address) {
     
     let event: InitializedEvent = InitializedEvent {
-        factory: factory
+        factory: factory,
+        native: native
     };
     e.events().publish(("SoroswapRouter", symbol_short!("init")), event);
 }
@@ -148,4 +150,19 @@ pub(crate) fn swap(
     };
 
     e.events().publish(("SoroswapRouter", symbol_short!("swap")), event);
-}
\ No newline at end of file
+}
+
+// UPGRADE EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    pub new_wasm_hash: BytesN<32>
+}
+
+pub(crate) fn upgrade(e: &Env, new_wasm_hash: BytesN<32>) {
+    
+    let event: UpgradeEvent = UpgradeEvent {
+        new_wasm_hash: new_wasm_hash
+    };
+    e.events().publish(("SoroswapRouter", symbol_short!("upgrade")), event);
+}
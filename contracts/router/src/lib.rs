@@ -1,7 +1,7 @@
 #![no_std]
 use soroban_sdk::token::Client as TokenClient;
-use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
-use soroswap_library::{SoroswapLibraryError};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, U256, Vec};
+use soroswap_library::{SoroswapLibraryError, SwapQuote, PoolType};
 
 mod pair;
 mod factory;
@@ -12,7 +12,7 @@ mod error;
 
 use factory::SoroswapFactoryClient;
 use pair::SoroswapPairClient;
-use storage::{put_factory, has_factory, get_factory};
+use storage::{put_factory, has_factory, get_factory, put_native, get_native, extend_instance_ttl};
 pub use error::{SoroswapRouterError, CombinedRouterError};
 
 pub fn check_nonnegative_amount(amount: i128) -> Result<(), CombinedRouterError> {
@@ -121,6 +121,60 @@ fn add_liquidity_amounts(
     }
 }
 
+/// Computes the amount of `amount_in` to swap through a pair so that the rest of it and the swap output
+/// are in the ratio of the pair reserves after the swap, as needed by a single-sided deposit.
+///
+/// The swap amount `s` is the positive root of `c*s^2 + reserve_in*(10000 + c)*s - 10000*amount_in*reserve_in = 0`,
+/// where `c = 10000 - fee`, that is `s = (sqrt(reserve_in*(reserve_in*(10000 + c)^2 + 40000*c*amount_in)) - reserve_in*(10000 + c)) / (2*c)`.
+/// The discriminant does not fit in an `i128` for large reserves, so it is computed as a `U256`.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `amount_in` - The total amount of the input token.
+/// * `reserve_in` - Reserves of the input token in the pair.
+/// * `fee` - The swap fee of the pair, in basis points.
+///
+/// # Returns
+/// The amount of the input token to swap, rounded down.
+fn zap_swap_amount(e: &Env, amount_in: i128, reserve_in: i128, fee: u32) -> i128 {
+    let fee_complement: u128 = 10000 - u128::from(fee);
+    let reserve_in = U256::from_u128(e, reserve_in as u128);
+    let b = reserve_in.mul(&U256::from_u128(e, 10000 + fee_complement));
+    let discriminant = reserve_in.mul(
+        &reserve_in.mul(&U256::from_u128(e, (10000 + fee_complement).pow(2)))
+            .add(&U256::from_u128(e, 40000 * fee_complement).mul(&U256::from_u128(e, amount_in as u128)))
+    );
+    let root = sqrt(e, &discriminant);
+    root.sub(&b).div(&U256::from_u128(e, 2 * fee_complement)).to_u128().unwrap() as i128
+}
+
+/// Returns the square root of `n`, rounded down.
+fn sqrt(e: &Env, n: &U256) -> U256 {
+    let zero = U256::from_u32(e, 0);
+    if *n == zero {
+        return zero;
+    }
+    // Number of bits of `n`, found by bisection as each shift is a host call
+    let (mut low, mut bits): (u32, u32) = (0, 256);
+    while bits - low > 1 {
+        let middle = (low + bits) / 2;
+        if n.shr(middle) > zero {
+            low = middle;
+        } else {
+            bits = middle;
+        }
+    }
+    // Starts above the root, at the power of two with half the bits of `n`, and decreases to it
+    let mut root = U256::from_u32(e, 1).shl((bits + 1) / 2);
+    loop {
+        let next = root.add(&n.div(&root)).shr(1);
+        if next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
 /// Executes a series of token swaps along the provided trading route.
 /// Requires that the initial amount has already been sent to the first pair in the route.
 ///
@@ -162,7 +216,7 @@ fn swap(e: &Env, factory_address: &Address, amounts: &Vec<i128>, path: &Vec<Addr
             &e,
             &soroswap_library::pair_for(e.clone(), factory_address.clone(), input, output)?,
         )
-        .swap(&amount_0_out, &amount_1_out, &to);
+        .swap(&amount_0_out, &amount_1_out, &to, &Bytes::new(&e));
 
     }
 
@@ -170,14 +224,125 @@ fn swap(e: &Env, factory_address: &Address, amounts: &Vec<i128>, path: &Vec<Addr
 }
 
 
+/// Executes a series of token swaps along the provided trading route, for tokens that may take a fee on transfer.
+/// Requires that the initial amount has already been sent to the first pair in the route.
+/// The input of each hop is the amount actually received by the pair, computed as its balance minus its reserve.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `factory_address` - The address of the Soroswap factory contract.
+/// * `path` - A vector representing the trading route, where each element is a token address.
+/// * `_to` - The final destination address for the swapped tokens.
+///
+/// # Returns
+/// A vector containing the amount received by the first pair, followed by the output amount of each step.
+fn swap_supporting_fee_on_transfer(e: &Env, factory_address: &Address, path: &Vec<Address>, _to: &Address) -> Result<Vec<i128>, CombinedRouterError>{
+    let mut amounts: Vec<i128> = Vec::new(e);
+    for i in 0..path.len() - 1 {
+        let (input, output): (Address, Address) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
+
+        let (token_0, _token_1): (Address, Address) =
+            (soroswap_library::sort_tokens(input.clone(), output.clone()))?;
+
+        let pair = soroswap_library::pair_for(e.clone(), factory_address.clone(), input.clone(), output.clone())?;
+
+        // The pair may have received less than what was sent, so the input is read from its balance
+        let (reserve_input, reserve_output) = soroswap_library::get_reserves(
+            e.clone(),
+            factory_address.clone(),
+            input.clone(),
+            output.clone(),
+        )?;
+        let amount_input = TokenClient::new(&e, &input).balance(&pair).checked_sub(reserve_input).unwrap();
+        let fee = soroswap_library::get_fee(e.clone(), factory_address.clone(), input.clone(), output.clone())?;
+        let amount_output = soroswap_library::get_amount_out_with_fee(amount_input, reserve_input, reserve_output, fee)?;
+        if i == 0 {
+            amounts.push_back(amount_input);
+        }
+        amounts.push_back(amount_output);
+
+        let (amount_0_out, amount_1_out): (i128, i128) = if input == token_0 {
+            (0, amount_output)
+        } else {
+            (amount_output, 0)
+        };
+
+        // before the end, "to" must be the next pair... "to" will be the user only at the end
+        let to: Address = if i < path.len() - 2 {
+            soroswap_library::pair_for(
+                e.clone(),
+                factory_address.clone(),
+                output.clone(),
+                path.get(i + 2).unwrap(),
+            )?
+        } else {
+            _to.clone()
+        };
+
+        SoroswapPairClient::new(&e, &pair)
+            .swap(&amount_0_out, &amount_1_out, &to, &Bytes::new(&e));
+    }
+
+    Ok(amounts)
+}
+
+/// Executes a series of token swaps along the provided trading route, where each hop goes through the pool of the
+/// given curve. Requires that the initial amount has already been sent to the first pool in the route.
+///
+/// # Arguments
+/// * `e` - The runtime environment.
+/// * `factory_address` - The address of the Soroswap factory contract.
+/// * `amounts` - A vector containing the output amounts for each step of the trading route.
+/// * `path` - A vector representing the trading route, where each element is a token address.
+/// * `pool_types` - The curve of the pool used by each hop of the trading route.
+/// * `_to` - The final destination address for the swapped tokens.
+fn swap_mixed(e: &Env, factory_address: &Address, amounts: &Vec<i128>, path: &Vec<Address>, pool_types: &Vec<PoolType>, _to: &Address) -> Result<(), CombinedRouterError>{
+    for i in 0..path.len() - 1 {
+        let (input, output): (Address, Address) = (path.get(i).unwrap(), path.get(i + 1).unwrap());
+
+        let (token_0, _token_1): (Address, Address) =
+            (soroswap_library::sort_tokens(input.clone(), output.clone()))?;
+
+        let amount_out: i128 = amounts.get(i + 1).unwrap();
+
+        let (amount_0_out, amount_1_out): (i128, i128) = if input == token_0 {
+            (0, amount_out)
+        } else {
+            (amount_out, 0)
+        };
+
+        // before the end, "to" must be the next pool... "to" will be the user only at the end
+        let to: Address = if i < path.len() - 2 {
+            soroswap_library::pool_for(
+                e.clone(),
+                factory_address.clone(),
+                output.clone(),
+                path.get(i + 2).unwrap(),
+                pool_types.get(i + 1).unwrap(),
+            )?
+        } else {
+            _to.clone()
+        };
+
+        // Stable and weighted pairs and concentrated pools share the swap interface of the constant product pairs
+        SoroswapPairClient::new(
+            &e,
+            &soroswap_library::pool_for(e.clone(), factory_address.clone(), input, output, pool_types.get(i).unwrap())?,
+        )
+        .swap(&amount_0_out, &amount_1_out, &to, &Bytes::new(&e));
+    }
+
+    Ok(())
+}
+
 /*
     SOROSWAP ROUTER SMART CONTRACT INTERFACE:
 */
 
 pub trait SoroswapRouterTrait {
 
-    /// Initializes the contract and sets the factory address
-    fn initialize(e: Env, factory: Address) -> Result<(), CombinedRouterError>;
+    /// Initializes the contract and sets the factory address and the native asset (XLM) contract address
+    fn initialize(e: Env, factory: Address, native: Address) -> Result<(), CombinedRouterError>;
 
     /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
     /// of both tokens are added, subject to minimum requirements.
@@ -284,136 +449,123 @@ pub trait SoroswapRouterTrait {
         deadline: u64,
     ) -> Result<Vec<i128>, CombinedRouterError>;
 
-    /*  *** Read only functions: *** */
-
-    /// This function retrieves the factory contract's address associated with the provided environment.
-    /// It also checks if the factory has been initialized and raises an assertion error if not.
-    /// If the factory is not initialized, this code will raise an assertion error with the message "SoroswapRouter: not yet initialized".
+    /// Splits a swap of exact input amounts across several trading routes, executed atomically in one call.
+    /// Every route must start with the same input token and end with the same output token, and only the
+    /// total output is checked against `amount_out_min`.
     ///
     /// # Arguments
     /// * `e` - The contract environment (`Env`) in which the contract is executing.
-    fn get_factory(e: Env) -> Result<Address, CombinedRouterError>;
-
-    /*
-    LIBRARY FUNCTIONS:
-    */
-
-    /// Calculates the deterministic address for a pair without making any external calls.
-    /// check <https://github.com/paltalabs/deterministic-address-soroban>
-    ///
-    /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `token_a` - The address of the first token.
-    /// * `token_b` - The address of the second token.
+    /// * `routes` - The routes to execute, each one given as the exact amount of input tokens to swap through it
+    ///              and its `path`, as in `swap_exact_tokens_for_tokens`.
+    /// * `amount_out_min` - The minimum required total amount of output tokens to receive.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
-    fn router_pair_for(e: Env, token_a: Address, token_b: Address) -> Result<Address, CombinedRouterError>;
+    /// The total amount of output tokens received.
+    fn swap_exact_tokens_multi(
+        e: Env,
+        routes: Vec<(i128, Vec<Address>)>,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<i128, CombinedRouterError>;
 
-    /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
+    /// Swaps an exact amount of input tokens along a trading route whose hops may go through constant product
+    /// or stable pairs, as given by `pool_types`.
     ///
     /// # Arguments
-    ///
-    /// * `amount_a` - The amount of the first asset.
-    /// * `reserve_a` - Reserves of the first asset in the pair.
-    /// * `reserve_b` - Reserves of the second asset in the pair.
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token
+    ///            and the last is the output token.
+    /// * `pool_types` - The curve of the pool used by each hop, one less than the tokens of `path`.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated equivalent amount, and `Err` indicates an error such as insufficient amount or liquidity
-    fn router_quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, CombinedRouterError>;
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_mixed(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        pool_types: Vec<PoolType>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
 
-    /// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset.
+    /// Swaps tokens for an exact amount of output token along a trading route whose hops may go through
+    /// constant product or stable pairs, as given by `pool_types`.
     ///
     /// # Arguments
-    ///
-    /// * `amount_in` - The input amount of the asset.
-    /// * `reserve_in` - Reserves of the input asset in the pair.
-    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route, where the first element is the input token
+    ///            and the last is the output token.
+    /// * `pool_types` - The curve of the pool used by each hop, one less than the tokens of `path`.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
-    fn router_get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_tokens_for_exact_mixed(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        pool_types: Vec<PoolType>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
 
-    /// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset.
+    /// Adds liquidity to an existing pair holding only one of its tokens. Swaps the optimal part of `amount_in`
+    /// for `token_other` through the pair, accounting for the pair swap fee, and deposits the rest together
+    /// with the swap output. Any dust that does not fit the pool ratio is never taken from `to`.
     ///
     /// # Arguments
-    ///
-    /// * `amount_out` - The output amount of the asset.
-    /// * `reserve_in` - Reserves of the input asset in the pair.
-    /// * `reserve_out` - Reserves of the output asset in the pair.
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `token_in` - The address of the token provided.
+    /// * `amount_in` - The total amount of `token_in` to use.
+    /// * `token_other` - The address of the other token of the pair.
+    /// * `amount_out_min_swap` - The minimum required amount of `token_other` to receive from the swap.
+    /// * `min_liquidity` - The minimum required amount of liquidity tokens to mint.
+    /// * `to` - The address providing `token_in`, and where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
     ///
     /// # Returns
-    ///
-    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
-    fn router_get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+    /// A tuple containing the amount of `token_in` swapped, the amounts of `token_in` and `token_other`
+    /// deposited, and the amount of liquidity tokens minted.
+    fn zap_in(
+        e: Env,
+        token_in: Address,
+        amount_in: i128,
+        token_other: Address,
+        amount_out_min_swap: i128,
+        min_liquidity: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128, i128), CombinedRouterError>;
 
-    /// Performs chained get_amount_out calculations on any number of pairs.
-    ///
-    /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `amount_in` - The input amount.
-    /// * `path` - Vector of token addresses representing the path.
-    ///
-    /// # Returns
-    ///
-    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
-    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
-    
-    /// Performs chained get_amount_in calculations on any number of pairs.
-    ///
-    /// # Arguments
-    ///
-    /// * `e` - The environment.
-    /// * `amount_out` - The output amount.
-    /// * `path` - Vector of token addresses representing the path.
-    ///
-    /// # Returns
+    /// Same as `add_liquidity`, for tokens that take a fee on transfer. The minimum amounts are checked against
+    /// the amounts actually received by the pair.
     ///
-    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
-    fn router_get_amounts_in(e: Env, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
-
-    
-
-}
-
-#[contract]
-struct SoroswapRouter;
-
-#[contractimpl]
-impl SoroswapRouterTrait for SoroswapRouter {
-    /// Initializes the contract and sets the factory address
-    fn initialize(e: Env, factory: Address) -> Result<(), CombinedRouterError> {
-        if !has_factory(&e) {
-            put_factory(&e, &factory);
-            event::initialized(&e, factory);
-            Ok(())
-        } else {
-            Err(SoroswapRouterError::InitializeAlreadyInitialized.into())
-        }
-        
-    }  
-
-    /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
-    /// of both tokens are added, subject to minimum requirements.
-    /// This function is responsible for transferring tokens from the user to the pool and minting liquidity tokens in return.
     /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
     /// * `token_a` - The address of the first token to add liquidity for.
     /// * `token_b` - The address of the second token to add liquidity for.
-    /// * `amount_a_desired` - The desired amount of the first token to add.
-    /// * `amount_b_desired` - The desired amount of the second token to add.
-    /// * `amount_a_min` - The minimum required amount of the first token to add.
-    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `amount_a_desired` - The desired amount of the first token to send.
+    /// * `amount_b_desired` - The desired amount of the second token to send.
+    /// * `amount_a_min` - The minimum required amount of the first token received by the pool.
+    /// * `amount_b_min` - The minimum required amount of the second token received by the pool.
     /// * `to` - The address where the liquidity tokens will be minted and sent.
     /// * `deadline` - The deadline for executing the operation.
+    ///
     /// # Returns
-    /// A tuple containing: amounts of token A and B added to the pool.
-    /// plus the amount of liquidity tokens minted.
-    fn add_liquidity(
+    /// A tuple containing the amounts of token A and B received by the pool, as well as the amount of liquidity tokens minted.
+    fn add_liquidity_fot(
         e: Env,
         token_a: Address,
         token_b: Address,
@@ -423,89 +575,1274 @@ impl SoroswapRouterTrait for SoroswapRouter {
         amount_b_min: i128,
         to: Address,
         deadline: u64,
-    ) -> Result<(i128, i128, i128), CombinedRouterError> {
-        check_initialized(&e)?;
-        check_nonnegative_amount(amount_a_desired)?;
-        check_nonnegative_amount(amount_b_desired)?;
-        check_nonnegative_amount(amount_a_min)?;
-        check_nonnegative_amount(amount_b_min)?;
-
-        to.require_auth();
-        ensure_deadline(&e, deadline)?;
-
-        let factory = get_factory(&e);
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
 
-        let (amount_a, amount_b) = add_liquidity_amounts(
-            e.clone(),
-            factory.clone(),
-            token_a.clone(),
-            token_b.clone(),
-            amount_a_desired,
-            amount_b_desired,
-            amount_a_min,
-            amount_b_min,
-        )?;
+    /// Same as `remove_liquidity`, for tokens that take a fee on transfer. The minimum amounts are checked against
+    /// the amounts actually received by `to`.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` received by `to`.
+    fn remove_liquidity_fot(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Same as `swap_exact_tokens_for_tokens`, for tokens that take a fee on transfer (fot).
+    /// The input of each step is the amount actually received by its pair, and only the amount
+    /// finally received by `to` is checked against `amount_out_min`.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `amount_in` - The exact amount of input tokens to be sent.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amount of output tokens received by `to`.
+    fn swap_exact_tokens_for_tokens_fot(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<i128, CombinedRouterError>;
+
+    /// Adds liquidity to a `token`/XLM pool, creating it if it doesn't exist. XLM is handled through the
+    /// native asset contract configured at `initialize`, so this is `add_liquidity` with `token_b` set to it.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `token` - The address of the token to pair with XLM.
+    /// * `amount_token_desired` - The desired amount of `token` to add.
+    /// * `amount_xlm_desired` - The desired amount of XLM to add.
+    /// * `amount_token_min` - The minimum required amount of `token` to add.
+    /// * `amount_xlm_min` - The minimum required amount of XLM to add.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token` and XLM added to the pool, and the amount of liquidity tokens minted.
+    fn add_liquidity_xlm(
+        e: Env,
+        token: Address,
+        amount_token_desired: i128,
+        amount_xlm_desired: i128,
+        amount_token_min: i128,
+        amount_xlm_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity from a `token`/XLM pool. This is `remove_liquidity` with `token_b` set to
+    /// the native asset contract.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `token` - The address of the token paired with XLM.
+    /// * `liquidity` - The amount of liquidity tokens to remove.
+    /// * `amount_token_min` - The minimum required amount of `token` to receive.
+    /// * `amount_xlm_min` - The minimum required amount of XLM to receive.
+    /// * `to` - The address where the tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token` and XLM withdrawn from the pool.
+    fn remove_liquidity_xlm(
+        e: Env,
+        token: Address,
+        liquidity: i128,
+        amount_token_min: i128,
+        amount_xlm_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Removes liquidity from a token pair's pool on behalf of `owner`, who authorizes this call (so its minimums
+    /// and deadline) and the transfer of its LP tokens to the pair it makes. The authorization is checked by the
+    /// `owner` account and carries its own nonce, so it can be signed offline and relayers can submit withdrawals
+    /// without a separate `permit` of the LP token. The paired tokens are always sent to `owner`.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `liquidity` - The amount of liquidity tokens to remove.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `owner` - The owner of the LP tokens, and where the paired tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity_with_permit(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        owner: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError>;
+
+    /// Swaps an exact amount of XLM for as many output tokens as possible. The first element of `path`
+    /// must be the native asset contract.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `amount_in` - The exact amount of XLM to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - The trading route, starting with the native asset contract.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_xlm_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Swaps tokens for an exact amount of XLM. The last element of `path` must be the native asset contract.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `amount_out` - The exact amount of XLM to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - The trading route, ending with the native asset contract.
+    /// * `to` - The address where the XLM will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_tokens_for_exact_xlm(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Extends the time to live of the router instance and code. Anyone can call it.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    fn extend_ttl(e: Env) -> Result<(), CombinedRouterError>;
+
+    /// Replaces the code of the router. Only the `fee_to_setter` of the factory can call it.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `new_wasm_hash` - The hash of the new Wasm, that must already be uploaded.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), CombinedRouterError>;
+
+    /*  *** Read only functions: *** */
+
+    /// This function retrieves the factory contract's address associated with the provided environment.
+    /// It also checks if the factory has been initialized and raises an assertion error if not.
+    /// If the factory is not initialized, this code will raise an assertion error with the message "SoroswapRouter: not yet initialized".
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    fn get_factory(e: Env) -> Result<Address, CombinedRouterError>;
+
+    /// Returns the address of the native asset (XLM) contract used by the `_xlm` functions.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    fn get_native(e: Env) -> Result<Address, CombinedRouterError>;
+
+    /*
+    LIBRARY FUNCTIONS:
+    */
+
+    /// Calculates the deterministic address for a pair without making any external calls.
+    /// check <https://github.com/paltalabs/deterministic-address-soroban>
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `token_a` - The address of the first token.
+    /// * `token_b` - The address of the second token.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Address, SoroswapLibraryError>` where `Ok` contains the deterministic address for the pair, and `Err` indicates an error such as identical tokens or an issue with sorting.
+    fn router_pair_for(e: Env, token_a: Address, token_b: Address) -> Result<Address, CombinedRouterError>;
+
+    /// Given some amount of an asset and pair reserves, returns an equivalent amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_a` - The amount of the first asset.
+    /// * `reserve_a` - Reserves of the first asset in the pair.
+    /// * `reserve_b` - Reserves of the second asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated equivalent amount, and `Err` indicates an error such as insufficient amount or liquidity
+    fn router_quote(amount_a: i128, reserve_a: i128, reserve_b: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Given an input amount of an asset and pair reserves, returns the maximum output amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_in` - The input amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the calculated maximum output amount, and `Err` indicates an error such as insufficient input amount or liquidity.
+    fn router_get_amount_out(amount_in: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Given an output amount of an asset and pair reserves, returns a required input amount of the other asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_out` - The output amount of the asset.
+    /// * `reserve_in` - Reserves of the input asset in the pair.
+    /// * `reserve_out` - Reserves of the output asset in the pair.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<i128, SoroswapLibraryError>` where `Ok` contains the required input amount, and `Err` indicates an error such as insufficient output amount or liquidity.
+    fn router_get_amount_in(amount_out: i128, reserve_in: i128, reserve_out: i128) -> Result<i128, CombinedRouterError>;
+
+    /// Performs chained get_amount_out calculations on any number of pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_out(e: Env, amount_in: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
+    
+    /// Performs chained get_amount_in calculations on any number of pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, SoroswapLibraryError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_in(e: Env, amount_out: i128, path: Vec<Address>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Finds the path from `token_in` to `token_out` that gives the maximal output for `amount_in`,
+    /// going through at most `max_hops` pairs and using any of the `intermediates` tokens at most once.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `token_in` - The address of the input token.
+    /// * `token_out` - The address of the output token.
+    /// * `amount_in` - The input amount.
+    /// * `intermediates` - Vector of token addresses that can be used between `token_in` and `token_out`, at most 4.
+    /// * `max_hops` - The maximum number of pairs in the path, at most 4.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Address>, CombinedRouterError>` where `Ok` contains the best path, and `Err` indicates an error such as no path with liquidity or too many hops or intermediates.
+    fn router_best_path(e: Env, token_in: Address, token_out: Address, amount_in: i128, intermediates: Vec<Address>, max_hops: u32) -> Result<Vec<Address>, CombinedRouterError>;
+
+    /// Quotes a swap of an exact input amount along a path: amounts of each hop, mid price and execution price
+    /// (scaled by `soroswap_library::PRICE_SCALE`), price impact in basis points and the fees paid to each pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<SwapQuote, CombinedRouterError>` where `Ok` contains the quote of the swap, and `Err` indicates an error such as an invalid path.
+    fn router_get_swap_quote(e: Env, amount_in: i128, path: Vec<Address>) -> Result<SwapQuote, CombinedRouterError>;
+
+    /// Performs chained get_amount_out calculations on a path whose hops may go through constant product or stable pairs.
+    fn router_get_amounts_out_mixed(e: Env, amount_in: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    /// Performs chained get_amount_in calculations on a path whose hops may go through constant product or stable pairs.
+    fn router_get_amounts_in_mixed(e: Env, amount_out: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, CombinedRouterError>;
+
+    
+
+}
+
+#[contract]
+struct SoroswapRouter;
+
+#[contractimpl]
+impl SoroswapRouterTrait for SoroswapRouter {
+    /// Initializes the contract and sets the factory address and the native asset (XLM) contract address
+    fn initialize(e: Env, factory: Address, native: Address) -> Result<(), CombinedRouterError> {
+        if !has_factory(&e) {
+            put_factory(&e, &factory);
+            put_native(&e, &native);
+            extend_instance_ttl(&e);
+            event::initialized(&e, factory, native);
+            Ok(())
+        } else {
+            Err(SoroswapRouterError::InitializeAlreadyInitialized.into())
+        }
+        
+    }  
+
+    /// Adds liquidity to a token pair's pool, creating it if it doesn't exist. Ensures that exactly the desired amounts
+    /// of both tokens are added, subject to minimum requirements.
+    /// This function is responsible for transferring tokens from the user to the pool and minting liquidity tokens in return.
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to add.
+    /// * `amount_b_desired` - The desired amount of the second token to add.
+    /// * `amount_a_min` - The minimum required amount of the first token to add.
+    /// * `amount_b_min` - The minimum required amount of the second token to add.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A tuple containing: amounts of token A and B added to the pool.
+    /// plus the amount of liquidity tokens minted.
+    fn add_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_a_desired)?;
+        check_nonnegative_amount(amount_b_desired)?;
+        check_nonnegative_amount(amount_a_min)?;
+        check_nonnegative_amount(amount_b_min)?;
+
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let factory = get_factory(&e);
+
+        let (amount_a, amount_b) = add_liquidity_amounts(
+            e.clone(),
+            factory.clone(),
+            token_a.clone(),
+            token_b.clone(),
+            amount_a_desired,
+            amount_b_desired,
+            amount_a_min,
+            amount_b_min,
+        )?;
+
+        let pair: Address = soroswap_library::pair_for(
+            e.clone(),
+            factory,
+            token_a.clone(),
+            token_b.clone(),
+        ).map_err(SoroswapLibraryError::from)?;
+
+        TokenClient::new(&e, &token_a).transfer(&to, &pair, &amount_a);
+        TokenClient::new(&e, &token_b).transfer(&to, &pair, &amount_b);
+
+        let liquidity = SoroswapPairClient::new(&e, &pair).deposit(&to);
+
+        event::add_liquidity(
+            &e,
+            token_a,
+            token_b,
+            pair,
+            amount_a,
+            amount_b,
+            liquidity,
+            to);
+            
+        Ok((amount_a, amount_b, liquidity))
+    }
+
+    /// Removes liquidity from a token pair's pool.
+    ///
+    /// This function facilitates the removal of liquidity from a Soroswap Liquidity Pool by burning a specified amount
+    /// of Liquidity Pool tokens (`liquidity`) owned by the caller. In return, it transfers back the corresponding
+    /// amounts of the paired tokens (`token_a` and `token_b`) to the caller's specified address (`to`).
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
+    fn remove_liquidity(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(liquidity)?;
+        check_nonnegative_amount(amount_a_min)?;
+        check_nonnegative_amount(amount_b_min)?;
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        // Ensure that the pair exists in the Soroswap factory
+        let factory_address = get_factory(&e);
+        let factory = SoroswapFactoryClient::new(&e, &factory_address);
+
+        if !factory.pair_exists(&token_a, &token_b) {
+            return Err(SoroswapRouterError::PairDoesNotExist.into());
+        }
+
+        // Retrieve the pair's contract address using the Soroswap library
+        let pair: Address = soroswap_library::pair_for(
+            e.clone(),
+            get_factory(&e),
+            token_a.clone(),
+            token_b.clone(),
+        )?;
+
+        // Transfer LP tokens from the caller to the pair contract
+        TokenClient::new(&e, &pair).transfer(&to, &pair, &liquidity);
+        
+        // Withdraw paired tokens from the pool
+        let (amount_0, amount_1) = SoroswapPairClient::new(&e, &pair).withdraw(&to);
+
+        // Sort tokens to match the expected order
+        let (token_0, _token_1) = soroswap_library::sort_tokens(token_a.clone(), token_b.clone())?;
+        let (amount_a, amount_b) = if token_a == token_0 {
+            (amount_0, amount_1)
+        } else {
+            (amount_1, amount_0)
+        };
+
+        // Check if the received amounts meet the minimum requirements
+        if amount_a < amount_a_min {
+            return Err(SoroswapRouterError::InsufficientAAmount.into());
+        }
+        if amount_b < amount_b_min {
+            return Err(SoroswapRouterError::InsufficientBAmount.into());
+        }
+
+        event::remove_liquidity(
+            &e,
+            token_a,
+            token_b,
+            pair,
+            amount_a,
+            amount_b,
+            liquidity,
+            to);
+
+        // Return the amounts of paired tokens withdrawn
+        Ok((amount_a, amount_b))
+    }
+
+    /// Swaps an exact amount of input tokens for as many output tokens as possible
+    /// along the specified trading route. The route is determined by the `path` vector,
+    /// where the first element is the input token, the last is the output token, 
+    /// and any intermediate elements represent pairs to trade through if a direct pair does not exist.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_for_tokens(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(amount_out_min)?;
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        // Get the expected output amounts for each step of the trading route        
+        let factory_address = get_factory(&e);
+        let amounts = soroswap_library::get_amounts_out(
+            e.clone(),
+            factory_address.clone(),
+            amount_in,
+            path.clone(),
+        )?;
+
+        // Ensure that the final output amount meets the minimum requirement        
+        if amounts.get(amounts.len() - 1).unwrap() < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
+        
+        // Determine the pair contract address for the first step of the trading route
+        let pair = soroswap_library::pair_for(
+            e.clone(),
+            factory_address.clone(),
+            path.get(0).unwrap(),
+            path.get(1).unwrap(),
+        )?;
+        
+        // Transfer input tokens to the pair contract
+        // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
+        // If we implement, we will include an additional cross-contract call...
+        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amounts.get(0).unwrap());
+
+        // Execute the tokens swap
+        swap(&e, &factory_address, &amounts, &path, &to)?;
+    
+        event::swap(
+            &e,
+            path,
+            amounts.clone(),
+            to);
+
+        // Return the amounts of tokens received at each step of the trading route
+        Ok(amounts)
+    }
+
+    /// Swaps tokens for an exact amount of output token, following the specified trading route.
+    /// The route is determined by the `path` vector, where the first element is the input token,
+    /// the last is the output token, and any intermediate elements represent pairs to trade through.
+    ///
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route, where the first element is the input token 
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_tokens_for_exact_tokens(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_out)?;
+        check_nonnegative_amount(amount_in_max)?;
+        to.require_auth(); 
+        ensure_deadline(&e, deadline)?;
+
+        // Get the expected input amounts for each step of the trading route
+        let factory_address = get_factory(&e);
+        let amounts = soroswap_library::get_amounts_in(
+            e.clone(),
+            factory_address.clone(),
+            amount_out,
+            path.clone(),
+        )?;
+        
+        // Ensure that the input amount does not exceed the maximum allowed
+        if amounts.get(0).unwrap() > amount_in_max {
+            return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+        }
+
+        // Determine the pair contract address for the first step of the trading route
+        let pair = soroswap_library::pair_for(
+            e.clone(),
+            factory_address.clone(),
+            path.get(0).unwrap(),
+            path.get(1).unwrap(),
+        )?;
+        // Transfer input tokens to the pair contract
+        // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
+        // If we implement, we will include an additional cross-contract call...
+        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amounts.get(0).unwrap());
+
+        // Execute the token swap
+        swap(&e, &factory_address, &amounts, &path, &to)?;
+    
+        event::swap(
+            &e,
+            path,
+            amounts.clone(),
+            to);
+
+        // Return the amounts of tokens used at each step of the trading route
+        Ok(amounts)
+    }
+
+    /// Splits a swap of exact input amounts across several trading routes, executed atomically in one call.
+    /// # Arguments
+    /// * `routes` - The routes to execute, each one given as the exact amount of input tokens to swap through it
+    ///              and its `path`. All of them must have the same input and output tokens.
+    /// * `amount_out_min` - The minimum required total amount of output tokens to receive.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// The total amount of output tokens received.
+    fn swap_exact_tokens_multi(
+        e: Env,
+        routes: Vec<(i128, Vec<Address>)>,
+        amount_out_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<i128, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_out_min)?;
+        for (amount_in, _path) in routes.iter() {
+            check_nonnegative_amount(amount_in)?;
+        }
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let (_, first_path) = routes.first().ok_or(SoroswapRouterError::InvalidPath)?;
+        let token_in = first_path.first().ok_or(SoroswapRouterError::InvalidPath)?;
+        let token_out = first_path.last().ok_or(SoroswapRouterError::InvalidPath)?;
+
+        let factory_address = get_factory(&e);
+        let mut total_amount_out: i128 = 0;
+        for (amount_in, path) in routes.iter() {
+            if path.first() != Some(token_in.clone()) || path.last() != Some(token_out.clone()) {
+                return Err(SoroswapRouterError::InvalidPath.into());
+            }
+
+            // Reserves are read again for every route, as previous routes may have used the same pairs
+            let amounts = soroswap_library::get_amounts_out(
+                e.clone(),
+                factory_address.clone(),
+                amount_in,
+                path.clone(),
+            )?;
+
+            let pair = soroswap_library::pair_for(
+                e.clone(),
+                factory_address.clone(),
+                path.get(0).unwrap(),
+                path.get(1).unwrap(),
+            )?;
+            TokenClient::new(&e, &token_in).transfer(&to, &pair, &amount_in);
+
+            swap(&e, &factory_address, &amounts, &path, &to)?;
+
+            total_amount_out = total_amount_out.checked_add(amounts.last().unwrap()).unwrap();
+
+            event::swap(
+                &e,
+                path,
+                amounts,
+                to.clone());
+        }
+
+        if total_amount_out < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
+
+        Ok(total_amount_out)
+    }
+
+    /// Swaps an exact amount of input tokens along a trading route whose hops may go through constant product
+    /// or stable pairs, as given by `pool_types`.
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route.
+    /// * `pool_types` - The curve of the pool used by each hop, one less than the tokens of `path`.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A vector containing the amounts of tokens received at each step of the trading route.
+    fn swap_exact_tokens_mixed(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        pool_types: Vec<PoolType>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(amount_out_min)?;
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let factory_address = get_factory(&e);
+        let amounts = soroswap_library::get_amounts_out_mixed(
+            e.clone(),
+            factory_address.clone(),
+            amount_in,
+            path.clone(),
+            pool_types.clone(),
+        )?;
+
+        if amounts.get(amounts.len() - 1).unwrap() < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
+
+        let pool = soroswap_library::pool_for(
+            e.clone(),
+            factory_address.clone(),
+            path.get(0).unwrap(),
+            path.get(1).unwrap(),
+            pool_types.get(0).unwrap(),
+        )?;
+        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pool, &amounts.get(0).unwrap());
+
+        swap_mixed(&e, &factory_address, &amounts, &path, &pool_types, &to)?;
+
+        event::swap(
+            &e,
+            path,
+            amounts.clone(),
+            to);
+
+        Ok(amounts)
+    }
+
+    /// Swaps tokens for an exact amount of output token along a trading route whose hops may go through
+    /// constant product or stable pairs, as given by `pool_types`.
+    /// # Arguments
+    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
+    /// * `path` - A vector representing the trading route.
+    /// * `pool_types` - The curve of the pool used by each hop, one less than the tokens of `path`.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A vector containing the amounts of tokens used at each step of the trading route.
+    fn swap_tokens_for_exact_mixed(
+        e: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        pool_types: Vec<PoolType>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_out)?;
+        check_nonnegative_amount(amount_in_max)?;
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let factory_address = get_factory(&e);
+        let amounts = soroswap_library::get_amounts_in_mixed(
+            e.clone(),
+            factory_address.clone(),
+            amount_out,
+            path.clone(),
+            pool_types.clone(),
+        )?;
+
+        if amounts.get(0).unwrap() > amount_in_max {
+            return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+        }
+
+        let pool = soroswap_library::pool_for(
+            e.clone(),
+            factory_address.clone(),
+            path.get(0).unwrap(),
+            path.get(1).unwrap(),
+            pool_types.get(0).unwrap(),
+        )?;
+        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pool, &amounts.get(0).unwrap());
+
+        swap_mixed(&e, &factory_address, &amounts, &path, &pool_types, &to)?;
+
+        event::swap(
+            &e,
+            path,
+            amounts.clone(),
+            to);
+
+        Ok(amounts)
+    }
+
+    /// Adds liquidity to an existing pair holding only one of its tokens. The part of `amount_in` to swap is the
+    /// closed-form solution that leaves the rest of it and the swap output in the pool ratio after the swap,
+    /// taking the pair swap fee into account. Only the amounts that fit the pool ratio are deposited, so any dust
+    /// stays with `to`.
+    /// # Arguments
+    /// * `token_in` - The address of the token provided.
+    /// * `amount_in` - The total amount of `token_in` to use.
+    /// * `token_other` - The address of the other token of the pair.
+    /// * `amount_out_min_swap` - The minimum required amount of `token_other` to receive from the swap.
+    /// * `min_liquidity` - The minimum required amount of liquidity tokens to mint.
+    /// * `to` - The address providing `token_in`, and where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A tuple containing: the amount of `token_in` swapped, the amounts of `token_in` and `token_other` deposited,
+    /// plus the amount of liquidity tokens minted.
+    fn zap_in(
+        e: Env,
+        token_in: Address,
+        amount_in: i128,
+        token_other: Address,
+        amount_out_min_swap: i128,
+        min_liquidity: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(amount_out_min_swap)?;
+        check_nonnegative_amount(min_liquidity)?;
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let factory_address = get_factory(&e);
+        let factory = SoroswapFactoryClient::new(&e, &factory_address);
+        if !factory.pair_exists(&token_in, &token_other) {
+            return Err(SoroswapRouterError::PairDoesNotExist.into());
+        }
+        let pair = soroswap_library::pair_for(e.clone(), factory_address.clone(), token_in.clone(), token_other.clone())?;
+
+        let (reserve_in, reserve_out) = soroswap_library::get_reserves(
+            e.clone(),
+            factory_address.clone(),
+            token_in.clone(),
+            token_other.clone(),
+        )?;
+        if reserve_in <= 0 || reserve_out <= 0 {
+            return Err(SoroswapRouterError::InsufficientLiquidity.into());
+        }
+        let fee = soroswap_library::get_fee(e.clone(), factory_address.clone(), token_in.clone(), token_other.clone())?;
+        let swap_amount = zap_swap_amount(&e, amount_in, reserve_in, fee);
+        let amount_out = soroswap_library::get_amount_out_with_fee(swap_amount, reserve_in, reserve_out, fee)?;
+        if amount_out < amount_out_min_swap {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
+
+        // Swap part of the input, sending the output to `to`
+        let mut path = Vec::new(&e);
+        path.push_back(token_in.clone());
+        path.push_back(token_other.clone());
+        let mut amounts = Vec::new(&e);
+        amounts.push_back(swap_amount);
+        amounts.push_back(amount_out);
+        TokenClient::new(&e, &token_in).transfer(&to, &pair, &swap_amount);
+        swap(&e, &factory_address, &amounts, &path, &to)?;
+        event::swap(&e, path, amounts, to.clone());
+
+        // Deposit the rest of the input with the output, at the reserves ratio after the swap
+        let (amount_in_deposited, amount_other_deposited) = add_liquidity_amounts(
+            e.clone(),
+            factory_address,
+            token_in.clone(),
+            token_other.clone(),
+            amount_in.checked_sub(swap_amount).unwrap(),
+            amount_out,
+            0,
+            0,
+        )?;
+        TokenClient::new(&e, &token_in).transfer(&to, &pair, &amount_in_deposited);
+        TokenClient::new(&e, &token_other).transfer(&to, &pair, &amount_other_deposited);
+        let liquidity = SoroswapPairClient::new(&e, &pair).deposit(&to);
+        if liquidity < min_liquidity {
+            return Err(SoroswapRouterError::InsufficientLiquidity.into());
+        }
+
+        event::add_liquidity(
+            &e,
+            token_in,
+            token_other,
+            pair,
+            amount_in_deposited,
+            amount_other_deposited,
+            liquidity,
+            to);
+
+        Ok((swap_amount, amount_in_deposited, amount_other_deposited, liquidity))
+    }
+
+    /// Adds liquidity to a token pair's pool for tokens that take a fee on transfer, creating the pool if it doesn't exist.
+    /// The amounts to send are computed as in `add_liquidity`, but the minimum amounts are checked against
+    /// the amounts actually received by the pair, read from its balances.
+    /// # Arguments
+    /// * `token_a` - The address of the first token to add liquidity for.
+    /// * `token_b` - The address of the second token to add liquidity for.
+    /// * `amount_a_desired` - The desired amount of the first token to send.
+    /// * `amount_b_desired` - The desired amount of the second token to send.
+    /// * `amount_a_min` - The minimum required amount of the first token received by the pool.
+    /// * `amount_b_min` - The minimum required amount of the second token received by the pool.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A tuple containing: amounts of token A and B received by the pool.
+    /// plus the amount of liquidity tokens minted.
+    fn add_liquidity_fot(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        amount_a_desired: i128,
+        amount_b_desired: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_a_desired)?;
+        check_nonnegative_amount(amount_b_desired)?;
+        check_nonnegative_amount(amount_a_min)?;
+        check_nonnegative_amount(amount_b_min)?;
+
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        let factory = get_factory(&e);
+
+        // The minimum amounts are checked below, against the received amounts
+        let (amount_a, amount_b) = add_liquidity_amounts(
+            e.clone(),
+            factory.clone(),
+            token_a.clone(),
+            token_b.clone(),
+            amount_a_desired,
+            amount_b_desired,
+            0,
+            0,
+        )?;
+
+        let pair: Address = soroswap_library::pair_for(
+            e.clone(),
+            factory.clone(),
+            token_a.clone(),
+            token_b.clone(),
+        )?;
+
+        let (reserve_a, reserve_b) = soroswap_library::get_reserves(
+            e.clone(),
+            factory,
+            token_a.clone(),
+            token_b.clone(),
+        )?;
+
+        let token_a_client = TokenClient::new(&e, &token_a);
+        let token_b_client = TokenClient::new(&e, &token_b);
+        token_a_client.transfer(&to, &pair, &amount_a);
+        token_b_client.transfer(&to, &pair, &amount_b);
+
+        let received_a = token_a_client.balance(&pair).checked_sub(reserve_a).unwrap();
+        let received_b = token_b_client.balance(&pair).checked_sub(reserve_b).unwrap();
+        if received_a < amount_a_min {
+            return Err(SoroswapRouterError::InsufficientAAmount.into());
+        }
+        if received_b < amount_b_min {
+            return Err(SoroswapRouterError::InsufficientBAmount.into());
+        }
+
+        let liquidity = SoroswapPairClient::new(&e, &pair).deposit(&to);
+
+        event::add_liquidity(
+            &e,
+            token_a,
+            token_b,
+            pair,
+            received_a,
+            received_b,
+            liquidity,
+            to);
+
+        Ok((received_a, received_b, liquidity))
+    }
+
+    /// Removes liquidity from a token pair's pool, for tokens that take a fee on transfer.
+    /// The minimum amounts are checked against the amounts actually received by `to`, read from its balances.
+    ///
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the Liquidity Pool.
+    /// * `token_b` - The address of the second token in the Liquidity Pool.
+    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `amount_a_min` - The minimum required amount of the first token to receive.
+    /// * `amount_b_min` - The minimum required amount of the second token to receive.
+    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of `token_a` and `token_b` received by `to`.
+    fn remove_liquidity_fot(
+        e: Env,
+        token_a: Address,
+        token_b: Address,
+        liquidity: i128,
+        amount_a_min: i128,
+        amount_b_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(liquidity)?;
+        check_nonnegative_amount(amount_a_min)?;
+        check_nonnegative_amount(amount_b_min)?;
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        // Ensure that the pair exists in the Soroswap factory
+        let factory_address = get_factory(&e);
+        let factory = SoroswapFactoryClient::new(&e, &factory_address);
+
+        if !factory.pair_exists(&token_a, &token_b) {
+            return Err(SoroswapRouterError::PairDoesNotExist.into());
+        }
+
+        let pair: Address = soroswap_library::pair_for(
+            e.clone(),
+            factory_address,
+            token_a.clone(),
+            token_b.clone(),
+        )?;
+
+        let token_a_client = TokenClient::new(&e, &token_a);
+        let token_b_client = TokenClient::new(&e, &token_b);
+        let balance_a_before = token_a_client.balance(&to);
+        let balance_b_before = token_b_client.balance(&to);
+
+        // Transfer LP tokens from the caller to the pair contract and withdraw paired tokens from the pool
+        TokenClient::new(&e, &pair).transfer(&to, &pair, &liquidity);
+        SoroswapPairClient::new(&e, &pair).withdraw(&to);
+
+        let amount_a = token_a_client.balance(&to).checked_sub(balance_a_before).unwrap();
+        let amount_b = token_b_client.balance(&to).checked_sub(balance_b_before).unwrap();
+
+        // Check if the received amounts meet the minimum requirements
+        if amount_a < amount_a_min {
+            return Err(SoroswapRouterError::InsufficientAAmount.into());
+        }
+        if amount_b < amount_b_min {
+            return Err(SoroswapRouterError::InsufficientBAmount.into());
+        }
+
+        event::remove_liquidity(
+            &e,
+            token_a,
+            token_b,
+            pair,
+            amount_a,
+            amount_b,
+            liquidity,
+            to);
+
+        Ok((amount_a, amount_b))
+    }
+
+    /// Swaps an exact amount of input tokens for as many output tokens as possible along the specified trading route,
+    /// for tokens that take a fee on transfer (fot). The input of each step is the amount actually received by its pair,
+    /// and only the amount finally received by `to` is checked against `amount_out_min`.
+    ///
+    /// # Arguments
+    /// * `amount_in` - The exact amount of input tokens to be sent.
+    /// * `amount_out_min` - The minimum required amount of output tokens to receive.
+    /// * `path` - A vector representing the trading route, where the first element is the input token
+    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `to` - The address where the output tokens will be sent to.
+    /// * `deadline` - The deadline for executing the operation.
+    ///
+    /// # Returns
+    /// The amount of output tokens received by `to`.
+    fn swap_exact_tokens_for_tokens_fot(
+        e: Env,
+        amount_in: i128,
+        amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> Result<i128, CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        check_nonnegative_amount(amount_in)?;
+        check_nonnegative_amount(amount_out_min)?;
+        to.require_auth();
+        ensure_deadline(&e, deadline)?;
+
+        if path.len() < 2 {
+            return Err(SoroswapLibraryError::InvalidPath.into());
+        }
 
-        let pair: Address = soroswap_library::pair_for(
+        // Determine the pair contract address for the first step of the trading route
+        let factory_address = get_factory(&e);
+        let pair = soroswap_library::pair_for(
             e.clone(),
-            factory,
-            token_a.clone(),
-            token_b.clone(),
-        ).map_err(SoroswapLibraryError::from)?;
+            factory_address.clone(),
+            path.get(0).unwrap(),
+            path.get(1).unwrap(),
+        )?;
 
-        TokenClient::new(&e, &token_a).transfer(&to, &pair, &amount_a);
-        TokenClient::new(&e, &token_b).transfer(&to, &pair, &amount_b);
+        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amount_in);
 
-        let liquidity = SoroswapPairClient::new(&e, &pair).deposit(&to);
+        // Only the amount that reaches `to` is checked
+        let token_out = TokenClient::new(&e, &path.get(path.len() - 1).unwrap());
+        let balance_before = token_out.balance(&to);
+        let amounts = swap_supporting_fee_on_transfer(&e, &factory_address, &path, &to)?;
+        let amount_out = token_out.balance(&to).checked_sub(balance_before).unwrap();
 
-        event::add_liquidity(
+        if amount_out < amount_out_min {
+            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        }
+
+        event::swap(
             &e,
-            token_a,
-            token_b,
-            pair,
-            amount_a,
-            amount_b,
-            liquidity,
+            path,
+            amounts,
             to);
-            
-        Ok((amount_a, amount_b, liquidity))
+
+        Ok(amount_out)
     }
 
-    /// Removes liquidity from a token pair's pool.
-    ///
-    /// This function facilitates the removal of liquidity from a Soroswap Liquidity Pool by burning a specified amount
-    /// of Liquidity Pool tokens (`liquidity`) owned by the caller. In return, it transfers back the corresponding
-    /// amounts of the paired tokens (`token_a` and `token_b`) to the caller's specified address (`to`).
-    ///
+    /// Adds liquidity to a `token`/XLM pool, creating it if it doesn't exist.
     /// # Arguments
-    /// * `token_a` - The address of the first token in the Liquidity Pool.
-    /// * `token_b` - The address of the second token in the Liquidity Pool.
-    /// * `liquidity` - The desired amount of Liquidity Pool tokens to be burned.
+    /// * `token` - The address of the token to pair with XLM.
+    /// * `amount_token_desired` - The desired amount of `token` to add.
+    /// * `amount_xlm_desired` - The desired amount of XLM to add.
+    /// * `amount_token_min` - The minimum required amount of `token` to add.
+    /// * `amount_xlm_min` - The minimum required amount of XLM to add.
+    /// * `to` - The address where the liquidity tokens will be minted and sent.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A tuple containing: amounts of `token` and XLM added to the pool.
+    /// plus the amount of liquidity tokens minted.
+    fn add_liquidity_xlm(
+        e: Env,
+        token: Address,
+        amount_token_desired: i128,
+        amount_xlm_desired: i128,
+        amount_token_min: i128,
+        amount_xlm_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        let native = get_native(&e);
+        Self::add_liquidity(
+            e,
+            token,
+            native,
+            amount_token_desired,
+            amount_xlm_desired,
+            amount_token_min,
+            amount_xlm_min,
+            to,
+            deadline,
+        )
+    }
+
+    /// Removes liquidity from a `token`/XLM pool.
+    /// # Arguments
+    /// * `token` - The address of the token paired with XLM.
+    /// * `liquidity` - The amount of liquidity tokens to remove.
+    /// * `amount_token_min` - The minimum required amount of `token` to receive.
+    /// * `amount_xlm_min` - The minimum required amount of XLM to receive.
+    /// * `to` - The address where the tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `deadline` - The deadline for executing the operation.
+    /// # Returns
+    /// A tuple containing the amounts of `token` and XLM withdrawn from the pool.
+    fn remove_liquidity_xlm(
+        e: Env,
+        token: Address,
+        liquidity: i128,
+        amount_token_min: i128,
+        amount_xlm_min: i128,
+        to: Address,
+        deadline: u64,
+    ) -> Result<(i128, i128), CombinedRouterError> {
+        check_initialized(&e)?;
+        let native = get_native(&e);
+        Self::remove_liquidity(
+            e,
+            token,
+            native,
+            liquidity,
+            amount_token_min,
+            amount_xlm_min,
+            to,
+            deadline,
+        )
+    }
+
+    /// Removes liquidity from a token pair's pool on behalf of `owner`, with a single authorization of `owner`.
+    /// # Arguments
+    /// * `token_a` - The address of the first token in the pair.
+    /// * `token_b` - The address of the second token in the pair.
+    /// * `liquidity` - The amount of liquidity tokens to remove.
     /// * `amount_a_min` - The minimum required amount of the first token to receive.
     /// * `amount_b_min` - The minimum required amount of the second token to receive.
-    /// * `to` - The address where the paired tokens will be sent to, and from where the LP tokens will be taken.
+    /// * `owner` - The owner of the LP tokens, and where the paired tokens will be sent to.
     /// * `deadline` - The deadline for executing the operation.
-    ///
     /// # Returns
     /// A tuple containing the amounts of `token_a` and `token_b` withdrawn from the pool.
-    fn remove_liquidity(
+    fn remove_liquidity_with_permit(
         e: Env,
         token_a: Address,
         token_b: Address,
         liquidity: i128,
         amount_a_min: i128,
         amount_b_min: i128,
-        to: Address,
+        owner: Address,
         deadline: u64,
     ) -> Result<(i128, i128), CombinedRouterError> {
         check_initialized(&e)?;
+        extend_instance_ttl(&e);
         check_nonnegative_amount(liquidity)?;
         check_nonnegative_amount(amount_a_min)?;
         check_nonnegative_amount(amount_b_min)?;
-        to.require_auth();
+        // The owner, not the relayer, sets the minimum amounts and the deadline
+        owner.require_auth();
         ensure_deadline(&e, deadline)?;
 
-        // Ensure that the pair exists in the Soroswap factory
         let factory_address = get_factory(&e);
         let factory = SoroswapFactoryClient::new(&e, &factory_address);
 
@@ -513,21 +1850,18 @@ impl SoroswapRouterTrait for SoroswapRouter {
             return Err(SoroswapRouterError::PairDoesNotExist.into());
         }
 
-        // Retrieve the pair's contract address using the Soroswap library
         let pair: Address = soroswap_library::pair_for(
             e.clone(),
-            get_factory(&e),
+            factory_address,
             token_a.clone(),
             token_b.clone(),
         )?;
 
-        // Transfer LP tokens from the caller to the pair contract
-        TokenClient::new(&e, &pair).transfer(&to, &pair, &liquidity);
-        
-        // Withdraw paired tokens from the pool
-        let (amount_0, amount_1) = SoroswapPairClient::new(&e, &pair).withdraw(&to);
+        // Authorized by the owner as part of this call, so no allowance is needed
+        TokenClient::new(&e, &pair).transfer(&owner, &pair, &liquidity);
+
+        let (amount_0, amount_1) = SoroswapPairClient::new(&e, &pair).withdraw(&owner);
 
-        // Sort tokens to match the expected order
         let (token_0, _token_1) = soroswap_library::sort_tokens(token_a.clone(), token_b.clone())?;
         let (amount_a, amount_b) = if token_a == token_0 {
             (amount_0, amount_1)
@@ -535,7 +1869,6 @@ impl SoroswapRouterTrait for SoroswapRouter {
             (amount_1, amount_0)
         };
 
-        // Check if the received amounts meet the minimum requirements
         if amount_a < amount_a_min {
             return Err(SoroswapRouterError::InsufficientAAmount.into());
         }
@@ -551,28 +1884,21 @@ impl SoroswapRouterTrait for SoroswapRouter {
             amount_a,
             amount_b,
             liquidity,
-            to);
+            owner);
 
-        // Return the amounts of paired tokens withdrawn
         Ok((amount_a, amount_b))
     }
 
-    /// Swaps an exact amount of input tokens for as many output tokens as possible
-    /// along the specified trading route. The route is determined by the `path` vector,
-    /// where the first element is the input token, the last is the output token, 
-    /// and any intermediate elements represent pairs to trade through if a direct pair does not exist.
-    ///
+    /// Swaps an exact amount of XLM for as many output tokens as possible.
     /// # Arguments
-    /// * `amount_in` - The exact amount of input tokens to be swapped.
+    /// * `amount_in` - The exact amount of XLM to be swapped.
     /// * `amount_out_min` - The minimum required amount of output tokens to receive.
-    /// * `path` - A vector representing the trading route, where the first element is the input token 
-    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
+    /// * `path` - The trading route, starting with the native asset contract.
     /// * `to` - The address where the output tokens will be sent to.
     /// * `deadline` - The deadline for executing the operation.
-    ///
     /// # Returns
     /// A vector containing the amounts of tokens received at each step of the trading route.
-    fn swap_exact_tokens_for_tokens(
+    fn swap_exact_xlm_for_tokens(
         e: Env,
         amount_in: i128,
         amount_out_min: i128,
@@ -581,66 +1907,22 @@ impl SoroswapRouterTrait for SoroswapRouter {
         deadline: u64,
     ) -> Result<Vec<i128>, CombinedRouterError> {
         check_initialized(&e)?;
-        check_nonnegative_amount(amount_in)?;
-        check_nonnegative_amount(amount_out_min)?;
-        to.require_auth();
-        ensure_deadline(&e, deadline)?;
-
-        // Get the expected output amounts for each step of the trading route        
-        let factory_address = get_factory(&e);
-        let amounts = soroswap_library::get_amounts_out(
-            e.clone(),
-            factory_address.clone(),
-            amount_in,
-            path.clone(),
-        )?;
-
-        // Ensure that the final output amount meets the minimum requirement        
-        if amounts.get(amounts.len() - 1).unwrap() < amount_out_min {
-            return Err(SoroswapRouterError::InsufficientOutputAmount.into());
+        if path.first() != Some(get_native(&e)) {
+            return Err(SoroswapRouterError::InvalidPath.into());
         }
-        
-        // Determine the pair contract address for the first step of the trading route
-        let pair = soroswap_library::pair_for(
-            e.clone(),
-            factory_address.clone(),
-            path.get(0).unwrap(),
-            path.get(1).unwrap(),
-        )?;
-        
-        // Transfer input tokens to the pair contract
-        // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
-        // If we implement, we will include an additional cross-contract call...
-        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amounts.get(0).unwrap());
-
-        // Execute the tokens swap
-        swap(&e, &factory_address, &amounts, &path, &to)?;
-    
-        event::swap(
-            &e,
-            path,
-            amounts.clone(),
-            to);
-
-        // Return the amounts of tokens received at each step of the trading route
-        Ok(amounts)
+        Self::swap_exact_tokens_for_tokens(e, amount_in, amount_out_min, path, to, deadline)
     }
 
-    /// Swaps tokens for an exact amount of output token, following the specified trading route.
-    /// The route is determined by the `path` vector, where the first element is the input token,
-    /// the last is the output token, and any intermediate elements represent pairs to trade through.
-    ///
+    /// Swaps tokens for an exact amount of XLM.
     /// # Arguments
-    /// * `amount_out` - The exact amount of output token to be received.
+    /// * `amount_out` - The exact amount of XLM to be received.
     /// * `amount_in_max` - The maximum allowed amount of input tokens to be swapped.
-    /// * `path` - A vector representing the trading route, where the first element is the input token 
-    ///            and the last is the output token. Intermediate elements represent pairs to trade through.
-    /// * `to` - The address where the output tokens will be sent to.
+    /// * `path` - The trading route, ending with the native asset contract.
+    /// * `to` - The address where the XLM will be sent to.
     /// * `deadline` - The deadline for executing the operation.
-    ///
     /// # Returns
     /// A vector containing the amounts of tokens used at each step of the trading route.
-    fn swap_tokens_for_exact_tokens(
+    fn swap_tokens_for_exact_xlm(
         e: Env,
         amount_out: i128,
         amount_in_max: i128,
@@ -649,52 +1931,43 @@ impl SoroswapRouterTrait for SoroswapRouter {
         deadline: u64,
     ) -> Result<Vec<i128>, CombinedRouterError> {
         check_initialized(&e)?;
-        check_nonnegative_amount(amount_out)?;
-        check_nonnegative_amount(amount_in_max)?;
-        to.require_auth(); 
-        ensure_deadline(&e, deadline)?;
-
-        // Get the expected input amounts for each step of the trading route
-        let factory_address = get_factory(&e);
-        let amounts = soroswap_library::get_amounts_in(
-            e.clone(),
-            factory_address.clone(),
-            amount_out,
-            path.clone(),
-        )?;
-        
-        // Ensure that the input amount does not exceed the maximum allowed
-        if amounts.get(0).unwrap() > amount_in_max {
-            return Err(SoroswapRouterError::ExcessiveInputAmount.into());
+        if path.last() != Some(get_native(&e)) {
+            return Err(SoroswapRouterError::InvalidPath.into());
         }
+        Self::swap_tokens_for_exact_tokens(e, amount_out, amount_in_max, path, to, deadline)
+    }
 
-        // Determine the pair contract address for the first step of the trading route
-        let pair = soroswap_library::pair_for(
-            e.clone(),
-            factory_address.clone(),
-            path.get(0).unwrap(),
-            path.get(1).unwrap(),
-        )?;
-        // Transfer input tokens to the pair contract
-        // If the pair does not exist, this will fail here: Should be implement factory.pair_exists?
-        // If we implement, we will include an additional cross-contract call...
-        TokenClient::new(&e, &path.get(0).unwrap()).transfer(&to, &pair, &amounts.get(0).unwrap());
+    /*  *** Read only functions: *** */
 
-        // Execute the token swap
-        swap(&e, &factory_address, &amounts, &path, &to)?;
-    
-        event::swap(
-            &e,
-            path,
-            amounts.clone(),
-            to);
 
-        // Return the amounts of tokens used at each step of the trading route
-        Ok(amounts)
+    /// Extends the time to live of the router instance and code, so the router is not archived
+    /// while nobody uses it. Anyone can call this function.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    fn extend_ttl(e: Env) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(())
     }
 
-    /*  *** Read only functions: *** */
+    /// Replaces the code of the router with the Wasm identified by `new_wasm_hash`. The router has no admin of its own,
+    /// so the `fee_to_setter` of the factory must authorize the upgrade.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `new_wasm_hash` - The hash of the new Wasm, that must already be uploaded.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), CombinedRouterError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
 
+        let factory = SoroswapFactoryClient::new(&e, &get_factory(&e));
+        factory.fee_to_setter().require_auth();
+
+        e.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        event::upgrade(&e, new_wasm_hash);
+        Ok(())
+    }
 
     /// This function retrieves the factory contract's address associated with the provided environment.
     /// It also checks if the factory has been initialized and raises an assertion error if not.
@@ -708,6 +1981,15 @@ impl SoroswapRouterTrait for SoroswapRouter {
         Ok(factory_address)
     }
 
+    /// Returns the address of the native asset (XLM) contract used by the `_xlm` functions.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    fn get_native(e: Env) -> Result<Address, CombinedRouterError> {
+        check_initialized(&e)?;
+        Ok(get_native(&e))
+    }
+
 
     /// Calculates the deterministic address for a pair without making any external calls.
     /// check <https://github.com/paltalabs/deterministic-address-soroban>
@@ -811,5 +2093,80 @@ impl SoroswapRouterTrait for SoroswapRouter {
         Ok(soroswap_library::get_amounts_in(e, factory, amount_out, path)?)
     }
 
+    /// Finds the path from `token_in` to `token_out` that gives the maximal output for `amount_in`,
+    /// going through at most `max_hops` pairs and using any of the `intermediates` tokens at most once.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `token_in` - The address of the input token.
+    /// * `token_out` - The address of the output token.
+    /// * `amount_in` - The input amount.
+    /// * `intermediates` - Vector of token addresses that can be used between `token_in` and `token_out`, at most 4.
+    /// * `max_hops` - The maximum number of pairs in the path, at most 4.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<Address>, CombinedRouterError>` where `Ok` contains the best path, and `Err` indicates an error such as no path with liquidity or too many hops or intermediates.
+    fn router_best_path(e: Env, token_in: Address, token_out: Address, amount_in: i128, intermediates: Vec<Address>, max_hops: u32) -> Result<Vec<Address>, CombinedRouterError> {
+        check_initialized(&e)?;
+        let factory = get_factory(&e);
+        Ok(soroswap_library::best_path_out(e, factory, token_in, token_out, amount_in, intermediates, max_hops)?)
+    }
+
+    /// Quotes a swap of an exact input amount along a path: amounts of each hop, mid price and execution price
+    /// (scaled by `soroswap_library::PRICE_SCALE`), price impact in basis points and the fees paid to each pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<SwapQuote, CombinedRouterError>` where `Ok` contains the quote of the swap, and `Err` indicates an error such as an invalid path.
+    fn router_get_swap_quote(e: Env, amount_in: i128, path: Vec<Address>) -> Result<SwapQuote, CombinedRouterError> {
+        check_initialized(&e)?;
+        let factory = get_factory(&e);
+        Ok(soroswap_library::get_swap_quote(e, factory, amount_in, path)?)
+    }
+
+    /// Performs chained get_amount_out calculations on a path whose hops may go through constant product or stable pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_in` - The input amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `pool_types` - The curve of the pool used by each hop, one less than the tokens of `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, CombinedRouterError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_out_mixed(e: Env, amount_in: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        let factory = get_factory(&e);
+        Ok(soroswap_library::get_amounts_out_mixed(e, factory, amount_in, path, pool_types)?)
+    }
+
+    /// Performs chained get_amount_in calculations on a path whose hops may go through constant product or stable pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `e` - The environment.
+    /// * `amount_out` - The output amount.
+    /// * `path` - Vector of token addresses representing the path.
+    /// * `pool_types` - The curve of the pool used by each hop, one less than the tokens of `path`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<Vec<i128>, CombinedRouterError>` where `Ok` contains a vector of calculated amounts, and `Err` indicates an error such as an invalid path.
+    fn router_get_amounts_in_mixed(e: Env, amount_out: i128, path: Vec<Address>, pool_types: Vec<PoolType>) -> Result<Vec<i128>, CombinedRouterError> {
+        check_initialized(&e)?;
+        let factory = get_factory(&e);
+        Ok(soroswap_library::get_amounts_in_mixed(e, factory, amount_out, path, pool_types)?)
+    }
+
 
 }
@@ -1,10 +1,15 @@
 use soroban_sdk::{contracttype, Env, Address};
 
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
 #[derive(Clone)]
 #[contracttype]
 
 enum DataKey {
     Factory, // Address of the Factory Contract
+    Native,  // Address of the native asset (XLM) Stellar Asset Contract
 }
 
 pub fn put_factory(e: &Env, factory: &Address) {
@@ -18,3 +23,15 @@ pub fn has_factory(e: &Env) -> bool {
 pub fn get_factory(e: &Env) -> Address {
     e.storage().instance().get(&DataKey::Factory).unwrap()
 }
+
+pub fn put_native(e: &Env, native: &Address) {
+    e.storage().instance().set(&DataKey::Native, &native);
+}
+
+pub fn get_native(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Native).unwrap()
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
@@ -37,6 +37,33 @@ fn pair_contract_wasm(e: &Env) -> BytesN<32> {
 }
 
 // SoroswapFactory Contract
+mod stable_pair {
+    soroban_sdk::contractimport!(file = "../stable-pair/target/wasm32-unknown-unknown/release/soroswap_stable_pair.wasm");
+    pub type SoroswapStablePairClient<'a> = Client<'a>;
+}
+
+fn stable_pair_contract_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(stable_pair::WASM)
+}
+
+mod weighted_pair {
+    soroban_sdk::contractimport!(file = "../weighted-pair/target/wasm32-unknown-unknown/release/soroswap_weighted_pair.wasm");
+    pub type SoroswapWeightedPairClient<'a> = Client<'a>;
+}
+
+fn weighted_pair_contract_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(weighted_pair::WASM)
+}
+
+mod concentrated_pool {
+    soroban_sdk::contractimport!(file = "../concentrated-pool/target/wasm32-unknown-unknown/release/soroswap_concentrated_pool.wasm");
+    pub type SoroswapConcentratedPoolClient<'a> = Client<'a>;
+}
+
+fn concentrated_pool_contract_wasm(e: &Env) -> BytesN<32> {
+    e.deployer().upload_contract_wasm(concentrated_pool::WASM)
+}
+
 mod factory {
     soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
     pub type SoroswapFactoryClient<'a> = Client<'a>;
@@ -52,6 +79,13 @@ fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryCl
 }
 
 // SoroswapRouter Contract
+fn router_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "./target/wasm32-unknown-unknown/release/soroswap_router.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
 fn create_soroswap_router<'a>(e: &Env) -> SoroswapRouterClient<'a> {
     SoroswapRouterClient::new(e, &e.register_contract(None, SoroswapRouter {}))
 }
@@ -64,6 +98,7 @@ pub struct SoroswapRouterTest<'a> {
     token_0: TokenClient<'a>,
     token_1: TokenClient<'a>,
     factory: SoroswapFactoryClient<'a>,
+    native: Address,
     user: Address,
     admin: Address
 }
@@ -87,6 +122,9 @@ impl<'a> SoroswapRouterTest<'a> {
         token_0.mint(&user, &10_000_000_000_000_000_000);
         token_1.mint(&user, &10_000_000_000_000_000_000);
 
+        // Stands for the native asset (XLM) Stellar Asset Contract
+        let native = create_token_contract(&env, &admin).address;
+
         let factory = create_soroswap_factory(&env, &admin);
         env.budget().reset_unlimited();
 
@@ -96,6 +134,7 @@ impl<'a> SoroswapRouterTest<'a> {
             token_0,
             token_1,
             factory,
+            native,
             user,
             admin
         }
@@ -122,6 +161,8 @@ impl<'a> SoroswapRouterTest<'a> {
         token_0.mint(&user, &initial_user_balance);
         token_1.mint(&user, &initial_user_balance);
 
+        let native = create_token_contract(&env, &admin).address;
+
         let factory = create_soroswap_factory(&env, &admin);
 
         SoroswapRouterTest {
@@ -130,6 +171,7 @@ impl<'a> SoroswapRouterTest<'a> {
             token_0,
             token_1,
             factory,
+            native,
             user,
             admin
         }
@@ -146,6 +188,17 @@ pub mod library_functions;
 pub mod swap_tokens_for_exact_tokens;
 pub mod swap_exact_tokens_for_tokens;
 pub mod events;
+pub mod ttl;
+pub mod upgrade;
+pub mod taxed_token;
+pub mod fee_on_transfer;
+pub mod xlm;
+pub mod remove_liquidity_with_permit;
+pub mod swap_exact_tokens_multi;
+pub mod best_path;
+pub mod swap_quote;
+pub mod zap_in;
+pub mod swap_mixed;
 
 // BUDGET TEST MOD
 mod budget;
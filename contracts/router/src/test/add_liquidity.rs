@@ -63,7 +63,7 @@ fn test_add_liquidity_not_yet_initialized() {
 #[test]
 fn test_add_liquidity_amount_a_desired_negative() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let result = test.contract.try_add_liquidity(
         &test.token_0.address,  // token_a: Address,
         &test.token_1.address,  // token_b: Address,
@@ -80,7 +80,7 @@ fn test_add_liquidity_amount_a_desired_negative() {
 #[test]
 fn test_add_liquidity_amount_b_desired_negative() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let result = test.contract.try_add_liquidity(
         &test.token_0.address,  // token_a: Address,
         &test.token_1.address,  // token_b: Address,
@@ -97,7 +97,7 @@ fn test_add_liquidity_amount_b_desired_negative() {
 #[test]
 fn test_add_liquidity_amount_a_min_negative() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let result = test.contract.try_add_liquidity(
         &test.token_0.address,  // token_a: Address,
         &test.token_1.address,  // token_b: Address,
@@ -114,7 +114,7 @@ fn test_add_liquidity_amount_a_min_negative() {
 #[test]
 fn test_add_liquidity_amount_b_min_negative() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let result = test.contract.try_add_liquidity(
         &test.token_0.address,  // token_a: Address,
         &test.token_1.address,  // token_b: Address,
@@ -133,7 +133,7 @@ fn test_add_liquidity_amount_b_min_negative() {
 #[should_panic(expected = "Unauthorized function call for address")]
 fn test_add_liquidity_not_authorized() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let alice = Address::generate(&test.env);
     let bob = Address::generate(&test.env);
     // alice is not equal to bob
@@ -180,7 +180,7 @@ fn test_add_liquidity_not_authorized() {
 // #[test]
 // fn test_add_liquidity_authorized() {
 //     let test = SoroswapRouterTest::setup();
-//     test.contract.initialize(&test.factory.address);
+//     test.contract.initialize(&test.factory.address, &test.native);
 //     let ledger_timestamp = 100;
 //     let desired_deadline = 1000;
 
@@ -269,7 +269,7 @@ fn test_add_liquidity_not_authorized() {
 #[test]
 fn test_add_liquidity_deadline_expired() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let alice = Address::generate(&test.env);
     let bob = Address::generate(&test.env);
@@ -307,7 +307,7 @@ fn test_add_liquidity_deadline_expired() {
 #[test]
 fn test_add_liquidity() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let ledger_timestamp = 100;
     let desired_deadline = 1000;
@@ -407,7 +407,7 @@ fn test_add_liquidity() {
 fn test_add_liquidity_deducted_amount_reserve() {
     let test = SoroswapRouterTest::setup_deducted_reserve();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let ledger_timestamp = 100;
     let desired_deadline = 1000;
@@ -479,7 +479,7 @@ fn test_add_liquidity_deducted_amount_reserve() {
 #[test]
 fn insufficient_b_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let ledger_timestamp = 100;
     let desired_deadline = 1000;
@@ -512,7 +512,7 @@ fn insufficient_b_amount() {
 #[test]
 fn insufficient_a_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let ledger_timestamp = 100;
     let desired_deadline = 1000;
@@ -546,7 +546,7 @@ fn insufficient_a_amount() {
 #[test]
 fn amount_a_desired_higher() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let ledger_timestamp = 100;
     let desired_deadline = 1000;
@@ -582,7 +582,7 @@ fn amount_a_desired_higher() {
 #[test]
 fn amount_b_desired_higher() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let ledger_timestamp = 100;
     let desired_deadline = 1000;
@@ -0,0 +1,60 @@
+use soroban_sdk::{Address, vec, Vec};
+
+use crate::test::{SoroswapRouterTest, create_token_contract};
+use crate::error::CombinedRouterError;
+
+#[test]
+fn router_best_path_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let intermediates: Vec<Address> = Vec::new(&test.env);
+
+    let result = test.contract.try_router_best_path(&test.token_0.address, &test.token_1.address, &100, &intermediates, &2);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn router_best_path_no_path_found() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let intermediates: Vec<Address> = Vec::new(&test.env);
+
+    let result = test.contract.try_router_best_path(&test.token_0.address, &test.token_1.address, &100, &intermediates, &2);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryNoPathFound)));
+}
+
+#[test]
+fn router_best_path_too_many_hops() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let intermediates: Vec<Address> = Vec::new(&test.env);
+
+    let result = test.contract.try_router_best_path(&test.token_0.address, &test.token_1.address, &100, &intermediates, &5);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryTooManyHops)));
+}
+
+#[test]
+fn router_best_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    test.env.budget().reset_unlimited();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+
+    // The direct pool is much shallower than the pools through token_2
+    for (token_a, token_b, amount) in [
+        (&test.token_0.address, &test.token_1.address, 10_000_i128),
+        (&test.token_0.address, &token_2.address, 1_000_000_000_i128),
+        (&token_2.address, &test.token_1.address, 1_000_000_000_i128),
+    ] {
+        test.contract.add_liquidity(token_a, token_b, &amount, &amount, &0, &0, &test.user, &deadline);
+    }
+    let intermediates = vec![&test.env, token_2.address.clone()];
+
+    let path = test.contract.router_best_path(&test.token_0.address, &test.token_1.address, &1_000, &intermediates, &2);
+    assert_eq!(path, vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()]);
+
+    // With a single hop only the direct pair can be used
+    let path = test.contract.router_best_path(&test.token_0.address, &test.token_1.address, &1_000, &intermediates, &1);
+    assert_eq!(path, vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]);
+}
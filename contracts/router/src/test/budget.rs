@@ -13,7 +13,7 @@ use soroban_sdk::{
 #[test]
 fn budget_add_liquidity() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let ledger_timestamp = 100;
     let desired_deadline = 1000;
@@ -49,7 +49,7 @@ fn budget_add_liquidity() {
 fn budget_remove_liquidity_equal_amount_exact_minimum_out() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let amount_0: i128 = 10_000_000_000;
     let amount_1: i128 = 10_000_000_000;
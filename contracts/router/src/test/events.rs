@@ -18,12 +18,13 @@ use crate::event::{
 #[test]
 fn initialized_event() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let initialized_event = test.env.events().all().last().unwrap();
 
     let expected_initialized_event: InitializedEvent = InitializedEvent {
-        factory: test.factory.address.clone()
+        factory: test.factory.address.clone(),
+        native: test.native.clone()
     };
 
     assert_eq!(
@@ -40,6 +41,7 @@ fn initialized_event() {
 
     let false_initialized_event: InitializedEvent = InitializedEvent {
         factory: test.user,
+        native: test.native,
     };
 
     assert_ne!(
@@ -88,7 +90,7 @@ fn initialized_event() {
 #[test]
 fn add_liquidity_event() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let amount_0: i128 = 1_000_000_000_000_000_000;
     let amount_1: i128 = 4_000_000_000_000_000_000;
@@ -178,7 +180,7 @@ fn add_liquidity_event() {
 #[test]
 fn remove_liquidity_event() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let ledger_timestamp = 100;
     let desired_deadline = 900;
@@ -295,7 +297,7 @@ fn remove_liquidity_event() {
 #[test]
 fn swap_exact_tokens_for_tokens_event() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;  
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -401,7 +403,7 @@ fn swap_exact_tokens_for_tokens_event() {
 fn swap_tokens_for_exact_tokens_event() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;  
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -0,0 +1,188 @@
+use soroban_sdk::{Address, vec, Vec};
+use num_integer::Roots;
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::test::taxed_token::{TaxedToken, TaxedTokenClient, after_tax};
+use crate::error::CombinedRouterError;
+
+fn create_taxed_token<'a>(test: &SoroswapRouterTest) -> TaxedTokenClient<'a> {
+    let taxed_token = TaxedTokenClient::new(&test.env, &test.env.register_contract(None, TaxedToken {}));
+    taxed_token.mint(&test.user, &10_000_000_000_000_000_000);
+    taxed_token
+}
+
+fn deadline(test: &SoroswapRouterTest) -> u64 {
+    test.env.ledger().timestamp() + 1000
+}
+
+// Adds 1_000_000_000 of `token_a` and `token_b`, the taxed token must be `token_a`
+fn add_liquidity_fot(test: &SoroswapRouterTest, token_a: &Address, token_b: &Address) -> (i128, i128, i128) {
+    test.contract.add_liquidity_fot(
+        token_a,
+        token_b,
+        &1_000_000_000,
+        &1_000_000_000,
+        &0,
+        &0,
+        &test.user,
+        &deadline(test),
+    )
+}
+
+#[test]
+fn fee_on_transfer_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    let taxed_token = create_taxed_token(&test);
+    let path: Vec<Address> = vec![&test.env, taxed_token.address.clone(), test.token_0.address.clone()];
+
+    let result = test.contract.try_add_liquidity_fot(&taxed_token.address, &test.token_0.address, &0, &0, &0, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+    let result = test.contract.try_remove_liquidity_fot(&taxed_token.address, &test.token_0.address, &0, &0, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+    let result = test.contract.try_swap_exact_tokens_for_tokens_fot(&0, &0, &path, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn add_liquidity_fot_taxed_token() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let taxed_token = create_taxed_token(&test);
+
+    let (amount_a, amount_b, liquidity) = add_liquidity_fot(&test, &taxed_token.address, &test.token_0.address);
+    assert_eq!(amount_a, 990_000_000);
+    assert_eq!(amount_b, 1_000_000_000);
+    assert_eq!(liquidity, (990_000_000_i128 * 1_000_000_000).sqrt() - 1000);
+
+    // The minimum amounts are checked against the received amounts
+    let result = test.contract.try_add_liquidity_fot(
+        &taxed_token.address,
+        &test.token_0.address,
+        &1_000_000_000,
+        &1_000_000_000,
+        &1_000_000_000,
+        &0,
+        &test.user,
+        &deadline(&test),
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientAAmount)));
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_fot_invalid_path() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
+
+    let result = test.contract.try_swap_exact_tokens_for_tokens_fot(&0, &0, &path, &test.user, &deadline(&test));
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_fot_taxed_input() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let taxed_token = create_taxed_token(&test);
+    add_liquidity_fot(&test, &taxed_token.address, &test.token_0.address);
+    let path: Vec<Address> = vec![&test.env, taxed_token.address.clone(), test.token_0.address.clone()];
+    let amount_in = 1_000_000;
+
+    // The regular swap expects the pair to receive the whole amount
+    let result = test.contract.try_swap_exact_tokens_for_tokens(&amount_in, &0, &path, &test.user, &deadline(&test));
+    assert!(result.is_err());
+
+    let expected_amount_out = soroswap_library::get_amount_out(after_tax(amount_in), 990_000_000, 1_000_000_000).unwrap();
+    let balance_before = test.token_0.balance(&test.user);
+    let amount_out = test.contract.swap_exact_tokens_for_tokens_fot(&amount_in, &expected_amount_out, &path, &test.user, &deadline(&test));
+
+    assert_eq!(amount_out, expected_amount_out);
+    assert_eq!(test.token_0.balance(&test.user), balance_before + expected_amount_out);
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_fot_taxed_output() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let taxed_token = create_taxed_token(&test);
+    add_liquidity_fot(&test, &taxed_token.address, &test.token_0.address);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), taxed_token.address.clone()];
+    let amount_in = 1_000_000;
+
+    let pair_amount_out = soroswap_library::get_amount_out(amount_in, 1_000_000_000, 990_000_000).unwrap();
+    let expected_amount_out = after_tax(pair_amount_out);
+
+    // Only the amount received by `to` is checked
+    let result = test.contract.try_swap_exact_tokens_for_tokens_fot(&amount_in, &pair_amount_out, &path, &test.user, &deadline(&test));
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+
+    let balance_before = taxed_token.balance(&test.user);
+    let amount_out = test.contract.swap_exact_tokens_for_tokens_fot(&amount_in, &expected_amount_out, &path, &test.user, &deadline(&test));
+
+    assert_eq!(amount_out, expected_amount_out);
+    assert_eq!(taxed_token.balance(&test.user), balance_before + expected_amount_out);
+}
+
+#[test]
+fn swap_exact_tokens_for_tokens_fot_taxed_intermediate() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let taxed_token = create_taxed_token(&test);
+    add_liquidity_fot(&test, &taxed_token.address, &test.token_0.address);
+    add_liquidity_fot(&test, &taxed_token.address, &test.token_1.address);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), taxed_token.address.clone(), test.token_1.address.clone()];
+    let amount_in = 1_000_000;
+
+    // The second pair receives the output of the first one minus the tax
+    let first_amount_out = soroswap_library::get_amount_out(amount_in, 1_000_000_000, 990_000_000).unwrap();
+    let expected_amount_out = soroswap_library::get_amount_out(after_tax(first_amount_out), 990_000_000, 1_000_000_000).unwrap();
+
+    let balance_before = test.token_1.balance(&test.user);
+    let amount_out = test.contract.swap_exact_tokens_for_tokens_fot(&amount_in, &expected_amount_out, &path, &test.user, &deadline(&test));
+
+    assert_eq!(amount_out, expected_amount_out);
+    assert_eq!(test.token_1.balance(&test.user), balance_before + expected_amount_out);
+}
+
+#[test]
+fn remove_liquidity_fot_taxed_token() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let taxed_token = create_taxed_token(&test);
+    let (_, _, liquidity) = add_liquidity_fot(&test, &taxed_token.address, &test.token_0.address);
+
+    let pair = SoroswapPairClient::new(&test.env, &test.factory.get_pair(&taxed_token.address, &test.token_0.address));
+    let total_shares = pair.total_shares();
+    let withdrawn_a = liquidity * 990_000_000 / total_shares;
+    let withdrawn_b = liquidity * 1_000_000_000 / total_shares;
+
+    // The pair sends `withdrawn_a`, but `to` only receives it after the tax
+    let result = test.contract.try_remove_liquidity_fot(
+        &taxed_token.address,
+        &test.token_0.address,
+        &liquidity,
+        &withdrawn_a,
+        &withdrawn_b,
+        &test.user,
+        &deadline(&test),
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientAAmount)));
+
+    let (amount_a, amount_b) = test.contract.remove_liquidity_fot(
+        &taxed_token.address,
+        &test.token_0.address,
+        &liquidity,
+        &after_tax(withdrawn_a),
+        &withdrawn_b,
+        &test.user,
+        &deadline(&test),
+    );
+    assert_eq!(amount_a, after_tax(withdrawn_a));
+    assert_eq!(amount_b, withdrawn_b);
+}
@@ -8,8 +8,9 @@ use crate::test::SoroswapRouterTest;
 #[test]
 fn test_initialize_and_get_factory() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     assert_eq!(test.factory.address, test.contract.get_factory());
+    assert_eq!(test.native, test.contract.get_native());
 }
 
 #[test]
@@ -18,15 +19,17 @@ fn test_get_factory_not_yet_initialized() {
     let result = test.contract.try_get_factory();
 
     assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+    let result = test.contract.try_get_native();
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
 }
 
 #[test]
 fn test_initialize_twice() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let factory_another = Address::generate(&test.env);
-    let result_second_init = test.contract.try_initialize(&factory_another);
+    let result_second_init = test.contract.try_initialize(&factory_another, &test.native);
     assert_eq!(
         result_second_init,
         Err(Ok(CombinedRouterError::RouterInitializeAlreadyInitialized))
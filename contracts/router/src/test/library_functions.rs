@@ -103,7 +103,7 @@ fn try_router_get_amount_in_insufficient_liquidity_1() {
 #[test]
 fn try_router_get_amounts_out_invalid_path() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let path = vec![&test.env, test.token_0.address];
     let result = test.contract.try_router_get_amounts_out(&2, &path);
     assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
@@ -125,7 +125,7 @@ fn test_get_amounts_out() {
     // TODO: Get rid of this hack?
     test.env.budget().reset_unlimited();
 
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let amount_0: i128 = 10_000;
     let amount_1: i128 = 10_000;
@@ -143,7 +143,7 @@ fn test_get_amounts_out() {
 #[test]
 fn try_router_get_amounts_in_invalid_path() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let path = vec![&test.env, test.token_0.address];
     let result = test.contract.try_router_get_amounts_in(&1, &path);
     assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
@@ -165,7 +165,7 @@ fn test_get_amounts_in() {
     // TODO: Get rid of this hack?
     test.env.budget().reset_unlimited();
 
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let amount_0: i128 = 10_000;
     let amount_1: i128 = 10_000;
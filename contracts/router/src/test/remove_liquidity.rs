@@ -35,7 +35,7 @@ use soroban_sdk::{
     #[test]
     fn test_remove_liquidity_liquidity_negative() {
         let test = SoroswapRouterTest::setup();
-        test.contract.initialize(&test.factory.address);
+        test.contract.initialize(&test.factory.address, &test.native);
         
         let result = test.contract.try_remove_liquidity(
             &test.token_0.address,  // token_a: Address,
@@ -53,7 +53,7 @@ use soroban_sdk::{
     #[test]
     fn test_remove_liquidity_amount_a_min_negative() {
         let test = SoroswapRouterTest::setup();
-        test.contract.initialize(&test.factory.address);
+        test.contract.initialize(&test.factory.address, &test.native);
         
         let result = test.contract.try_remove_liquidity(
             &test.token_0.address,  // token_a: Address,
@@ -71,7 +71,7 @@ use soroban_sdk::{
     #[test]
     fn test_remove_liquidity_amount_b_min_negative() {
         let test = SoroswapRouterTest::setup();
-        test.contract.initialize(&test.factory.address);
+        test.contract.initialize(&test.factory.address, &test.native);
         
         let result = test.contract.try_remove_liquidity(
             &test.token_0.address,  // token_a: Address,
@@ -92,7 +92,7 @@ use soroban_sdk::{
 #[should_panic(expected = "Unauthorized function call for address")] 
 fn test_remove_liquidity_not_authorized() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let alice = Address::generate(&test.env);
     let bob = Address::generate(&test.env);
     // alice is not equal to bob
@@ -139,7 +139,7 @@ fn test_remove_liquidity_not_authorized() {
 #[test]
 fn test_remove_liquidity_deadline_expired() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let ledger_timestamp = 100;
     let desired_deadline = 90;
@@ -169,7 +169,7 @@ fn test_remove_liquidity_deadline_expired() {
 #[test]
 fn test_remove_liquidity_pair_does_not_exist() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     // We don't create any LP for token0 & token 1
     let ledger_timestamp = 100;
@@ -200,7 +200,7 @@ fn test_remove_liquidity_pair_does_not_exist() {
 #[should_panic]
 fn test_remove_liquidity_insufficient_sent_shares() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     // We don't create any LP for token0 & token 1
     let ledger_timestamp = 100;
@@ -228,7 +228,7 @@ fn test_remove_liquidity_insufficient_sent_shares() {
 #[test]
 fn test_remove_liquidity_sufficient_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     // We don't create any LP for token0 & token 1
     let ledger_timestamp = 100;
@@ -274,7 +274,7 @@ fn test_remove_liquidity_sufficient_amount() {
 #[test]
 fn test_remove_liquidity_sufficient_amount_inverse() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     // We don't create any LP for token0 & token 1
     let ledger_timestamp = 100;
@@ -320,7 +320,7 @@ fn test_remove_liquidity_sufficient_amount_inverse() {
 #[test]
 fn test_remove_liquidity_insufficient_a_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     // We don't create any LP for token0 & token 1
     let ledger_timestamp = 100;
@@ -363,7 +363,7 @@ fn test_remove_liquidity_insufficient_a_amount() {
 #[test]
 fn test_remove_liquidity_insufficient_b_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     // We don't create any LP for token0 & token 1
     let ledger_timestamp = 100;
@@ -408,7 +408,7 @@ fn test_remove_liquidity_insufficient_b_amount() {
 fn test_remove_liquidity_equal_amount_0_minimum_out() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let initial_user_balance = 10000000000000000000;
     assert_eq!(test.token_0.balance(&test.user), initial_user_balance);
@@ -478,7 +478,7 @@ fn test_remove_liquidity_equal_amount_0_minimum_out() {
 fn test_remove_liquidity_equal_amount_exact_minimum_out() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let initial_user_balance = 10000000000000000000;
     assert_eq!(test.token_0.balance(&test.user), initial_user_balance);
@@ -550,7 +550,7 @@ fn test_remove_liquidity_equal_amount_exact_minimum_out() {
 fn test_remove_liquidity_inequal_amount_0_minimum_out() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let initial_user_balance = 10000000000000000000;
     assert_eq!(test.token_0.balance(&test.user), initial_user_balance);
@@ -620,7 +620,7 @@ fn test_remove_liquidity_inequal_amount_0_minimum_out() {
 fn test_remove_liquidity_inequal_amount_exact_minimum_out() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let initial_user_balance = 10000000000000000000;
     assert_eq!(test.token_0.balance(&test.user), initial_user_balance);
@@ -694,7 +694,7 @@ fn test_remove_liquidity_inequal_amount_exact_minimum_out() {
 fn test_remove_liquidity_inequal_amount_exact_minimum_out_other_way() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     
     let initial_user_balance = 10000000000000000000;
     assert_eq!(test.token_0.balance(&test.user), initial_user_balance);
@@ -0,0 +1,228 @@
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::error::CombinedRouterError;
+extern crate std;
+
+use soroban_sdk::{
+    Address,
+    IntoVal,
+    Symbol,
+    testutils::{
+        Address as _,
+        AuthorizedFunction,
+        AuthorizedInvocation,
+        Ledger,
+        MockAuth,
+        MockAuthInvoke}};
+
+// Gives LP tokens to a new owner, that never calls the router itself
+fn setup_owner_liquidity<'a>(test: &SoroswapRouterTest<'a>) -> (Address, SoroswapPairClient<'a>, i128) {
+    let owner = Address::generate(&test.env);
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 100;
+    });
+    let (_, _, liquidity) = test.contract.add_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000_000_000,
+        &4_000_000_000,
+        &0,
+        &0,
+        &test.user,
+        &1000,
+    );
+    let pair = SoroswapPairClient::new(&test.env, &test.factory.get_pair(&test.token_0.address, &test.token_1.address));
+    pair.transfer(&test.user, &owner, &liquidity);
+    (owner, pair, liquidity)
+}
+
+// Mocks the authorization of `owner` for the given router call and the given transfer of its LP tokens
+fn mock_owner_auths(
+    test: &SoroswapRouterTest,
+    pair: &SoroswapPairClient,
+    owner: &Address,
+    liquidity: i128,
+    amount_b_min: i128,
+    transfer_amount: i128,
+) {
+    test.env.mock_auths(&[MockAuth {
+        address: owner,
+        invoke: &MockAuthInvoke {
+            contract: &test.contract.address,
+            fn_name: "remove_liquidity_with_permit",
+            args: (
+                &test.token_0.address,
+                &test.token_1.address,
+                liquidity,
+                0_i128,
+                amount_b_min,
+                owner,
+                1000_u64,
+            ).into_val(&test.env),
+            sub_invokes: &[MockAuthInvoke {
+                contract: &pair.address,
+                fn_name: "transfer",
+                args: (owner, &pair.address, transfer_amount).into_val(&test.env),
+                sub_invokes: &[],
+            }],
+        },
+    }]);
+}
+
+#[test]
+fn remove_liquidity_with_permit_not_yet_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let owner = Address::generate(&test.env);
+
+    let result = test.contract.try_remove_liquidity_with_permit(
+        &test.token_0.address, &test.token_1.address, &0, &0, &0, &owner, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn remove_liquidity_with_permit_pair_does_not_exist() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let owner = Address::generate(&test.env);
+
+    let result = test.contract.try_remove_liquidity_with_permit(
+        &test.token_0.address, &test.token_1.address, &0, &0, &0, &owner, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairDoesNotExist)));
+}
+
+#[test]
+fn remove_liquidity_with_permit_deadline_expired() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let (owner, _, liquidity) = setup_owner_liquidity(&test);
+
+    let result = test.contract.try_remove_liquidity_with_permit(
+        &test.token_0.address, &test.token_1.address, &liquidity, &0, &0, &owner, &90);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+}
+
+#[test]
+fn remove_liquidity_with_permit() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let (owner, pair, liquidity) = setup_owner_liquidity(&test);
+
+    let (amount_a, amount_b) = test.contract.remove_liquidity_with_permit(
+        &test.token_0.address, //     token_a: Address,
+        &test.token_1.address, //     token_b: Address,
+        &liquidity,            //     liquidity: i128,
+        &0,                    //     amount_a_min: i128,
+        &0,                    //     amount_b_min: i128,
+        &owner,                //     owner: Address,
+        &1000,                 //     deadline: u64,
+    );
+
+    // The owner authorizes the whole call, minimums and deadline included, and the transfer of its LP tokens
+    assert_eq!(
+        test.env.auths(),
+        std::vec![(
+            owner.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    test.contract.address.clone(),
+                    Symbol::new(&test.env, "remove_liquidity_with_permit"),
+                    (
+                        &test.token_0.address,
+                        &test.token_1.address,
+                        liquidity,
+                        0_i128,
+                        0_i128,
+                        &owner,
+                        1000_u64,
+                    ).into_val(&test.env),
+                )),
+                sub_invocations: std::vec![AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        pair.address.clone(),
+                        Symbol::new(&test.env, "transfer"),
+                        (&owner, &pair.address, liquidity).into_val(&test.env),
+                    )),
+                    sub_invocations: std::vec![]
+                }]
+            }
+        )]
+    );
+    // Minimum liquidity stays in the pool
+    assert_eq!(amount_a, 1_000_000_000 - 500);
+    assert_eq!(amount_b, 4_000_000_000 - 2000);
+    assert_eq!(test.token_0.balance(&owner), amount_a);
+    assert_eq!(test.token_1.balance(&owner), amount_b);
+    assert_eq!(pair.my_balance(&owner), 0);
+    // No allowance is left behind
+    assert_eq!(pair.allowance(&owner, &test.contract.address), 0);
+}
+
+#[test]
+fn remove_liquidity_with_permit_insufficient_b_amount() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let (owner, pair, liquidity) = setup_owner_liquidity(&test);
+
+    let result = test.contract.try_remove_liquidity_with_permit(
+        &test.token_0.address, &test.token_1.address, &liquidity, &0, &4_000_000_000, &owner, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientBAmount)));
+    // Nothing changed
+    assert_eq!(pair.my_balance(&owner), liquidity);
+}
+
+#[test]
+fn remove_liquidity_with_permit_authorized() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let (owner, pair, liquidity) = setup_owner_liquidity(&test);
+
+    mock_owner_auths(&test, &pair, &owner, liquidity, 1, liquidity);
+    test.contract.remove_liquidity_with_permit(
+        &test.token_0.address, &test.token_1.address, &liquidity, &0, &1, &owner, &1000);
+    assert_eq!(pair.my_balance(&owner), 0);
+}
+
+#[test]
+#[should_panic]
+fn remove_liquidity_with_permit_minimum_not_authorized() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let (owner, pair, liquidity) = setup_owner_liquidity(&test);
+
+    // A relayer can not lower the minimum amount set by the owner
+    mock_owner_auths(&test, &pair, &owner, liquidity, 1, liquidity);
+    test.contract.remove_liquidity_with_permit(
+        &test.token_0.address, &test.token_1.address, &liquidity, &0, &0, &owner, &1000);
+}
+
+#[test]
+#[should_panic]
+fn remove_liquidity_with_permit_only_transfer_authorized() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let (owner, pair, liquidity) = setup_owner_liquidity(&test);
+
+    // The transfer alone does not authorize the minimum amounts and the deadline of the call
+    test.env.mock_auths(&[MockAuth {
+        address: &owner,
+        invoke: &MockAuthInvoke {
+            contract: &pair.address,
+            fn_name: "transfer",
+            args: (&owner, &pair.address, liquidity).into_val(&test.env),
+            sub_invokes: &[],
+        },
+    }]);
+    test.contract.remove_liquidity_with_permit(
+        &test.token_0.address, &test.token_1.address, &liquidity, &0, &0, &owner, &1000);
+}
+
+#[test]
+#[should_panic]
+fn remove_liquidity_with_permit_more_than_authorized() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let (owner, pair, liquidity) = setup_owner_liquidity(&test);
+
+    mock_owner_auths(&test, &pair, &owner, liquidity, 0, liquidity / 2);
+    test.contract.remove_liquidity_with_permit(
+        &test.token_0.address, &test.token_1.address, &liquidity, &0, &0, &owner, &1000);
+}
@@ -29,7 +29,7 @@ fn swap_exact_tokens_for_tokens_amount_in_negative() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
 
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let path: Vec<Address> = Vec::new(&test.env);
 
     let result = test.contract.try_swap_exact_tokens_for_tokens(
@@ -51,7 +51,7 @@ fn swap_exact_tokens_for_tokens_amount_out_min_negative() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
 
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let path: Vec<Address> = Vec::new(&test.env);
 
     let result = test.contract.try_swap_exact_tokens_for_tokens(
@@ -71,7 +71,7 @@ fn swap_exact_tokens_for_tokens_amount_out_min_negative() {
 #[test]
 fn swap_exact_tokens_for_tokens_expired() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let path: Vec<Address> = Vec::new(&test.env);
 
     let result = test.contract.try_swap_exact_tokens_for_tokens(
@@ -92,7 +92,7 @@ fn swap_exact_tokens_for_tokens_expired() {
 #[test]
 fn try_swap_exact_tokens_for_tokens_invalid_path() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;
     let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
     let result = test.contract.try_swap_exact_tokens_for_tokens(
@@ -113,7 +113,7 @@ fn try_swap_exact_tokens_for_tokens_invalid_path() {
 #[should_panic]
 fn swap_exact_tokens_for_tokens_pair_does_not_exist() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;  
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -131,7 +131,7 @@ fn swap_exact_tokens_for_tokens_pair_does_not_exist() {
 #[test]
 fn try_swap_exact_tokens_for_tokens_insufficient_input_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -159,7 +159,7 @@ fn try_swap_exact_tokens_for_tokens_insufficient_input_amount() {
 #[test]
 fn swap_exact_tokens_for_tokens_insufficient_output_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -197,7 +197,7 @@ fn swap_exact_tokens_for_tokens_insufficient_output_amount() {
 #[test]
 fn swap_exact_tokens_for_tokens_enough_output_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;  
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -235,7 +235,7 @@ fn swap_exact_tokens_for_tokens_enough_output_amount() {
 fn swap_exact_tokens_for_tokens_2_hops() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;  
     let initial_user_balance = 10_000_000_000_000_000_000;
 
@@ -297,3 +297,44 @@ fn swap_exact_tokens_for_tokens_2_hops() {
     assert_eq!(token_2.balance(&test.user), initial_user_balance -amount_2 + expected_amount_out);
 }
 
+
+#[test]
+fn swap_exact_tokens_for_tokens_pair_fee_tier() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;  
+
+    // Pair with a 1% swap fee
+    test.factory.create_pair_with_fee(&test.token_0.address, &test.token_1.address, &100);
+
+    let amount_0: i128 = 1_000_000_000;
+    let amount_1: i128 = 4_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let mut path: Vec<Address> = Vec::new(&test.env);
+    path.push_back(test.token_0.address.clone());
+    path.push_back(test.token_1.address.clone());
+
+    let amount_in = 123_456_789;
+    // (123456789*9900*4000000000)/(1000000000*10000 + 9900*123456789) = 435643560,7
+    let expected_amount_out = 435643560;
+
+    let result = test.contract.try_swap_exact_tokens_for_tokens(
+        &amount_in, //amount_in
+        &(expected_amount_out + 1),  // amount_out_min
+        &path, // path
+        &test.user, // to
+        &deadline); // deadline
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+
+    let executed_amounts = test.contract.swap_exact_tokens_for_tokens(
+        &amount_in, //amount_in
+        &expected_amount_out,  // amount_out_min
+        &path, // path
+        &test.user, // to
+        &deadline); // deadline
+
+    assert_eq!(executed_amounts.get(0).unwrap(), amount_in);
+    assert_eq!(executed_amounts.get(1).unwrap(), expected_amount_out);
+}
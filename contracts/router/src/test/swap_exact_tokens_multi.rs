@@ -0,0 +1,157 @@
+use soroban_sdk::{Address, vec, Vec, IntoVal, symbol_short, testutils::Events};
+
+use crate::test::{SoroswapRouterTest, TokenClient, create_token_contract};
+use crate::error::CombinedRouterError;
+use crate::event::SwapEvent;
+
+// Creates the pools token_0/token_1, token_0/token_2 and token_2/token_1, so token_0 can be swapped
+// for token_1 directly or through token_2
+fn setup_pools<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    test.env.budget().reset_unlimited();
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000_000_000_000);
+
+    for (token_a, token_b, amount_a, amount_b) in [
+        (&test.token_0.address, &test.token_1.address, 1_000_000_000_i128, 4_000_000_000_i128),
+        (&test.token_0.address, &token_2.address, 1_000_000_000_i128, 2_000_000_000_i128),
+        (&token_2.address, &test.token_1.address, 2_000_000_000_i128, 4_000_000_000_i128),
+    ] {
+        test.contract.add_liquidity(token_a, token_b, &amount_a, &amount_b, &0, &0, &test.user, &deadline);
+    }
+    token_2
+}
+
+#[test]
+fn swap_exact_tokens_multi_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let routes: Vec<(i128, Vec<Address>)> = Vec::new(&test.env);
+
+    let result = test.contract.try_swap_exact_tokens_multi(&routes, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn swap_exact_tokens_multi_amount_in_negative() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let routes = vec![&test.env, (1_000_i128, path.clone()), (-1_i128, path)];
+
+    let result = test.contract.try_swap_exact_tokens_multi(&routes, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNegativeNotAllowed)));
+}
+
+#[test]
+fn swap_exact_tokens_multi_no_routes() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let routes: Vec<(i128, Vec<Address>)> = Vec::new(&test.env);
+
+    let result = test.contract.try_swap_exact_tokens_multi(&routes, &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidPath)));
+}
+
+#[test]
+fn swap_exact_tokens_multi_different_output_tokens() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let token_2 = setup_pools(&test);
+
+    let routes = vec![
+        &test.env,
+        (1_000_000_i128, vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()]),
+        (1_000_000_i128, vec![&test.env, test.token_0.address.clone(), token_2.address.clone()]),
+    ];
+    let result = test.contract.try_swap_exact_tokens_multi(&routes, &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidPath)));
+}
+
+#[test]
+fn swap_exact_tokens_multi_insufficient_output_amount() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let token_2 = setup_pools(&test);
+
+    let direct_path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let indirect_path = vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()];
+    let expected_amount_out = test.contract.router_get_amounts_out(&1_000_000, &direct_path).last().unwrap()
+        + test.contract.router_get_amounts_out(&1_000_000, &indirect_path).last().unwrap();
+
+    let routes = vec![&test.env, (1_000_000_i128, direct_path), (1_000_000_i128, indirect_path)];
+    let result = test.contract.try_swap_exact_tokens_multi(&routes, &(expected_amount_out + 1), &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
+
+#[test]
+fn swap_exact_tokens_multi() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let token_2 = setup_pools(&test);
+
+    let direct_path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let indirect_path = vec![&test.env, test.token_0.address.clone(), token_2.address.clone(), test.token_1.address.clone()];
+    let direct_amounts = test.contract.router_get_amounts_out(&10_000_000, &direct_path);
+    let indirect_amounts = test.contract.router_get_amounts_out(&20_000_000, &indirect_path);
+    let expected_amount_out = direct_amounts.last().unwrap() + indirect_amounts.last().unwrap();
+    let token_0_balance_before = test.token_0.balance(&test.user);
+    let token_1_balance_before = test.token_1.balance(&test.user);
+
+    let routes = vec![&test.env, (10_000_000_i128, direct_path.clone()), (20_000_000_i128, indirect_path.clone())];
+    let amount_out = test.contract.swap_exact_tokens_multi(&routes, &expected_amount_out, &test.user, &deadline);
+
+    assert_eq!(amount_out, expected_amount_out);
+    assert_eq!(test.token_0.balance(&test.user), token_0_balance_before - 30_000_000);
+    assert_eq!(test.token_1.balance(&test.user), token_1_balance_before + expected_amount_out);
+    // The intermediate token only goes through the pairs
+    assert_eq!(token_2.balance(&test.user), 10_000_000_000_000_000_000 - 2_000_000_000 - 2_000_000_000);
+
+    // One swap event per route, as the last events of the router
+    let mut router_events = Vec::new(&test.env);
+    for event in test.env.events().all().iter() {
+        if event.0 == test.contract.address {
+            router_events.push_back(event);
+        }
+    }
+    let swap_events = router_events.slice(router_events.len() - 2..);
+    let expected_swap_event = |path: Vec<Address>, amounts: Vec<i128>| {
+        (
+            test.contract.address.clone(),
+            ("SoroswapRouter", symbol_short!("swap")).into_val(&test.env),
+            (SwapEvent { path, amounts, to: test.user.clone() }).into_val(&test.env),
+        )
+    };
+    assert_eq!(
+        swap_events,
+        vec![
+            &test.env,
+            expected_swap_event(direct_path, direct_amounts),
+            expected_swap_event(indirect_path, indirect_amounts),
+        ]
+    );
+}
+
+#[test]
+fn swap_exact_tokens_multi_same_pair() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    setup_pools(&test);
+
+    let path = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+    let first_amount_out = test.contract.router_get_amounts_out(&10_000_000, &path).last().unwrap();
+
+    let routes = vec![&test.env, (10_000_000_i128, path.clone()), (10_000_000_i128, path.clone())];
+    let amount_out = test.contract.swap_exact_tokens_multi(&routes, &0, &test.user, &deadline);
+
+    // The second route uses the reserves left by the first one
+    let pair = test.factory.get_pair(&test.token_0.address, &test.token_1.address);
+    let (reserve_0, reserve_1) = crate::test::SoroswapPairClient::new(&test.env, &pair).get_reserves();
+    assert_eq!(reserve_0, 1_000_000_000 + 20_000_000);
+    assert_eq!(reserve_1, 4_000_000_000 - amount_out);
+    assert!(amount_out - first_amount_out < first_amount_out);
+}
@@ -0,0 +1,235 @@
+use soroban_sdk::{Address, vec, Vec, testutils::Ledger};
+use soroswap_library::PoolType;
+
+use crate::test::{SoroswapRouterTest, create_token_contract, stable_pair_contract_wasm, weighted_pair_contract_wasm, concentrated_pool_contract_wasm};
+use crate::test::stable_pair::SoroswapStablePairClient;
+use crate::test::weighted_pair::SoroswapWeightedPairClient;
+use crate::test::concentrated_pool::SoroswapConcentratedPoolClient;
+use crate::test::token::TokenClient;
+use crate::error::CombinedRouterError;
+
+// A stable pair of token_0 and token_1 with 1_000_000_000 of each token, and a constant product pair of
+// token_1 and a new token_2 with 1_000_000_000 and 2_000_000_000
+fn setup_mixed_pools<'a>(test: &SoroswapRouterTest<'a>) -> (TokenClient<'a>, SoroswapStablePairClient<'a>) {
+    test.contract.initialize(&test.factory.address, &test.native);
+    test.env.ledger().with_mut(|li| {
+        li.timestamp = 100;
+    });
+
+    test.factory.set_stable_pair_wasm_hash(&stable_pair_contract_wasm(&test.env));
+    let stable_pair = SoroswapStablePairClient::new(&test.env, &test.factory.create_stable_pair(&test.token_0.address, &test.token_1.address, &100));
+    test.token_0.transfer(&test.user, &stable_pair.address, &1_000_000_000);
+    test.token_1.transfer(&test.user, &stable_pair.address, &1_000_000_000);
+    stable_pair.deposit(&test.user);
+
+    let token_2 = create_token_contract(&test.env, &test.admin);
+    token_2.mint(&test.user, &10_000_000_000);
+    test.contract.add_liquidity(
+        &test.token_1.address,
+        &token_2.address,
+        &1_000_000_000,
+        &2_000_000_000,
+        &0,
+        &0,
+        &test.user,
+        &1000,
+    );
+    (token_2, stable_pair)
+}
+
+#[test]
+fn swap_exact_tokens_mixed_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let path: Vec<Address> = Vec::new(&test.env);
+    let pool_types: Vec<PoolType> = Vec::new(&test.env);
+    let result = test.contract.try_swap_exact_tokens_mixed(&0, &0, &path, &pool_types, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn swap_exact_tokens_mixed_invalid_pool_types() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, _stable_pair) = setup_mixed_pools(&test);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Stable];
+    let result = test.contract.try_swap_exact_tokens_mixed(&1_000, &0, &path, &pool_types, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
+
+#[test]
+fn swap_exact_tokens_mixed_insufficient_output() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, _stable_pair) = setup_mixed_pools(&test);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Stable, PoolType::ConstantProduct];
+    let amounts = test.contract.router_get_amounts_out_mixed(&1_000_000, &path, &pool_types);
+    let result = test.contract.try_swap_exact_tokens_mixed(&1_000_000, &(amounts.last().unwrap() + 1), &path, &pool_types, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
+
+#[test]
+fn swap_exact_tokens_mixed() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, stable_pair) = setup_mixed_pools(&test);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Stable, PoolType::ConstantProduct];
+
+    let amount_in = 10_000_000;
+    let first_hop = soroswap_library::get_stable_amount_out(&test.env, amount_in, 1_000_000_000, 1_000_000_000, 100, 5).unwrap();
+    let second_hop = soroswap_library::get_amount_out(first_hop, 1_000_000_000, 2_000_000_000).unwrap();
+    assert_eq!(test.contract.router_get_amounts_out_mixed(&amount_in, &path, &pool_types), vec![&test.env, amount_in, first_hop, second_hop]);
+
+    let balance_0_before = test.token_0.balance(&test.user);
+    let balance_2_before = token_2.balance(&test.user);
+    let amounts = test.contract.swap_exact_tokens_mixed(&amount_in, &second_hop, &path, &pool_types, &test.user, &1000);
+
+    assert_eq!(amounts, vec![&test.env, amount_in, first_hop, second_hop]);
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before - amount_in);
+    assert_eq!(token_2.balance(&test.user), balance_2_before + second_hop);
+    assert_eq!(stable_pair.get_reserves(), (1_000_000_000 + amount_in, 1_000_000_000 - first_hop));
+}
+
+#[test]
+fn swap_exact_tokens_mixed_stable_last() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, stable_pair) = setup_mixed_pools(&test);
+    let path: Vec<Address> = vec![&test.env, token_2.address.clone(), test.token_1.address.clone(), test.token_0.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::ConstantProduct, PoolType::Stable];
+
+    let amounts = test.contract.router_get_amounts_out_mixed(&20_000_000, &path, &pool_types);
+    let balance_0_before = test.token_0.balance(&test.user);
+    test.contract.swap_exact_tokens_mixed(&20_000_000, &0, &path, &pool_types, &test.user, &1000);
+
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before + amounts.get(2).unwrap());
+    assert_eq!(stable_pair.get_reserves(), (1_000_000_000 - amounts.get(2).unwrap(), 1_000_000_000 + amounts.get(1).unwrap()));
+}
+
+#[test]
+fn swap_tokens_for_exact_mixed() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, _stable_pair) = setup_mixed_pools(&test);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Stable, PoolType::ConstantProduct];
+
+    let amount_out = 5_000_000;
+    let expected_amounts = test.contract.router_get_amounts_in_mixed(&amount_out, &path, &pool_types);
+
+    let result = test.contract.try_swap_tokens_for_exact_mixed(&amount_out, &(expected_amounts.first().unwrap() - 1), &path, &pool_types, &test.user, &1000);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterExcessiveInputAmount)));
+
+    let balance_0_before = test.token_0.balance(&test.user);
+    let balance_2_before = token_2.balance(&test.user);
+    let amounts = test.contract.swap_tokens_for_exact_mixed(&amount_out, &expected_amounts.first().unwrap(), &path, &pool_types, &test.user, &1000);
+
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before - amounts.first().unwrap());
+    assert_eq!(token_2.balance(&test.user), balance_2_before + amount_out);
+}
+
+// An 80/20 weighted pair of token_2 and token_0 next to the pools of setup_mixed_pools, with token_2 weighing 80%
+fn setup_weighted_pool<'a>(test: &SoroswapRouterTest<'a>, token_2: &TokenClient<'a>) -> SoroswapWeightedPairClient<'a> {
+    test.factory.set_weighted_pair_wasm_hash(&weighted_pair_contract_wasm(&test.env));
+    let weighted_pair = SoroswapWeightedPairClient::new(&test.env, &test.factory.create_weighted_pair(&token_2.address, &test.token_0.address, &8000));
+    token_2.transfer(&test.user, &weighted_pair.address, &4_000_000_000);
+    test.token_0.transfer(&test.user, &weighted_pair.address, &1_000_000_000);
+    weighted_pair.deposit(&test.user);
+    weighted_pair
+}
+
+#[test]
+fn swap_exact_tokens_mixed_through_weighted_pair() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, _stable_pair) = setup_mixed_pools(&test);
+    let weighted_pair = setup_weighted_pool(&test, &token_2);
+    // Around the path token_0 -> token_1 -> token_2 -> token_0 through the three curves
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone(), test.token_0.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Stable, PoolType::ConstantProduct, PoolType::Weighted];
+
+    let amount_in = 10_000_000;
+    let amounts = test.contract.router_get_amounts_out_mixed(&amount_in, &path, &pool_types);
+    let expected_weights = if token_2.address < test.token_0.address { (8000, 2000) } else { (2000, 8000) };
+    assert_eq!(weighted_pair.weights(), expected_weights);
+    let last_hop = soroswap_library::get_weighted_amount_out(amounts.get(2).unwrap(), 4_000_000_000, 1_000_000_000, 8000, 30).unwrap();
+    assert_eq!(amounts.get(3).unwrap(), last_hop);
+
+    let balance_0_before = test.token_0.balance(&test.user);
+    test.contract.swap_exact_tokens_mixed(&amount_in, &last_hop, &path, &pool_types, &test.user, &1000);
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before - amount_in + last_hop);
+}
+
+#[test]
+fn swap_tokens_for_exact_mixed_through_weighted_pair() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, _stable_pair) = setup_mixed_pools(&test);
+    setup_weighted_pool(&test, &token_2);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), token_2.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Weighted];
+
+    let amount_out = 50_000_000;
+    let expected_amounts = test.contract.router_get_amounts_in_mixed(&amount_out, &path, &pool_types);
+    let amount_in = soroswap_library::get_weighted_amount_in(amount_out, 1_000_000_000, 4_000_000_000, 2000, 30).unwrap();
+    assert_eq!(expected_amounts, vec![&test.env, amount_in, amount_out]);
+
+    let balance_2_before = token_2.balance(&test.user);
+    test.contract.swap_tokens_for_exact_mixed(&amount_out, &amount_in, &path, &pool_types, &test.user, &1000);
+    assert_eq!(token_2.balance(&test.user), balance_2_before + amount_out);
+}
+
+// A concentrated pool of token_2 and token_0 in the 0.3% tier next to the pools of setup_mixed_pools, at a price of 1
+// with its liquidity between the ticks -600 and 600
+fn setup_concentrated_pool<'a>(test: &SoroswapRouterTest<'a>, token_2: &TokenClient<'a>) -> SoroswapConcentratedPoolClient<'a> {
+    test.factory.set_concentrated_pool_wasm_hash(&concentrated_pool_contract_wasm(&test.env));
+    let pool = SoroswapConcentratedPoolClient::new(&test.env, &test.factory.create_concentrated_pool(&token_2.address, &test.token_0.address, &30));
+    pool.initialize_price(&(1 << 64));
+    pool.mint(&test.user, &-600, &600, &100_000_000_000, &i128::MAX, &i128::MAX);
+    pool
+}
+
+#[test]
+fn swap_exact_tokens_mixed_through_concentrated_pool() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, _stable_pair) = setup_mixed_pools(&test);
+    let pool = setup_concentrated_pool(&test, &token_2);
+    // Around the path token_0 -> token_1 -> token_2 -> token_0, the last hop in the concentrated pool
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone(), token_2.address.clone(), test.token_0.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Stable, PoolType::ConstantProduct, PoolType::Concentrated(30)];
+
+    let amount_in = 10_000_000;
+    let amounts = test.contract.router_get_amounts_out_mixed(&amount_in, &path, &pool_types);
+    let last_hop = pool.quote_exact_input(&(token_2.address < test.token_0.address), &amounts.get(2).unwrap());
+    assert_eq!(amounts.get(3).unwrap(), last_hop);
+
+    let (reserve_0, reserve_1) = pool.get_reserves();
+    let balance_0_before = test.token_0.balance(&test.user);
+    test.contract.swap_exact_tokens_mixed(&amount_in, &last_hop, &path, &pool_types, &test.user, &1000);
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before - amount_in + last_hop);
+    // All the reserves are accounted for, there is nothing to skim
+    if token_2.address < test.token_0.address {
+        assert_eq!(pool.get_reserves(), (reserve_0 + amounts.get(2).unwrap(), reserve_1 - last_hop));
+    } else {
+        assert_eq!(pool.get_reserves(), (reserve_0 - last_hop, reserve_1 + amounts.get(2).unwrap()));
+    }
+}
+
+#[test]
+fn swap_tokens_for_exact_mixed_through_concentrated_pool() {
+    let test = SoroswapRouterTest::setup();
+    let (token_2, _stable_pair) = setup_mixed_pools(&test);
+    let pool = setup_concentrated_pool(&test, &token_2);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), token_2.address.clone()];
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Concentrated(30)];
+
+    let amount_out = 50_000_000;
+    let expected_amounts = test.contract.router_get_amounts_in_mixed(&amount_out, &path, &pool_types);
+    let amount_in = pool.quote_exact_output(&(test.token_0.address < token_2.address), &amount_out);
+    assert_eq!(expected_amounts, vec![&test.env, amount_in, amount_out]);
+
+    let balance_2_before = token_2.balance(&test.user);
+    test.contract.swap_tokens_for_exact_mixed(&amount_out, &amount_in, &path, &pool_types, &test.user, &1000);
+    assert_eq!(token_2.balance(&test.user), balance_2_before + amount_out);
+
+    // Without a pool in the fee tier the path has no liquidity
+    let pool_types: Vec<PoolType> = vec![&test.env, PoolType::Concentrated(100)];
+    let result = test.contract.try_router_get_amounts_in_mixed(&amount_out, &path, &pool_types);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInsufficientLiquidity)));
+}
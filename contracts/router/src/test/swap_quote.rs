@@ -0,0 +1,50 @@
+use soroban_sdk::{Address, vec, Vec};
+
+use crate::test::SoroswapRouterTest;
+use crate::error::CombinedRouterError;
+
+#[test]
+fn router_get_swap_quote_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    let result = test.contract.try_router_get_swap_quote(&10_000, &path);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn router_get_swap_quote_invalid_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
+
+    let result = test.contract.try_router_get_swap_quote(&10_000, &path);
+    assert_eq!(result, Err(Ok(CombinedRouterError::LibraryInvalidPath)));
+}
+
+#[test]
+fn router_get_swap_quote() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    test.contract.add_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000_000,
+        &2_000_000,
+        &0,
+        &0,
+        &test.user,
+        &deadline,
+    );
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    let quote = test.contract.router_get_swap_quote(&10_000, &path);
+
+    assert_eq!(quote.amounts, test.contract.router_get_amounts_out(&10_000, &path));
+    assert_eq!(quote.amounts, vec![&test.env, 10_000, 19_743]);
+    assert_eq!(quote.mid_price, 2_000_000_000_000_000_000);
+    assert_eq!(quote.execution_price, 1_974_300_000_000_000_000);
+    assert_eq!(quote.price_impact, 128);
+    assert_eq!(quote.fee_amounts, vec![&test.env, 30]);
+}
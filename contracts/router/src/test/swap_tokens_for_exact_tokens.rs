@@ -29,7 +29,7 @@ fn swap_tokens_for_exact_tokens_not_initialized() {
 fn swap_tokens_for_exact_tokens_amount_out_negative() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let path: Vec<Address> = Vec::new(&test.env);
 
     let result = test.contract.try_swap_tokens_for_exact_tokens(
@@ -50,7 +50,7 @@ fn swap_tokens_for_exact_tokens_amount_out_negative() {
 fn swap_tokens_for_exact_tokens_amount_in_max_negative() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let path: Vec<Address> = Vec::new(&test.env);
 
     let result = test.contract.try_swap_tokens_for_exact_tokens(
@@ -70,7 +70,7 @@ fn swap_tokens_for_exact_tokens_amount_in_max_negative() {
 #[test]
 fn swap_tokens_for_exact_tokens_expired() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let path: Vec<Address> = Vec::new(&test.env);
 
     let result = test.contract.try_swap_tokens_for_exact_tokens(
@@ -88,7 +88,7 @@ fn swap_tokens_for_exact_tokens_expired() {
 #[test]
 fn try_swap_tokens_for_exact_tokens_invalid_path() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;
     let path: Vec<Address> = vec![&test.env, test.token_0.address.clone()];
 
@@ -109,7 +109,7 @@ fn try_swap_tokens_for_exact_tokens_invalid_path() {
 #[should_panic]
 fn swap_tokens_for_exact_tokens_pair_does_not_exist() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;  
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -128,7 +128,7 @@ fn swap_tokens_for_exact_tokens_pair_does_not_exist() {
 #[test]
 fn try_swap_tokens_for_exact_tokens_insufficient_output_amount() {
     let test = SoroswapRouterTest::setup();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -155,7 +155,7 @@ fn try_swap_tokens_for_exact_tokens_insufficient_output_amount() {
 fn swap_tokens_for_exact_tokens_amount_in_max_not_enough() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -187,7 +187,7 @@ fn swap_tokens_for_exact_tokens_amount_in_max_not_enough() {
 fn swap_tokens_for_exact_tokens_amount_in_max_not_enough_amount_in_should_minus_1() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -225,7 +225,7 @@ fn swap_tokens_for_exact_tokens_amount_in_max_not_enough_amount_in_should_minus_
 fn swap_tokens_for_exact_tokens_amount_in_should() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let deadline: u64 = test.env.ledger().timestamp() + 1000;  
 
     let mut path: Vec<Address> = Vec::new(&test.env);
@@ -267,7 +267,7 @@ fn swap_tokens_for_exact_tokens_amount_in_should() {
 fn swap_tokens_for_exact_tokens() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
 
     let amount_0: i128 = 1_000_000_000_000_000_000;
     let amount_1: i128 = 4_000_000_000_000_000_000;
@@ -318,7 +318,7 @@ fn swap_tokens_for_exact_tokens() {
 fn swap_tokens_for_exact_tokens_2_hops() {
     let test = SoroswapRouterTest::setup();
     test.env.budget().reset_unlimited();
-    test.contract.initialize(&test.factory.address);
+    test.contract.initialize(&test.factory.address, &test.native);
     let ledger_timestamp = 100;
     let desired_deadline = 1000;
     assert!(desired_deadline > ledger_timestamp);
@@ -403,3 +403,34 @@ fn swap_tokens_for_exact_tokens_2_hops() {
     assert_eq!(test.token_1.balance(&pair_address_1_2), amount_1 + middle_amount_in);
     assert_eq!(token_2.balance(&pair_address_1_2), amount_2 - expected_amount_out);
 }
+
+#[test]
+fn swap_tokens_for_exact_tokens_pair_fee_tier() {
+    let test = SoroswapRouterTest::setup();
+    test.env.budget().reset_unlimited();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;  
+
+    // Pair with a 1% swap fee
+    test.factory.create_pair_with_fee(&test.token_0.address, &test.token_1.address, &100);
+
+    let amount_0: i128 = 1_000_000_000;
+    let amount_1: i128 = 4_000_000_000;
+    add_liquidity(&test, &amount_0, &amount_1);
+
+    let path: Vec<Address> = vec![&test.env, test.token_0.address.clone(), test.token_1.address.clone()];
+
+    let expected_amount_out = 400_000_000;
+    // (1000000000*400000000*10000)/((4000000000-400000000)*9900) + 1 = 112233446
+    let amount_in_should = 112233446;
+
+    let executed_amounts = test.contract.swap_tokens_for_exact_tokens(
+        &expected_amount_out, //amount_out
+        &amount_in_should,  // amount_in_max
+        &path, // path
+        &test.user, // to
+        &deadline); // deadline
+
+    assert_eq!(executed_amounts.get(0).unwrap(), amount_in_should);
+    assert_eq!(executed_amounts.get(1).unwrap(), expected_amount_out);
+}
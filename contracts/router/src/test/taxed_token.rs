@@ -0,0 +1,48 @@
+//! Minimal token that burns a fixed share of every transfer, used to test the fee-on-transfer router functions.
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+// 1% of every transferred amount is burned
+pub const TAX_BPS: i128 = 100;
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Balance(Address),
+}
+
+fn read_balance(e: &Env, id: &Address) -> i128 {
+    e.storage().persistent().get(&DataKey::Balance(id.clone())).unwrap_or(0)
+}
+
+fn write_balance(e: &Env, id: &Address, amount: i128) {
+    e.storage().persistent().set(&DataKey::Balance(id.clone()), &amount);
+}
+
+/// Amount received when `amount` is transferred
+pub fn after_tax(amount: i128) -> i128 {
+    amount - amount * TAX_BPS / 10000
+}
+
+#[contract]
+pub struct TaxedToken;
+
+#[contractimpl]
+impl TaxedToken {
+    pub fn mint(e: Env, to: Address, amount: i128) {
+        write_balance(&e, &to, read_balance(&e, &to) + amount);
+    }
+
+    pub fn balance(e: Env, id: Address) -> i128 {
+        read_balance(&e, &id)
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let from_balance = read_balance(&e, &from);
+        if from_balance < amount {
+            panic!("insufficient balance");
+        }
+        write_balance(&e, &from, from_balance - amount);
+        write_balance(&e, &to, read_balance(&e, &to) + after_tax(amount));
+    }
+}
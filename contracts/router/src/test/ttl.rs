@@ -0,0 +1,40 @@
+use soroban_sdk::testutils::Ledger;
+
+use crate::error::CombinedRouterError;
+use crate::storage::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD};
+use crate::test::SoroswapRouterTest;
+
+fn advance_ledgers(test: &SoroswapRouterTest, ledgers: u32) {
+    test.env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+    });
+}
+
+#[test]
+fn extend_ttl_not_yet_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_extend_ttl();
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+#[should_panic]
+fn router_archived_without_extending_ttl() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+
+    advance_ledgers(&test, INSTANCE_BUMP_AMOUNT + 1);
+    test.contract.extend_ttl();
+}
+
+#[test]
+fn extend_ttl_keeps_router_alive() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+
+    for _ in 0..10 {
+        advance_ledgers(&test, INSTANCE_LIFETIME_THRESHOLD);
+        test.contract.extend_ttl();
+    }
+    assert_eq!(test.factory.address, test.contract.get_factory());
+}
@@ -0,0 +1,67 @@
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    IntoVal,
+    symbol_short,
+};
+
+use crate::error::CombinedRouterError;
+use crate::event::UpgradeEvent;
+use crate::test::{SoroswapRouterTest, router_contract_wasm};
+
+#[test]
+fn upgrade_not_yet_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let new_wasm_hash = router_contract_wasm(&test.env);
+    let result = test.contract.try_upgrade(&new_wasm_hash);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn upgrade_keeps_factory() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let new_wasm_hash = router_contract_wasm(&test.env);
+
+    test.contract.upgrade(&new_wasm_hash);
+    let upgrade_event = test.env.events().all().last().unwrap();
+    let expected_upgrade_event: UpgradeEvent = UpgradeEvent {
+        new_wasm_hash: new_wasm_hash.clone(),
+    };
+    assert_eq!(
+        vec![&test.env, upgrade_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapRouter", symbol_short!("upgrade")).into_val(&test.env),
+                (expected_upgrade_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The Wasm router serves the same storage
+    assert_eq!(test.factory.address, test.contract.get_factory());
+    let result = test.contract.try_initialize(&test.factory.address, &test.native);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInitializeAlreadyInitialized)));
+}
+
+#[test]
+#[should_panic]
+fn upgrade_not_fee_to_setter() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let new_wasm_hash = router_contract_wasm(&test.env);
+
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &test.user,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+}
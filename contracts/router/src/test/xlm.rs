@@ -0,0 +1,191 @@
+use soroban_sdk::{Address, vec, Vec};
+
+use crate::test::{SoroswapRouterTest, TokenClient};
+use crate::error::CombinedRouterError;
+
+fn native_client<'a>(test: &SoroswapRouterTest<'a>) -> TokenClient<'a> {
+    let native = TokenClient::new(&test.env, &test.native);
+    native.mint(&test.user, &10_000_000_000_000_000_000);
+    native
+}
+
+fn add_liquidity_xlm(test: &SoroswapRouterTest, amount_token: &i128, amount_xlm: &i128) -> (i128, i128, i128) {
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    test.contract.add_liquidity_xlm(
+        &test.token_0.address, // token: Address,
+        amount_token,          // amount_token_desired: i128,
+        amount_xlm,            // amount_xlm_desired: i128,
+        &0,                    // amount_token_min: i128,
+        &0,                    // amount_xlm_min: i128,
+        &test.user,            // to: Address,
+        &deadline              // deadline: u64,
+    )
+}
+
+#[test]
+fn xlm_functions_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let path: Vec<Address> = Vec::new(&test.env);
+
+    let result = test.contract.try_add_liquidity_xlm(&test.token_0.address, &0, &0, &0, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+    let result = test.contract.try_remove_liquidity_xlm(&test.token_0.address, &0, &0, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+    let result = test.contract.try_swap_exact_xlm_for_tokens(&0, &0, &path, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+    let result = test.contract.try_swap_tokens_for_exact_xlm(&0, &0, &path, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn add_liquidity_xlm_creates_pair() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let native = native_client(&test);
+
+    let amount_token: i128 = 1_000_000_000;
+    let amount_xlm: i128 = 4_000_000_000;
+    let (added_token, added_xlm, liquidity) = add_liquidity_xlm(&test, &amount_token, &amount_xlm);
+
+    assert_eq!(added_token, amount_token);
+    assert_eq!(added_xlm, amount_xlm);
+    // sqrt(1_000_000_000 * 4_000_000_000) - 1000
+    assert_eq!(liquidity, 2_000_000_000 - 1000);
+
+    let pair = test.factory.get_pair(&test.token_0.address, &test.native);
+    assert_eq!(test.contract.router_pair_for(&test.token_0.address, &test.native), pair);
+    assert_eq!(native.balance(&pair), amount_xlm);
+    assert_eq!(test.token_0.balance(&pair), amount_token);
+    assert_eq!(native.balance(&test.user), 10_000_000_000_000_000_000 - amount_xlm);
+}
+
+#[test]
+fn remove_liquidity_xlm() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let native = native_client(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let (_, _, liquidity) = add_liquidity_xlm(&test, &1_000_000_000, &4_000_000_000);
+    let token_balance_before = test.token_0.balance(&test.user);
+    let xlm_balance_before = native.balance(&test.user);
+
+    let (amount_token, amount_xlm) = test.contract.remove_liquidity_xlm(
+        &test.token_0.address, // token: Address,
+        &liquidity,            // liquidity: i128,
+        &0,                    // amount_token_min: i128,
+        &0,                    // amount_xlm_min: i128,
+        &test.user,            // to: Address,
+        &deadline              // deadline: u64,
+    );
+
+    // Minimum liquidity stays in the pool
+    assert_eq!(amount_token, 1_000_000_000 - 500);
+    assert_eq!(amount_xlm, 4_000_000_000 - 2000);
+    assert_eq!(test.token_0.balance(&test.user), token_balance_before + amount_token);
+    assert_eq!(native.balance(&test.user), xlm_balance_before + amount_xlm);
+}
+
+#[test]
+fn remove_liquidity_xlm_insufficient_xlm_amount() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    native_client(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+
+    let (_, _, liquidity) = add_liquidity_xlm(&test, &1_000_000_000, &4_000_000_000);
+
+    let result = test.contract.try_remove_liquidity_xlm(
+        &test.token_0.address,
+        &liquidity,
+        &0,
+        &4_000_000_000,
+        &test.user,
+        &deadline,
+    );
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientBAmount)));
+}
+
+#[test]
+fn swap_exact_xlm_for_tokens() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let native = native_client(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    add_liquidity_xlm(&test, &1_000_000_000, &4_000_000_000);
+
+    let path = vec![&test.env, test.native.clone(), test.token_0.address.clone()];
+    let amount_in: i128 = 1_000_000;
+    let expected_amounts = test.contract.router_get_amounts_out(&amount_in, &path);
+    let token_balance_before = test.token_0.balance(&test.user);
+    let xlm_balance_before = native.balance(&test.user);
+
+    let amounts = test.contract.swap_exact_xlm_for_tokens(
+        &amount_in,                          // amount_in
+        &expected_amounts.get(1).unwrap(),   // amount_out_min
+        &path,                               // path
+        &test.user,                          // to
+        &deadline);                          // deadline
+
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(native.balance(&test.user), xlm_balance_before - amount_in);
+    assert_eq!(test.token_0.balance(&test.user), token_balance_before + amounts.get(1).unwrap());
+}
+
+#[test]
+fn swap_exact_xlm_for_tokens_invalid_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    native_client(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    add_liquidity_xlm(&test, &1_000_000_000, &4_000_000_000);
+
+    // XLM is the output instead of the input
+    let path = vec![&test.env, test.token_0.address.clone(), test.native.clone()];
+    let result = test.contract.try_swap_exact_xlm_for_tokens(&1_000_000, &0, &path, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidPath)));
+
+    let path: Vec<Address> = Vec::new(&test.env);
+    let result = test.contract.try_swap_exact_xlm_for_tokens(&1_000_000, &0, &path, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidPath)));
+}
+
+#[test]
+fn swap_tokens_for_exact_xlm() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let native = native_client(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    add_liquidity_xlm(&test, &1_000_000_000, &4_000_000_000);
+
+    let path = vec![&test.env, test.token_0.address.clone(), test.native.clone()];
+    let amount_out: i128 = 4_000_000;
+    let expected_amounts = test.contract.router_get_amounts_in(&amount_out, &path);
+    let token_balance_before = test.token_0.balance(&test.user);
+    let xlm_balance_before = native.balance(&test.user);
+
+    let amounts = test.contract.swap_tokens_for_exact_xlm(
+        &amount_out,                         // amount_out
+        &expected_amounts.get(0).unwrap(),   // amount_in_max
+        &path,                               // path
+        &test.user,                          // to
+        &deadline);                          // deadline
+
+    assert_eq!(amounts, expected_amounts);
+    assert_eq!(native.balance(&test.user), xlm_balance_before + amount_out);
+    assert_eq!(test.token_0.balance(&test.user), token_balance_before - amounts.get(0).unwrap());
+}
+
+#[test]
+fn swap_tokens_for_exact_xlm_invalid_path() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    native_client(&test);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    add_liquidity_xlm(&test, &1_000_000_000, &4_000_000_000);
+
+    // XLM is the input instead of the output
+    let path = vec![&test.env, test.native.clone(), test.token_0.address.clone()];
+    let result = test.contract.try_swap_tokens_for_exact_xlm(&1_000_000, &i128::MAX, &path, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInvalidPath)));
+}
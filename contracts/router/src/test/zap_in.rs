@@ -0,0 +1,149 @@
+use soroban_sdk::{Address, testutils::Address as _};
+
+use crate::test::{SoroswapRouterTest, SoroswapPairClient};
+use crate::error::CombinedRouterError;
+
+fn add_liquidity(test: &SoroswapRouterTest) -> SoroswapPairClient<'static> {
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    test.contract.add_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &1_000_000_000,
+        &4_000_000_000,
+        &0,
+        &0,
+        &test.user,
+        &deadline,
+    );
+    SoroswapPairClient::new(&test.env, &test.factory.get_pair(&test.token_0.address, &test.token_1.address))
+}
+
+#[test]
+fn zap_in_not_initialized() {
+    let test = SoroswapRouterTest::setup();
+    let result = test.contract.try_zap_in(&test.token_0.address, &0, &test.token_1.address, &0, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNotInitialized)));
+}
+
+#[test]
+fn zap_in_amount_in_negative() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let result = test.contract.try_zap_in(&test.token_0.address, &-1, &test.token_1.address, &0, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterNegativeNotAllowed)));
+}
+
+#[test]
+fn zap_in_deadline_expired() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let result = test.contract.try_zap_in(&test.token_0.address, &1_000_000, &test.token_1.address, &0, &0, &test.user, &0);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterDeadlineExpired)));
+}
+
+#[test]
+fn zap_in_pair_does_not_exist() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let other_token = Address::generate(&test.env);
+
+    let result = test.contract.try_zap_in(&test.token_0.address, &1_000_000, &other_token, &0, &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterPairDoesNotExist)));
+}
+
+#[test]
+fn zap_in() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    let pair = add_liquidity(&test);
+    let token_0_balance_before = test.token_0.balance(&test.user);
+    let token_1_balance_before = test.token_1.balance(&test.user);
+    let lp_balance_before = pair.balance(&test.user);
+
+    let amount_in: i128 = 100_000_000;
+    let amount_out = test.contract.router_get_amount_out(&48_000_000, &1_000_000_000, &4_000_000_000);
+    let (swap_amount, amount_0, amount_1, liquidity) = test.contract.zap_in(
+        &test.token_0.address, //     token_in: Address,
+        &amount_in,            //     amount_in: i128,
+        &test.token_1.address, //     token_other: Address,
+        &amount_out,           //     amount_out_min_swap: i128,
+        &0,                    //     min_liquidity: i128,
+        &test.user,            //     to: Address,
+        &deadline,             //     deadline: u64,
+    );
+
+    // A bit less than half, as the pool ratio moves with the swap
+    assert!(swap_amount > 48_000_000 && swap_amount < 50_000_000);
+    // Everything is deposited but rounding dust, that stays with the user
+    assert!(amount_in - swap_amount - amount_0 <= 4);
+    let swap_output = test.token_1.balance(&test.user) - token_1_balance_before + amount_1;
+    assert!(swap_output - amount_1 <= 4);
+    assert_eq!(test.token_0.balance(&test.user), token_0_balance_before - swap_amount - amount_0);
+    assert_eq!(pair.balance(&test.user), lp_balance_before + liquidity);
+    assert_eq!(test.token_0.balance(&pair.address), 1_000_000_000 + swap_amount + amount_0);
+    assert_eq!(test.token_1.balance(&pair.address), 4_000_000_000 - swap_output + amount_1);
+    // The router does not keep any token
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn zap_in_insufficient_output_amount() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    add_liquidity(&test);
+
+    let result = test.contract.try_zap_in(&test.token_0.address, &100_000_000, &test.token_1.address, &200_000_000, &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientOutputAmount)));
+}
+
+#[test]
+fn zap_in_insufficient_liquidity() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    add_liquidity(&test);
+
+    // Half of the input gives about 5% of the pool, so 10% of the pool liquidity can not be minted
+    let result = test.contract.try_zap_in(&test.token_0.address, &100_000_000, &test.token_1.address, &0, &200_000_000, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientLiquidity)));
+}
+
+#[test]
+fn zap_in_empty_reserves() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    test.factory.create_pair(&test.token_0.address, &test.token_1.address);
+
+    let result = test.contract.try_zap_in(&test.token_0.address, &0, &test.token_1.address, &0, &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientLiquidity)));
+    let result = test.contract.try_zap_in(&test.token_0.address, &100_000_000, &test.token_1.address, &0, &0, &test.user, &deadline);
+    assert_eq!(result, Err(Ok(CombinedRouterError::RouterInsufficientLiquidity)));
+}
+
+#[test]
+fn zap_in_large_reserves() {
+    let test = SoroswapRouterTest::setup();
+    test.contract.initialize(&test.factory.address, &test.native);
+    let deadline: u64 = test.env.ledger().timestamp() + 1000;
+    test.contract.add_liquidity(
+        &test.token_0.address,
+        &test.token_1.address,
+        &10_000_000_000_000_000,
+        &10_000_000_000_000_000,
+        &0,
+        &0,
+        &test.user,
+        &deadline,
+    );
+
+    // `reserve_in^2 * (10000 + c)^2` does not fit in an i128
+    let amount_in: i128 = 1_000_000_000_000_000;
+    let (swap_amount, amount_0, _, _) = test.contract.zap_in(&test.token_0.address, &amount_in, &test.token_1.address, &0, &0, &test.user, &deadline);
+    assert!(swap_amount > 480_000_000_000_000 && swap_amount < 500_000_000_000_000);
+    assert!(amount_in - swap_amount - amount_0 <= 4);
+}
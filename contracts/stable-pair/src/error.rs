@@ -0,0 +1,76 @@
+use soroban_sdk::{self, contracterror};
+use soroswap_library::SoroswapLibraryError;
+use soroswap_pool_common::PoolError;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapStablePairError {
+    /// SoroswapStablePair: already initialized
+    InitializeAlreadyInitialized = 801,
+    /// SoroswapStablePair: not yet initialized
+    NotInitialized = 802,
+
+    /// SoroswapStablePair: token_0 must be less than token_1 while initializing
+    InitializeTokenOrderInvalid = 803,
+    /// SoroswapStablePair: fee must be less than 10000 basis points while initializing
+    InitializeFeeInvalid = 804,
+    /// SoroswapStablePair: amplification coefficient must be between 1 and MAX_AMP while initializing
+    InitializeAmpInvalid = 805,
+
+    /// SoroswapStablePair: insufficient amount of token 0 sent while doing deposit
+    DepositInsufficientAmountToken0 = 806,
+    /// SoroswapStablePair: insufficient amount of token 1 sent while doing deposit
+    DepositInsufficientAmountToken1 = 807,
+    /// SoroswapStablePair: insufficient first liquidity minted while doing deposit
+    DepositInsufficientFirstLiquidity = 808,
+    /// SoroswapStablePair: insufficient liquidity minted while doing deposit
+    DepositInsufficientLiquidityMinted = 809,
+
+    /// SoroswapStablePair: insufficient output amount while doing swap
+    SwapInsufficientOutputAmount = 810,
+    /// SoroswapStablePair: negatives amounts out dont supported while doing swap
+    SwapNegativesOutNotSupported = 811,
+    /// SoroswapStablePair: insufficient liquidity to do the swap
+    SwapInsufficientLiquidity = 812,
+    /// SoroswapStablePair: invalid to to do the swap
+    SwapInvalidTo = 813,
+    /// SoroswapStablePair: insufficient input amount while doing swap
+    SwapInsufficientInputAmount = 814,
+    /// SoroswapStablePair: negatives amounts in dont supported while doing swap
+    SwapNegativesInNotSupported = 815,
+    /// SoroswapStablePair: the stable invariant is not met while doing swap
+    SwapInvariantNotMet = 816,
+
+    /// SoroswapStablePair: liquidity was not initialized yet while doing withdraw
+    WithdrawLiquidityNotInitialized = 817,
+    /// SoroswapStablePair: insufficient sent shares while doing withdraw
+    WithdrawInsufficientSentShares = 818,
+    /// SoroswapStablePair: insufficient liquidity burned while doing withdraw
+    WithdrawInsufficientLiquidityBurned = 819,
+
+    /// SoroswapStablePair: the stable invariant could not be computed for the balances
+    InvariantFailed = 820,
+
+    /// SoroswapStablePair: locked, nested calls are not allowed
+    Locked = 821,
+
+    /// SoroswapStablePair: storage version is newer than the contract version while doing migrate
+    MigrateVersionInvalid = 822,
+}
+
+// The invariant of the pair is computed by the SoroswapLibrary, so any of its errors means it could not be computed
+impl From<SoroswapLibraryError> for SoroswapStablePairError {
+    fn from(_: SoroswapLibraryError) -> Self {
+        SoroswapStablePairError::InvariantFailed
+    }
+}
+
+impl From<PoolError> for SoroswapStablePairError {
+    fn from(pool_error: PoolError) -> Self {
+        match pool_error {
+            PoolError::Locked => SoroswapStablePairError::Locked,
+            PoolError::MigrateVersionInvalid => SoroswapStablePairError::MigrateVersionInvalid,
+        }
+    }
+}
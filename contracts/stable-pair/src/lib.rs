@@ -0,0 +1,540 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Bytes, BytesN, Env, IntoVal};
+use soroban_sdk::token::Interface;
+use soroswap_factory_interface::SoroswapFactoryClient;
+use soroswap_callee_interface::SoroswapCalleeClient;
+
+mod storage;
+mod error;
+mod test;
+
+use storage::*;
+use soroswap_pool_common::{balances::*, migration, pool::*, storage::*, event::PoolEvents};
+use soroswap_pair_token::SoroswapPairToken;
+use error::SoroswapStablePairError;
+use soroswap_library::{get_stable_d, get_stable_y, MAX_AMP};
+
+static MINIMUM_LIQUIDITY: i128 = 1000;
+// Swap fees are expressed in basis points
+static FEE_DENOMINATOR: i128 = 10000;
+// Name of the contract in the first topic of its events
+static POOL_NAME: &str = "SoroswapStablePair";
+// Version of the storage layout, see soroswap_pool_common::migration
+const CONTRACT_VERSION: u32 = 1;
+
+// Metadata that is added on to the WASM custom section
+contractmeta!(
+    key = "Description",
+    val = "StableSwap AMM with an amplification coefficient and a configurable swap fee"
+);
+
+pub trait SoroswapStablePairTrait {
+    // Sets the token contract addresses, the swap fee (in basis points) and the amplification coefficient for this pool
+    fn initialize_pair(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32, amp: u32) -> Result<(), SoroswapStablePairError>;
+
+    fn deposit(e: Env, to: Address) -> Result<i128, SoroswapStablePairError>;
+
+    // Swaps. This function should be called from another contract that has already sent tokens to the pair contract,
+    // unless a non-empty data is given: then `to` is called back to pay for the tokens (flash swap)
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapStablePairError>;
+
+    fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapStablePairError>;
+
+    // Transfers the excess token balances from the pair to the specified to address
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapStablePairError>;
+
+    // Updates the reserves of the pair to match the current token balances
+    fn sync(e: Env) -> Result<(), SoroswapStablePairError>;
+
+    fn token_0(e: Env) -> Address;
+    fn token_1(e: Env) -> Address;
+    fn factory(e: Env) -> Address;
+    fn fee(e: Env) -> u32;
+    fn amp(e: Env) -> u32;
+
+    fn d_last(e: Env) -> i128;
+
+    fn get_reserves(e: Env) -> (i128, i128);
+
+    // Extends the TTL of the pair instance and code, so the pool does not get archived. Anyone can call it.
+    fn extend_pair_ttl(e: Env) -> Result<(), SoroswapStablePairError>;
+
+    // Replaces the code of the stable pair. Only the factory can call it, and it must call `migrate` afterwards.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapStablePairError>;
+
+    // Runs the pending storage migrations after an upgrade. Only the factory can call it.
+    fn migrate(e: Env) -> Result<u32, SoroswapStablePairError>;
+
+    // Returns the version of the storage layout of the stable pair.
+    fn version(e: Env) -> u32;
+
+    fn my_balance(e: Env, id: Address) -> i128;
+    fn total_shares(e: Env) -> i128;
+}
+
+#[contract]
+struct SoroswapStablePair;
+
+#[contractimpl]
+impl SoroswapStablePairTrait for SoroswapStablePair {
+
+    /// Initializes a new Soroswap stable pair by setting token addresses, factory, swap fee and amplification coefficient.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `factory` - The address of the Soroswap factory contract.
+    /// * `token_0` - The address of the first token in the pair.
+    /// * `token_1` - The address of the second token in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    /// * `amp` - The amplification coefficient of the StableSwap invariant, between 1 and `MAX_AMP`.
+    fn initialize_pair(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32, amp: u32) -> Result<(), SoroswapStablePairError> {
+        if has_token_0(&e) {
+            return Err(SoroswapStablePairError::InitializeAlreadyInitialized);
+        }
+
+        if token_0 >= token_1 {
+            return Err(SoroswapStablePairError::InitializeTokenOrderInvalid);
+        }
+
+        if i128::from(fee) >= FEE_DENOMINATOR {
+            return Err(SoroswapStablePairError::InitializeFeeInvalid);
+        }
+
+        if amp == 0 || amp > MAX_AMP {
+            return Err(SoroswapStablePairError::InitializeAmpInvalid);
+        }
+
+        put_factory(&e, factory);
+
+        SoroswapPairToken::initialize(
+            e.clone(),
+            e.current_contract_address(),
+            7,
+            "Soroswap Stable LP Token".into_val(&e),
+            "SOROSWAP-SLP".into_val(&e),
+        );
+
+        put_token_0(&e, token_0);
+        put_token_1(&e, token_1);
+        put_fee(&e, fee);
+        put_amp(&e, amp);
+        put_total_shares(&e, 0);
+        put_reserve_0(&e, 0);
+        put_reserve_1(&e, 0);
+        put_version(&e, CONTRACT_VERSION);
+        extend_instance_ttl(&e);
+
+        Ok(())
+    }
+
+    /// Returns the address of the first token in the stable pair.
+    fn token_0(e: Env) -> Address {
+        get_token_0(&e)
+    }
+
+    /// Returns the address of the second token in the stable pair.
+    fn token_1(e: Env) -> Address {
+        get_token_1(&e)
+    }
+
+    /// Returns the address of the Soroswap factory contract.
+    fn factory(e: Env) -> Address {
+        get_factory(&e)
+    }
+
+    /// Returns the swap fee of the stable pair, in basis points.
+    fn fee(e: Env) -> u32 {
+        get_fee(&e)
+    }
+
+    /// Returns the amplification coefficient of the StableSwap invariant.
+    fn amp(e: Env) -> u32 {
+        get_amp(&e)
+    }
+
+    /// Deposits tokens into the stable pair and mints LP tokens in return.
+    /// The first deposit mints the invariant of the amounts, and later deposits mint LP tokens
+    /// in proportion to the reserves, as in the constant product pair.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the minted LP tokens will be sent.
+    ///
+    /// # Returns
+    /// The amount of minted LP tokens.
+    /// Possible errors:
+    /// - `SoroswapStablePairError::NotInitialized`
+    /// - `SoroswapStablePairError::DepositInsufficientAmountToken0`
+    /// - `SoroswapStablePairError::DepositInsufficientAmountToken1`
+    /// - `SoroswapStablePairError::DepositInsufficientFirstLiquidity`
+    /// - `SoroswapStablePairError::DepositInsufficientLiquidityMinted`
+    fn deposit(e: Env, to: Address) -> Result<i128, SoroswapStablePairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let amount_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapStablePairError::DepositInsufficientAmountToken0)?;
+        let amount_1 = balance_1.checked_sub(reserve_1).ok_or(SoroswapStablePairError::DepositInsufficientAmountToken1)?;
+
+        if amount_0 <= 0 {
+            return Err(SoroswapStablePairError::DepositInsufficientAmountToken0);
+        }
+
+        if amount_1 <= 0 {
+            return Err(SoroswapStablePairError::DepositInsufficientAmountToken1);
+        }
+
+        let fee_on = mint_fee(&e, reserve_0, reserve_1)?;
+        let total_shares = get_total_shares(&e);
+
+        let liquidity = if total_shares == 0 {
+            // When the liquidity pool is being initialized, we block the minimum liquidity forever in this contract
+            mint_shares(&e, &e.current_contract_address(), MINIMUM_LIQUIDITY);
+            let previous_liquidity = get_stable_d(&e, amount_0, amount_1, get_amp(&e))?;
+            if previous_liquidity <= MINIMUM_LIQUIDITY {
+                return Err(SoroswapStablePairError::DepositInsufficientFirstLiquidity);
+            }
+            previous_liquidity.checked_sub(MINIMUM_LIQUIDITY).unwrap()
+        } else {
+            let shares_0 = mul_div(&e, amount_0, total_shares, reserve_0);
+            let shares_1 = mul_div(&e, amount_1, total_shares, reserve_1);
+            shares_0.min(shares_1)
+        };
+
+        if liquidity <= 0 {
+            return Err(SoroswapStablePairError::DepositInsufficientLiquidityMinted);
+        }
+
+        mint_shares(&e, &to, liquidity);
+        update(&e, POOL_NAME, balance_0, balance_1);
+
+        (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        if fee_on {
+            put_dlast(&e, get_stable_d(&e, reserve_0, reserve_1, get_amp(&e))?);
+        }
+
+        PoolEvents::new(&e, POOL_NAME).deposit(to, amount_0, amount_1, liquidity, reserve_0, reserve_1);
+
+        unlock(&e);
+        Ok(liquidity)
+    }
+
+    /// Executes a token swap within the stable pair. The balances after the swap, without the swap fee,
+    /// must keep at least the StableSwap invariant of the reserves.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `amount_0_out` - The desired amount of the first token to receive.
+    /// * `amount_1_out` - The desired amount of the second token to receive.
+    /// * `to` - The address where the swapped tokens will be sent.
+    /// * `data` - If not empty, `soroswap_call` is invoked on `to` with this payload after the tokens have been sent,
+    ///   so `to` can pay for them before the invariant is checked (flash swap).
+    ///
+    /// # Errors
+    /// - `SoroswapStablePairError::NotInitialized`
+    /// - `SoroswapStablePairError::SwapInsufficientOutputAmount`
+    /// - `SoroswapStablePairError::SwapNegativesOutNotSupported`
+    /// - `SoroswapStablePairError::SwapInsufficientLiquidity`
+    /// - `SoroswapStablePairError::SwapInvalidTo`
+    /// - `SoroswapStablePairError::SwapInsufficientInputAmount`
+    /// - `SoroswapStablePairError::SwapNegativesInNotSupported`
+    /// - `SoroswapStablePairError::SwapInvariantNotMet`
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapStablePairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+
+        if amount_0_out == 0 && amount_1_out == 0 {
+            return Err(SoroswapStablePairError::SwapInsufficientOutputAmount);
+        }
+        if amount_0_out < 0 || amount_1_out < 0 {
+            return Err(SoroswapStablePairError::SwapNegativesOutNotSupported);
+        }
+        if amount_0_out >= reserve_0 || amount_1_out >= reserve_1 {
+            return Err(SoroswapStablePairError::SwapInsufficientLiquidity);
+        }
+        if to == get_token_0(&e) || to == get_token_1(&e) {
+            return Err(SoroswapStablePairError::SwapInvalidTo);
+        }
+
+        if amount_0_out > 0 {
+            transfer_token_0_from_pair(&e, &to, amount_0_out);
+        }
+        if amount_1_out > 0 {
+            transfer_token_1_from_pair(&e, &to, amount_1_out);
+        }
+        if !data.is_empty() {
+            SoroswapCalleeClient::new(&e, &to).soroswap_call(&e.current_contract_address(), &amount_0_out, &amount_1_out, &data);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+
+        let amount_0_in = if balance_0 > reserve_0.checked_sub(amount_0_out).unwrap() {
+            balance_0.checked_sub(reserve_0.checked_sub(amount_0_out).unwrap()).unwrap()
+        } else {
+            0
+        };
+        let amount_1_in = if balance_1 > reserve_1.checked_sub(amount_1_out).unwrap() {
+            balance_1.checked_sub(reserve_1.checked_sub(amount_1_out).unwrap()).unwrap()
+        } else {
+            0
+        };
+
+        if amount_0_in == 0 && amount_1_in == 0 {
+            return Err(SoroswapStablePairError::SwapInsufficientInputAmount);
+        }
+        if amount_0_in < 0 || amount_1_in < 0 {
+            return Err(SoroswapStablePairError::SwapNegativesInNotSupported);
+        }
+
+        let fee: i128 = get_fee(&e).into();
+        let fee_0 = (amount_0_in.checked_mul(fee).unwrap()).checked_div(FEE_DENOMINATOR).unwrap();
+        let fee_1 = (amount_1_in.checked_mul(fee).unwrap()).checked_div(FEE_DENOMINATOR).unwrap();
+
+        let balance_0_minus_fee = balance_0.checked_sub(fee_0).unwrap();
+        let balance_1_minus_fee = balance_1.checked_sub(fee_1).unwrap();
+
+        // The balance of the output side must be at least the one that keeps the invariant of the reserves,
+        // computed from the input side with the SoroswapLibrary, so the amounts it quotes always pass this check
+        let amp = get_amp(&e);
+        let d = get_stable_d(&e, reserve_0, reserve_1, amp)?;
+        let invariant_met = if amount_0_in > 0 {
+            balance_1_minus_fee >= get_stable_y(&e, balance_0_minus_fee, d, amp)?
+        } else {
+            balance_0_minus_fee >= get_stable_y(&e, balance_1_minus_fee, d, amp)?
+        };
+        if !invariant_met {
+            return Err(SoroswapStablePairError::SwapInvariantNotMet);
+        }
+
+        update(&e, POOL_NAME, balance_0, balance_1);
+
+        PoolEvents::new(&e, POOL_NAME).swap(to, amount_0_in, amount_1_in, amount_0_out, amount_1_out);
+
+        unlock(&e);
+        Ok(())
+    }
+
+    /// Withdraws liquidity from the stable pair, burning LP tokens and returning the corresponding tokens to the user.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the withdrawn tokens will be sent.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of token 0 and token 1 withdrawn from the pair.
+    fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapStablePairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let balance_shares = get_balance_shares(&e);
+        if balance_shares == 0 {
+            return Err(SoroswapStablePairError::WithdrawLiquidityNotInitialized);
+        }
+
+        let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (mut balance_0, mut balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let user_sent_shares = balance_shares.checked_sub(MINIMUM_LIQUIDITY).unwrap();
+
+        if user_sent_shares <= 0 {
+            return Err(SoroswapStablePairError::WithdrawInsufficientSentShares);
+        }
+
+        let fee_on = mint_fee(&e, reserve_0, reserve_1)?;
+        let total_shares = get_total_shares(&e);
+
+        let amount_0 = mul_div(&e, balance_0, user_sent_shares, total_shares);
+        let amount_1 = mul_div(&e, balance_1, user_sent_shares, total_shares);
+
+        if amount_0 <= 0 || amount_1 <= 0 {
+            return Err(SoroswapStablePairError::WithdrawInsufficientLiquidityBurned);
+        }
+
+        burn_shares(&e, user_sent_shares);
+
+        transfer_token_0_from_pair(&e, &to, amount_0);
+        transfer_token_1_from_pair(&e, &to, amount_1);
+
+        (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+
+        update(&e, POOL_NAME, balance_0, balance_1);
+
+        (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        if fee_on {
+            put_dlast(&e, get_stable_d(&e, reserve_0, reserve_1, get_amp(&e))?);
+        }
+
+        PoolEvents::new(&e, POOL_NAME).withdraw(to, user_sent_shares, amount_0, amount_1, reserve_0, reserve_1);
+        unlock(&e);
+        Ok((amount_0, amount_1))
+    }
+
+    /// Skims excess tokens from reserves and sends them to the specified address.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the excess tokens will be sent.
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapStablePairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let skimmed_0 = balance_0.checked_sub(reserve_0).unwrap();
+        let skimmed_1 = balance_1.checked_sub(reserve_1).unwrap();
+        transfer_token_0_from_pair(&e, &to, skimmed_0);
+        transfer_token_1_from_pair(&e, &to, skimmed_1);
+        PoolEvents::new(&e, POOL_NAME).skim(skimmed_0, skimmed_1);
+        unlock(&e);
+        Ok(())
+    }
+
+    /// Forces reserves to match current balances.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn sync(e: Env) -> Result<(), SoroswapStablePairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        update(&e, POOL_NAME, balance_0, balance_1);
+        unlock(&e);
+        Ok(())
+    }
+
+    /// Returns the current reserves of token 0 and token 1.
+    fn get_reserves(e: Env) -> (i128, i128) {
+        (get_reserve_0(&e), get_reserve_1(&e))
+    }
+
+    /// Returns the invariant of the reserves after the last deposit or withdraw, while fees are enabled.
+    fn d_last(e: Env) -> i128 {
+        get_dlast(&e)
+    }
+
+    /// Extends the time to live of the pair instance storage and of its code, so the pool is not archived
+    /// while nobody interacts with it. Anyone can call this function.
+    ///
+    /// # Errors
+    /// - `SoroswapStablePairError::NotInitialized`
+    fn extend_pair_ttl(e: Env) -> Result<(), SoroswapStablePairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Replaces the code of the stable pair with the Wasm identified by `new_wasm_hash`. Reserves, LP balances
+    /// and the rest of the storage are kept. The factory must then call `migrate` in order to update the storage layout.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `new_wasm_hash` - The hash of the new Wasm, that must already be uploaded.
+    ///
+    /// # Errors
+    /// - `SoroswapStablePairError::NotInitialized`
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapStablePairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+        extend_instance_ttl(&e);
+        e.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Runs the storage migrations between the stored version and the version of the current code.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The new storage version.
+    ///
+    /// # Errors
+    /// - `SoroswapStablePairError::NotInitialized`
+    /// - `SoroswapStablePairError::MigrateVersionInvalid`: The storage was written by a newer version of the stable pair.
+    fn migrate(e: Env) -> Result<u32, SoroswapStablePairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapStablePairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+        extend_instance_ttl(&e);
+        let (old_version, new_version) = migration::migrate(&e, CONTRACT_VERSION)?;
+        if old_version != new_version {
+            PoolEvents::new(&e, POOL_NAME).migrate(old_version, new_version);
+        }
+        Ok(new_version)
+    }
+
+    /// Returns the version of the storage layout of the stable pair.
+    fn version(e: Env) -> u32 {
+        get_version(&e)
+    }
+
+    /// Returns the balance of LP shares for a specific address.
+    fn my_balance(e: Env, id: Address) -> i128 {
+        SoroswapPairToken::balance(e.clone(), id)
+    }
+
+    /// Returns the total number of LP shares in circulation.
+    fn total_shares(e: Env) -> i128 {
+        get_total_shares(&e)
+    }
+}
+
+fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> Result<bool, SoroswapStablePairError> {
+
+    /*
+            Same as the constant product pair, with the invariant D in place of sqrt(k):
+            the protocol takes share_numerator/share_denominator of the growth of D since the last
+            deposit or withdraw, minted as new LP tokens to the fee beneficiary.
+    */
+
+    let factory_client = SoroswapFactoryClient::new(e, &get_factory(e));
+    let fee_on = factory_client.fees_enabled();
+    let d_last = get_dlast(e);
+
+    if fee_on {
+        let (share_numerator, share_denominator) = factory_client.protocol_fee_share();
+        let share_numerator: i128 = share_numerator.into();
+        let share_denominator: i128 = share_denominator.into();
+
+        if d_last != 0 && share_numerator > 0 {
+            let d = get_stable_d(e, reserve_0, reserve_1, get_amp(e))?;
+            if d > d_last {
+                let total_shares = get_total_shares(e);
+                let growth = d.checked_sub(d_last).unwrap().checked_mul(share_numerator).unwrap();
+                let denominator = d.checked_mul(share_denominator.checked_sub(share_numerator).unwrap()).unwrap()
+                    .checked_add(d_last.checked_mul(share_numerator).unwrap()).unwrap();
+                let liquidity_pool_shares_fees = mul_div(e, total_shares, growth, denominator);
+
+                if liquidity_pool_shares_fees > 0 {
+                    mint_shares(e, &factory_client.fee_to(), liquidity_pool_shares_fees);
+                }
+            }
+        }
+    } else if d_last != 0 {
+        put_dlast(e, 0);
+    }
+
+    Ok(fee_on)
+}
+
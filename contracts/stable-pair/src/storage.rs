@@ -0,0 +1,19 @@
+//! Storage of the parameters of the stable pair. The rest of its storage is laid out by soroswap_pool_common.
+use soroban_sdk::Env;
+use soroswap_pool_common::storage::{get_parameter, put_parameter, get_invariant_last, put_invariant_last};
+
+pub fn get_amp(e: &Env) -> u32 {
+    get_parameter(e)
+}
+
+pub fn get_dlast(e: &Env) -> i128 {
+    get_invariant_last(e)
+}
+
+pub fn put_amp(e: &Env, amp: u32) {
+    put_parameter(e, amp);
+}
+
+pub fn put_dlast(e: &Env, d_last: i128) {
+    put_invariant_last(e, d_last);
+}
@@ -0,0 +1,59 @@
+#![cfg(test)]
+use core::ops::Deref;
+use soroban_sdk::{BytesN, Env};
+use soroswap_pool_common::testutils::PoolTest;
+pub(crate) use soroswap_pool_common::testutils::pair_wasm;
+use crate::{SoroswapStablePairClient};
+
+// STABLE PAIR CONTRACT
+fn stable_pair_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../stable-pair/target/wasm32-unknown-unknown/release/soroswap_stable_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+fn create_stable_pair_contract<'a>(e: & Env) -> SoroswapStablePairClient<'a> {
+    SoroswapStablePairClient::new(e, &e.register_contract(None, crate::SoroswapStablePair {}))
+}
+
+// THE TEST
+// The tokens, the user and the factory are shared with the weighted pair tests
+pub struct SoroswapStablePairTest<'a> {
+    pool: PoolTest<'a>,
+    contract: SoroswapStablePairClient<'a>,
+}
+
+impl<'a> Deref for SoroswapStablePairTest<'a> {
+    type Target = PoolTest<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pool
+    }
+}
+
+impl<'a> SoroswapStablePairTest<'a> {
+    fn setup() -> Self {
+        let pool = PoolTest::setup();
+        let contract = create_stable_pair_contract(&pool.env);
+        SoroswapStablePairTest { pool, contract }
+    }
+
+    // Initializes the pair with a 0.05% fee and the given amplification coefficient
+    fn initialize(&self, amp: u32) {
+        self.contract.initialize_pair(&self.factory.address, &self.token_0.address, &self.token_1.address, &5, &amp);
+    }
+
+    fn add_liquidity(&self, amount_0: i128, amount_1: i128) -> i128 {
+        self.token_0.transfer(&self.user, &self.contract.address, &amount_0);
+        self.token_1.transfer(&self.user, &self.contract.address, &amount_1);
+        self.contract.deposit(&self.user)
+    }
+}
+
+mod initialize_pair;
+mod deposit;
+mod swap;
+mod withdraw;
+mod fee;
+mod upgrade;
@@ -0,0 +1,86 @@
+use crate::test::SoroswapStablePairTest;
+use crate::error::SoroswapStablePairError;
+
+#[test]
+fn deposit_not_yet_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+}
+
+#[test]
+fn deposit_zero_tokens_sent() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::DepositInsufficientAmountToken0)));
+}
+
+#[test]
+fn deposit_only_token_0_sent() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::DepositInsufficientAmountToken1)));
+}
+
+#[test]
+fn deposit_insufficient_first_liquidity() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.token_0.transfer(&test.user, &test.contract.address, &500);
+    test.token_1.transfer(&test.user, &test.contract.address, &500);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::DepositInsufficientFirstLiquidity)));
+}
+
+#[test]
+fn deposit_balanced_first_liquidity() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    // For a balanced pool the invariant is the sum of the reserves
+    let liquidity = test.add_liquidity(1_000_000_000, 1_000_000_000);
+    assert_eq!(liquidity, 2_000_000_000 - 1000);
+    assert_eq!(test.contract.my_balance(&test.user), liquidity);
+    assert_eq!(test.contract.my_balance(&test.contract.address), 1000);
+    assert_eq!(test.contract.total_shares(), 2_000_000_000);
+    assert_eq!(test.contract.get_reserves(), (1_000_000_000, 1_000_000_000));
+}
+
+#[test]
+fn deposit_imbalanced_first_liquidity() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let liquidity = test.add_liquidity(1_000_000_000, 3_000_000_000);
+    let d = soroswap_library::get_stable_d(&test.env, 1_000_000_000, 3_000_000_000, 100).unwrap();
+    assert_eq!(liquidity, d - 1000);
+    // The invariant is below the sum of the reserves when the pool is imbalanced
+    assert!(d < 4_000_000_000);
+}
+
+#[test]
+fn deposit_second_liquidity_proportional() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000_000, 1_000_000_000);
+    let total_shares = test.contract.total_shares();
+    let liquidity = test.add_liquidity(500_000_000, 700_000_000);
+    // The smallest proportional share is minted, the excess of token 1 is donated to the pool
+    assert_eq!(liquidity, total_shares / 2);
+    assert_eq!(test.contract.get_reserves(), (1_500_000_000, 1_700_000_000));
+}
+
+#[test]
+fn deposit_large_reserves_max_amp() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(10_000);
+    test.token_0.mint(&test.user, &2_000_000_000_000_000_000_000_000);
+    test.token_1.mint(&test.user, &2_000_000_000_000_000_000_000_000);
+    let liquidity = test.add_liquidity(1_000_000_000_000_000_000_000_000, 1_000_000_000_000_000_000_000_000);
+    assert_eq!(liquidity, 2_000_000_000_000_000_000_000_000 - 1000);
+
+    let liquidity = test.add_liquidity(1_000_000_000_000_000_000_000_000, 1_000_000_000_000_000_000_000_000);
+    assert_eq!(liquidity, 2_000_000_000_000_000_000_000_000);
+    assert_eq!(test.contract.get_reserves(), (2_000_000_000_000_000_000_000_000, 2_000_000_000_000_000_000_000_000));
+}
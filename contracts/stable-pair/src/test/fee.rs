@@ -0,0 +1,56 @@
+use crate::test::SoroswapStablePairTest;
+use soroban_sdk::Bytes;
+
+fn swap_back_and_forth(test: &SoroswapStablePairTest) {
+    for zero_for_one in [true, false, true, false] {
+        let (reserve_0, reserve_1) = test.contract.get_reserves();
+        if zero_for_one {
+            let amount_out = soroswap_library::get_stable_amount_out(&test.env, 100_000_000, reserve_0, reserve_1, 100, 5).unwrap();
+            test.token_0.transfer(&test.user, &test.contract.address, &100_000_000);
+            test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+        } else {
+            let amount_out = soroswap_library::get_stable_amount_out(&test.env, 100_000_000, reserve_1, reserve_0, 100, 5).unwrap();
+            test.token_1.transfer(&test.user, &test.contract.address, &100_000_000);
+            test.contract.swap(&amount_out, &0, &test.user, &Bytes::new(&test.env));
+        }
+    }
+}
+
+#[test]
+fn protocol_fee_not_minted_when_fees_disabled() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000_000, 1_000_000_000);
+    assert_eq!(test.contract.d_last(), 0);
+
+    swap_back_and_forth(&test);
+    test.add_liquidity(1_000_000, 1_000_000);
+
+    assert_eq!(test.contract.my_balance(&test.admin), 0);
+    assert_eq!(test.contract.d_last(), 0);
+}
+
+#[test]
+fn protocol_fee_minted_on_invariant_growth() {
+    let test = SoroswapStablePairTest::setup();
+    test.factory.set_fees_enabled(&true);
+    test.initialize(100);
+    test.add_liquidity(1_000_000_000, 1_000_000_000);
+    let d_last = test.contract.d_last();
+    assert_eq!(d_last, 2_000_000_000);
+
+    swap_back_and_forth(&test);
+    let (reserve_0, reserve_1) = test.contract.get_reserves();
+    let d = soroswap_library::get_stable_d(&test.env, reserve_0, reserve_1, 100).unwrap();
+    assert!(d > d_last);
+
+    let total_shares = test.contract.total_shares();
+    test.add_liquidity(1_000_000, 1_000_000);
+
+    // 1/6 of the growth of the invariant is minted to fee_to, as in the constant product pair
+    let expected_fee_shares = total_shares * (d - d_last) / (d * 5 + d_last);
+    assert!(expected_fee_shares > 0);
+    assert_eq!(test.contract.my_balance(&test.admin), expected_fee_shares);
+    let (reserve_0, reserve_1) = test.contract.get_reserves();
+    assert_eq!(test.contract.d_last(), soroswap_library::get_stable_d(&test.env, reserve_0, reserve_1, 100).unwrap());
+}
@@ -0,0 +1,52 @@
+use crate::test::SoroswapStablePairTest;
+use crate::error::SoroswapStablePairError;
+
+#[test]
+fn initialize_pair_token_1_less_than_token_0() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_1.address, &test.token_0.address, &5, &100);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeTokenOrderInvalid)));
+}
+
+#[test]
+fn double_initialize_pair() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &5, &100);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_pair_fee_invalid() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &10000, &100);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeFeeInvalid)));
+}
+
+#[test]
+fn initialize_pair_amp_zero() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &5, &0);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeAmpInvalid)));
+}
+
+#[test]
+fn initialize_pair_amp_too_high() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &5, &10_001);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::InitializeAmpInvalid)));
+}
+
+#[test]
+fn initialize_pair_initial_values() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.fee(), 5);
+    assert_eq!(test.contract.amp(), 100);
+    assert_eq!(test.contract.get_reserves(), (0, 0));
+    assert_eq!(test.contract.total_shares(), 0);
+    assert_eq!(test.contract.d_last(), 0);
+}
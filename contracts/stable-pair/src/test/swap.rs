@@ -0,0 +1,156 @@
+use crate::test::SoroswapStablePairTest;
+use crate::error::SoroswapStablePairError;
+use soroban_sdk::Bytes;
+
+#[test]
+fn swap_not_yet_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_swap(&0, &1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+}
+
+#[test]
+fn swap_amounts_zero() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let res = test.contract.try_swap(&0, &0, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInsufficientOutputAmount)));
+}
+
+#[test]
+fn swap_negative_out() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let res = test.contract.try_swap(&-1, &1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapNegativesOutNotSupported)));
+}
+
+#[test]
+fn swap_insufficient_liquidity() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000, 1_000_000);
+    let res = test.contract.try_swap(&0, &1_000_000, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInsufficientLiquidity)));
+}
+
+#[test]
+fn swap_to_token() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000, 1_000_000);
+    let res = test.contract.try_swap(&0, &1000, &test.token_0.address, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInvalidTo)));
+}
+
+#[test]
+fn swap_nothing_sent() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000, 1_000_000);
+    let res = test.contract.try_swap(&0, &1000, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInsufficientInputAmount)));
+}
+
+#[test]
+fn swap_token_0_for_token_1_library_amount() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000_000, 1_000_000_000);
+
+    let amount_in = 100_000_000;
+    let amount_out = soroswap_library::get_stable_amount_out(&test.env, amount_in, 1_000_000_000, 1_000_000_000, 100, 5).unwrap();
+    // Much closer to 1:1 than the constant product curve
+    let constant_product_out = soroswap_library::get_amount_out_with_fee(amount_in, 1_000_000_000, 1_000_000_000, 5).unwrap();
+    assert!(amount_out > constant_product_out);
+    assert!(amount_out > 99_000_000);
+
+    let balance_1_before = test.token_1.balance(&test.user);
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+
+    // The quote keeps one unit in the pair, so two more units than the quote break the invariant
+    let res = test.contract.try_swap(&0, &(amount_out + 2), &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInvariantNotMet)));
+
+    test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.token_1.balance(&test.user), balance_1_before + amount_out);
+    assert_eq!(test.contract.get_reserves(), (1_000_000_000 + amount_in, 1_000_000_000 - amount_out));
+}
+
+#[test]
+fn swap_token_1_for_token_0_library_amount() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(50);
+    test.add_liquidity(2_000_000_000, 1_000_000_000);
+
+    let amount_in = 300_000_000;
+    let amount_out = soroswap_library::get_stable_amount_out(&test.env, amount_in, 1_000_000_000, 2_000_000_000, 50, 5).unwrap();
+
+    test.token_1.transfer(&test.user, &test.contract.address, &amount_in);
+    let res = test.contract.try_swap(&(amount_out + 2), &0, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInvariantNotMet)));
+
+    test.contract.swap(&amount_out, &0, &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.contract.get_reserves(), (2_000_000_000 - amount_out, 1_000_000_000 + amount_in));
+}
+
+#[test]
+fn swap_exact_output_library_amount() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(200);
+    test.add_liquidity(1_000_000_000, 1_000_000_000);
+
+    let amount_out = 250_000_000;
+    let amount_in = soroswap_library::get_stable_amount_in(&test.env, amount_out, 1_000_000_000, 1_000_000_000, 200, 5).unwrap();
+    assert!(amount_in > amount_out);
+    // The required input is enough to get the output back from the library
+    assert!(soroswap_library::get_stable_amount_out(&test.env, amount_in, 1_000_000_000, 1_000_000_000, 200, 5).unwrap() >= amount_out);
+
+    // Half of the required input is not enough
+    test.token_0.transfer(&test.user, &test.contract.address, &(amount_in / 2));
+    let res = test.contract.try_swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInvariantNotMet)));
+
+    test.token_0.transfer(&test.user, &test.contract.address, &(amount_in - amount_in / 2));
+    test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.contract.get_reserves(), (1_000_000_000 + amount_in, 1_000_000_000 - amount_out));
+}
+
+#[test]
+fn swap_keeps_invariant_growing() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000_000, 1_000_000_000);
+    let d_before = soroswap_library::get_stable_d(&test.env, 1_000_000_000, 1_000_000_000, 100).unwrap();
+
+    for _ in 0..5 {
+        let (reserve_0, reserve_1) = test.contract.get_reserves();
+        let amount_out = soroswap_library::get_stable_amount_out(&test.env, 50_000_000, reserve_0, reserve_1, 100, 5).unwrap();
+        test.token_0.transfer(&test.user, &test.contract.address, &50_000_000);
+        test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    }
+
+    let (reserve_0, reserve_1) = test.contract.get_reserves();
+    // The swap fees stay in the pool
+    assert!(soroswap_library::get_stable_d(&test.env, reserve_0, reserve_1, 100).unwrap() > d_before);
+}
+
+#[test]
+fn swap_large_reserves_max_amp() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(10_000);
+    test.token_0.mint(&test.user, &2_000_000_000_000_000_000_000_000);
+    test.token_1.mint(&test.user, &1_000_000_000_000_000_000_000_000);
+    test.add_liquidity(1_000_000_000_000_000_000_000_000, 1_000_000_000_000_000_000_000_000);
+
+    let amount_in = 100_000_000_000_000_000_000_000;
+    let amount_out = soroswap_library::get_stable_amount_out(&test.env, amount_in, 1_000_000_000_000_000_000_000_000, 1_000_000_000_000_000_000_000_000, 10_000, 5).unwrap();
+    // Almost 1:1 at the highest amplification
+    assert!(amount_out > 99_900_000_000_000_000_000_000);
+
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+    let res = test.contract.try_swap(&0, &(amount_out + 2), &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::SwapInvariantNotMet)));
+    test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.contract.get_reserves(), (1_000_000_000_000_000_000_000_000 + amount_in, 1_000_000_000_000_000_000_000_000 - amount_out));
+}
@@ -0,0 +1,113 @@
+use crate::test::{SoroswapStablePairTest, pair_wasm, stable_pair_wasm};
+use crate::error::SoroswapStablePairError;
+use soroswap_pool_common::event::MigrateEvent;
+use soroswap_pool_common::storage::{DataKey, put_version};
+use soroban_sdk::{
+    testutils::{Events, MockAuth, MockAuthInvoke},
+    vec,
+    IntoVal,
+    symbol_short,
+};
+
+#[test]
+fn upgrade_not_yet_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    let new_wasm_hash = pair_wasm(&test.env);
+    let res = test.contract.try_upgrade(&new_wasm_hash);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+    let res = test.contract.try_migrate();
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+}
+
+#[test]
+fn initialize_sets_current_version() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    assert_eq!(test.contract.version(), 1);
+
+    // Nothing to migrate
+    assert_eq!(test.contract.migrate(), 1);
+}
+
+#[test]
+fn migrate_unversioned_stable_pair() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000, 4_000_000);
+
+    // Storage as written by stable pairs deployed before versioning
+    test.env.as_contract(&test.contract.address, || {
+        test.env.storage().instance().remove(&DataKey::Version);
+    });
+    assert_eq!(test.contract.version(), 0);
+
+    assert_eq!(test.contract.migrate(), 1);
+    let migrate_event = test.env.events().all().last().unwrap();
+    let expected_migrate_event: MigrateEvent = MigrateEvent {
+        old_version: 0,
+        new_version: 1,
+    };
+    assert_eq!(
+        vec![&test.env, migrate_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapStablePair", symbol_short!("migrate")).into_val(&test.env),
+                (expected_migrate_event).into_val(&test.env)
+            ),
+        ]
+    );
+    assert_eq!(test.contract.version(), 1);
+    assert_eq!(test.contract.get_reserves(), (1_000_000, 4_000_000));
+}
+
+#[test]
+fn migrate_newer_version() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.env.as_contract(&test.contract.address, || put_version(&test.env, 2));
+
+    let res = test.contract.try_migrate();
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::MigrateVersionInvalid)));
+}
+
+#[test]
+fn upgrade_keeps_reserves_and_balances() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let liquidity = test.add_liquidity(1_000_000, 4_000_000);
+    let total_shares = test.contract.total_shares();
+
+    test.contract.upgrade(&stable_pair_wasm(&test.env));
+    assert_eq!(test.contract.migrate(), 1);
+
+    assert_eq!(test.contract.get_reserves(), (1_000_000, 4_000_000));
+    assert_eq!(test.contract.my_balance(&test.user), liquidity);
+    assert_eq!(test.contract.total_shares(), total_shares);
+    assert_eq!(test.contract.amp(), 100);
+
+    // The upgraded stable pair keeps working
+    test.add_liquidity(1_000_000, 4_000_000);
+    assert_eq!(test.contract.get_reserves(), (2_000_000, 8_000_000));
+}
+
+#[test]
+#[should_panic]
+fn upgrade_not_factory() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let new_wasm_hash = pair_wasm(&test.env);
+
+    test.contract
+        .mock_auths(&[MockAuth {
+            address: &test.user,
+            invoke: &MockAuthInvoke {
+                contract: &test.contract.address,
+                fn_name: "upgrade",
+                args: (new_wasm_hash.clone(),).into_val(&test.env),
+                sub_invokes: &[],
+            },
+        }])
+        .upgrade(&new_wasm_hash);
+}
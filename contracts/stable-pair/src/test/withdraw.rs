@@ -0,0 +1,69 @@
+use crate::test::SoroswapStablePairTest;
+use soroswap_pair_token::SoroswapPairTokenClient;
+use crate::error::SoroswapStablePairError;
+
+#[test]
+fn withdraw_not_yet_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::NotInitialized)));
+}
+
+#[test]
+fn withdraw_liquidity_not_initialized() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::WithdrawLiquidityNotInitialized)));
+}
+
+#[test]
+fn withdraw_no_shares_sent() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    test.add_liquidity(1_000_000_000, 1_000_000_000);
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapStablePairError::WithdrawInsufficientSentShares)));
+}
+
+#[test]
+fn withdraw_all_user_shares() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(100);
+    let liquidity = test.add_liquidity(1_000_000_000, 3_000_000_000);
+    let total_shares = test.contract.total_shares();
+    let balance_0_before = test.token_0.balance(&test.user);
+    let balance_1_before = test.token_1.balance(&test.user);
+
+    // The LP token functions are dispatched by the SoroswapPairToken contract of the same Wasm
+    let pair_token_client = SoroswapPairTokenClient::new(&test.env, &test.env.register_contract(&test.contract.address, crate::SoroswapPairToken {}));
+    pair_token_client.transfer(&test.user, &test.contract.address, &liquidity);
+    test.env.register_contract(&test.contract.address, crate::SoroswapStablePair {});
+    let (amount_0, amount_1) = test.contract.withdraw(&test.user);
+
+    assert_eq!(amount_0, 1_000_000_000 * liquidity / total_shares);
+    assert_eq!(amount_1, 3_000_000_000 * liquidity / total_shares);
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before + amount_0);
+    assert_eq!(test.token_1.balance(&test.user), balance_1_before + amount_1);
+    assert_eq!(test.contract.total_shares(), 1000);
+    assert_eq!(test.contract.my_balance(&test.user), 0);
+    assert_eq!(test.contract.get_reserves(), (1_000_000_000 - amount_0, 3_000_000_000 - amount_1));
+}
+
+#[test]
+fn withdraw_large_reserves_max_amp() {
+    let test = SoroswapStablePairTest::setup();
+    test.initialize(10_000);
+    test.token_0.mint(&test.user, &1_000_000_000_000_000_000_000_000);
+    test.token_1.mint(&test.user, &1_000_000_000_000_000_000_000_000);
+    let liquidity = test.add_liquidity(1_000_000_000_000_000_000_000_000, 1_000_000_000_000_000_000_000_000);
+
+    let pair_token_client = SoroswapPairTokenClient::new(&test.env, &test.env.register_contract(&test.contract.address, crate::SoroswapPairToken {}));
+    pair_token_client.transfer(&test.user, &test.contract.address, &liquidity);
+    test.env.register_contract(&test.contract.address, crate::SoroswapStablePair {});
+    let (amount_0, amount_1) = test.contract.withdraw(&test.user);
+    // The minimum liquidity stays locked in the pair
+    assert_eq!(amount_0, 1_000_000_000_000_000_000_000_000 - 500);
+    assert_eq!(amount_1, 1_000_000_000_000_000_000_000_000 - 500);
+    assert_eq!(test.contract.get_reserves(), (500, 500));
+}
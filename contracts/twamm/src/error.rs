@@ -0,0 +1,30 @@
+use soroban_sdk::{self, contracterror};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TwammError {
+    /// SoroswapTWAMM: not yet initialized
+    NotInitialized = 701,
+
+    /// SoroswapTWAMM: already initialized
+    InitializeAlreadyInitialized = 702,
+
+    /// SoroswapTWAMM: the amount is too small to be sold over the order intervals
+    InvalidAmount = 703,
+
+    /// SoroswapTWAMM: the token is not one of the pair tokens
+    InvalidToken = 704,
+
+    /// SoroswapTWAMM: the number of intervals and the interval length must be positive
+    InvalidIntervals = 705,
+
+    /// SoroswapTWAMM: order not found
+    OrderNotFound = 706,
+
+    /// SoroswapTWAMM: order expired, its proceeds can only be withdrawn
+    OrderExpired = 707,
+
+    /// SoroswapTWAMM: the orders are not executed until the current timestamp, call execute_virtual_orders first
+    ExecutionBehind = 708,
+}
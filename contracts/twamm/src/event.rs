@@ -0,0 +1,88 @@
+//! Definition of the Events used in the contract
+use soroban_sdk::{contracttype, symbol_short, Env, Address};
+
+// INITIALIZED
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InitializedEvent {
+    pub pair: Address,
+    pub interval: u64
+}
+
+pub(crate) fn initialized(e: &Env, pair: Address, interval: u64) {
+    let event = InitializedEvent { pair, interval };
+    e.events().publish(("SoroswapTWAMM", symbol_short!("init")), event);
+}
+
+// ORDER SUBMITTED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrderSubmittedEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub token_in: Address,
+    pub amount_in: i128,
+    pub sale_rate: i128,
+    pub expiration: u64,
+}
+
+/// Publishes an `OrderSubmittedEvent` to the event stream.
+///
+/// # Arguments
+///
+/// * `e` - An instance of the `Env` struct.
+/// * `order_id` - The id of the new order.
+/// * `owner` - The address selling the tokens.
+/// * `token_in` - The token sold.
+/// * `amount_in` - The amount of the token sold, taken from the owner.
+/// * `sale_rate` - The amount of the token sold per second.
+/// * `expiration` - The ledger timestamp when the order ends.
+pub(crate) fn order_submitted(
+    e: &Env,
+    order_id: u64,
+    owner: Address,
+    token_in: Address,
+    amount_in: i128,
+    sale_rate: i128,
+    expiration: u64,
+) {
+    let event = OrderSubmittedEvent {
+        order_id,
+        owner,
+        token_in,
+        amount_in,
+        sale_rate,
+        expiration,
+    };
+    e.events().publish(("SoroswapTWAMM", symbol_short!("submit")), event);
+}
+
+// PROCEEDS WITHDRAWN EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub unsold: i128,
+    pub proceeds: i128,
+}
+
+pub(crate) fn withdraw(e: &Env, order_id: u64, owner: Address, unsold: i128, proceeds: i128) {
+    let event = WithdrawEvent { order_id, owner, unsold, proceeds };
+    e.events().publish(("SoroswapTWAMM", symbol_short!("withdraw")), event);
+}
+
+// ORDER CANCELLED EVENT
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelEvent {
+    pub order_id: u64,
+    pub owner: Address,
+    pub unsold: i128,
+    pub proceeds: i128,
+}
+
+pub(crate) fn cancel(e: &Env, order_id: u64, owner: Address, unsold: i128, proceeds: i128) {
+    let event = CancelEvent { order_id, owner, unsold, proceeds };
+    e.events().publish(("SoroswapTWAMM", symbol_short!("cancel")), event);
+}
@@ -0,0 +1,456 @@
+#![no_std]
+use soroban_sdk::token::Client as TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Bytes, Env};
+
+mod pair;
+mod price;
+mod event;
+mod storage;
+mod error;
+mod test;
+
+use pair::{SoroswapPairClient, UQ128x128};
+use storage::*;
+pub use storage::LongTermOrder;
+pub use error::TwammError;
+
+/// Scale of the reward factors, the output accumulated per unit of sale rate.
+pub const REWARD_FACTOR_SCALE: i128 = 1_000_000_000_000;
+
+/// Maximum number of expirations visited when the orders are executed as part of another interaction.
+pub const MAX_INTERVALS_PER_INTERACTION: u32 = 32;
+
+/// Maximum deviation of the average execution price of the orders from the pair TWAP, fee included, in basis points.
+pub const MAX_PRICE_DEVIATION_BPS: i128 = 100;
+
+fn check_initialized(e: &Env) -> Result<(), TwammError> {
+    if has_pair(e) {
+        Ok(())
+    } else {
+        Err(TwammError::NotInitialized)
+    }
+}
+
+fn read_order(e: &Env, order_id: u64) -> Result<LongTermOrder, TwammError> {
+    get_order(e, order_id).ok_or(TwammError::OrderNotFound)
+}
+
+fn token_in(e: &Env, zero_for_one: bool) -> Address {
+    if zero_for_one { get_token_0(e) } else { get_token_1(e) }
+}
+
+fn token_out(e: &Env, zero_for_one: bool) -> Address {
+    if zero_for_one { get_token_1(e) } else { get_token_0(e) }
+}
+
+/// Sells as much of `amount_in` held by this contract through the pair as keeps the average execution price within
+/// `MAX_PRICE_DEVIATION_BPS` of `price`, the TWAP of the input token. The output stays in this contract.
+///
+/// # Returns
+/// A tuple containing the amount sold and the amount received, both zero when nothing can be sold within the bound.
+fn swap(e: &Env, zero_for_one: bool, amount_in: i128, price: &UQ128x128) -> (i128, i128) {
+    if amount_in == 0 {
+        return (0, 0);
+    }
+    let pair = SoroswapPairClient::new(e, &get_pair(e));
+    let fee = get_fee(e);
+    let (reserve_0, reserve_1) = pair.get_reserves();
+    let (reserve_in, reserve_out) = if zero_for_one { (reserve_0, reserve_1) } else { (reserve_1, reserve_0) };
+    let amount_in = amount_in.min(price::max_amount_in(e, amount_in, reserve_in, reserve_out, fee, price));
+    if amount_in <= 0 {
+        return (0, 0);
+    }
+    let amount_out = soroswap_library::get_amount_out_with_fee(amount_in, reserve_in, reserve_out, fee).unwrap_or(0);
+    if amount_out == 0 {
+        return (0, 0);
+    }
+
+    TokenClient::new(e, &token_in(e, zero_for_one)).transfer(&e.current_contract_address(), &pair.address, &amount_in);
+    let (amount_0_out, amount_1_out) = if zero_for_one { (0, amount_out) } else { (amount_out, 0) };
+    pair.swap(&amount_0_out, &amount_1_out, &e.current_contract_address(), &Bytes::new(e));
+    (amount_in, amount_out)
+}
+
+/// Input of one side of the orders for a segment of `duration` seconds, including what previous segments carried.
+/// Zero when nobody sells that token, as the output could not be accrued to anyone.
+fn segment_input(e: &Env, zero_for_one: bool, duration: u64) -> i128 {
+    let sale_rate = get_sale_rate(e, zero_for_one);
+    if sale_rate == 0 {
+        return 0;
+    }
+    sale_rate
+        .checked_mul(duration.into()).unwrap()
+        .checked_add(get_carried_input(e, zero_for_one)).unwrap()
+}
+
+/// Accrues `amount_out` to the reward factor of one side of the orders, and carries what it did not sell of its
+/// `amount_in` to the next segment.
+fn settle_segment(e: &Env, zero_for_one: bool, amount_in: i128, amount_sold: i128, amount_out: i128) {
+    let sale_rate = get_sale_rate(e, zero_for_one);
+    if sale_rate == 0 {
+        return;
+    }
+    put_carried_input(e, zero_for_one, amount_in - amount_sold);
+    if amount_out > 0 {
+        let reward_factor = get_reward_factor(e, zero_for_one)
+            .checked_add(amount_out.checked_mul(REWARD_FACTOR_SCALE).unwrap().checked_div(sale_rate).unwrap())
+            .unwrap();
+        put_reward_factor(e, zero_for_one, reward_factor);
+    }
+}
+
+/// Sells what both sides of the orders sold during `duration` seconds, and accrues the output to their reward factors.
+/// Opposite orders are matched with each other at the TWAP, and the excess of one side is sold through the pair
+/// within the price bound. Input that can not be sold, because it is too small, the spot price is too far from the
+/// TWAP or there is no TWAP yet, is carried to the next segment instead.
+fn execute_segment(e: &Env, duration: u64, prices: &Option<(UQ128x128, UQ128x128)>) {
+    let amount_in_0 = segment_input(e, true, duration);
+    let amount_in_1 = segment_input(e, false, duration);
+    let (price_0, price_1) = match prices {
+        Some(prices) => prices,
+        None => {
+            settle_segment(e, true, amount_in_0, 0, 0);
+            settle_segment(e, false, amount_in_1, 0, 0);
+            return;
+        }
+    };
+
+    let (matched_0, matched_1) = price::match_orders(e, amount_in_0, amount_in_1, price_0);
+    let (sold_0, amount_out_0) = swap(e, true, amount_in_0 - matched_0, price_0);
+    let (sold_1, amount_out_1) = swap(e, false, amount_in_1 - matched_1, price_1);
+    settle_segment(e, true, amount_in_0, matched_0 + sold_0, matched_1 + amount_out_0);
+    settle_segment(e, false, amount_in_1, matched_1 + sold_1, matched_0 + amount_out_1);
+}
+
+/// Returns the input carried by one side of the orders to the orders selling it now, through their unsold factor.
+/// Called before their sale rate changes, so orders never sell the input of the orders that sold before them.
+fn refund_carried_input(e: &Env, zero_for_one: bool) {
+    let sale_rate = get_sale_rate(e, zero_for_one);
+    let carried_input = get_carried_input(e, zero_for_one);
+    if sale_rate == 0 || carried_input == 0 {
+        return;
+    }
+    let unsold_factor = carried_input.checked_mul(REWARD_FACTOR_SCALE).unwrap() / sale_rate;
+    put_unsold_factor(e, zero_for_one, get_unsold_factor(e, zero_for_one).checked_add(unsold_factor).unwrap());
+    // What is lost to rounding stays carried
+    put_carried_input(e, zero_for_one, carried_input - sale_rate * unsold_factor / REWARD_FACTOR_SCALE);
+}
+
+/// Executes the long-term orders from the last execution towards the current ledger timestamp, stopping at each of
+/// the next `max_intervals` expirations so orders ending there stop selling. The intervals between two expirations
+/// are executed as a single segment.
+///
+/// # Returns
+/// The ledger timestamp the orders are executed until, the current one once every expiration before it is visited.
+fn execute_virtual_orders(e: &Env, max_intervals: u32) -> u64 {
+    let now = e.ledger().timestamp();
+    let mut last_execution = get_last_execution(e);
+    let mut expirations = get_expirations(e);
+    let mut visited: u32 = 0;
+    // Every segment is bounded by the TWAP until now, read before the orders move the price of the pair
+    let prices = if now > last_execution {
+        price::reference_prices(&SoroswapPairClient::new(e, &get_pair(e)), get_interval(e))
+    } else {
+        None
+    };
+
+    while let Some(expiration) = expirations.first() {
+        if expiration > now || visited == max_intervals {
+            break;
+        }
+        execute_segment(e, expiration - last_execution, &prices);
+        for zero_for_one in [true, false] {
+            let sale_rate_ending = get_sale_rate_ending(e, zero_for_one, expiration);
+            if sale_rate_ending > 0 {
+                refund_carried_input(e, zero_for_one);
+                put_reward_factor_at(e, zero_for_one, expiration, get_reward_factor(e, zero_for_one));
+                put_unsold_factor_at(e, zero_for_one, expiration, get_unsold_factor(e, zero_for_one));
+                put_sale_rate(e, zero_for_one, get_sale_rate(e, zero_for_one) - sale_rate_ending);
+            }
+        }
+        expirations.pop_front();
+        last_execution = expiration;
+        visited += 1;
+    }
+    if visited > 0 {
+        put_expirations(e, &expirations);
+    }
+    // Stops at the last visited expiration while there are more of them before now
+    if expirations.first().map_or(true, |expiration| expiration > now) {
+        if now > last_execution {
+            execute_segment(e, now - last_execution, &prices);
+        }
+        last_execution = now;
+    }
+    put_last_execution(e, last_execution);
+    last_execution
+}
+
+/// Executes the long-term orders until the current ledger timestamp before another interaction.
+fn execute_until_now(e: &Env) -> Result<(), TwammError> {
+    if execute_virtual_orders(e, MAX_INTERVALS_PER_INTERACTION) < e.ledger().timestamp() {
+        return Err(TwammError::ExecutionBehind);
+    }
+    Ok(())
+}
+
+/// Proceeds and returned input of an order since its last withdrawal. The order is updated with the reward and
+/// unsold factors they were computed with.
+///
+/// # Returns
+/// A tuple containing the amount of the input token returned and the amount of the output token received.
+fn settle_order(e: &Env, order: &mut LongTermOrder) -> (i128, i128) {
+    let (reward_factor, unsold_factor) = if e.ledger().timestamp() >= order.expiration {
+        (
+            get_reward_factor_at(e, order.zero_for_one, order.expiration),
+            get_unsold_factor_at(e, order.zero_for_one, order.expiration),
+        )
+    } else {
+        (get_reward_factor(e, order.zero_for_one), get_unsold_factor(e, order.zero_for_one))
+    };
+    let proceeds = order.sale_rate
+        .checked_mul(reward_factor - order.reward_factor).unwrap()
+        .checked_div(REWARD_FACTOR_SCALE).unwrap();
+    let unsold = order.sale_rate
+        .checked_mul(unsold_factor - order.unsold_factor).unwrap()
+        .checked_div(REWARD_FACTOR_SCALE).unwrap();
+    order.reward_factor = reward_factor;
+    order.unsold_factor = unsold_factor;
+    (unsold, proceeds)
+}
+
+pub trait SoroswapTwammTrait {
+
+    /// Initializes the contract with the pair the orders are executed against, and the length of the order intervals.
+    /// The orders are executed within `MAX_PRICE_DEVIATION_BPS` of the pair TWAP over the last interval, so the pair
+    /// must keep observations for at least an interval: its observation cardinality should be increased if it is
+    /// traded more than once per interval.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `pair` - The address of the SoroswapPair.
+    /// * `interval` - The length of the order intervals, in seconds.
+    fn initialize(e: Env, pair: Address, interval: u64) -> Result<(), TwammError>;
+
+    /// Submits a long-term order selling `amount_in` of `token_in` at a constant rate until the end of the
+    /// `number_of_intervals`-th interval, counting the current one. What can not be sold at a whole rate per
+    /// second is not taken from `owner`.
+    ///
+    /// # Arguments
+    /// * `e` - The contract environment (`Env`) in which the contract is executing.
+    /// * `owner` - The address selling the tokens and receiving the proceeds.
+    /// * `token_in` - The token to sell, one of the pair tokens.
+    /// * `amount_in` - The maximum amount of `token_in` to sell.
+    /// * `number_of_intervals` - The number of intervals to sell over.
+    ///
+    /// # Returns
+    /// The id of the new order.
+    fn submit_order(
+        e: Env,
+        owner: Address,
+        token_in: Address,
+        amount_in: i128,
+        number_of_intervals: u64,
+    ) -> Result<u64, TwammError>;
+
+    /// Sends the proceeds of an order to its owner, and the input it could not sell within the price bound before
+    /// the orders selling the same token changed. Orders are removed once expired and withdrawn.
+    ///
+    /// # Returns
+    /// A tuple containing the amount of the input token returned and the amount of the output token sent.
+    fn withdraw_proceeds(e: Env, order_id: u64) -> Result<(i128, i128), TwammError>;
+
+    /// Cancels an order that did not expire yet, sending the unsold input and the proceeds to its owner.
+    ///
+    /// # Returns
+    /// A tuple containing the amount of the input token not sold and the amount of the output token received.
+    fn cancel_order(e: Env, order_id: u64) -> Result<(i128, i128), TwammError>;
+
+    /// Executes the long-term orders towards the current ledger timestamp, visiting at most `max_intervals` order
+    /// expirations. This happens in every interaction with the contract, but can be called by anyone to keep the pair
+    /// price up to date, or in several calls to catch up when more than `MAX_INTERVALS_PER_INTERACTION` expirations
+    /// passed since the last execution.
+    ///
+    /// # Returns
+    /// The ledger timestamp the orders are executed until, the current one once caught up.
+    fn execute_virtual_orders(e: Env, max_intervals: u32) -> Result<u64, TwammError>;
+
+    /*  *** Read only functions: *** */
+
+    /// Returns an open long-term order.
+    fn get_order(e: Env, order_id: u64) -> Result<LongTermOrder, TwammError>;
+
+    /// Returns the address of the pair the orders are executed against.
+    fn get_pair(e: Env) -> Result<Address, TwammError>;
+}
+
+#[contract]
+struct SoroswapTwamm;
+
+#[contractimpl]
+impl SoroswapTwammTrait for SoroswapTwamm {
+
+    fn initialize(e: Env, pair: Address, interval: u64) -> Result<(), TwammError> {
+        if has_pair(&e) {
+            return Err(TwammError::InitializeAlreadyInitialized);
+        }
+        if interval == 0 {
+            return Err(TwammError::InvalidIntervals);
+        }
+        let pair_client = SoroswapPairClient::new(&e, &pair);
+        put_pair(&e, &pair, &pair_client.token_0(), &pair_client.token_1());
+        // The fee of a pair does not change. Pairs deployed before swap fees were configurable have no `fee` function
+        let fee = match pair_client.try_fee() {
+            Ok(Ok(fee)) => fee,
+            _ => soroswap_library::DEFAULT_FEE,
+        };
+        put_fee(&e, fee);
+        put_interval(&e, interval);
+        put_last_execution(&e, e.ledger().timestamp());
+        extend_instance_ttl(&e);
+        event::initialized(&e, pair, interval);
+        Ok(())
+    }
+
+    fn submit_order(
+        e: Env,
+        owner: Address,
+        token_in: Address,
+        amount_in: i128,
+        number_of_intervals: u64,
+    ) -> Result<u64, TwammError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        if amount_in <= 0 {
+            return Err(TwammError::InvalidAmount);
+        }
+        if number_of_intervals == 0 {
+            return Err(TwammError::InvalidIntervals);
+        }
+        let zero_for_one = if token_in == get_token_0(&e) {
+            true
+        } else if token_in == get_token_1(&e) {
+            false
+        } else {
+            return Err(TwammError::InvalidToken);
+        };
+        owner.require_auth();
+
+        // Orders already submitted are executed at the previous price before this one starts
+        execute_until_now(&e)?;
+        refund_carried_input(&e, zero_for_one);
+
+        let now = e.ledger().timestamp();
+        let interval = get_interval(&e);
+        let expiration = (now / interval + number_of_intervals).checked_mul(interval).unwrap();
+        let duration: i128 = (expiration - now).into();
+        let sale_rate = amount_in / duration;
+        if sale_rate == 0 {
+            return Err(TwammError::InvalidAmount);
+        }
+        let amount_in = sale_rate.checked_mul(duration).unwrap();
+        TokenClient::new(&e, &token_in).transfer(&owner, &e.current_contract_address(), &amount_in);
+
+        put_sale_rate(&e, zero_for_one, get_sale_rate(&e, zero_for_one).checked_add(sale_rate).unwrap());
+        put_sale_rate_ending(
+            &e,
+            zero_for_one,
+            expiration,
+            get_sale_rate_ending(&e, zero_for_one, expiration).checked_add(sale_rate).unwrap(),
+        );
+        add_expiration(&e, expiration);
+
+        let order_id = next_order_id(&e);
+        put_order(&e, order_id, &LongTermOrder {
+            owner: owner.clone(),
+            zero_for_one,
+            sale_rate,
+            reward_factor: get_reward_factor(&e, zero_for_one),
+            unsold_factor: get_unsold_factor(&e, zero_for_one),
+            expiration,
+        });
+
+        event::order_submitted(&e, order_id, owner, token_in, amount_in, sale_rate, expiration);
+        Ok(order_id)
+    }
+
+    fn withdraw_proceeds(e: Env, order_id: u64) -> Result<(i128, i128), TwammError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let mut order = read_order(&e, order_id)?;
+        order.owner.require_auth();
+        execute_until_now(&e)?;
+
+        let (unsold, proceeds) = settle_order(&e, &mut order);
+        if unsold > 0 {
+            TokenClient::new(&e, &token_in(&e, order.zero_for_one)).transfer(&e.current_contract_address(), &order.owner, &unsold);
+        }
+        if proceeds > 0 {
+            TokenClient::new(&e, &token_out(&e, order.zero_for_one)).transfer(&e.current_contract_address(), &order.owner, &proceeds);
+        }
+        if e.ledger().timestamp() >= order.expiration {
+            remove_order(&e, order_id);
+        } else {
+            put_order(&e, order_id, &order);
+        }
+
+        event::withdraw(&e, order_id, order.owner, unsold, proceeds);
+        Ok((unsold, proceeds))
+    }
+
+    fn cancel_order(e: Env, order_id: u64) -> Result<(i128, i128), TwammError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        let mut order = read_order(&e, order_id)?;
+        order.owner.require_auth();
+        execute_until_now(&e)?;
+
+        let now = e.ledger().timestamp();
+        if now >= order.expiration {
+            return Err(TwammError::OrderExpired);
+        }
+        refund_carried_input(&e, order.zero_for_one);
+        let (returned, proceeds) = settle_order(&e, &mut order);
+        let unsold = order.sale_rate
+            .checked_mul((order.expiration - now).into()).unwrap()
+            .checked_add(returned).unwrap();
+
+        put_sale_rate(&e, order.zero_for_one, get_sale_rate(&e, order.zero_for_one) - order.sale_rate);
+        put_sale_rate_ending(
+            &e,
+            order.zero_for_one,
+            order.expiration,
+            get_sale_rate_ending(&e, order.zero_for_one, order.expiration) - order.sale_rate,
+        );
+        if get_sale_rate_ending(&e, true, order.expiration) == 0 && get_sale_rate_ending(&e, false, order.expiration) == 0 {
+            remove_expiration(&e, order.expiration);
+        }
+        remove_order(&e, order_id);
+
+        TokenClient::new(&e, &token_in(&e, order.zero_for_one)).transfer(&e.current_contract_address(), &order.owner, &unsold);
+        if proceeds > 0 {
+            TokenClient::new(&e, &token_out(&e, order.zero_for_one)).transfer(&e.current_contract_address(), &order.owner, &proceeds);
+        }
+
+        event::cancel(&e, order_id, order.owner, unsold, proceeds);
+        Ok((unsold, proceeds))
+    }
+
+    fn execute_virtual_orders(e: Env, max_intervals: u32) -> Result<u64, TwammError> {
+        check_initialized(&e)?;
+        extend_instance_ttl(&e);
+        Ok(execute_virtual_orders(&e, max_intervals))
+    }
+
+    /*  *** Read only functions: *** */
+
+    fn get_order(e: Env, order_id: u64) -> Result<LongTermOrder, TwammError> {
+        check_initialized(&e)?;
+        read_order(&e, order_id)
+    }
+
+    fn get_pair(e: Env) -> Result<Address, TwammError> {
+        check_initialized(&e)?;
+        Ok(get_pair(&e))
+    }
+}
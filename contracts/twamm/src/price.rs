@@ -0,0 +1,84 @@
+//! Prices the virtual orders are executed at. Opposite orders are matched with each other at the pair TWAP, and the
+//! excess of one side is sold through the pair only while its average execution price stays close to the TWAP, so
+//! moving the spot price of the pair right before an execution does not change what the orders get.
+use soroban_sdk::{Env, U256};
+use crate::pair::{SoroswapPairClient, UQ128x128};
+use crate::MAX_PRICE_DEVIATION_BPS;
+
+/// Returns the TWAP of token_0 in token_1 and of token_1 in token_0 over the last `window` seconds, or `None` if the
+/// pair does not have observations old enough.
+pub(crate) fn reference_prices(pair: &SoroswapPairClient, window: u64) -> Option<(UQ128x128, UQ128x128)> {
+    match pair.try_consult(&window) {
+        Ok(Ok(prices)) => Some(prices),
+        _ => None,
+    }
+}
+
+// a * b / c, rounded down, or None when it does not fit in an i128. All of them are positive
+fn mul_div(e: &Env, a: i128, b: i128, c: i128) -> Option<i128> {
+    U256::from_u128(e, a as u128)
+        .mul(&U256::from_u128(e, b as u128))
+        .div(&U256::from_u128(e, c as u128))
+        .to_u128()
+        .and_then(|result| i128::try_from(result).ok())
+}
+
+/// Returns the value of `amount` at `price`, rounded down, or `None` when it does not fit in an i128.
+pub(crate) fn quote(e: &Env, amount: i128, price: &UQ128x128) -> Option<i128> {
+    let amount = U256::from_u128(e, amount as u128);
+    amount.mul(&U256::from_u128(e, price.hi))
+        .add(&amount.mul(&U256::from_u128(e, price.lo)).shr(128))
+        .to_u128()
+        .and_then(|value| i128::try_from(value).ok())
+}
+
+/// Matches the input of the orders selling token_0 with the input of the orders selling token_1 at `price_0`, the
+/// price of token_0 in token_1. The smaller side is matched as a whole.
+///
+/// # Returns
+/// A tuple containing the amount of token_0 and the amount of token_1 matched, both zero when either is too small
+/// to be worth anything at `price_0`.
+pub(crate) fn match_orders(e: &Env, amount_in_0: i128, amount_in_1: i128, price_0: &UQ128x128) -> (i128, i128) {
+    if amount_in_0 == 0 || amount_in_1 == 0 {
+        return (0, 0);
+    }
+    let value_0 = quote(e, amount_in_0, price_0).unwrap_or(i128::MAX);
+    let (matched_0, matched_1) = if value_0 <= amount_in_1 {
+        (amount_in_0, value_0)
+    } else {
+        // amount_in_1 < value_0, so the result is lower than amount_in_0
+        (mul_div(e, amount_in_0, amount_in_1, value_0).unwrap(), amount_in_1)
+    };
+    if matched_0 == 0 || matched_1 == 0 {
+        return (0, 0);
+    }
+    (matched_0, matched_1)
+}
+
+/// Returns the largest part of `amount_in` that can be sold to a constant product pool with `reserve_in`,
+/// `reserve_out` and a fee of `fee` basis points, keeping its average execution price at most
+/// `MAX_PRICE_DEVIATION_BPS` below `price`, the price of the input token, but for the rounding of the output. Zero or
+/// negative when the spot price of the pool is already beyond the bound.
+pub(crate) fn max_amount_in(
+    e: &Env,
+    amount_in: i128,
+    reserve_in: i128,
+    reserve_out: i128,
+    fee: u32,
+    price: &UQ128x128,
+) -> i128 {
+    // More than the whole reserve_out at the TWAP can not be received
+    let value = match quote(e, amount_in, price) {
+        Some(value) => value,
+        None => return 0,
+    };
+    let min_amount_out = mul_div(e, value, 10000 - MAX_PRICE_DEVIATION_BPS, 10000).unwrap();
+    if min_amount_out == 0 {
+        return amount_in;
+    }
+    // Selling x gets x·f·R_out / (R_in + x·f), with f = 1 - fee. Its price stays above p = min_amount_out / amount_in
+    // while x <= R_out / p - R_in / f
+    let fee: i128 = fee.into();
+    mul_div(e, reserve_out, amount_in, min_amount_out).unwrap_or(i128::MAX)
+        - mul_div(e, reserve_in, 10000, 10000 - fee).unwrap()
+}
@@ -0,0 +1,219 @@
+use soroban_sdk::{contracttype, Env, Address, Vec};
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub(crate) const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// A long-term order, selling `sale_rate` tokens per second until `expiration`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LongTermOrder {
+    pub owner: Address,
+    /// Whether the order sells token_0 for token_1, or token_1 for token_0.
+    pub zero_for_one: bool,
+    /// Amount of the input token sold per second.
+    pub sale_rate: i128,
+    /// Reward factor of the orders selling the same token when the proceeds were last withdrawn.
+    pub reward_factor: i128,
+    /// Unsold factor of the orders selling the same token when the proceeds were last withdrawn.
+    pub unsold_factor: i128,
+    /// Ledger timestamp when the order ends, always at the end of an interval.
+    pub expiration: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum DataKey {
+    Pair,                        // Address of the SoroswapPair the orders are executed against
+    Token0,                      // token_0 of the pair
+    Token1,                      // token_1 of the pair
+    Fee,                         // Swap fee of the pair, in basis points
+    Interval,                    // Length of the order intervals, in seconds
+    LastExecution,               // Ledger timestamp of the last virtual execution
+    SaleRate(bool),              // Total sale rate of the orders selling token_0 (true) or token_1 (false)
+    RewardFactor(bool),          // Accumulated output per unit of sale rate, scaled by REWARD_FACTOR_SCALE
+    SaleRateEnding(bool, u64),   // Sale rate of the orders ending at a timestamp
+    RewardFactorAt(bool, u64),   // Reward factor at the end of an interval where orders ended
+    Expirations,                 // Sorted timestamps where orders of either side end, not yet executed
+    CarriedInput(bool),          // Input that could not be sold within the price bound yet, sold with the next segment
+    UnsoldFactor(bool),          // Accumulated carried input returned per unit of sale rate, scaled by REWARD_FACTOR_SCALE
+    UnsoldFactorAt(bool, u64),   // Unsold factor at the end of an interval where orders ended
+    OrderCount,                  // Number of orders submitted, used as the next order id
+    Order(u64),                  // Open long-term order by id
+}
+
+pub fn put_pair(e: &Env, pair: &Address, token_0: &Address, token_1: &Address) {
+    e.storage().instance().set(&DataKey::Pair, pair);
+    e.storage().instance().set(&DataKey::Token0, token_0);
+    e.storage().instance().set(&DataKey::Token1, token_1);
+}
+
+pub fn has_pair(e: &Env) -> bool {
+    e.storage().instance().has(&DataKey::Pair)
+}
+
+pub fn get_pair(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Pair).unwrap()
+}
+
+pub fn get_token_0(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token0).unwrap()
+}
+
+pub fn get_token_1(e: &Env) -> Address {
+    e.storage().instance().get(&DataKey::Token1).unwrap()
+}
+
+pub fn put_fee(e: &Env, fee: u32) {
+    e.storage().instance().set(&DataKey::Fee, &fee);
+}
+
+pub fn get_fee(e: &Env) -> u32 {
+    e.storage().instance().get(&DataKey::Fee).unwrap()
+}
+
+pub fn put_interval(e: &Env, interval: u64) {
+    e.storage().instance().set(&DataKey::Interval, &interval);
+}
+
+pub fn get_interval(e: &Env) -> u64 {
+    e.storage().instance().get(&DataKey::Interval).unwrap()
+}
+
+pub fn put_last_execution(e: &Env, timestamp: u64) {
+    e.storage().instance().set(&DataKey::LastExecution, &timestamp);
+}
+
+pub fn get_last_execution(e: &Env) -> u64 {
+    e.storage().instance().get(&DataKey::LastExecution).unwrap()
+}
+
+pub fn put_sale_rate(e: &Env, zero_for_one: bool, sale_rate: i128) {
+    e.storage().instance().set(&DataKey::SaleRate(zero_for_one), &sale_rate);
+}
+
+pub fn get_sale_rate(e: &Env, zero_for_one: bool) -> i128 {
+    e.storage().instance().get(&DataKey::SaleRate(zero_for_one)).unwrap_or(0)
+}
+
+pub fn put_reward_factor(e: &Env, zero_for_one: bool, reward_factor: i128) {
+    e.storage().instance().set(&DataKey::RewardFactor(zero_for_one), &reward_factor);
+}
+
+pub fn get_reward_factor(e: &Env, zero_for_one: bool) -> i128 {
+    e.storage().instance().get(&DataKey::RewardFactor(zero_for_one)).unwrap_or(0)
+}
+
+pub fn put_sale_rate_ending(e: &Env, zero_for_one: bool, timestamp: u64, sale_rate: i128) {
+    let key = DataKey::SaleRateEnding(zero_for_one, timestamp);
+    e.storage().persistent().set(&key, &sale_rate);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_sale_rate_ending(e: &Env, zero_for_one: bool, timestamp: u64) -> i128 {
+    e.storage().persistent().get(&DataKey::SaleRateEnding(zero_for_one, timestamp)).unwrap_or(0)
+}
+
+pub fn put_reward_factor_at(e: &Env, zero_for_one: bool, timestamp: u64, reward_factor: i128) {
+    let key = DataKey::RewardFactorAt(zero_for_one, timestamp);
+    e.storage().persistent().set(&key, &reward_factor);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_reward_factor_at(e: &Env, zero_for_one: bool, timestamp: u64) -> i128 {
+    e.storage().persistent().get(&DataKey::RewardFactorAt(zero_for_one, timestamp)).unwrap()
+}
+
+pub fn put_unsold_factor(e: &Env, zero_for_one: bool, unsold_factor: i128) {
+    e.storage().instance().set(&DataKey::UnsoldFactor(zero_for_one), &unsold_factor);
+}
+
+pub fn get_unsold_factor(e: &Env, zero_for_one: bool) -> i128 {
+    e.storage().instance().get(&DataKey::UnsoldFactor(zero_for_one)).unwrap_or(0)
+}
+
+pub fn put_unsold_factor_at(e: &Env, zero_for_one: bool, timestamp: u64, unsold_factor: i128) {
+    let key = DataKey::UnsoldFactorAt(zero_for_one, timestamp);
+    e.storage().persistent().set(&key, &unsold_factor);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn get_unsold_factor_at(e: &Env, zero_for_one: bool, timestamp: u64) -> i128 {
+    e.storage().persistent().get(&DataKey::UnsoldFactorAt(zero_for_one, timestamp)).unwrap()
+}
+
+pub fn get_expirations(e: &Env) -> Vec<u64> {
+    let key = DataKey::Expirations;
+    match e.storage().persistent().get(&key) {
+        Some(expirations) => {
+            e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+            expirations
+        }
+        None => Vec::new(e),
+    }
+}
+
+pub fn put_expirations(e: &Env, expirations: &Vec<u64>) {
+    let key = DataKey::Expirations;
+    e.storage().persistent().set(&key, expirations);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Adds `timestamp` to the sorted expirations, unless it is there already.
+pub fn add_expiration(e: &Env, timestamp: u64) {
+    let mut expirations = get_expirations(e);
+    if let Err(index) = expirations.binary_search(timestamp) {
+        expirations.insert(index, timestamp);
+        put_expirations(e, &expirations);
+    }
+}
+
+/// Removes `timestamp` from the sorted expirations, if it is there.
+pub fn remove_expiration(e: &Env, timestamp: u64) {
+    let mut expirations = get_expirations(e);
+    if let Ok(index) = expirations.binary_search(timestamp) {
+        expirations.remove(index);
+        put_expirations(e, &expirations);
+    }
+}
+
+pub fn put_carried_input(e: &Env, zero_for_one: bool, amount: i128) {
+    e.storage().instance().set(&DataKey::CarriedInput(zero_for_one), &amount);
+}
+
+pub fn get_carried_input(e: &Env, zero_for_one: bool) -> i128 {
+    e.storage().instance().get(&DataKey::CarriedInput(zero_for_one)).unwrap_or(0)
+}
+
+/// Returns the id of the next order and increases the order count.
+pub fn next_order_id(e: &Env) -> u64 {
+    let order_id: u64 = e.storage().instance().get(&DataKey::OrderCount).unwrap_or(0);
+    e.storage().instance().set(&DataKey::OrderCount, &(order_id + 1));
+    order_id
+}
+
+pub fn get_order(e: &Env, order_id: u64) -> Option<LongTermOrder> {
+    let key = DataKey::Order(order_id);
+    let order: Option<LongTermOrder> = e.storage().persistent().get(&key);
+    if order.is_some() {
+        e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+    order
+}
+
+pub fn put_order(e: &Env, order_id: u64, order: &LongTermOrder) {
+    let key = DataKey::Order(order_id);
+    e.storage().persistent().set(&key, order);
+    e.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+pub fn remove_order(e: &Env, order_id: u64) {
+    e.storage().persistent().remove(&DataKey::Order(order_id));
+}
+
+pub fn extend_instance_ttl(e: &Env) {
+    e.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
@@ -0,0 +1,136 @@
+#![cfg(test)]
+extern crate std;
+use crate::{SoroswapTwamm, SoroswapTwammClient};
+use crate::pair::SoroswapPairClient;
+use soroban_sdk::{
+    Env,
+    Bytes,
+    BytesN,
+    Address,
+    testutils::{
+        Address as _,
+        Ledger,
+    },
+};
+
+// Token Contract
+mod token {
+    soroban_sdk::contractimport!(file = "../token/target/wasm32-unknown-unknown/release/soroban_token_contract.wasm");
+    pub type TokenClient<'a> = Client<'a>;
+}
+use token::TokenClient;
+
+pub fn create_token_contract<'a>(e: &Env, admin: & Address) -> TokenClient<'a> {
+    TokenClient::new(e, &e.register_stellar_asset_contract(admin.clone()))
+}
+
+fn pair_contract_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../pair/target/wasm32-unknown-unknown/release/soroswap_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+// SoroswapFactory Contract
+mod factory {
+    soroban_sdk::contractimport!(file = "../factory/target/wasm32-unknown-unknown/release/soroswap_factory.wasm");
+    pub type SoroswapFactoryClient<'a> = Client<'a>;
+}
+use factory::SoroswapFactoryClient;
+
+fn create_soroswap_factory<'a>(e: & Env, setter: & Address) -> SoroswapFactoryClient<'a> {
+    let pair_hash = pair_contract_wasm(e);
+    let factory_address = &e.register_contract_wasm(None, factory::WASM);
+    let factory = SoroswapFactoryClient::new(e, factory_address);
+    factory.initialize(setter, &pair_hash);
+    factory
+}
+
+fn create_soroswap_twamm<'a>(e: &Env) -> SoroswapTwammClient<'a> {
+    SoroswapTwammClient::new(e, &e.register_contract(None, SoroswapTwamm {}))
+}
+
+// SoroswapTwamm TEST
+
+pub struct SoroswapTwammTest<'a> {
+    env: Env,
+    contract: SoroswapTwammClient<'a>,
+    token_0: TokenClient<'a>,
+    token_1: TokenClient<'a>,
+    pair: SoroswapPairClient<'a>,
+    owner: Address,
+    user: Address,
+}
+
+impl<'a> SoroswapTwammTest<'a> {
+    // A pair with 1_000_000_000 of each token, at timestamp 1000
+    fn setup() -> Self {
+
+        let env = Env::default();
+        env.mock_all_auths();
+        env.budget().reset_unlimited();
+        let contract = create_soroswap_twamm(&env);
+
+        let admin = Address::generate(&env);
+        let owner = Address::generate(&env);
+        let user = Address::generate(&env);
+
+        let mut token_0 = create_token_contract(&env, &admin);
+        let mut token_1 = create_token_contract(&env, &admin);
+        if token_1.address < token_0.address {
+            std::mem::swap(&mut token_0, &mut token_1);
+        }
+        for address in [&owner, &user] {
+            token_0.mint(address, &10_000_000_000);
+            token_1.mint(address, &10_000_000_000);
+        }
+
+        let factory = create_soroswap_factory(&env, &admin);
+        let pair = SoroswapPairClient::new(&env, &factory.create_pair(&token_0.address, &token_1.address));
+        token_0.transfer(&user, &pair.address, &1_000_000_000);
+        token_1.transfer(&user, &pair.address, &1_000_000_000);
+        pair.deposit(&user);
+        // The orders are bounded by the TWAP, that needs an older observation when the pair is updated in the same ledger
+        pair.increase_observation_cardinality(&8);
+
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1000;
+        });
+
+        SoroswapTwammTest {
+            env,
+            contract,
+            token_0,
+            token_1,
+            pair,
+            owner,
+            user,
+        }
+    }
+
+    fn set_timestamp(&self, timestamp: u64) {
+        self.env.ledger().with_mut(|li| {
+            li.timestamp = timestamp;
+        });
+    }
+
+    // Swaps directly through the pair, as a user selling in small pieces would do
+    fn swap(&self, zero_for_one: bool, amount_in: i128) -> i128 {
+        let (reserve_0, reserve_1) = self.pair.get_reserves();
+        let (token_in, reserve_in, reserve_out) = if zero_for_one {
+            (&self.token_0, reserve_0, reserve_1)
+        } else {
+            (&self.token_1, reserve_1, reserve_0)
+        };
+        let amount_out = soroswap_library::get_amount_out_with_fee(amount_in, reserve_in, reserve_out, self.pair.fee()).unwrap();
+        token_in.transfer(&self.user, &self.pair.address, &amount_in);
+        let (amount_0_out, amount_1_out) = if zero_for_one { (0, amount_out) } else { (amount_out, 0) };
+        self.pair.swap(&amount_0_out, &amount_1_out, &self.user, &Bytes::new(&self.env));
+        amount_out
+    }
+}
+
+mod initialize;
+mod submit_order;
+mod execute;
+mod cancel_order;
@@ -0,0 +1,77 @@
+use soroban_sdk::{vec, IntoVal, symbol_short, testutils::Events};
+
+use crate::test::SoroswapTwammTest;
+use crate::error::TwammError;
+use crate::event::CancelEvent;
+
+#[test]
+fn cancel_order() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+
+    test.set_timestamp(1500);
+    let (unsold, proceeds) = test.contract.cancel_order(&order_id);
+
+    assert_eq!(unsold, 500_000);
+    assert_eq!(proceeds, SoroswapTwammTest::setup().swap(true, 500_000));
+    assert_eq!(test.token_0.balance(&test.owner), 10_000_000_000 - 500_000);
+    assert_eq!(test.token_1.balance(&test.owner), 10_000_000_000 + proceeds);
+    assert_eq!(test.contract.try_get_order(&order_id), Err(Ok(TwammError::OrderNotFound)));
+
+    let cancel_event = test.env.events().all().last().unwrap();
+    let expected_event = CancelEvent { order_id, owner: test.owner.clone(), unsold, proceeds };
+    assert_eq!(
+        vec![&test.env, cancel_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapTWAMM", symbol_short!("cancel")).into_val(&test.env),
+                (expected_event).into_val(&test.env)
+            ),
+        ]
+    );
+
+    // The cancelled order does not sell anymore
+    let reserves = test.pair.get_reserves();
+    test.set_timestamp(2000);
+    test.contract.execute_virtual_orders(&10);
+    assert_eq!(test.pair.get_reserves(), reserves);
+}
+
+#[test]
+fn cancel_order_returns_carried_input() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+
+    // The spot price is moved beyond the bound, so what the order sold until now is carried
+    test.set_timestamp(1500);
+    test.swap(true, 50_000_000);
+    let (unsold, proceeds) = test.contract.cancel_order(&order_id);
+
+    // The carried input is returned with the part of the order not sold yet
+    assert_eq!(unsold, 1_000_000);
+    assert_eq!(proceeds, 0);
+    assert_eq!(test.token_0.balance(&test.owner), 10_000_000_000);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn cancel_order_expired() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+
+    test.set_timestamp(2000);
+    assert_eq!(test.contract.try_cancel_order(&order_id), Err(Ok(TwammError::OrderExpired)));
+    assert!(test.contract.withdraw_proceeds(&order_id).1 > 0);
+}
+
+#[test]
+fn cancel_order_not_found() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    assert_eq!(test.contract.try_cancel_order(&0), Err(Ok(TwammError::OrderNotFound)));
+}
@@ -0,0 +1,235 @@
+use crate::test::SoroswapTwammTest;
+use crate::error::TwammError;
+
+// Output of selling 1_000_000 of token_0 in 10 swaps of 100_000
+fn sequential_swaps_output() -> i128 {
+    let test = SoroswapTwammTest::setup();
+    let mut amount_out = 0;
+    for _ in 0..10 {
+        amount_out += test.swap(true, 100_000);
+    }
+    amount_out
+}
+
+#[test]
+fn execute_matches_sequential_swaps() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+
+    for timestamp in (1100..=2000).step_by(100) {
+        test.set_timestamp(timestamp);
+        assert_eq!(test.contract.execute_virtual_orders(&10), timestamp);
+    }
+    let (_, proceeds) = test.contract.withdraw_proceeds(&order_id);
+
+    assert_eq!(proceeds, sequential_swaps_output());
+    assert_eq!(test.token_1.balance(&test.owner), 10_000_000_000 + proceeds);
+    assert_eq!(test.pair.get_reserves(), (1_000_000_000 + 1_000_000, 1_000_000_000 - proceeds));
+    // Expired orders are removed once withdrawn
+    assert_eq!(test.contract.try_withdraw_proceeds(&order_id), Err(Ok(TwammError::OrderNotFound)));
+}
+
+#[test]
+fn execute_virtually_on_interaction() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+
+    // Nobody interacts until long after the expiration, so the order is sold as a whole up to its expiration
+    test.set_timestamp(5000);
+    let (_, proceeds) = test.contract.withdraw_proceeds(&order_id);
+    assert_eq!(proceeds, SoroswapTwammTest::setup().swap(true, 1_000_000));
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn withdraw_proceeds_during_order() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+
+    test.set_timestamp(1500);
+    let (_, first_proceeds) = test.contract.withdraw_proceeds(&order_id);
+    let sequential = SoroswapTwammTest::setup();
+    assert_eq!(first_proceeds, sequential.swap(true, 500_000));
+    assert_eq!(test.contract.get_order(&order_id).expiration, 2000);
+
+    test.set_timestamp(2000);
+    let (_, second_proceeds) = test.contract.withdraw_proceeds(&order_id);
+    assert_eq!(second_proceeds, sequential.swap(true, 500_000));
+    assert_eq!(test.token_1.balance(&test.owner), 10_000_000_000 + first_proceeds + second_proceeds);
+}
+
+#[test]
+fn execute_opposite_orders() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_0 = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+    let order_1 = test.contract.submit_order(&test.user, &test.token_1.address, &1_000_000, &10);
+
+    test.set_timestamp(2000);
+    let (_, proceeds_0) = test.contract.withdraw_proceeds(&order_0);
+    let (_, proceeds_1) = test.contract.withdraw_proceeds(&order_1);
+
+    // Both sides are matched with each other at the TWAP of 1, without going through the pair
+    assert_eq!(proceeds_0, 1_000_000);
+    assert_eq!(proceeds_1, 1_000_000);
+    assert!(proceeds_0 > sequential_swaps_output());
+    assert_eq!(test.pair.get_reserves(), (1_000_000_000, 1_000_000_000));
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn execute_opposite_orders_excess() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_0 = test.contract.submit_order(&test.owner, &test.token_0.address, &3_000_000, &10);
+    let order_1 = test.contract.submit_order(&test.user, &test.token_1.address, &1_000_000, &10);
+
+    test.set_timestamp(2000);
+    let (_, proceeds_0) = test.contract.withdraw_proceeds(&order_0);
+    let (_, proceeds_1) = test.contract.withdraw_proceeds(&order_1);
+
+    // The orders selling token_1 are matched as a whole, only the excess of token_0 goes through the pair
+    assert_eq!(proceeds_1, 1_000_000);
+    let (reserve_0, reserve_1) = test.pair.get_reserves();
+    assert_eq!(reserve_0, 1_000_000_000 + 2_000_000);
+    assert_eq!(proceeds_0, 1_000_000 + 1_000_000_000 - reserve_1);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.token_1.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn execute_sandwich() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+    let attacker_balance_0 = test.token_0.balance(&test.user);
+
+    // The attacker drops the price of token_0 right before the orders sell it, and buys it back afterwards
+    test.set_timestamp(1500);
+    let front_run_output = test.swap(true, 50_000_000);
+    let reserves = test.pair.get_reserves();
+    assert_eq!(test.contract.execute_virtual_orders(&10), 1500);
+
+    // The spot price is beyond the bound from the TWAP, so nothing is sold and the input is carried
+    assert_eq!(test.pair.get_reserves(), reserves);
+    assert_eq!(test.token_0.balance(&test.contract.address), 1_000_000);
+    test.swap(false, front_run_output);
+    assert!(test.token_0.balance(&test.user) < attacker_balance_0);
+
+    // Once the price is back, the carried input is sold with the rest of the order
+    test.set_timestamp(2000);
+    let (unsold, proceeds) = test.contract.withdraw_proceeds(&order_id);
+    assert_eq!(unsold, 0);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    let unattacked_proceeds = SoroswapTwammTest::setup().swap(true, 1_000_000);
+    assert!(proceeds * 1000 >= unattacked_proceeds * 999);
+}
+
+#[test]
+fn execute_bounded_by_twap() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &100_000_000, &1);
+
+    // Selling 10% of the reserve at once would move the price far beyond the bound, so only a part is sold
+    test.set_timestamp(1100);
+    test.contract.execute_virtual_orders(&10);
+    let (reserve_0, reserve_1) = test.pair.get_reserves();
+    let sold = reserve_0 - 1_000_000_000;
+    let amount_out = 1_000_000_000 - reserve_1;
+    assert!(sold > 0 && sold < 100_000_000);
+    // The average execution price is just within 1% of the TWAP of 1, but for the rounding of the output
+    assert!((amount_out + 1) * 10000 >= sold * (10000 - crate::MAX_PRICE_DEVIATION_BPS));
+    assert!(amount_out * 10000 < sold * (10000 - crate::MAX_PRICE_DEVIATION_BPS + 1));
+
+    // The rest is returned to the order when it expires
+    let (unsold, proceeds) = test.contract.withdraw_proceeds(&order_id);
+    assert_eq!(proceeds, amount_out);
+    assert_eq!(unsold, 100_000_000 - sold);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+}
+
+#[test]
+fn execute_orders_with_different_expirations() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let short_order = test.contract.submit_order(&test.owner, &test.token_0.address, &500_000, &5);
+    let long_order = test.contract.submit_order(&test.user, &test.token_0.address, &1_000_000, &10);
+
+    test.set_timestamp(3000);
+    let (_, short_proceeds) = test.contract.withdraw_proceeds(&short_order);
+    let (_, long_proceeds) = test.contract.withdraw_proceeds(&long_order);
+
+    // Both orders sold until the short one expired, then only the long one
+    let sequential = SoroswapTwammTest::setup();
+    let amount_out = sequential.swap(true, 1_000_000);
+    let expected_short_proceeds = amount_out / 2;
+    let expected_long_proceeds = amount_out / 2 + sequential.swap(true, 500_000);
+    assert!((short_proceeds - expected_short_proceeds).abs() <= 5);
+    assert!((long_proceeds - expected_long_proceeds).abs() <= 5);
+}
+
+#[test]
+fn execute_thousands_of_intervals() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &1);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &10_000_000, &10_000);
+
+    // Only the expiration is visited, so each execution fits in the default budget
+    test.set_timestamp(1000 + 5000);
+    test.env.budget().reset_default();
+    assert_eq!(test.contract.execute_virtual_orders(&1), 6000);
+    test.set_timestamp(1000 + 20_000);
+    test.env.budget().reset_default();
+    assert_eq!(test.contract.execute_virtual_orders(&1), 21_000);
+    test.env.budget().reset_unlimited();
+
+    let (_, proceeds) = test.contract.withdraw_proceeds(&order_id);
+    let sequential = SoroswapTwammTest::setup();
+    assert_eq!(proceeds, sequential.swap(true, 5_000_000) + sequential.swap(true, 5_000_000));
+}
+
+#[test]
+fn execute_in_several_calls() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &1);
+    let mut order_ids = soroban_sdk::Vec::new(&test.env);
+    for number_of_intervals in 1..=40 {
+        order_ids.push_back(test.contract.submit_order(&test.owner, &test.token_0.address, &10_000, &number_of_intervals));
+    }
+
+    // More expirations passed than an interaction visits
+    test.set_timestamp(1100);
+    assert_eq!(test.contract.try_withdraw_proceeds(&order_ids.get(0).unwrap()), Err(Ok(TwammError::ExecutionBehind)));
+    assert_eq!(test.contract.execute_virtual_orders(&10), 1010);
+    assert_eq!(test.contract.execute_virtual_orders(&10), 1020);
+    assert_eq!(test.contract.execute_virtual_orders(&100), 1100);
+
+    for order_id in order_ids {
+        assert!(test.contract.withdraw_proceeds(&order_id).1 > 0);
+    }
+}
+
+#[test]
+fn execute_carries_input_without_output() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000, &10);
+
+    // Each second sells 1, which is too small to get any output, so it is carried to the next execution
+    test.set_timestamp(1001);
+    test.contract.execute_virtual_orders(&10);
+    assert_eq!(test.token_0.balance(&test.contract.address), 1_000);
+    test.set_timestamp(1002);
+    test.contract.execute_virtual_orders(&10);
+    assert_eq!(test.token_0.balance(&test.contract.address), 998);
+
+    test.set_timestamp(2000);
+    let (_, proceeds) = test.contract.withdraw_proceeds(&order_id);
+    assert_eq!(test.token_0.balance(&test.contract.address), 0);
+    assert_eq!(test.pair.get_reserves(), (1_000_000_000 + 1_000, 1_000_000_000 - proceeds));
+}
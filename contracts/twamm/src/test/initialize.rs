@@ -0,0 +1,53 @@
+use soroban_sdk::{vec, IntoVal, symbol_short, testutils::Events};
+
+use crate::test::SoroswapTwammTest;
+use crate::error::TwammError;
+use crate::event::InitializedEvent;
+
+#[test]
+fn not_yet_initialized() {
+    let test = SoroswapTwammTest::setup();
+    assert_eq!(test.contract.try_get_pair(), Err(Ok(TwammError::NotInitialized)));
+    assert_eq!(test.contract.try_get_order(&0), Err(Ok(TwammError::NotInitialized)));
+    assert_eq!(test.contract.try_execute_virtual_orders(&10), Err(Ok(TwammError::NotInitialized)));
+    let result = test.contract.try_submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+    assert_eq!(result, Err(Ok(TwammError::NotInitialized)));
+    assert_eq!(test.contract.try_withdraw_proceeds(&0), Err(Ok(TwammError::NotInitialized)));
+    assert_eq!(test.contract.try_cancel_order(&0), Err(Ok(TwammError::NotInitialized)));
+}
+
+#[test]
+fn initialize() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    assert_eq!(test.contract.get_pair(), test.pair.address);
+
+    let initialized_event = test.env.events().all().last().unwrap();
+    let expected_event = InitializedEvent { pair: test.pair.address.clone(), interval: 100 };
+    assert_eq!(
+        vec![&test.env, initialized_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapTWAMM", symbol_short!("init")).into_val(&test.env),
+                (expected_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn initialize_twice() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    let result = test.contract.try_initialize(&test.pair.address, &100);
+    assert_eq!(result, Err(Ok(TwammError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_zero_interval() {
+    let test = SoroswapTwammTest::setup();
+    let result = test.contract.try_initialize(&test.pair.address, &0);
+    assert_eq!(result, Err(Ok(TwammError::InvalidIntervals)));
+}
@@ -0,0 +1,80 @@
+use soroban_sdk::{vec, Address, IntoVal, symbol_short, testutils::{Address as _, Events}};
+
+use crate::test::SoroswapTwammTest;
+use crate::error::TwammError;
+use crate::event::OrderSubmittedEvent;
+use crate::LongTermOrder;
+
+#[test]
+fn submit_order() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+
+    // Sells over the intervals ending at 1100, ..., 2000
+    let order_id = test.contract.submit_order(&test.owner, &test.token_0.address, &1_000_000, &10);
+    assert_eq!(order_id, 0);
+    let expected_order = LongTermOrder {
+        owner: test.owner.clone(),
+        zero_for_one: true,
+        sale_rate: 1_000,
+        reward_factor: 0,
+        unsold_factor: 0,
+        expiration: 2000,
+    };
+    assert_eq!(test.contract.get_order(&order_id), expected_order);
+    assert_eq!(test.token_0.balance(&test.contract.address), 1_000_000);
+    assert_eq!(test.token_0.balance(&test.owner), 10_000_000_000 - 1_000_000);
+
+    let submitted_event = test.env.events().all().last().unwrap();
+    let expected_event = OrderSubmittedEvent {
+        order_id,
+        owner: test.owner.clone(),
+        token_in: test.token_0.address.clone(),
+        amount_in: 1_000_000,
+        sale_rate: 1_000,
+        expiration: 2000,
+    };
+    assert_eq!(
+        vec![&test.env, submitted_event],
+        vec![
+            &test.env,
+            (
+                test.contract.address.clone(),
+                ("SoroswapTWAMM", symbol_short!("submit")).into_val(&test.env),
+                (expected_event).into_val(&test.env)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn submit_order_during_interval() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+    test.set_timestamp(1050);
+
+    // The current interval counts as the first one, so the order sells for 950 seconds
+    let order_id = test.contract.submit_order(&test.owner, &test.token_1.address, &1_000_000, &10);
+    let order = test.contract.get_order(&order_id);
+    assert_eq!(order.expiration, 2000);
+    assert_eq!(order.sale_rate, 1_000_000 / 950);
+    assert!(!order.zero_for_one);
+    // The remainder is not taken from the owner
+    assert_eq!(test.token_1.balance(&test.contract.address), 1_000_000 / 950 * 950);
+}
+
+#[test]
+fn submit_order_invalid_arguments() {
+    let test = SoroswapTwammTest::setup();
+    test.contract.initialize(&test.pair.address, &100);
+
+    let result = test.contract.try_submit_order(&test.owner, &test.token_0.address, &0, &10);
+    assert_eq!(result, Err(Ok(TwammError::InvalidAmount)));
+    // Less than one token per second
+    let result = test.contract.try_submit_order(&test.owner, &test.token_0.address, &999, &10);
+    assert_eq!(result, Err(Ok(TwammError::InvalidAmount)));
+    let result = test.contract.try_submit_order(&test.owner, &test.token_0.address, &1_000_000, &0);
+    assert_eq!(result, Err(Ok(TwammError::InvalidIntervals)));
+    let result = test.contract.try_submit_order(&test.owner, &Address::generate(&test.env), &1_000_000, &10);
+    assert_eq!(result, Err(Ok(TwammError::InvalidToken)));
+}
@@ -0,0 +1,76 @@
+use soroban_sdk::{self, contracterror};
+use soroswap_library::SoroswapLibraryError;
+use soroswap_pool_common::PoolError;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SoroswapWeightedPairError {
+    /// SoroswapWeightedPair: already initialized
+    InitializeAlreadyInitialized = 1001,
+    /// SoroswapWeightedPair: not yet initialized
+    NotInitialized = 1002,
+
+    /// SoroswapWeightedPair: token_0 must be less than token_1 while initializing
+    InitializeTokenOrderInvalid = 1003,
+    /// SoroswapWeightedPair: fee must be less than 10000 basis points while initializing
+    InitializeFeeInvalid = 1004,
+    /// SoroswapWeightedPair: weight of token 0 must be between MIN_WEIGHT and WEIGHT_DENOMINATOR - MIN_WEIGHT while initializing
+    InitializeWeightInvalid = 1005,
+
+    /// SoroswapWeightedPair: insufficient amount of token 0 sent while doing deposit
+    DepositInsufficientAmountToken0 = 1006,
+    /// SoroswapWeightedPair: insufficient amount of token 1 sent while doing deposit
+    DepositInsufficientAmountToken1 = 1007,
+    /// SoroswapWeightedPair: insufficient first liquidity minted while doing deposit
+    DepositInsufficientFirstLiquidity = 1008,
+    /// SoroswapWeightedPair: insufficient liquidity minted while doing deposit
+    DepositInsufficientLiquidityMinted = 1009,
+
+    /// SoroswapWeightedPair: insufficient output amount while doing swap
+    SwapInsufficientOutputAmount = 1010,
+    /// SoroswapWeightedPair: negatives amounts out dont supported while doing swap
+    SwapNegativesOutNotSupported = 1011,
+    /// SoroswapWeightedPair: insufficient liquidity to do the swap
+    SwapInsufficientLiquidity = 1012,
+    /// SoroswapWeightedPair: invalid to to do the swap
+    SwapInvalidTo = 1013,
+    /// SoroswapWeightedPair: insufficient input amount while doing swap
+    SwapInsufficientInputAmount = 1014,
+    /// SoroswapWeightedPair: negatives amounts in dont supported while doing swap
+    SwapNegativesInNotSupported = 1015,
+    /// SoroswapWeightedPair: the weighted invariant is not met while doing swap
+    SwapInvariantNotMet = 1016,
+
+    /// SoroswapWeightedPair: liquidity was not initialized yet while doing withdraw
+    WithdrawLiquidityNotInitialized = 1017,
+    /// SoroswapWeightedPair: insufficient sent shares while doing withdraw
+    WithdrawInsufficientSentShares = 1018,
+    /// SoroswapWeightedPair: insufficient liquidity burned while doing withdraw
+    WithdrawInsufficientLiquidityBurned = 1019,
+
+    /// SoroswapWeightedPair: the weighted invariant could not be computed for the balances
+    InvariantFailed = 1020,
+
+    /// SoroswapWeightedPair: locked, nested calls are not allowed
+    Locked = 1021,
+
+    /// SoroswapWeightedPair: storage version is newer than the contract version while doing migrate
+    MigrateVersionInvalid = 1022,
+}
+
+// The invariant of the pair is computed by the SoroswapLibrary, so any of its errors means it could not be computed
+impl From<SoroswapLibraryError> for SoroswapWeightedPairError {
+    fn from(_: SoroswapLibraryError) -> Self {
+        SoroswapWeightedPairError::InvariantFailed
+    }
+}
+
+impl From<PoolError> for SoroswapWeightedPairError {
+    fn from(pool_error: PoolError) -> Self {
+        match pool_error {
+            PoolError::Locked => SoroswapWeightedPairError::Locked,
+            PoolError::MigrateVersionInvalid => SoroswapWeightedPairError::MigrateVersionInvalid,
+        }
+    }
+}
@@ -0,0 +1,559 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Bytes, BytesN, Env, IntoVal};
+use soroban_sdk::token::Interface;
+use soroswap_factory_interface::SoroswapFactoryClient;
+use soroswap_callee_interface::SoroswapCalleeClient;
+
+mod storage;
+mod error;
+mod test;
+
+use storage::*;
+use soroswap_pool_common::{balances::*, migration, pool::*, storage::*, event::PoolEvents};
+use soroswap_pair_token::SoroswapPairToken;
+use error::SoroswapWeightedPairError;
+use soroswap_library::{get_weighted_invariant, get_weighted_invariant_up, get_weighted_y, MIN_WEIGHT, WEIGHT_DENOMINATOR};
+
+static MINIMUM_LIQUIDITY: i128 = 1000;
+// Swap fees are expressed in basis points
+static FEE_DENOMINATOR: i128 = 10000;
+// Name of the contract in the first topic of its events
+static POOL_NAME: &str = "SoroswapWeightedPair";
+// Version of the storage layout, see soroswap_pool_common::migration
+const CONTRACT_VERSION: u32 = 1;
+
+// Metadata that is added on to the WASM custom section
+contractmeta!(
+    key = "Description",
+    val = "Weighted constant mean AMM with configurable token weights and swap fee"
+);
+
+pub trait SoroswapWeightedPairTrait {
+    // Sets the token contract addresses, the swap fee and the weight of token 0 (both in basis points) for this pool
+    fn initialize_pair(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32, weight_0: u32) -> Result<(), SoroswapWeightedPairError>;
+
+    fn deposit(e: Env, to: Address) -> Result<i128, SoroswapWeightedPairError>;
+
+    // Swaps. This function should be called from another contract that has already sent tokens to the pair contract,
+    // unless a non-empty data is given: then `to` is called back to pay for the tokens (flash swap)
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapWeightedPairError>;
+
+    fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapWeightedPairError>;
+
+    // Transfers the excess token balances from the pair to the specified to address
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapWeightedPairError>;
+
+    // Updates the reserves of the pair to match the current token balances
+    fn sync(e: Env) -> Result<(), SoroswapWeightedPairError>;
+
+    fn token_0(e: Env) -> Address;
+    fn token_1(e: Env) -> Address;
+    fn factory(e: Env) -> Address;
+    fn fee(e: Env) -> u32;
+    fn weights(e: Env) -> (u32, u32);
+
+    fn invariant_last(e: Env) -> i128;
+
+    fn get_reserves(e: Env) -> (i128, i128);
+
+    // Extends the TTL of the pair instance and code, so the pool does not get archived. Anyone can call it.
+    fn extend_pair_ttl(e: Env) -> Result<(), SoroswapWeightedPairError>;
+
+    // Replaces the code of the weighted pair. Only the factory can call it, and it must call `migrate` afterwards.
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapWeightedPairError>;
+
+    // Runs the pending storage migrations after an upgrade. Only the factory can call it.
+    fn migrate(e: Env) -> Result<u32, SoroswapWeightedPairError>;
+
+    // Returns the version of the storage layout of the weighted pair.
+    fn version(e: Env) -> u32;
+
+    fn my_balance(e: Env, id: Address) -> i128;
+    fn total_shares(e: Env) -> i128;
+}
+
+#[contract]
+struct SoroswapWeightedPair;
+
+#[contractimpl]
+impl SoroswapWeightedPairTrait for SoroswapWeightedPair {
+
+    /// Initializes a new Soroswap weighted pair by setting token addresses, factory, swap fee and token weights.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `factory` - The address of the Soroswap factory contract.
+    /// * `token_0` - The address of the first token in the pair.
+    /// * `token_1` - The address of the second token in the pair.
+    /// * `fee` - The swap fee of the pair, in basis points.
+    /// * `weight_0` - The weight of the first token in basis points, between `MIN_WEIGHT` and `WEIGHT_DENOMINATOR - MIN_WEIGHT`.
+    ///   The second token weighs the rest up to `WEIGHT_DENOMINATOR`.
+    fn initialize_pair(e: Env, factory: Address, token_0: Address, token_1: Address, fee: u32, weight_0: u32) -> Result<(), SoroswapWeightedPairError> {
+        if has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::InitializeAlreadyInitialized);
+        }
+
+        if token_0 >= token_1 {
+            return Err(SoroswapWeightedPairError::InitializeTokenOrderInvalid);
+        }
+
+        if i128::from(fee) >= FEE_DENOMINATOR {
+            return Err(SoroswapWeightedPairError::InitializeFeeInvalid);
+        }
+
+        if !(MIN_WEIGHT..=WEIGHT_DENOMINATOR - MIN_WEIGHT).contains(&weight_0) {
+            return Err(SoroswapWeightedPairError::InitializeWeightInvalid);
+        }
+
+        put_factory(&e, factory);
+
+        SoroswapPairToken::initialize(
+            e.clone(),
+            e.current_contract_address(),
+            7,
+            "Soroswap Weighted LP Token".into_val(&e),
+            "SOROSWAP-WLP".into_val(&e),
+        );
+
+        put_token_0(&e, token_0);
+        put_token_1(&e, token_1);
+        put_fee(&e, fee);
+        put_weight_0(&e, weight_0);
+        put_total_shares(&e, 0);
+        put_reserve_0(&e, 0);
+        put_reserve_1(&e, 0);
+        put_version(&e, CONTRACT_VERSION);
+        extend_instance_ttl(&e);
+
+        Ok(())
+    }
+
+    /// Returns the address of the first token in the weighted pair.
+    fn token_0(e: Env) -> Address {
+        get_token_0(&e)
+    }
+
+    /// Returns the address of the second token in the weighted pair.
+    fn token_1(e: Env) -> Address {
+        get_token_1(&e)
+    }
+
+    /// Returns the address of the Soroswap factory contract.
+    fn factory(e: Env) -> Address {
+        get_factory(&e)
+    }
+
+    /// Returns the swap fee of the weighted pair, in basis points.
+    fn fee(e: Env) -> u32 {
+        get_fee(&e)
+    }
+
+    /// Returns the weights of token 0 and token 1, in basis points.
+    fn weights(e: Env) -> (u32, u32) {
+        let weight_0 = get_weight_0(&e);
+        (weight_0, WEIGHT_DENOMINATOR - weight_0)
+    }
+
+    /// Deposits tokens into the weighted pair and mints LP tokens in return.
+    /// The first deposit mints the invariant of the amounts, and later deposits mint LP tokens in proportion
+    /// to the growth of the invariant, so a single token can be deposited. The part of a deposit above the
+    /// ratio of the reserves pays the swap fee.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the minted LP tokens will be sent.
+    ///
+    /// # Returns
+    /// The amount of minted LP tokens.
+    /// Possible errors:
+    /// - `SoroswapWeightedPairError::NotInitialized`
+    /// - `SoroswapWeightedPairError::DepositInsufficientAmountToken0`
+    /// - `SoroswapWeightedPairError::DepositInsufficientAmountToken1`
+    /// - `SoroswapWeightedPairError::DepositInsufficientFirstLiquidity`
+    /// - `SoroswapWeightedPairError::DepositInsufficientLiquidityMinted`
+    fn deposit(e: Env, to: Address) -> Result<i128, SoroswapWeightedPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let amount_0 = balance_0.checked_sub(reserve_0).ok_or(SoroswapWeightedPairError::DepositInsufficientAmountToken0)?;
+        let amount_1 = balance_1.checked_sub(reserve_1).ok_or(SoroswapWeightedPairError::DepositInsufficientAmountToken1)?;
+
+        let fee_on = mint_fee(&e, reserve_0, reserve_1)?;
+        let total_shares = get_total_shares(&e);
+        let weight_0 = get_weight_0(&e);
+
+        let liquidity = if total_shares == 0 {
+            if amount_0 <= 0 {
+                return Err(SoroswapWeightedPairError::DepositInsufficientAmountToken0);
+            }
+            if amount_1 <= 0 {
+                return Err(SoroswapWeightedPairError::DepositInsufficientAmountToken1);
+            }
+            // When the liquidity pool is being initialized, we block the minimum liquidity forever in this contract
+            mint_shares(&e, &e.current_contract_address(), MINIMUM_LIQUIDITY);
+            let previous_liquidity = get_weighted_invariant(amount_0, amount_1, weight_0)?;
+            if previous_liquidity <= MINIMUM_LIQUIDITY {
+                return Err(SoroswapWeightedPairError::DepositInsufficientFirstLiquidity);
+            }
+            previous_liquidity.checked_sub(MINIMUM_LIQUIDITY).unwrap()
+        } else {
+            // A single token is enough
+            if amount_0 < 0 || (amount_0 == 0 && amount_1 == 0) {
+                return Err(SoroswapWeightedPairError::DepositInsufficientAmountToken0);
+            }
+            if amount_1 < 0 {
+                return Err(SoroswapWeightedPairError::DepositInsufficientAmountToken1);
+            }
+            // The amount above the ratio of the reserves is charged the swap fee, rounded up
+            let fee: i128 = get_fee(&e).into();
+            let (fee_0, fee_1) = if amount_0.checked_mul(reserve_1).unwrap() > amount_1.checked_mul(reserve_0).unwrap() {
+                let excess_0 = amount_0.checked_sub(amount_1.checked_mul(reserve_0).unwrap().checked_div(reserve_1).unwrap()).unwrap();
+                (ceil_div(excess_0.checked_mul(fee).unwrap(), FEE_DENOMINATOR), 0)
+            } else {
+                let excess_1 = amount_1.checked_sub(amount_0.checked_mul(reserve_1).unwrap().checked_div(reserve_0).unwrap()).unwrap();
+                (0, ceil_div(excess_1.checked_mul(fee).unwrap(), FEE_DENOMINATOR))
+            };
+
+            let invariant = get_weighted_invariant_up(reserve_0, reserve_1, weight_0)?;
+            let new_invariant = get_weighted_invariant(balance_0.checked_sub(fee_0).unwrap(), balance_1.checked_sub(fee_1).unwrap(), weight_0)?;
+            total_shares.checked_mul(new_invariant.checked_sub(invariant).unwrap()).unwrap().checked_div(invariant).unwrap()
+        };
+
+        if liquidity <= 0 {
+            return Err(SoroswapWeightedPairError::DepositInsufficientLiquidityMinted);
+        }
+
+        mint_shares(&e, &to, liquidity);
+        update(&e, POOL_NAME, balance_0, balance_1);
+
+        (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        if fee_on {
+            put_invariant_last(&e, get_weighted_invariant(reserve_0, reserve_1, get_weight_0(&e))?);
+        }
+
+        PoolEvents::new(&e, POOL_NAME).deposit(to, amount_0, amount_1, liquidity, reserve_0, reserve_1);
+
+        unlock(&e);
+        Ok(liquidity)
+    }
+
+    /// Executes a token swap within the weighted pair. The balances without the swap fee must keep
+    /// at least the weighted invariant of the reserves.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `amount_0_out` - The desired amount of the first token to receive.
+    /// * `amount_1_out` - The desired amount of the second token to receive.
+    /// * `to` - The address where the swapped tokens will be sent.
+    /// * `data` - If not empty, `soroswap_call` is invoked on `to` with this payload after the tokens have been sent,
+    ///   so `to` can pay for them before the invariant is checked (flash swap).
+    ///
+    /// # Errors
+    /// - `SoroswapWeightedPairError::NotInitialized`
+    /// - `SoroswapWeightedPairError::SwapInsufficientOutputAmount`
+    /// - `SoroswapWeightedPairError::SwapNegativesOutNotSupported`
+    /// - `SoroswapWeightedPairError::SwapInsufficientLiquidity`
+    /// - `SoroswapWeightedPairError::SwapInvalidTo`
+    /// - `SoroswapWeightedPairError::SwapInsufficientInputAmount`
+    /// - `SoroswapWeightedPairError::SwapNegativesInNotSupported`
+    /// - `SoroswapWeightedPairError::SwapInvariantNotMet`
+    fn swap(e: Env, amount_0_out: i128, amount_1_out: i128, to: Address, data: Bytes) -> Result<(), SoroswapWeightedPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+
+        if amount_0_out == 0 && amount_1_out == 0 {
+            return Err(SoroswapWeightedPairError::SwapInsufficientOutputAmount);
+        }
+        if amount_0_out < 0 || amount_1_out < 0 {
+            return Err(SoroswapWeightedPairError::SwapNegativesOutNotSupported);
+        }
+        if amount_0_out >= reserve_0 || amount_1_out >= reserve_1 {
+            return Err(SoroswapWeightedPairError::SwapInsufficientLiquidity);
+        }
+        if to == get_token_0(&e) || to == get_token_1(&e) {
+            return Err(SoroswapWeightedPairError::SwapInvalidTo);
+        }
+
+        if amount_0_out > 0 {
+            transfer_token_0_from_pair(&e, &to, amount_0_out);
+        }
+        if amount_1_out > 0 {
+            transfer_token_1_from_pair(&e, &to, amount_1_out);
+        }
+        if !data.is_empty() {
+            SoroswapCalleeClient::new(&e, &to).soroswap_call(&e.current_contract_address(), &amount_0_out, &amount_1_out, &data);
+        }
+
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+
+        let amount_0_in = if balance_0 > reserve_0.checked_sub(amount_0_out).unwrap() {
+            balance_0.checked_sub(reserve_0.checked_sub(amount_0_out).unwrap()).unwrap()
+        } else {
+            0
+        };
+        let amount_1_in = if balance_1 > reserve_1.checked_sub(amount_1_out).unwrap() {
+            balance_1.checked_sub(reserve_1.checked_sub(amount_1_out).unwrap()).unwrap()
+        } else {
+            0
+        };
+
+        if amount_0_in == 0 && amount_1_in == 0 {
+            return Err(SoroswapWeightedPairError::SwapInsufficientInputAmount);
+        }
+        if amount_0_in < 0 || amount_1_in < 0 {
+            return Err(SoroswapWeightedPairError::SwapNegativesInNotSupported);
+        }
+
+        let fee: i128 = get_fee(&e).into();
+        let fee_0 = (amount_0_in.checked_mul(fee).unwrap()).checked_div(FEE_DENOMINATOR).unwrap();
+        let fee_1 = (amount_1_in.checked_mul(fee).unwrap()).checked_div(FEE_DENOMINATOR).unwrap();
+
+        let balance_0_minus_fee = balance_0.checked_sub(fee_0).unwrap();
+        let balance_1_minus_fee = balance_1.checked_sub(fee_1).unwrap();
+
+        // The balance of the output side must be at least the one that keeps the invariant of the reserves,
+        // computed from the input side with the SoroswapLibrary, so the amounts it quotes always pass this check
+        let weight_0 = get_weight_0(&e);
+        let invariant_met = if amount_0_in > 0 {
+            balance_1_minus_fee >= get_weighted_y(balance_0_minus_fee, reserve_0, reserve_1, weight_0)?
+        } else {
+            balance_0_minus_fee >= get_weighted_y(balance_1_minus_fee, reserve_1, reserve_0, WEIGHT_DENOMINATOR - weight_0)?
+        };
+        if !invariant_met {
+            return Err(SoroswapWeightedPairError::SwapInvariantNotMet);
+        }
+
+        update(&e, POOL_NAME, balance_0, balance_1);
+
+        PoolEvents::new(&e, POOL_NAME).swap(to, amount_0_in, amount_1_in, amount_0_out, amount_1_out);
+
+        unlock(&e);
+        Ok(())
+    }
+
+    /// Withdraws liquidity from the weighted pair, burning LP tokens and returning the corresponding tokens to the user.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the withdrawn tokens will be sent.
+    ///
+    /// # Returns
+    /// A tuple containing the amounts of token 0 and token 1 withdrawn from the pair.
+    fn withdraw(e: Env, to: Address) -> Result<(i128, i128), SoroswapWeightedPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+
+        let balance_shares = get_balance_shares(&e);
+        if balance_shares == 0 {
+            return Err(SoroswapWeightedPairError::WithdrawLiquidityNotInitialized);
+        }
+
+        let (mut reserve_0, mut reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let (mut balance_0, mut balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let user_sent_shares = balance_shares.checked_sub(MINIMUM_LIQUIDITY).unwrap();
+
+        if user_sent_shares <= 0 {
+            return Err(SoroswapWeightedPairError::WithdrawInsufficientSentShares);
+        }
+
+        let fee_on = mint_fee(&e, reserve_0, reserve_1)?;
+        let total_shares = get_total_shares(&e);
+
+        let amount_0 = (balance_0.checked_mul(user_sent_shares).unwrap()).checked_div(total_shares).unwrap();
+        let amount_1 = (balance_1.checked_mul(user_sent_shares).unwrap()).checked_div(total_shares).unwrap();
+
+        if amount_0 <= 0 || amount_1 <= 0 {
+            return Err(SoroswapWeightedPairError::WithdrawInsufficientLiquidityBurned);
+        }
+
+        burn_shares(&e, user_sent_shares);
+
+        transfer_token_0_from_pair(&e, &to, amount_0);
+        transfer_token_1_from_pair(&e, &to, amount_1);
+
+        (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+
+        update(&e, POOL_NAME, balance_0, balance_1);
+
+        (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        if fee_on {
+            put_invariant_last(&e, get_weighted_invariant(reserve_0, reserve_1, get_weight_0(&e))?);
+        }
+
+        PoolEvents::new(&e, POOL_NAME).withdraw(to, user_sent_shares, amount_0, amount_1, reserve_0, reserve_1);
+        unlock(&e);
+        Ok((amount_0, amount_1))
+    }
+
+    /// Skims excess tokens from reserves and sends them to the specified address.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `to` - The address where the excess tokens will be sent.
+    fn skim(e: Env, to: Address) -> Result<(), SoroswapWeightedPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        let (reserve_0, reserve_1) = (get_reserve_0(&e), get_reserve_1(&e));
+        let skimmed_0 = balance_0.checked_sub(reserve_0).unwrap();
+        let skimmed_1 = balance_1.checked_sub(reserve_1).unwrap();
+        transfer_token_0_from_pair(&e, &to, skimmed_0);
+        transfer_token_1_from_pair(&e, &to, skimmed_1);
+        PoolEvents::new(&e, POOL_NAME).skim(skimmed_0, skimmed_1);
+        unlock(&e);
+        Ok(())
+    }
+
+    /// Forces reserves to match current balances.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    fn sync(e: Env) -> Result<(), SoroswapWeightedPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+        lock(&e)?;
+        extend_instance_ttl(&e);
+        let (balance_0, balance_1) = (get_balance_0(&e), get_balance_1(&e));
+        update(&e, POOL_NAME, balance_0, balance_1);
+        unlock(&e);
+        Ok(())
+    }
+
+    /// Returns the current reserves of token 0 and token 1.
+    fn get_reserves(e: Env) -> (i128, i128) {
+        (get_reserve_0(&e), get_reserve_1(&e))
+    }
+
+    /// Returns the invariant of the reserves after the last deposit or withdraw, while fees are enabled.
+    fn invariant_last(e: Env) -> i128 {
+        get_invariant_last(&e)
+    }
+
+    /// Extends the time to live of the pair instance storage and of its code, so the pool is not archived
+    /// while nobody interacts with it. Anyone can call this function.
+    ///
+    /// # Errors
+    /// - `SoroswapWeightedPairError::NotInitialized`
+    fn extend_pair_ttl(e: Env) -> Result<(), SoroswapWeightedPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+        extend_instance_ttl(&e);
+        Ok(())
+    }
+
+    /// Replaces the code of the weighted pair with the Wasm identified by `new_wasm_hash`. Reserves, LP balances
+    /// and the rest of the storage are kept. The factory must then call `migrate` in order to update the storage layout.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    /// * `new_wasm_hash` - The hash of the new Wasm, that must already be uploaded.
+    ///
+    /// # Errors
+    /// - `SoroswapWeightedPairError::NotInitialized`
+    fn upgrade(e: Env, new_wasm_hash: BytesN<32>) -> Result<(), SoroswapWeightedPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+        extend_instance_ttl(&e);
+        e.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Runs the storage migrations between the stored version and the version of the current code.
+    ///
+    /// # Arguments
+    /// * `e` - The runtime environment.
+    ///
+    /// # Returns
+    /// The new storage version.
+    ///
+    /// # Errors
+    /// - `SoroswapWeightedPairError::NotInitialized`
+    /// - `SoroswapWeightedPairError::MigrateVersionInvalid`: The storage was written by a newer version of the weighted pair.
+    fn migrate(e: Env) -> Result<u32, SoroswapWeightedPairError> {
+        if !has_token_0(&e) {
+            return Err(SoroswapWeightedPairError::NotInitialized);
+        }
+        get_factory(&e).require_auth();
+        extend_instance_ttl(&e);
+        let (old_version, new_version) = migration::migrate(&e, CONTRACT_VERSION)?;
+        if old_version != new_version {
+            PoolEvents::new(&e, POOL_NAME).migrate(old_version, new_version);
+        }
+        Ok(new_version)
+    }
+
+    /// Returns the version of the storage layout of the weighted pair.
+    fn version(e: Env) -> u32 {
+        get_version(&e)
+    }
+
+    /// Returns the balance of LP shares for a specific address.
+    fn my_balance(e: Env, id: Address) -> i128 {
+        SoroswapPairToken::balance(e.clone(), id)
+    }
+
+    /// Returns the total number of LP shares in circulation.
+    fn total_shares(e: Env) -> i128 {
+        get_total_shares(&e)
+    }
+}
+
+fn mint_fee(e: &Env, reserve_0: i128, reserve_1: i128) -> Result<bool, SoroswapWeightedPairError> {
+
+    /*
+            Same as the constant product pair, with the weighted invariant V in place of sqrt(k):
+            the protocol takes share_numerator/share_denominator of the growth of V since the last
+            deposit or withdraw, minted as new LP tokens to the fee beneficiary.
+    */
+
+    let factory_client = SoroswapFactoryClient::new(e, &get_factory(e));
+    let fee_on = factory_client.fees_enabled();
+    let invariant_last = get_invariant_last(e);
+
+    if fee_on {
+        let (share_numerator, share_denominator) = factory_client.protocol_fee_share();
+        let share_numerator: i128 = share_numerator.into();
+        let share_denominator: i128 = share_denominator.into();
+
+        if invariant_last != 0 && share_numerator > 0 {
+            let invariant = get_weighted_invariant(reserve_0, reserve_1, get_weight_0(e))?;
+            if invariant > invariant_last {
+                let total_shares = get_total_shares(e);
+                let numerator = total_shares.checked_mul(invariant.checked_sub(invariant_last).unwrap()).unwrap()
+                    .checked_mul(share_numerator).unwrap();
+                let denominator = invariant.checked_mul(share_denominator.checked_sub(share_numerator).unwrap()).unwrap()
+                    .checked_add(invariant_last.checked_mul(share_numerator).unwrap()).unwrap();
+                let liquidity_pool_shares_fees = numerator.checked_div(denominator).unwrap();
+
+                if liquidity_pool_shares_fees > 0 {
+                    mint_shares(e, &factory_client.fee_to(), liquidity_pool_shares_fees);
+                }
+            }
+        }
+    } else if invariant_last != 0 {
+        put_invariant_last(e, 0);
+    }
+
+    Ok(fee_on)
+}
+
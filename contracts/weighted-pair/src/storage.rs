@@ -0,0 +1,11 @@
+//! Storage of the parameters of the weighted pair. The rest of its storage is laid out by soroswap_pool_common.
+use soroban_sdk::Env;
+use soroswap_pool_common::storage::{get_parameter, put_parameter};
+
+pub fn get_weight_0(e: &Env) -> u32 {
+    get_parameter(e)
+}
+
+pub fn put_weight_0(e: &Env, weight_0: u32) {
+    put_parameter(e, weight_0);
+}
@@ -0,0 +1,59 @@
+#![cfg(test)]
+use core::ops::Deref;
+use soroban_sdk::{BytesN, Env};
+use soroswap_pool_common::testutils::PoolTest;
+pub(crate) use soroswap_pool_common::testutils::pair_wasm;
+use crate::{SoroswapWeightedPairClient};
+
+// WEIGHTED PAIR CONTRACT
+fn weighted_pair_wasm(e: &Env) -> BytesN<32> {
+    soroban_sdk::contractimport!(
+        file = "../weighted-pair/target/wasm32-unknown-unknown/release/soroswap_weighted_pair.wasm"
+    );
+    e.deployer().upload_contract_wasm(WASM)
+}
+
+fn create_weighted_pair_contract<'a>(e: & Env) -> SoroswapWeightedPairClient<'a> {
+    SoroswapWeightedPairClient::new(e, &e.register_contract(None, crate::SoroswapWeightedPair {}))
+}
+
+// THE TEST
+// The tokens, the user and the factory are shared with the stable pair tests
+pub struct SoroswapWeightedPairTest<'a> {
+    pool: PoolTest<'a>,
+    contract: SoroswapWeightedPairClient<'a>,
+}
+
+impl<'a> Deref for SoroswapWeightedPairTest<'a> {
+    type Target = PoolTest<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pool
+    }
+}
+
+impl<'a> SoroswapWeightedPairTest<'a> {
+    fn setup() -> Self {
+        let pool = PoolTest::setup();
+        let contract = create_weighted_pair_contract(&pool.env);
+        SoroswapWeightedPairTest { pool, contract }
+    }
+
+    // Initializes the pair with a 0.3% fee and the given weight of token 0
+    fn initialize(&self, weight_0: u32) {
+        self.contract.initialize_pair(&self.factory.address, &self.token_0.address, &self.token_1.address, &30, &weight_0);
+    }
+
+    fn add_liquidity(&self, amount_0: i128, amount_1: i128) -> i128 {
+        self.token_0.transfer(&self.user, &self.contract.address, &amount_0);
+        self.token_1.transfer(&self.user, &self.contract.address, &amount_1);
+        self.contract.deposit(&self.user)
+    }
+}
+
+mod initialize_pair;
+mod deposit;
+mod swap;
+mod withdraw;
+mod fee;
+mod upgrade;
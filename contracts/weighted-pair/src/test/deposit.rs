@@ -0,0 +1,103 @@
+use crate::test::SoroswapWeightedPairTest;
+use crate::error::SoroswapWeightedPairError;
+
+#[test]
+fn deposit_not_yet_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn deposit_zero_tokens_sent() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositInsufficientAmountToken0)));
+}
+
+#[test]
+fn deposit_first_liquidity_only_token_0_sent() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositInsufficientAmountToken1)));
+}
+
+#[test]
+fn deposit_insufficient_first_liquidity() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.token_0.transfer(&test.user, &test.contract.address, &1_000);
+    test.token_1.transfer(&test.user, &test.contract.address, &1_000);
+    let res = test.contract.try_deposit(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::DepositInsufficientFirstLiquidity)));
+}
+
+#[test]
+fn deposit_first_liquidity() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    // 80/20 pool holding the same value of both tokens when token 0 is worth 1/4 of token 1
+    let liquidity = test.add_liquidity(16_000_000_000, 1_000_000_000);
+    let invariant = soroswap_library::get_weighted_invariant(16_000_000_000, 1_000_000_000, 8000).unwrap();
+    assert_eq!(liquidity, invariant - 1000);
+    // 16e9^0.8 · 1e9^0.2 = 2^3.2 · 1e9
+    assert!((invariant - 9_189_586_839).abs() <= 2);
+    assert_eq!(test.contract.my_balance(&test.user), liquidity);
+    assert_eq!(test.contract.my_balance(&test.contract.address), 1000);
+    assert_eq!(test.contract.get_reserves(), (16_000_000_000, 1_000_000_000));
+}
+
+#[test]
+fn deposit_second_liquidity_proportional() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+    let total_shares = test.contract.total_shares();
+    let liquidity = test.add_liquidity(8_000_000_000, 500_000_000);
+    // The invariant grows by half, no fee is charged, and the truncation errors stay below the precision margin
+    assert!(liquidity <= total_shares / 2);
+    assert!(liquidity >= total_shares / 2 - total_shares / 1_000_000_000);
+    assert_eq!(test.contract.get_reserves(), (24_000_000_000, 1_500_000_000));
+}
+
+#[test]
+fn deposit_single_sided() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+    let total_shares = test.contract.total_shares();
+
+    test.token_1.transfer(&test.user, &test.contract.address, &100_000_000);
+    let liquidity = test.contract.deposit(&test.user);
+
+    // Depositing 10% more of the token weighing 20% grows the invariant by 1.1^0.2, less the swap fee
+    let invariant = soroswap_library::get_weighted_invariant(16_000_000_000, 1_000_000_000, 8000).unwrap();
+    let fee = 100_000_000 * 30 / 10000;
+    let new_invariant = soroswap_library::get_weighted_invariant(16_000_000_000, 1_100_000_000 - fee, 8000).unwrap();
+    // The previous invariant is rounded up, so a few shares less than the growth are minted
+    let growth_shares = total_shares * (new_invariant - invariant) / invariant;
+    assert!(liquidity <= growth_shares);
+    assert!(liquidity >= growth_shares - 10);
+    assert!(liquidity < total_shares * 192 / 10_000);
+    assert!(liquidity > total_shares * 185 / 10_000);
+    assert_eq!(test.contract.get_reserves(), (16_000_000_000, 1_100_000_000));
+}
+
+#[test]
+fn deposit_single_sided_not_cheaper_than_swap() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+    let liquidity = test.add_liquidity(1_000_000_000, 0);
+
+    // Withdrawing right away gives back less than swapping part of the deposit for token 1
+    let total_shares = test.contract.total_shares();
+    let (reserve_0, reserve_1) = test.contract.get_reserves();
+    let amount_0 = reserve_0 * liquidity / total_shares;
+    let amount_1 = reserve_1 * liquidity / total_shares;
+    let swap_out = soroswap_library::get_weighted_amount_out(1_000_000_000 - amount_0, 16_000_000_000, 1_000_000_000, 8000, 30).unwrap();
+    assert!(amount_1 <= swap_out);
+}
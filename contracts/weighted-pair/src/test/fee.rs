@@ -0,0 +1,56 @@
+use crate::test::SoroswapWeightedPairTest;
+use soroban_sdk::Bytes;
+
+fn swap_back_and_forth(test: &SoroswapWeightedPairTest) {
+    for zero_for_one in [true, false, true, false] {
+        let (reserve_0, reserve_1) = test.contract.get_reserves();
+        if zero_for_one {
+            let amount_out = soroswap_library::get_weighted_amount_out(1_000_000_000, reserve_0, reserve_1, 8000, 30).unwrap();
+            test.token_0.transfer(&test.user, &test.contract.address, &1_000_000_000);
+            test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+        } else {
+            let amount_out = soroswap_library::get_weighted_amount_out(100_000_000, reserve_1, reserve_0, 2000, 30).unwrap();
+            test.token_1.transfer(&test.user, &test.contract.address, &100_000_000);
+            test.contract.swap(&amount_out, &0, &test.user, &Bytes::new(&test.env));
+        }
+    }
+}
+
+#[test]
+fn protocol_fee_not_minted_when_fees_disabled() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+    assert_eq!(test.contract.invariant_last(), 0);
+
+    swap_back_and_forth(&test);
+    test.add_liquidity(16_000_000, 1_000_000);
+
+    assert_eq!(test.contract.my_balance(&test.admin), 0);
+    assert_eq!(test.contract.invariant_last(), 0);
+}
+
+#[test]
+fn protocol_fee_minted_on_invariant_growth() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.factory.set_fees_enabled(&true);
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+    let invariant_last = test.contract.invariant_last();
+    assert_eq!(invariant_last, soroswap_library::get_weighted_invariant(16_000_000_000, 1_000_000_000, 8000).unwrap());
+
+    swap_back_and_forth(&test);
+    let (reserve_0, reserve_1) = test.contract.get_reserves();
+    let invariant = soroswap_library::get_weighted_invariant(reserve_0, reserve_1, 8000).unwrap();
+    assert!(invariant > invariant_last);
+
+    let total_shares = test.contract.total_shares();
+    test.add_liquidity(16_000_000, 1_000_000);
+
+    // 1/6 of the growth of the invariant is minted to fee_to, as in the constant product pair
+    let expected_fee_shares = total_shares * (invariant - invariant_last) / (invariant * 5 + invariant_last);
+    assert!(expected_fee_shares > 0);
+    assert_eq!(test.contract.my_balance(&test.admin), expected_fee_shares);
+    let (reserve_0, reserve_1) = test.contract.get_reserves();
+    assert_eq!(test.contract.invariant_last(), soroswap_library::get_weighted_invariant(reserve_0, reserve_1, 8000).unwrap());
+}
@@ -0,0 +1,52 @@
+use crate::test::SoroswapWeightedPairTest;
+use crate::error::SoroswapWeightedPairError;
+
+#[test]
+fn initialize_pair_token_1_less_than_token_0() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_1.address, &test.token_0.address, &30, &8000);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeTokenOrderInvalid)));
+}
+
+#[test]
+fn double_initialize_pair() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30, &8000);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeAlreadyInitialized)));
+}
+
+#[test]
+fn initialize_pair_fee_invalid() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &10000, &8000);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeFeeInvalid)));
+}
+
+#[test]
+fn initialize_pair_weight_too_low() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30, &99);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeWeightInvalid)));
+}
+
+#[test]
+fn initialize_pair_weight_too_high() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_initialize_pair(&test.factory.address, &test.token_0.address, &test.token_1.address, &30, &9901);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::InitializeWeightInvalid)));
+}
+
+#[test]
+fn initialize_pair_initial_values() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    assert_eq!(test.contract.token_0(), test.token_0.address);
+    assert_eq!(test.contract.token_1(), test.token_1.address);
+    assert_eq!(test.contract.factory(), test.factory.address);
+    assert_eq!(test.contract.fee(), 30);
+    assert_eq!(test.contract.weights(), (8000, 2000));
+    assert_eq!(test.contract.get_reserves(), (0, 0));
+    assert_eq!(test.contract.total_shares(), 0);
+    assert_eq!(test.contract.invariant_last(), 0);
+}
@@ -0,0 +1,138 @@
+use crate::test::SoroswapWeightedPairTest;
+use crate::error::SoroswapWeightedPairError;
+use soroban_sdk::Bytes;
+
+#[test]
+fn swap_not_yet_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_swap(&0, &1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn swap_amounts_zero() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    let res = test.contract.try_swap(&0, &0, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInsufficientOutputAmount)));
+}
+
+#[test]
+fn swap_negative_out() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    let res = test.contract.try_swap(&-1, &1, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapNegativesOutNotSupported)));
+}
+
+#[test]
+fn swap_insufficient_liquidity() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(4_000_000, 1_000_000);
+    let res = test.contract.try_swap(&0, &1_000_000, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInsufficientLiquidity)));
+}
+
+#[test]
+fn swap_to_token() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(4_000_000, 1_000_000);
+    let res = test.contract.try_swap(&0, &1000, &test.token_0.address, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInvalidTo)));
+}
+
+#[test]
+fn swap_nothing_sent() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(4_000_000, 1_000_000);
+    let res = test.contract.try_swap(&0, &1000, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInsufficientInputAmount)));
+}
+
+#[test]
+fn swap_token_0_for_token_1_library_amount() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+
+    let amount_in = 1_000_000_000;
+    let amount_out = soroswap_library::get_weighted_amount_out(amount_in, 16_000_000_000, 1_000_000_000, 8000, 30).unwrap();
+    // 1 - (16 / (16 + 0.997))^4 of the reserve of token 1
+    assert!((amount_out - 214_780_939).abs() <= 1);
+
+    let balance_1_before = test.token_1.balance(&test.user);
+    test.token_0.transfer(&test.user, &test.contract.address, &amount_in);
+
+    // The quote keeps one unit in the pair, so two more units than the quote break the invariant
+    let res = test.contract.try_swap(&0, &(amount_out + 2), &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInvariantNotMet)));
+
+    test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.token_1.balance(&test.user), balance_1_before + amount_out);
+    assert_eq!(test.contract.get_reserves(), (16_000_000_000 + amount_in, 1_000_000_000 - amount_out));
+}
+
+#[test]
+fn swap_token_1_for_token_0_library_amount() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+
+    let amount_in = 100_000_000;
+    let amount_out = soroswap_library::get_weighted_amount_out(amount_in, 1_000_000_000, 16_000_000_000, 2000, 30).unwrap();
+
+    test.token_1.transfer(&test.user, &test.contract.address, &amount_in);
+    let res = test.contract.try_swap(&(amount_out + 2), &0, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInvariantNotMet)));
+
+    test.contract.swap(&amount_out, &0, &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.contract.get_reserves(), (16_000_000_000 - amount_out, 1_000_000_000 + amount_in));
+}
+
+#[test]
+fn swap_exact_output_library_amount() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(2000);
+    test.add_liquidity(1_000_000_000, 16_000_000_000);
+
+    let amount_out = 2_500_000_000;
+    let amount_in = soroswap_library::get_weighted_amount_in(amount_out, 1_000_000_000, 16_000_000_000, 2000, 30).unwrap();
+    assert!(soroswap_library::get_weighted_amount_out(amount_in, 1_000_000_000, 16_000_000_000, 2000, 30).unwrap() >= amount_out);
+
+    // Half of the required input is not enough
+    test.token_0.transfer(&test.user, &test.contract.address, &(amount_in / 2));
+    let res = test.contract.try_swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::SwapInvariantNotMet)));
+
+    test.token_0.transfer(&test.user, &test.contract.address, &(amount_in - amount_in / 2));
+    test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+    assert_eq!(test.contract.get_reserves(), (1_000_000_000 + amount_in, 16_000_000_000 - amount_out));
+}
+
+#[test]
+fn swap_keeps_invariant_growing() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+    let invariant_before = soroswap_library::get_weighted_invariant(16_000_000_000, 1_000_000_000, 8000).unwrap();
+
+    for zero_for_one in [true, false, true, false, true] {
+        let (reserve_0, reserve_1) = test.contract.get_reserves();
+        if zero_for_one {
+            let amount_out = soroswap_library::get_weighted_amount_out(500_000_000, reserve_0, reserve_1, 8000, 30).unwrap();
+            test.token_0.transfer(&test.user, &test.contract.address, &500_000_000);
+            test.contract.swap(&0, &amount_out, &test.user, &Bytes::new(&test.env));
+        } else {
+            let amount_out = soroswap_library::get_weighted_amount_out(50_000_000, reserve_1, reserve_0, 2000, 30).unwrap();
+            test.token_1.transfer(&test.user, &test.contract.address, &50_000_000);
+            test.contract.swap(&amount_out, &0, &test.user, &Bytes::new(&test.env));
+        }
+    }
+
+    let (reserve_0, reserve_1) = test.contract.get_reserves();
+    // The swap fees stay in the pool
+    assert!(soroswap_library::get_weighted_invariant(reserve_0, reserve_1, 8000).unwrap() > invariant_before);
+}
@@ -0,0 +1,51 @@
+use crate::test::SoroswapWeightedPairTest;
+use soroswap_pair_token::SoroswapPairTokenClient;
+use crate::error::SoroswapWeightedPairError;
+
+#[test]
+fn withdraw_not_yet_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::NotInitialized)));
+}
+
+#[test]
+fn withdraw_liquidity_not_initialized() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::WithdrawLiquidityNotInitialized)));
+}
+
+#[test]
+fn withdraw_no_shares_sent() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    test.add_liquidity(16_000_000_000, 1_000_000_000);
+    let res = test.contract.try_withdraw(&test.user);
+    assert_eq!(res, Err(Ok(SoroswapWeightedPairError::WithdrawInsufficientSentShares)));
+}
+
+#[test]
+fn withdraw_all_user_shares() {
+    let test = SoroswapWeightedPairTest::setup();
+    test.initialize(8000);
+    let liquidity = test.add_liquidity(16_000_000_000, 1_000_000_000);
+    let total_shares = test.contract.total_shares();
+    let balance_0_before = test.token_0.balance(&test.user);
+    let balance_1_before = test.token_1.balance(&test.user);
+
+    // The LP token functions are dispatched by the SoroswapPairToken contract of the same Wasm
+    let pair_token_client = SoroswapPairTokenClient::new(&test.env, &test.env.register_contract(&test.contract.address, crate::SoroswapPairToken {}));
+    pair_token_client.transfer(&test.user, &test.contract.address, &liquidity);
+    test.env.register_contract(&test.contract.address, crate::SoroswapWeightedPair {});
+    let (amount_0, amount_1) = test.contract.withdraw(&test.user);
+
+    assert_eq!(amount_0, 16_000_000_000 * liquidity / total_shares);
+    assert_eq!(amount_1, 1_000_000_000 * liquidity / total_shares);
+    assert_eq!(test.token_0.balance(&test.user), balance_0_before + amount_0);
+    assert_eq!(test.token_1.balance(&test.user), balance_1_before + amount_1);
+    assert_eq!(test.contract.total_shares(), 1000);
+    assert_eq!(test.contract.my_balance(&test.user), 0);
+    assert_eq!(test.contract.get_reserves(), (16_000_000_000 - amount_0, 1_000_000_000 - amount_1));
+}
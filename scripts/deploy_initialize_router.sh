@@ -76,6 +76,11 @@ FACTORY_ID=$(cat $FACTORY_FILE)
 
 FACTORY_ADDRESS="$(node /workspace/scripts/address_workaround.js $FACTORY_ID)"
 
+# Get the native asset (XLM) contract address, deploying it if it does not exist yet
+soroban lab token wrap $ARGS --asset native || echo "Native asset contract already deployed"
+NATIVE_ID="$(soroban lab token id $ARGS --asset native)"
+NATIVE_ADDRESS="$(node /workspace/scripts/address_workaround.js $NATIVE_ID)"
+
 # Initialize the SoroswapRouter contract
 echo "Initialize the SoroswapRouter contract"
 soroban contract invoke \
@@ -84,6 +89,7 @@ soroban contract invoke \
   -- \
   initialize \
   --factory "$FACTORY_ADDRESS" \
+  --native "$NATIVE_ADDRESS" \
 
 echo "--"
 echo "--"